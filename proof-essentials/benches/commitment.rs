@@ -0,0 +1,64 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use proof_essentials::utils::rand::sample_vector;
+use proof_essentials::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+
+use ark_ec::ProjectiveCurve;
+use ark_std::UniformRand;
+use rand::rngs::OsRng;
+
+// Pedersen commitments to vectors of growing length, one at a time and as the columns of a matrix
+fn bench_curve<C: ProjectiveCurve>(c: &mut Criterion, curve: &str) {
+    let mut rng = OsRng;
+
+    {
+        let mut group = c.benchmark_group(format!("PEDERSEN_COMMIT/{}", curve));
+        for len in [13, 52, 520] {
+            let commit_key = pedersen::PedersenCommitment::<C>::setup(&mut rng, len);
+            let values: Vec<C::ScalarField> = sample_vector(&mut rng, len);
+            let r = C::ScalarField::rand(&mut rng);
+
+            group.throughput(Throughput::Elements(len as u64));
+            group.bench_with_input(BenchmarkId::from_parameter(len), &values, |b, values| {
+                b.iter(|| {
+                    pedersen::PedersenCommitment::<C>::commit(&commit_key, values, r).unwrap()
+                })
+            });
+        }
+    }
+
+    {
+        let mut group = c.benchmark_group(format!("PEDERSEN_BATCH_COMMIT/{}", curve));
+        for (m, n) in [(4, 13), (13, 4), (20, 26)] {
+            let commit_key = pedersen::PedersenCommitment::<C>::setup(&mut rng, n);
+            let columns = (0..m)
+                .map(|_| sample_vector(&mut rng, n))
+                .collect::<Vec<Vec<C::ScalarField>>>();
+            let randomness: Vec<C::ScalarField> = sample_vector(&mut rng, m);
+
+            group.throughput(Throughput::Elements((m * n) as u64));
+            group.bench_with_input(
+                BenchmarkId::from_parameter(format!("{} * {}", m, n)),
+                &columns,
+                |b, columns| {
+                    b.iter(|| {
+                        pedersen::PedersenCommitment::<C>::batch_commit(
+                            &commit_key,
+                            columns,
+                            &randomness,
+                        )
+                        .unwrap()
+                    })
+                },
+            );
+        }
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_curve::<starknet_curve::Projective>(c, "starknet");
+    bench_curve::<ark_bls12_381::G1Projective>(c, "bls12-381");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
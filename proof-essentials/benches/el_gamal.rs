@@ -0,0 +1,62 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use proof_essentials::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+use proof_essentials::utils::rand::sample_vector;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Zero;
+use ark_std::UniformRand;
+use rand::rngs::OsRng;
+
+// Encryption, decryption and re-randomisation of single ciphertexts, and the aggregation of decks of growing size
+fn bench_curve<C: ProjectiveCurve>(c: &mut Criterion, curve: &str) {
+    let mut rng = OsRng;
+
+    let pp = el_gamal::ElGamal::<C>::setup(&mut rng).unwrap();
+    let (pk, sk) = el_gamal::ElGamal::<C>::keygen(&pp, &mut rng).unwrap();
+    let message = el_gamal::Plaintext::<C>::rand(&mut rng);
+    let r = C::ScalarField::rand(&mut rng);
+    let cipher = el_gamal::ElGamal::<C>::encrypt(&pp, &pk, &message, &r).unwrap();
+
+    {
+        let mut group = c.benchmark_group(format!("EL_GAMAL/{}", curve));
+        group.bench_function("encrypt", |b| {
+            b.iter(|| el_gamal::ElGamal::<C>::encrypt(&pp, &pk, &message, &r).unwrap())
+        });
+        group.bench_function("decrypt", |b| {
+            b.iter(|| el_gamal::ElGamal::<C>::decrypt(&pp, &sk, &cipher).unwrap())
+        });
+        group.bench_function("rerandomize", |b| {
+            b.iter(|| {
+                cipher
+                    + el_gamal::ElGamal::<C>::encrypt(&pp, &pk, &el_gamal::Plaintext::zero(), &r)
+                        .unwrap()
+            })
+        });
+    }
+
+    {
+        let mut group = c.benchmark_group(format!("EL_GAMAL_MULTI_SCALAR_MUL/{}", curve));
+        for number_of_ciphers in [52, 520, 5200] {
+            let ciphers: Vec<el_gamal::Ciphertext<C>> = sample_vector(&mut rng, number_of_ciphers);
+            let scalars: Vec<C::ScalarField> = sample_vector(&mut rng, number_of_ciphers);
+
+            group.throughput(Throughput::Elements(number_of_ciphers as u64));
+            group.bench_with_input(
+                BenchmarkId::from_parameter(number_of_ciphers),
+                &(scalars, ciphers),
+                |b, (scalars, ciphers)| {
+                    b.iter(|| el_gamal::ElGamal::<C>::multi_scalar_mul(scalars, ciphers).unwrap())
+                },
+            );
+        }
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_curve::<starknet_curve::Projective>(c, "starknet");
+    bench_curve::<ark_bls12_381::G1Projective>(c, "bls12-381");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
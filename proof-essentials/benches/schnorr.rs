@@ -0,0 +1,69 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use proof_essentials::zkp::{proofs::schnorr_identification, ArgumentOfKnowledge};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_marlin::rng::FiatShamirRng;
+use ark_std::UniformRand;
+use blake2::Blake2s;
+use rand::rngs::OsRng;
+
+type FS = FiatShamirRng<Blake2s>;
+
+const DOMAIN: &[u8] = b"Schnorr Benchmark";
+
+// Prove and verify the knowledge of a secret key on each curve
+fn bench_curve<C: ProjectiveCurve>(c: &mut Criterion, curve: &str) {
+    let mut rng = OsRng;
+
+    let crs = C::rand(&mut rng).into_affine();
+    let sk = C::ScalarField::rand(&mut rng);
+    let pk = crs.mul(sk).into_affine();
+
+    let mut group = c.benchmark_group(format!("SCHNORR/{}", curve));
+
+    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+    group.bench_function("prove", |b| {
+        b.iter(|| {
+            schnorr_identification::SchnorrIdentification::<C>::prove(
+                &mut rng,
+                &crs,
+                &pk,
+                &sk,
+                DOMAIN,
+                &mut fs_rng,
+            )
+            .unwrap()
+        })
+    });
+
+    let proof = schnorr_identification::SchnorrIdentification::<C>::prove(
+        &mut rng,
+        &crs,
+        &pk,
+        &sk,
+        DOMAIN,
+        &mut FS::from_seed(b"Initialised with some input"),
+    )
+    .unwrap();
+    group.bench_function("verify", |b| {
+        b.iter(|| {
+            schnorr_identification::SchnorrIdentification::<C>::verify(
+                &crs,
+                &pk,
+                &proof,
+                DOMAIN,
+                &mut FS::from_seed(b"Initialised with some input"),
+            )
+            .unwrap()
+        })
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_curve::<starknet_curve::Projective>(c, "starknet");
+    bench_curve::<ark_bls12_381::G1Projective>(c, "bls12-381");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
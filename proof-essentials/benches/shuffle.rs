@@ -1,52 +1,44 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 
 use proof_essentials::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
 use proof_essentials::utils::rand::sample_vector;
 use proof_essentials::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
 use proof_essentials::zkp::{arguments::shuffle, ArgumentOfKnowledge};
 
+use ark_ec::ProjectiveCurve;
 use ark_ff::Zero;
 use ark_marlin::rng::FiatShamirRng;
 use ark_std::UniformRand;
 use blake2::Blake2s;
 use proof_essentials::utils::permutation::Permutation;
 use rand::rngs::OsRng;
-use starknet_curve;
 use std::iter::Iterator;
 
-// Choose ellitptic curve setting
-type Curve = starknet_curve::Projective;
-type Scalar = starknet_curve::Fr;
-
-// Type aliases for concrete instances using the chosen EC.
-type Enc = el_gamal::ElGamal<Curve>;
-type Comm = pedersen::PedersenCommitment<Curve>;
-type Plaintext = el_gamal::Plaintext<Curve>;
-type Generator = el_gamal::Generator<Curve>;
-type Ciphertext = el_gamal::Ciphertext<Curve>;
-type Witness<'a> = shuffle::Witness<'a, Scalar>;
-type Statement<'a> = shuffle::Statement<'a, Scalar, Enc>;
-type ShuffleArgument<'a> = shuffle::ShuffleArgument<'a, Scalar, Enc, Comm>;
-type Parameters<'a> = shuffle::Parameters<'a, Scalar, Enc, Comm>;
+// Type aliases for the instances over each curve
+type Enc<C> = el_gamal::ElGamal<C>;
+type Comm<C> = pedersen::PedersenCommitment<C>;
 
 type FS = FiatShamirRng<Blake2s>;
 
-fn criterion_benchmark(c: &mut Criterion) {
+const DOMAIN: &[u8] = b"Shuffle Benchmark";
+
+// Prove and verify shuffles of each split (m, n) of the deck sizes, with m = number_of_chunks, n = len_of_chunk
+fn bench_curve<C: ProjectiveCurve>(c: &mut Criterion, curve: &str, splits: &[(usize, usize)]) {
     let mut rng = OsRng;
 
-    let encrypt_parameters = Enc::setup(&mut rng).unwrap();
-    let (pk, _) = Enc::keygen(&encrypt_parameters, &mut rng).unwrap();
+    let encrypt_parameters = Enc::<C>::setup(&mut rng).unwrap();
+    let (pk, _) = Enc::<C>::keygen(&encrypt_parameters, &mut rng).unwrap();
 
-    let generator = Generator::rand(&mut rng);
+    let generator = el_gamal::Generator::<C>::rand(&mut rng);
 
     let prepare_proof_parameters = |m, n| {
         let mut rng = OsRng;
         let number_of_ciphers = n * m;
 
-        let commit_key = Comm::setup(&mut rng, n);
+        let commit_key = Comm::<C>::setup(&mut rng, n);
 
-        let ciphers: Vec<Ciphertext> = sample_vector(&mut rng, number_of_ciphers);
-        let masking_factors: Vec<Scalar> = sample_vector(&mut rng, number_of_ciphers);
+        let ciphers: Vec<el_gamal::Ciphertext<C>> = sample_vector(&mut rng, number_of_ciphers);
+        let masking_factors: Vec<C::ScalarField> = sample_vector(&mut rng, number_of_ciphers);
 
         let permutation = Permutation::new(&mut rng, number_of_ciphers);
 
@@ -56,9 +48,10 @@ fn criterion_benchmark(c: &mut Criterion) {
             .iter()
             .zip(masking_factors.iter())
             .map(|(&cipher, masking_factor)| {
-                let zero_cipher = Plaintext::zero();
+                let zero_cipher = el_gamal::Plaintext::<C>::zero();
                 let masking_cipher =
-                    Enc::encrypt(&encrypt_parameters, &pk, &zero_cipher, masking_factor).unwrap();
+                    Enc::<C>::encrypt(&encrypt_parameters, &pk, &zero_cipher, masking_factor)
+                        .unwrap();
 
                 cipher + masking_cipher
             })
@@ -73,29 +66,32 @@ fn criterion_benchmark(c: &mut Criterion) {
         )
     };
 
-    // test for pairs (m, n) such that m = number_of_chunks, n = len_of_chunk and m*n is the number of ciphertexts
-    // since proof runs in O(m^2) we expect (2, 26) to be the fastest one
-    let num_of_chunks_x_chunk_length = vec![(4, 13), (13, 4), (2, 26), (26, 2)];
-
     {
-        let mut group = c.benchmark_group("PROVING");
+        let mut group = c.benchmark_group(format!("PROVING/{}", curve));
         group.sample_size(10);
-        for (m, n) in num_of_chunks_x_chunk_length.clone() {
+        for &(m, n) in splits {
             let (commit_key, ciphers, masking_factors, permutation, shuffled_ciphers) =
                 prepare_proof_parameters(m, n);
-            let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
-            let statement = Statement::new(&ciphers, &shuffled_ciphers, m, n);
-            let witness = Witness::new(&permutation, &masking_factors);
+            let parameters = shuffle::Parameters::<_, Enc<C>, Comm<C>>::new(
+                &encrypt_parameters,
+                &pk,
+                &commit_key,
+                &generator,
+            );
+            let statement = shuffle::Statement::<_, Enc<C>>::new(&ciphers, &shuffled_ciphers, m, n);
+            let witness = shuffle::Witness::new(&permutation, &masking_factors);
             let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            group.throughput(Throughput::Elements((m * n) as u64));
             let bench_id =
                 BenchmarkId::new("number_of_ciphers:", format!("({} * {} = {})", m, n, m * n));
             group.bench_function(bench_id, |b| {
                 b.iter(|| {
-                    ShuffleArgument::prove(
+                    shuffle::ShuffleArgument::<_, Enc<C>, Comm<C>>::prove(
                         &mut rng,
                         &parameters,
                         &statement,
                         &witness,
+                        DOMAIN,
                         &mut fs_rng,
                     )
                     .unwrap();
@@ -105,32 +101,68 @@ fn criterion_benchmark(c: &mut Criterion) {
     }
 
     {
-        let mut group = c.benchmark_group("VERIFYING");
+        let mut group = c.benchmark_group(format!("VERIFYING/{}", curve));
         group.sample_size(10);
-        for (m, n) in num_of_chunks_x_chunk_length.clone() {
+        for &(m, n) in splits {
             let (commit_key, ciphers, masking_factors, permutation, shuffled_ciphers) =
                 prepare_proof_parameters(m, n);
-            let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
-            let statement = Statement::new(&ciphers, &shuffled_ciphers, m, n);
-            let witness = Witness::new(&permutation, &masking_factors);
+            let parameters = shuffle::Parameters::<_, Enc<C>, Comm<C>>::new(
+                &encrypt_parameters,
+                &pk,
+                &commit_key,
+                &generator,
+            );
+            let statement = shuffle::Statement::<_, Enc<C>>::new(&ciphers, &shuffled_ciphers, m, n);
+            let witness = shuffle::Witness::new(&permutation, &masking_factors);
             let mut fs_rng = FS::from_seed(b"Initialised with some input");
-            let proof =
-                ShuffleArgument::prove(&mut rng, &parameters, &statement, &witness, &mut fs_rng)
-                    .unwrap();
+            let proof = shuffle::ShuffleArgument::<_, Enc<C>, Comm<C>>::prove(
+                &mut rng,
+                &parameters,
+                &statement,
+                &witness,
+                DOMAIN,
+                &mut fs_rng,
+            )
+            .unwrap();
             let mut fs_rng = FS::from_seed(b"Initialised with some input");
             assert_eq!(
                 Ok(()),
-                ShuffleArgument::verify(&parameters, &statement, &proof, &mut fs_rng)
+                shuffle::ShuffleArgument::<_, Enc<C>, Comm<C>>::verify(
+                    &parameters,
+                    &statement,
+                    &proof,
+                    DOMAIN,
+                    &mut fs_rng
+                )
             );
+            group.throughput(Throughput::Elements((m * n) as u64));
             let bench_id =
                 BenchmarkId::new("number_of_ciphers:", format!("({} * {} = {})", m, n, m * n));
             let mut fs_rng = FS::from_seed(b"Initialised with some input");
             group.bench_function(bench_id, |b| {
-                b.iter(|| ShuffleArgument::verify(&parameters, &statement, &proof, &mut fs_rng))
+                b.iter(|| {
+                    shuffle::ShuffleArgument::<_, Enc<C>, Comm<C>>::verify(
+                        &parameters,
+                        &statement,
+                        &proof,
+                        DOMAIN,
+                        &mut fs_rng,
+                    )
+                })
             });
         }
     }
 }
 
+fn criterion_benchmark(c: &mut Criterion) {
+    // Since proof runs in O(m^2) we expect (2, 26) to be the fastest split of a deck of 52
+    bench_curve::<starknet_curve::Projective>(
+        c,
+        "starknet",
+        &[(4, 13), (13, 4), (2, 26), (26, 2), (8, 13), (16, 13)],
+    );
+    bench_curve::<ark_bls12_381::G1Projective>(c, "bls12-381", &[(4, 13), (2, 26), (8, 13)]);
+}
+
 criterion_group!(benches, criterion_benchmark);
 criterion_main!(benches);
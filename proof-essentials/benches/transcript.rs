@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use proof_essentials::homomorphic_encryption::el_gamal;
+use proof_essentials::utils::rand::sample_vector;
+use proof_essentials::zkp::transcript::AbsorbSerializable;
+
+use ark_ff::to_bytes;
+use ark_marlin::rng::FiatShamirRng;
+use blake2::Blake2s;
+use rand::rngs::OsRng;
+
+type Curve = starknet_curve::Projective;
+type Ciphertext = el_gamal::Ciphertext<Curve>;
+
+type FS = FiatShamirRng<Blake2s>;
+
+// Absorb the ciphertexts of a shuffle statement, through `to_bytes!` or streamed into the transcript
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = OsRng;
+
+    let mut group = c.benchmark_group("ABSORB");
+    for number_of_ciphers in [52, 520, 5200] {
+        let ciphers: Vec<Ciphertext> = sample_vector(&mut rng, number_of_ciphers);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+
+        group.bench_with_input(
+            BenchmarkId::new("to_bytes", number_of_ciphers),
+            &ciphers,
+            |b, ciphers| b.iter(|| fs_rng.absorb(&to_bytes![ciphers].unwrap())),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("absorb_serializable", number_of_ciphers),
+            &ciphers,
+            |b, ciphers| b.iter(|| fs_rng.absorb_serializable(ciphers)),
+        );
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
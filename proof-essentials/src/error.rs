@@ -6,6 +6,9 @@ pub enum CryptoError {
     #[error("Failed to verify {0} proof")]
     ProofVerificationError(String),
 
+    #[error("Failed to verify {0} signature")]
+    SignatureVerificationError(String),
+
     #[error("Failed to output a {0} commitment: values {1} > bases {2}")]
     CommitmentLengthError(String, usize, usize),
 
@@ -21,6 +24,9 @@ pub enum CryptoError {
     #[error("Cannot cast vector of size {0} to matrix of {1} by {2}")]
     VectorCastingError(usize, usize, usize),
 
+    #[error("Ciphertext Vector error: left = {0} - right = {1}")]
+    CiphertextVectorLengthError(usize, usize),
+
     #[error("Diagonals Error: left = {0} - right = {1}")]
     DiagonalLengthError(usize, usize),
 
@@ -30,6 +36,174 @@ pub enum CryptoError {
     #[error("InvalidShuffleStatement")]
     InvalidShuffleStatement,
 
+    #[error("InvalidOrCompositionStatement")]
+    InvalidOrCompositionStatement,
+
+    #[error("InvalidAndCompositionStatement")]
+    InvalidAndCompositionStatement,
+
+    #[error("InvalidLinearRelation")]
+    InvalidLinearRelation,
+
+    #[error("InvalidRangeParameters")]
+    InvalidRangeParameters,
+
+    #[error("InvalidRangeStatement")]
+    InvalidRangeStatement,
+
+    #[error("InvalidOneOutOfManyStatement")]
+    InvalidOneOutOfManyStatement,
+
+    #[error("InvalidRing")]
+    InvalidRing,
+
+    #[error("InvalidSeed")]
+    InvalidSeed,
+
+    #[error("InvalidSecretKey")]
+    InvalidSecretKey,
+
+    #[error("InvalidSigningSession")]
+    InvalidSigningSession,
+
+    #[error("InvalidThresholdParameters")]
+    InvalidThresholdParameters,
+
+    #[error("InvalidKeyShare")]
+    InvalidKeyShare,
+
+    #[error("InvalidAdaptorSignature")]
+    InvalidAdaptorSignature,
+
+    #[error("InvalidInnerProductStatement")]
+    InvalidInnerProductStatement,
+
+    #[error("InvalidCompressionStatement")]
+    InvalidCompressionStatement,
+
+    #[error("InvalidBatchStatement")]
+    InvalidBatchStatement,
+
+    #[error("InvalidMerkleTree")]
+    InvalidMerkleTree,
+
+    #[error("InvalidOpening")]
+    InvalidOpening,
+
+    #[error("InvalidKzgParameters")]
+    InvalidKzgParameters,
+
+    #[error("InvalidCommitmentSumStatement")]
+    InvalidCommitmentSumStatement,
+
+    #[error("InvalidCiphertextStatement")]
+    InvalidCiphertextStatement,
+
+    #[error("InvalidShare")]
+    InvalidShare,
+
+    #[error("InsufficientValidShares")]
+    InsufficientValidShares,
+
+    #[error("InvalidDecryptionResponse")]
+    InvalidDecryptionResponse,
+
+    #[error("InvalidPaillierParameters")]
+    InvalidPaillierParameters,
+
+    #[error("InvalidPaillierRandomness")]
+    InvalidPaillierRandomness,
+
+    #[error("InvalidPaillierCiphertext")]
+    InvalidPaillierCiphertext,
+
+    #[error("InvalidPaillierPlaintext")]
+    InvalidPaillierPlaintext,
+
+    #[error("InvalidDkgRound")]
+    InvalidDkgRound,
+
+    #[error("InvalidDkgMessage")]
+    InvalidDkgMessage,
+
+    #[error("NoQualifiedDealers")]
+    NoQualifiedDealers,
+
+    #[error("InvalidVerifiableEncryption")]
+    InvalidVerifiableEncryption,
+
+    #[error("InvalidKeyEncoding")]
+    InvalidKeyEncoding,
+
+    #[error("InvalidKeyRegistration")]
+    InvalidKeyRegistration,
+
+    #[error("InvalidCrossGroupParameters")]
+    InvalidCrossGroupParameters,
+
+    #[error("InvalidCrossGroupWitness")]
+    InvalidCrossGroupWitness,
+
+    #[error("TransformSearchExhausted")]
+    TransformSearchExhausted,
+
+    #[error("InvalidProtocolRound")]
+    InvalidProtocolRound,
+
+    #[error("InvalidRepetitionParameters")]
+    InvalidRepetitionParameters,
+
+    #[error("InvalidRepresentationLength")]
+    InvalidRepresentationLength,
+
+    #[error("InvalidPublicKey")]
+    InvalidPublicKey,
+
+    #[error("InvalidCiphertext")]
+    InvalidCiphertext,
+
+    #[error("InvalidHashedCiphertext")]
+    InvalidHashedCiphertext,
+
+    #[error("InvalidHybridCiphertext")]
+    InvalidHybridCiphertext,
+
+    #[error("InvalidDiscreteLogTable")]
+    InvalidDiscreteLogTable,
+
+    #[error("DiscreteLogNotFound")]
+    DiscreteLogNotFound,
+
+    #[error("InvalidMultiRecipientCiphertext")]
+    InvalidMultiRecipientCiphertext,
+
+    #[error("Cannot encode {0} bytes into a point: at most {1} bytes fit")]
+    EncodingLengthError(usize, usize),
+
+    #[error("InvalidEncoding")]
+    InvalidEncoding,
+
+    #[error("Unsupported field: elements take {0} bytes instead of {1}")]
+    FieldSizeError(usize, usize),
+
+    #[error("Cannot hash {0} bytes: at most {1} bytes are supported")]
+    HashInputLengthError(usize, usize),
+
+    #[error("Cannot expand a message to {0} bytes: at most {1} bytes are supported")]
+    ExpansionLengthError(usize, usize),
+
+    #[error("InvalidPoseidonParameters")]
+    InvalidPoseidonParameters,
+
+    #[error("HashToCurveError")]
+    HashToCurveError,
+
+    #[error("ZeroInversion")]
+    ZeroInversion,
+
+    #[error("InvalidSerialization")]
+    InvalidSerialization,
+
     #[error("IoError: {0}")]
     IoError(String),
 }
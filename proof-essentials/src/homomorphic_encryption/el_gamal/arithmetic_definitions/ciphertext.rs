@@ -1,4 +1,6 @@
-use super::super::Ciphertext;
+use super::super::{Ciphertext, ElGamal};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_std::{UniformRand, Zero};
 use rand::Rng;
@@ -12,6 +14,25 @@ impl<C: ProjectiveCurve> std::ops::Add<Ciphertext<C>> for Ciphertext<C> {
     }
 }
 
+impl<C: ProjectiveCurve> std::ops::Sub<Ciphertext<C>> for Ciphertext<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(
+            (self.0.into_projective() - rhs.0.into_projective()).into_affine(),
+            (self.1.into_projective() - rhs.1.into_projective()).into_affine(),
+        )
+    }
+}
+
+impl<C: ProjectiveCurve> std::ops::Neg for Ciphertext<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
 impl<C: ProjectiveCurve> Mul<C::ScalarField> for Ciphertext<C> {
     type Output = Self;
     fn mul(self, x: C::ScalarField) -> Self::Output {
@@ -25,6 +46,17 @@ impl<C: ProjectiveCurve> std::iter::Sum for Ciphertext<C> {
     }
 }
 
+impl<C: ProjectiveCurve> Ciphertext<C> {
+    /// Compute $\sum_i x_i c_i$, an encryption of $\sum_i x_i m_i$ when $c_i$ encrypts $m_i$, with
+    /// [`ElGamal::multi_scalar_mul`](HomomorphicEncryptionScheme::multi_scalar_mul).
+    pub fn linear_combination(
+        ciphertexts: &[Self],
+        scalars: &[C::ScalarField],
+    ) -> Result<Self, CryptoError> {
+        ElGamal::<C>::multi_scalar_mul(scalars, ciphertexts)
+    }
+}
+
 impl<C: ProjectiveCurve> Zero for Ciphertext<C> {
     fn zero() -> Self {
         Self(C::Affine::zero(), C::Affine::zero())
@@ -1,7 +1,8 @@
 use super::super::Plaintext;
+use crate::error::CryptoError;
 
-use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::Zero;
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
 use ark_std::{rand::Rng, UniformRand};
 use std::ops::Mul;
 
@@ -20,6 +21,50 @@ impl<C: ProjectiveCurve> std::ops::Add<Plaintext<C>> for Plaintext<C> {
     }
 }
 
+impl<C: ProjectiveCurve> std::ops::Sub<Plaintext<C>> for Plaintext<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self((self.0.into_projective() - rhs.0.into_projective()).into_affine())
+    }
+}
+
+impl<C: ProjectiveCurve> std::ops::Neg for Plaintext<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<C: ProjectiveCurve> std::iter::Sum for Plaintext<C> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<C: ProjectiveCurve> Plaintext<C> {
+    /// Compute $\sum_i x_i m_i$.
+    pub fn linear_combination(
+        plaintexts: &[Self],
+        scalars: &[C::ScalarField],
+    ) -> Result<Self, CryptoError> {
+        if plaintexts.len() != scalars.len() {
+            return Err(CryptoError::DotProductLengthError(
+                plaintexts.len(),
+                scalars.len(),
+            ));
+        }
+
+        let scalars = scalars.iter().map(|x| x.into_repr()).collect::<Vec<_>>();
+        let points = plaintexts.iter().map(|m| m.0).collect::<Vec<_>>();
+
+        Ok(Self(
+            VariableBaseMSM::multi_scalar_mul(&points, &scalars).into_affine(),
+        ))
+    }
+}
+
 impl<C: ProjectiveCurve> UniformRand for Plaintext<C> {
     fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
         Self(C::rand(rng).into_affine())
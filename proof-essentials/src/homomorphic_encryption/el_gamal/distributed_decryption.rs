@@ -0,0 +1,169 @@
+use super::threshold::{DecryptionShare, KeyShare, Parameters, ThresholdElGamal};
+use super::{Ciphertext, Plaintext};
+use crate::error::CryptoError;
+
+use ark_ec::ProjectiveCurve;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::rand::Rng;
+use digest::Digest;
+
+/// Interactive driver for [`ThresholdElGamal`] decryption between a coordinator and the share holders.
+///
+/// The exchange takes two messages:
+/// 1. the coordinator sends a [`DecryptionRequest`] with the ciphertexts to decrypt to the parties;
+/// 2. every party answers with a [`DecryptionResponse`] holding its decryption share of each ciphertext, each with a
+///    Chaum-Pedersen proof against the verification key of the party.
+///
+/// The coordinator checks every response as it arrives and, once `t` parties answered correctly, combines their
+/// shares into the plaintexts. Both messages can be serialized to be sent over the network.
+pub struct Coordinator<C: ProjectiveCurve> {
+    pp: Parameters<C>,
+    verification_keys: Vec<C::Affine>,
+    ciphertexts: Vec<Ciphertext<C>>,
+    responses: Vec<DecryptionResponse<C>>,
+}
+
+/// A share holder taking part in distributed decryptions.
+pub struct Party<C: ProjectiveCurve> {
+    pp: Parameters<C>,
+    key_share: KeyShare<C>,
+}
+
+/// First message, from the coordinator to the parties: the ciphertexts to decrypt.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DecryptionRequest<C: ProjectiveCurve> {
+    pub ciphertexts: Vec<Ciphertext<C>>,
+}
+
+/// Second message, from party `index` to the coordinator: one decryption share per requested ciphertext, in the
+/// order of the request.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DecryptionResponse<C: ProjectiveCurve> {
+    pub index: u64,
+    pub shares: Vec<DecryptionShare<C>>,
+}
+
+impl<C: ProjectiveCurve> Party<C> {
+    pub fn new(pp: &Parameters<C>, key_share: &KeyShare<C>) -> Self {
+        Self {
+            pp: *pp,
+            key_share: *key_share,
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.key_share.index()
+    }
+
+    /// Compute and prove the decryption shares of the requested ciphertexts.
+    pub fn respond<R: Rng, D: Digest>(
+        &self,
+        rng: &mut R,
+        request: &DecryptionRequest<C>,
+    ) -> Result<DecryptionResponse<C>, CryptoError> {
+        let shares = request
+            .ciphertexts
+            .iter()
+            .map(|ciphertext| {
+                ThresholdElGamal::decryption_share::<R, D>(
+                    rng,
+                    &self.pp,
+                    &self.key_share,
+                    ciphertext,
+                )
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(DecryptionResponse {
+            index: self.index(),
+            shares,
+        })
+    }
+}
+
+impl<C: ProjectiveCurve> Coordinator<C> {
+    /// Start the decryption of `ciphertexts` by parties with the given verification keys, the key of party `i` being
+    /// at position `i - 1`.
+    pub fn new(
+        pp: &Parameters<C>,
+        verification_keys: &[C::Affine],
+        ciphertexts: &[Ciphertext<C>],
+    ) -> Result<Self, CryptoError> {
+        if verification_keys.len() != pp.participants {
+            return Err(CryptoError::InvalidThresholdParameters);
+        }
+
+        Ok(Self {
+            pp: *pp,
+            verification_keys: verification_keys.to_vec(),
+            ciphertexts: ciphertexts.to_vec(),
+            responses: Vec::with_capacity(pp.threshold),
+        })
+    }
+
+    /// The request to send to the parties.
+    pub fn request(&self) -> DecryptionRequest<C> {
+        DecryptionRequest {
+            ciphertexts: self.ciphertexts.clone(),
+        }
+    }
+
+    /// Check the response of a party and keep it if every share is valid. Responses from unknown or already
+    /// answered parties, or that do not match the request, are rejected.
+    pub fn receive<D: Digest>(
+        &mut self,
+        response: DecryptionResponse<C>,
+    ) -> Result<(), CryptoError> {
+        let index = response.index;
+        if index == 0
+            || index as usize > self.pp.participants
+            || self.responses.iter().any(|r| r.index == index)
+            || response.shares.len() != self.ciphertexts.len()
+            || response.shares.iter().any(|share| share.index != index)
+        {
+            return Err(CryptoError::InvalidDecryptionResponse);
+        }
+
+        let verification_key = &self.verification_keys[index as usize - 1];
+        for (ciphertext, share) in self.ciphertexts.iter().zip(response.shares.iter()) {
+            ThresholdElGamal::verify_decryption_share::<D>(
+                &self.pp,
+                verification_key,
+                ciphertext,
+                share,
+            )?;
+        }
+
+        self.responses.push(response);
+
+        Ok(())
+    }
+
+    /// Whether enough valid responses were received to decrypt.
+    pub fn is_complete(&self) -> bool {
+        self.responses.len() >= self.pp.threshold
+    }
+
+    /// Combine the shares of the first `t` valid responses into the plaintexts, in the order of the request.
+    pub fn finalize(&self) -> Result<Vec<Plaintext<C>>, CryptoError> {
+        if !self.is_complete() {
+            return Err(CryptoError::InsufficientValidShares);
+        }
+
+        let responses = &self.responses[..self.pp.threshold];
+
+        self.ciphertexts
+            .iter()
+            .enumerate()
+            .map(|(i, ciphertext)| {
+                let shares = responses
+                    .iter()
+                    .map(|response| &response.shares[i])
+                    .collect::<Vec<_>>();
+
+                ThresholdElGamal::interpolate(ciphertext, &shares)
+            })
+            .collect()
+    }
+}
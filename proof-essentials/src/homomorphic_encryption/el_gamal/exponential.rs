@@ -0,0 +1,110 @@
+use super::{Ciphertext, ElGamal, Parameters, Plaintext, PublicKey, SecretKey};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_std::marker::PhantomData;
+use std::collections::HashMap;
+
+/// Exponential ElGamal: a small integer $v$ is encrypted as the plaintext $vG$, so that adding ciphertexts adds the
+/// integers. Decryption recovers $vG$ and then $v$ with a baby-step giant-step search in a [`DiscreteLogTable`],
+/// which is only practical when $v$ is known to lie in a small range, such as vote counts or pot sizes.
+pub struct ExponentialElGamal<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// Precomputed baby steps $\{jG : 0 \le j < m\}$ for finding discrete logarithms of base $G$ in $[0, bound)$ with at
+/// most $\lceil bound / m \rceil$ giant steps. Larger tables trade memory for faster decoding; a table can be reused
+/// for any number of decryptions.
+pub struct DiscreteLogTable<C: ProjectiveCurve> {
+    bound: u64,
+    baby_steps: HashMap<C::Affine, u64>,
+    giant_step: C,
+    giant_steps: u64,
+}
+
+impl<C: ProjectiveCurve> DiscreteLogTable<C> {
+    /// Table for logarithms in `[0, bound)` with about $\sqrt{bound}$ baby steps.
+    pub fn new(generator: &C::Affine, bound: u64) -> Result<Self, CryptoError> {
+        let baby_steps = 1u64 << (64 - bound.saturating_sub(1).leading_zeros()).div_ceil(2);
+
+        Self::with_baby_steps(generator, bound, baby_steps)
+    }
+
+    /// Table for logarithms in `[0, bound)` with exactly `baby_steps` precomputed points.
+    pub fn with_baby_steps(
+        generator: &C::Affine,
+        bound: u64,
+        baby_steps: u64,
+    ) -> Result<Self, CryptoError> {
+        if bound == 0 || baby_steps == 0 {
+            return Err(CryptoError::InvalidDiscreteLogTable);
+        }
+
+        let mut table = HashMap::with_capacity(baby_steps as usize);
+        let mut point = C::zero();
+        for j in 0..baby_steps {
+            table.insert(point.into_affine(), j);
+            point.add_assign_mixed(generator);
+        }
+
+        Ok(Self {
+            bound,
+            baby_steps: table,
+            giant_step: -point,
+            giant_steps: bound.div_ceil(baby_steps),
+        })
+    }
+
+    pub fn bound(&self) -> u64 {
+        self.bound
+    }
+
+    /// Find $v \in [0, bound)$ such that $point = vG$.
+    pub fn solve(&self, point: &C::Affine) -> Option<u64> {
+        let baby_steps = self.baby_steps.len() as u64;
+
+        let mut point = point.into_projective();
+        (0..self.giant_steps)
+            .find_map(|i| {
+                let found = self
+                    .baby_steps
+                    .get(&point.into_affine())
+                    .map(|j| i * baby_steps + j);
+                point += self.giant_step;
+                found
+            })
+            .filter(|value| *value < self.bound)
+    }
+}
+
+impl<C: ProjectiveCurve> ExponentialElGamal<C> {
+    /// Plaintext $vG$ encoding the integer `value`.
+    pub fn encode(pp: &Parameters<C>, value: u64) -> Plaintext<C> {
+        Plaintext(pp.generator.mul(C::ScalarField::from(value)).into_affine())
+    }
+
+    pub fn encrypt(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        value: u64,
+        r: &C::ScalarField,
+    ) -> Result<Ciphertext<C>, CryptoError> {
+        ElGamal::<C>::encrypt(pp, pk, &Self::encode(pp, value), r)
+    }
+
+    /// Decrypt `ciphertext` and decode the integer it encrypts with `table`, which must be built for the generator
+    /// of `pp`. Fails if the integer is outside of the range of the table.
+    pub fn decrypt(
+        pp: &Parameters<C>,
+        sk: &SecretKey<C>,
+        ciphertext: &Ciphertext<C>,
+        table: &DiscreteLogTable<C>,
+    ) -> Result<u64, CryptoError> {
+        let plaintext = ElGamal::<C>::decrypt(pp, sk, ciphertext)?;
+
+        table
+            .solve(&plaintext.0)
+            .ok_or(CryptoError::DiscreteLogNotFound)
+    }
+}
@@ -0,0 +1,94 @@
+use super::hybrid::Kem;
+use super::{Parameters, PublicKey, SecretKey};
+use crate::error::CryptoError;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use chacha20poly1305::aead::{Aead, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+/// Hashed ElGamal (ECIES-style KEM/DEM) for arbitrary byte strings, using the same keys as [`super::ElGamal`].
+///
+/// The sender picks $r$, publishes $R = rG$ and derives a symmetric key with HKDF-SHA256 from the shared point
+/// $r \cdot pk$, salted with $R$ and $pk$. The message is then encrypted with ChaCha20-Poly1305 under that key,
+/// authenticating optional associated data. Each key is used for a single message, so a fixed nonce is used.
+///
+/// Unlike [`super::ElGamal`], ciphertexts are not homomorphic.
+pub struct HashedElGamal<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct HashedCiphertext<C: ProjectiveCurve> {
+    pub ephemeral_key: C::Affine,
+    pub payload: Vec<u8>,
+}
+
+impl<C: ProjectiveCurve> HashedElGamal<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Hashed ElGamal";
+
+    /// Encrypt `message` for the owner of `pk`, binding `associated_data` to the ciphertext.
+    pub fn encrypt<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        message: &[u8],
+        associated_data: &[u8],
+    ) -> Result<HashedCiphertext<C>, CryptoError> {
+        let r = C::ScalarField::rand(rng);
+        let ephemeral_key = pp.generator.mul(r.into_repr()).into_affine();
+        let shared_point = pk.mul(r.into_repr()).into_affine();
+
+        let cipher = Self::cipher(&ephemeral_key, pk, &shared_point)?;
+        let payload = cipher
+            .encrypt(
+                &Nonce::default(),
+                Payload {
+                    msg: message,
+                    aad: associated_data,
+                },
+            )
+            .map_err(|_| CryptoError::InvalidHashedCiphertext)?;
+
+        Ok(HashedCiphertext {
+            ephemeral_key,
+            payload,
+        })
+    }
+
+    /// Decrypt `ciphertext` with the secret key matching `pk`. Fails if the ciphertext or the associated data were
+    /// tampered with.
+    pub fn decrypt(
+        pk: &PublicKey<C>,
+        sk: &SecretKey<C>,
+        ciphertext: &HashedCiphertext<C>,
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, CryptoError> {
+        let shared_point = ciphertext.ephemeral_key.mul(sk.into_repr()).into_affine();
+
+        let cipher = Self::cipher(&ciphertext.ephemeral_key, pk, &shared_point)?;
+        cipher
+            .decrypt(
+                &Nonce::default(),
+                Payload {
+                    msg: &ciphertext.payload,
+                    aad: associated_data,
+                },
+            )
+            .map_err(|_| CryptoError::InvalidHashedCiphertext)
+    }
+
+    fn cipher(
+        ephemeral_key: &C::Affine,
+        pk: &PublicKey<C>,
+        shared_point: &C::Affine,
+    ) -> Result<ChaCha20Poly1305, CryptoError> {
+        Kem::<C>::derive_key(ephemeral_key, pk, shared_point, Self::PROTOCOL_NAME)
+            .map(|key| key.cipher())
+    }
+}
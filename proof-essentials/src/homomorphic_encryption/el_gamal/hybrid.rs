@@ -0,0 +1,191 @@
+use super::{Parameters, PublicKey, SecretKey, Validate};
+use crate::error::CryptoError;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use chacha20poly1305::aead::{Aead, NewAead, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+/// Key encapsulation with the keys of [`super::ElGamal`]: the sender picks $r$, publishes the encapsulation
+/// $R = rG$ and derives a symmetric key with HKDF-SHA256 from the shared point $r \cdot pk = sk \cdot R$, salted with
+/// $R$ and $pk$.
+pub struct Kem<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// Ephemeral public key $R = rG$ from which the recipient recovers the shared key.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Encapsulation<C: ProjectiveCurve>(pub C::Affine);
+
+/// A 256-bit symmetric key.
+#[derive(Clone, PartialEq)]
+pub struct SharedKey(pub(crate) [u8; 32]);
+
+impl<C: ProjectiveCurve> Kem<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"ElGamal KEM";
+
+    /// Sample a fresh shared key for the owner of `pk` with its encapsulation.
+    pub fn encapsulate<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+    ) -> Result<(SharedKey, Encapsulation<C>), CryptoError> {
+        let r = C::ScalarField::rand(rng);
+        let ephemeral_key = pp.generator.mul(r.into_repr()).into_affine();
+        let shared_point = pk.mul(r.into_repr()).into_affine();
+
+        let key = Self::derive_key(&ephemeral_key, pk, &shared_point, Self::PROTOCOL_NAME)?;
+
+        Ok((key, Encapsulation(ephemeral_key)))
+    }
+
+    /// Recover the shared key of `encapsulation` with the secret key matching `pk`. Fails if the encapsulation is
+    /// not a point of the prime-order subgroup other than the identity.
+    pub fn decapsulate(
+        pk: &PublicKey<C>,
+        sk: &SecretKey<C>,
+        encapsulation: &Encapsulation<C>,
+    ) -> Result<SharedKey, CryptoError> {
+        encapsulation
+            .0
+            .validate()
+            .map_err(|_| CryptoError::InvalidHybridCiphertext)?;
+
+        let shared_point = encapsulation.0.mul(sk.into_repr()).into_affine();
+
+        Self::derive_key(&encapsulation.0, pk, &shared_point, Self::PROTOCOL_NAME)
+    }
+
+    /// HKDF-SHA256 of the shared point, salted with the ephemeral and public keys, for the purpose `info`.
+    pub(crate) fn derive_key(
+        ephemeral_key: &C::Affine,
+        pk: &PublicKey<C>,
+        shared_point: &C::Affine,
+        info: &[u8],
+    ) -> Result<SharedKey, CryptoError> {
+        let salt = to_bytes![ephemeral_key, pk]?;
+        let hkdf = Hkdf::<Sha256>::new(Some(&salt), &to_bytes![shared_point]?);
+
+        let mut key = [0u8; 32];
+        hkdf.expand(info, &mut key)
+            .map_err(|_| CryptoError::InvalidHybridCiphertext)?;
+
+        Ok(SharedKey(key))
+    }
+}
+
+impl SharedKey {
+    pub(crate) fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&Key::from(self.0))
+    }
+}
+
+/// Hybrid encryption of large payloads, such as chat messages or game state, for the owner of an ElGamal key: a
+/// fresh key from [`Kem`] encrypts the payload with ChaCha20-Poly1305.
+///
+/// The payload is split into chunks of [`HybridEncryption::CHUNK_SIZE`] bytes, each sealed with a nonce holding its
+/// position and whether it is the last one (the STREAM construction), so that reordered, dropped or truncated
+/// chunks are detected. Every chunk authenticates the associated data.
+pub struct HybridEncryption<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct HybridCiphertext<C: ProjectiveCurve> {
+    pub encapsulation: Encapsulation<C>,
+    pub chunks: Vec<Vec<u8>>,
+}
+
+impl<C: ProjectiveCurve> HybridEncryption<C> {
+    /// Size in bytes of the plaintext chunks.
+    pub const CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Encrypt `payload` for the owner of `pk`, binding `associated_data` to the ciphertext.
+    pub fn encrypt<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        payload: &[u8],
+        associated_data: &[u8],
+    ) -> Result<HybridCiphertext<C>, CryptoError> {
+        let (key, encapsulation) = Kem::encapsulate(rng, pp, pk)?;
+        let cipher = key.cipher();
+
+        // an empty payload is still sealed as one empty chunk
+        let mut chunks: Vec<&[u8]> = payload.chunks(Self::CHUNK_SIZE).collect();
+        if chunks.is_empty() {
+            chunks.push(&[]);
+        }
+
+        let last = chunks.len() - 1;
+        let chunks = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                cipher
+                    .encrypt(
+                        &Self::nonce(i, i == last),
+                        Payload {
+                            msg: chunk,
+                            aad: associated_data,
+                        },
+                    )
+                    .map_err(|_| CryptoError::InvalidHybridCiphertext)
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(HybridCiphertext {
+            encapsulation,
+            chunks,
+        })
+    }
+
+    /// Decrypt `ciphertext` with the secret key matching `pk`. Fails if any chunk or the associated data were
+    /// tampered with, or if chunks were reordered or removed.
+    pub fn decrypt(
+        pk: &PublicKey<C>,
+        sk: &SecretKey<C>,
+        ciphertext: &HybridCiphertext<C>,
+        associated_data: &[u8],
+    ) -> Result<Vec<u8>, CryptoError> {
+        if ciphertext.chunks.is_empty() {
+            return Err(CryptoError::InvalidHybridCiphertext);
+        }
+
+        let cipher = Kem::decapsulate(pk, sk, &ciphertext.encapsulation)?.cipher();
+
+        // the payload is shorter than the chunks, which are already in memory
+        let last = ciphertext.chunks.len() - 1;
+        let mut payload = Vec::with_capacity(ciphertext.chunks.iter().map(Vec::len).sum());
+        for (i, chunk) in ciphertext.chunks.iter().enumerate() {
+            let plaintext = cipher
+                .decrypt(
+                    &Self::nonce(i, i == last),
+                    Payload {
+                        msg: chunk,
+                        aad: associated_data,
+                    },
+                )
+                .map_err(|_| CryptoError::InvalidHybridCiphertext)?;
+            payload.extend(plaintext);
+        }
+
+        Ok(payload)
+    }
+
+    /// Nonce of the `i`-th chunk: the big-endian position followed by a flag set on the last chunk.
+    fn nonce(i: usize, last: bool) -> Nonce {
+        let mut nonce = Nonce::default();
+        nonce[..8].copy_from_slice(&(i as u64).to_be_bytes());
+        nonce[11] = last as u8;
+
+        nonce
+    }
+}
@@ -0,0 +1,274 @@
+use super::Parameters;
+use crate::error::CryptoError;
+use crate::utils::encoding::{field_from_be_bytes, field_length, field_to_be_bytes};
+
+use ark_ec::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use ark_ec::{AffineCurve, ProjectiveCurve, SWModelParameters};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use pkcs8::der::asn1::BitStringRef;
+use pkcs8::der::{Decode, Document, Encode, SecretDocument};
+use pkcs8::SubjectPublicKeyInfoRef;
+use pkcs8::{AlgorithmIdentifierRef, LineEnding, ObjectIdentifier, PrivateKeyInfo};
+use sec1::EcPrivateKey;
+
+/// Object identifier of elliptic curve public keys, `id-ecPublicKey`, from RFC 5480.
+pub const ID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// Import and export of ElGamal keys over short Weierstrass curves in standard formats: SEC1 points and scalars,
+/// SubjectPublicKeyInfo and PKCS#8 documents in DER or PEM.
+///
+/// The DER and PEM documents name the curve with the object identifier `curve`, which the caller provides as not
+/// every curve has a registered one. Note that standard tools assume keys to be multiples of the standard generator of
+/// the curve, so they only agree with this crate on keys generated with [`Parameters`] using that generator.
+pub struct KeyEncoding;
+
+type PublicKey<P> = GroupAffine<P>;
+type SecretKey<P> = <P as ark_ec::ModelParameters>::ScalarField;
+
+impl KeyEncoding {
+    /// Compressed SEC1 encoding of a public key: `0x02` or `0x03` depending on the parity of $y$, followed by $x$ in
+    /// big-endian.
+    pub fn public_key_to_sec1<P: SWModelParameters>(public_key: &PublicKey<P>) -> Vec<u8>
+    where
+        P::BaseField: PrimeField,
+    {
+        if public_key.is_zero() {
+            return vec![0x00];
+        }
+
+        let mut bytes = vec![if public_key.y.into_repr().is_odd() {
+            0x03
+        } else {
+            0x02
+        }];
+        bytes.extend(field_to_be_bytes(&public_key.x));
+
+        bytes
+    }
+
+    /// Decode a public key from its compressed or uncompressed SEC1 encoding. The point must be in the prime-order
+    /// subgroup and must not be the identity.
+    pub fn public_key_from_sec1<P: SWModelParameters>(
+        bytes: &[u8],
+    ) -> Result<PublicKey<P>, CryptoError>
+    where
+        P::BaseField: PrimeField,
+    {
+        let length = field_length::<P::BaseField>();
+
+        let point = match bytes.first() {
+            Some(&tag) if (tag == 0x02 || tag == 0x03) && bytes.len() == 1 + length => {
+                let x = field_from_be_bytes::<P::BaseField>(&bytes[1..]).map_err(encoding_error)?;
+                let point = GroupAffine::<P>::get_point_from_x(x, true)
+                    .ok_or(CryptoError::InvalidKeyEncoding)?;
+
+                if point.y.into_repr().is_odd() == (tag == 0x03) {
+                    point
+                } else {
+                    -point
+                }
+            }
+            Some(0x04) if bytes.len() == 1 + 2 * length => {
+                let x = field_from_be_bytes::<P::BaseField>(&bytes[1..1 + length])
+                    .map_err(encoding_error)?;
+                let y = field_from_be_bytes::<P::BaseField>(&bytes[1 + length..])
+                    .map_err(encoding_error)?;
+                let point = GroupAffine::<P>::new(x, y, false);
+                if !point.is_on_curve() {
+                    return Err(CryptoError::InvalidKeyEncoding);
+                }
+
+                point
+            }
+            _ => return Err(CryptoError::InvalidKeyEncoding),
+        };
+
+        if point.is_zero() || !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(CryptoError::InvalidKeyEncoding);
+        }
+
+        Ok(point)
+    }
+
+    /// SEC1 encoding of a secret key: the scalar in big-endian, padded to the byte length of the group order.
+    pub fn secret_key_to_bytes<P: SWModelParameters>(secret_key: &SecretKey<P>) -> Vec<u8> {
+        field_to_be_bytes(secret_key)
+    }
+
+    /// Decode a secret key from its SEC1 encoding, rejecting zero and values larger than the group order.
+    pub fn secret_key_from_bytes<P: SWModelParameters>(
+        bytes: &[u8],
+    ) -> Result<SecretKey<P>, CryptoError> {
+        let secret_key = field_from_be_bytes::<P::ScalarField>(bytes).map_err(encoding_error)?;
+        if secret_key.is_zero() {
+            return Err(CryptoError::InvalidKeyEncoding);
+        }
+
+        Ok(secret_key)
+    }
+
+    /// DER encoding of a public key as a SubjectPublicKeyInfo document.
+    pub fn public_key_to_der<P: SWModelParameters>(
+        public_key: &PublicKey<P>,
+        curve: ObjectIdentifier,
+    ) -> Result<Vec<u8>, CryptoError>
+    where
+        P::BaseField: PrimeField,
+    {
+        let point = Self::public_key_to_sec1(public_key);
+        let info = SubjectPublicKeyInfoRef {
+            algorithm: algorithm(&curve),
+            subject_public_key: BitStringRef::from_bytes(&point).map_err(der_error)?,
+        };
+
+        Ok(Document::encode_msg(&info).map_err(der_error)?.into_vec())
+    }
+
+    /// Decode a public key from a DER SubjectPublicKeyInfo document for the curve `curve`.
+    pub fn public_key_from_der<P: SWModelParameters>(
+        bytes: &[u8],
+        curve: ObjectIdentifier,
+    ) -> Result<PublicKey<P>, CryptoError>
+    where
+        P::BaseField: PrimeField,
+    {
+        let info = SubjectPublicKeyInfoRef::from_der(bytes).map_err(der_error)?;
+        info.algorithm
+            .assert_oids(ID_EC_PUBLIC_KEY, curve)
+            .map_err(|_| CryptoError::InvalidKeyEncoding)?;
+
+        let point = info
+            .subject_public_key
+            .as_bytes()
+            .ok_or(CryptoError::InvalidKeyEncoding)?;
+
+        Self::public_key_from_sec1(point)
+    }
+
+    /// PEM encoding of a public key, with the label `PUBLIC KEY`.
+    pub fn public_key_to_pem<P: SWModelParameters>(
+        public_key: &PublicKey<P>,
+        curve: ObjectIdentifier,
+    ) -> Result<String, CryptoError>
+    where
+        P::BaseField: PrimeField,
+    {
+        let der = Self::public_key_to_der(public_key, curve)?;
+
+        Document::try_from(der)
+            .and_then(|document| document.to_pem("PUBLIC KEY", LineEnding::LF))
+            .map_err(der_error)
+    }
+
+    /// Decode a public key from a PEM document with the label `PUBLIC KEY`.
+    pub fn public_key_from_pem<P: SWModelParameters>(
+        pem: &str,
+        curve: ObjectIdentifier,
+    ) -> Result<PublicKey<P>, CryptoError>
+    where
+        P::BaseField: PrimeField,
+    {
+        let (label, document) = Document::from_pem(pem).map_err(der_error)?;
+        if label != "PUBLIC KEY" {
+            return Err(CryptoError::InvalidKeyEncoding);
+        }
+
+        Self::public_key_from_der(document.as_bytes(), curve)
+    }
+
+    /// DER encoding of a secret key as a PKCS#8 document wrapping a SEC1 `ECPrivateKey`, which also holds the public
+    /// key for the generator of `pp`.
+    pub fn secret_key_to_pkcs8_der<P: SWModelParameters>(
+        pp: &Parameters<GroupProjective<P>>,
+        secret_key: &SecretKey<P>,
+        curve: ObjectIdentifier,
+    ) -> Result<Vec<u8>, CryptoError>
+    where
+        P::BaseField: PrimeField,
+    {
+        let scalar = Self::secret_key_to_bytes::<P>(secret_key);
+        let point =
+            Self::public_key_to_sec1(&pp.generator.mul(secret_key.into_repr()).into_affine());
+
+        let ec_private_key = EcPrivateKey {
+            private_key: &scalar,
+            parameters: None,
+            public_key: Some(&point),
+        }
+        .to_der()
+        .map_err(der_error)?;
+
+        let info = PrivateKeyInfo::new(algorithm(&curve), &ec_private_key);
+
+        Ok(SecretDocument::encode_msg(&info)
+            .map_err(der_error)?
+            .as_bytes()
+            .to_vec())
+    }
+
+    /// Decode a secret key from a DER PKCS#8 document for the curve `curve`.
+    pub fn secret_key_from_pkcs8_der<P: SWModelParameters>(
+        bytes: &[u8],
+        curve: ObjectIdentifier,
+    ) -> Result<SecretKey<P>, CryptoError> {
+        let info = PrivateKeyInfo::from_der(bytes).map_err(der_error)?;
+        info.algorithm
+            .assert_oids(ID_EC_PUBLIC_KEY, curve)
+            .map_err(|_| CryptoError::InvalidKeyEncoding)?;
+
+        let ec_private_key = EcPrivateKey::from_der(info.private_key).map_err(der_error)?;
+        if ec_private_key
+            .parameters
+            .is_some_and(|parameters| parameters.named_curve() != Some(curve))
+        {
+            return Err(CryptoError::InvalidKeyEncoding);
+        }
+
+        Self::secret_key_from_bytes::<P>(ec_private_key.private_key)
+    }
+
+    /// PEM encoding of a secret key, with the label `PRIVATE KEY`.
+    pub fn secret_key_to_pkcs8_pem<P: SWModelParameters>(
+        pp: &Parameters<GroupProjective<P>>,
+        secret_key: &SecretKey<P>,
+        curve: ObjectIdentifier,
+    ) -> Result<String, CryptoError>
+    where
+        P::BaseField: PrimeField,
+    {
+        let der = Self::secret_key_to_pkcs8_der(pp, secret_key, curve)?;
+
+        SecretDocument::try_from(der.as_slice())
+            .and_then(|document| document.to_pem("PRIVATE KEY", LineEnding::LF))
+            .map(|pem| pem.to_string())
+            .map_err(der_error)
+    }
+
+    /// Decode a secret key from a PEM document with the label `PRIVATE KEY`.
+    pub fn secret_key_from_pkcs8_pem<P: SWModelParameters>(
+        pem: &str,
+        curve: ObjectIdentifier,
+    ) -> Result<SecretKey<P>, CryptoError> {
+        let (label, document) = SecretDocument::from_pem(pem).map_err(der_error)?;
+        if label != "PRIVATE KEY" {
+            return Err(CryptoError::InvalidKeyEncoding);
+        }
+
+        Self::secret_key_from_pkcs8_der::<P>(document.as_bytes(), curve)
+    }
+}
+
+fn algorithm(curve: &ObjectIdentifier) -> AlgorithmIdentifierRef<'_> {
+    AlgorithmIdentifierRef {
+        oid: ID_EC_PUBLIC_KEY,
+        parameters: Some(curve.into()),
+    }
+}
+
+fn der_error(_: pkcs8::der::Error) -> CryptoError {
+    CryptoError::InvalidKeyEncoding
+}
+
+fn encoding_error(_: CryptoError) -> CryptoError {
+    CryptoError::InvalidKeyEncoding
+}
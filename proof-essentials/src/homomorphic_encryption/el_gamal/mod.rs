@@ -1,8 +1,9 @@
 use crate::error::CryptoError;
 use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::utils::msm::{Plain, ScalarMul};
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{fields::PrimeField, ToBytes, UniformRand};
+use ark_ff::{fields::PrimeField, ToBytes, UniformRand, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::{
     io::{Read, Write},
@@ -12,10 +13,25 @@ use ark_std::{
 use std::hash::Hash;
 
 pub mod arithmetic_definitions;
+pub mod distributed_decryption;
+pub mod exponential;
+pub mod hashed;
+pub mod hybrid;
+pub mod key_encoding;
+pub mod multi_recipient;
+pub mod precomputed;
+pub mod proofs;
+pub mod proxy_reencryption;
 mod tests;
+pub mod threshold;
+pub mod vector;
+pub mod verifiable_encryption;
 
-pub struct ElGamal<C: ProjectiveCurve> {
+/// ElGamal encryption over `C`, with the scalar multiplications of the backend `M`, such as [`Glv`](crate::utils::glv::Glv) on
+/// curves with an efficient endomorphism.
+pub struct ElGamal<C: ProjectiveCurve, M: ScalarMul<C> = Plain> {
     _group: PhantomData<C>,
+    _scalar_mul: PhantomData<M>,
 }
 
 #[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
@@ -44,9 +60,59 @@ impl<C: ProjectiveCurve> ToBytes for Ciphertext<C> {
     }
 }
 
-impl<C: ProjectiveCurve> HomomorphicEncryptionScheme<C::ScalarField> for ElGamal<C>
+/// Checks on group elements received from other parties, before they flow into group arithmetic.
+pub trait Validate {
+    fn validate(&self) -> Result<(), CryptoError>;
+}
+
+/// A public key must be a point of the prime-order subgroup other than the identity, which would make every
+/// encryption under it transparent.
+impl<A: AffineCurve> Validate for A {
+    fn validate(&self) -> Result<(), CryptoError> {
+        if self.is_zero() || !is_in_prime_subgroup(self) {
+            return Err(CryptoError::InvalidPublicKey);
+        }
+
+        Ok(())
+    }
+}
+
+/// Both components of a ciphertext must be points of the prime-order subgroup, and the first one cannot be the
+/// identity, which would leave the plaintext in the clear.
+impl<C: ProjectiveCurve> Validate for Ciphertext<C> {
+    fn validate(&self) -> Result<(), CryptoError> {
+        if self.0.is_zero() || !is_in_prime_subgroup(&self.0) || !is_in_prime_subgroup(&self.1) {
+            return Err(CryptoError::InvalidCiphertext);
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether `point` is on the curve and in its prime-order subgroup. The point is rebuilt from its compressed
+/// encoding, which recomputes $y$ from $x$ and checks the subgroup, so a point off the curve does not survive the
+/// round trip.
+fn is_in_prime_subgroup<A: AffineCurve>(point: &A) -> bool {
+    let mut bytes = Vec::with_capacity(point.serialized_size());
+    point.serialize(&mut bytes).is_ok()
+        && A::deserialize(&bytes[..]).is_ok_and(|decoded| decoded == *point)
+}
+
+impl<C: ProjectiveCurve> Ciphertext<C> {
+    /// Re-randomize the ciphertext under `pk` with the factor `r`, i.e. add the encryption $(rG, r \cdot pk)$ of the
+    /// identity. The result decrypts to the same plaintext but cannot be linked to the original ciphertext.
+    pub fn rerandomize(&self, pp: &Parameters<C>, pk: &PublicKey<C>, r: &C::ScalarField) -> Self {
+        Self(
+            (self.0.into_projective() + pp.generator.mul(r.into_repr())).into_affine(),
+            (self.1.into_projective() + pk.mul(r.into_repr())).into_affine(),
+        )
+    }
+}
+
+impl<C, M> HomomorphicEncryptionScheme<C::ScalarField> for ElGamal<C, M>
 where
     C: ProjectiveCurve,
+    M: ScalarMul<C>,
 {
     type Parameters = Parameters<C>;
     type Generator = Generator<C>;
@@ -74,7 +140,7 @@ where
         let secret_key: <C as ProjectiveCurve>::ScalarField = C::ScalarField::rand(rng);
 
         // compute secret_key*generator to derive the public key
-        let public_key = pp.generator.mul(secret_key).into();
+        let public_key = M::mul(&pp.generator, &secret_key).into();
 
         Ok((public_key, secret_key))
     }
@@ -86,10 +152,10 @@ where
         r: &C::ScalarField,
     ) -> Result<Self::Ciphertext, CryptoError> {
         // compute s = r*pk
-        let s = Plaintext(pk.mul(r.into_repr()).into_affine());
+        let s = Plaintext(M::mul(pk, r).into_affine());
 
         // compute c1 = r*generator
-        let c1 = pp.generator.mul(r.into_repr()).into();
+        let c1 = M::mul(&pp.generator, r).into();
 
         // compute c2 = m + s
         let c2 = *message + s;
@@ -106,7 +172,7 @@ where
         let c2: <C as ProjectiveCurve>::Affine = ciphertext.1;
 
         // compute s = secret_key * c1
-        let s = c1.mul(sk.into_repr());
+        let s = M::mul(&c1, sk);
         let s_inv = -s;
 
         // compute message = c2 - s
@@ -114,4 +180,27 @@ where
 
         Ok(Plaintext(m))
     }
+
+    fn validate_public_key(pk: &Self::PublicKey) -> Result<(), CryptoError> {
+        pk.validate()
+    }
+
+    fn validate_ciphertext(
+        _pk: &Self::PublicKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<(), CryptoError> {
+        ciphertext.validate()
+    }
+
+    fn multi_scalar_mul(
+        scalars: &[C::ScalarField],
+        ciphertexts: &[Self::Ciphertext],
+    ) -> Result<Self::Ciphertext, CryptoError> {
+        let (first, second): (Vec<_>, Vec<_>) = ciphertexts.iter().map(|c| (c.0, c.1)).unzip();
+
+        Ok(Ciphertext(
+            M::multi_scalar_mul(&first, scalars)?.into_affine(),
+            M::multi_scalar_mul(&second, scalars)?.into_affine(),
+        ))
+    }
 }
@@ -0,0 +1,191 @@
+use super::{Ciphertext, Parameters, Plaintext, PublicKey, SecretKey};
+use crate::error::CryptoError;
+use crate::zkp::proofs::linear_relation::{self, Equation, LinearRelation, Term};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::ArgumentOfKnowledge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Multi-recipient ElGamal: one plaintext $m$ encrypted to $N$ public keys with a single ephemeral value $R = rG$,
+/// so that the ciphertext is $(R, m + r \cdot pk_1, \dots, m + r \cdot pk_N)$ instead of $N$ full ciphertexts.
+///
+/// Recipient $i$ reads $(R, m + r \cdot pk_i)$ as a regular [`super::ElGamal`] ciphertext. The sender can prove that
+/// all recipients get the same plaintext by proving knowledge of $r$ such that $R = rG$ and
+/// $c_i - c_1 = r (pk_i - pk_1)$ for every $i$.
+pub struct MultiRecipientElGamal<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultiRecipientCiphertext<C: ProjectiveCurve> {
+    pub ephemeral_key: C::Affine,
+    pub payloads: Vec<C::Affine>,
+}
+
+impl<C: ProjectiveCurve> MultiRecipientCiphertext<C> {
+    /// The ciphertext of the `index`-th recipient.
+    pub fn ciphertext(&self, index: usize) -> Option<Ciphertext<C>> {
+        self.payloads
+            .get(index)
+            .map(|payload| Ciphertext(self.ephemeral_key, *payload))
+    }
+
+    /// Number of recipients.
+    pub fn len(&self) -> usize {
+        self.payloads.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.payloads.is_empty()
+    }
+}
+
+/// Proof that all the payloads of a multi-recipient ciphertext hide the same plaintext.
+pub type SamePlaintextProof<C> = linear_relation::proof::Proof<C>;
+
+impl<C: ProjectiveCurve> MultiRecipientElGamal<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Multi-Recipient ElGamal";
+
+    /// Encrypt `message` to every key in `public_keys` with the shared randomness `r`.
+    pub fn encrypt(
+        pp: &Parameters<C>,
+        public_keys: &[PublicKey<C>],
+        message: &Plaintext<C>,
+        r: &C::ScalarField,
+    ) -> Result<MultiRecipientCiphertext<C>, CryptoError> {
+        if public_keys.is_empty() {
+            return Err(CryptoError::InvalidMultiRecipientCiphertext);
+        }
+
+        let ephemeral_key = pp.generator.mul(r.into_repr()).into_affine();
+        let payloads = public_keys
+            .iter()
+            .map(|pk| pk.mul(r.into_repr()).add_mixed(&message.0))
+            .collect::<Vec<_>>();
+
+        Ok(MultiRecipientCiphertext {
+            ephemeral_key,
+            payloads: C::batch_normalization_into_affine(&payloads),
+        })
+    }
+
+    /// Decrypt the payload of the `index`-th recipient with their secret key.
+    pub fn decrypt(
+        sk: &SecretKey<C>,
+        ciphertext: &MultiRecipientCiphertext<C>,
+        index: usize,
+    ) -> Result<Plaintext<C>, CryptoError> {
+        let payload = ciphertext
+            .payloads
+            .get(index)
+            .ok_or(CryptoError::InvalidMultiRecipientCiphertext)?;
+        let shared_secret = ciphertext.ephemeral_key.mul(sk.into_repr());
+
+        Ok(Plaintext(
+            (payload.into_projective() - shared_secret).into_affine(),
+        ))
+    }
+
+    /// Encrypt `message` to every key in `public_keys` and prove that all payloads hide the same plaintext.
+    pub fn encrypt_and_prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        public_keys: &[PublicKey<C>],
+        message: &Plaintext<C>,
+        r: &C::ScalarField,
+        fs_rng: &mut T,
+    ) -> Result<(MultiRecipientCiphertext<C>, SamePlaintextProof<C>), CryptoError> {
+        let ciphertext = Self::encrypt(pp, public_keys, message, r)?;
+
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            public_keys,
+            ciphertext.ephemeral_key,
+            ciphertext.payloads
+        ]?);
+
+        let (relation, images) = Self::relation(pp, public_keys, &ciphertext)?;
+        let proof = LinearRelation::prove(
+            rng,
+            &relation,
+            &images,
+            &vec![*r],
+            Self::PROTOCOL_NAME,
+            fs_rng,
+        )?;
+
+        Ok((ciphertext, proof))
+    }
+
+    /// Verify that all the payloads of `ciphertext` encrypt the same plaintext under `public_keys`.
+    pub fn verify<T: Transcript>(
+        pp: &Parameters<C>,
+        public_keys: &[PublicKey<C>],
+        ciphertext: &MultiRecipientCiphertext<C>,
+        proof: &SamePlaintextProof<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            public_keys,
+            ciphertext.ephemeral_key,
+            ciphertext.payloads
+        ]?);
+
+        let (relation, images) = Self::relation(pp, public_keys, ciphertext)?;
+
+        LinearRelation::verify(&relation, &images, proof, Self::PROTOCOL_NAME, fs_rng).map_err(
+            |_| CryptoError::ProofVerificationError(String::from("Multi-Recipient ElGamal")),
+        )
+    }
+
+    /// The linear relation over $r$: $R = rG$ and $c_i - c_1 = r (pk_i - pk_1)$ for $i > 1$.
+    fn relation(
+        pp: &Parameters<C>,
+        public_keys: &[PublicKey<C>],
+        ciphertext: &MultiRecipientCiphertext<C>,
+    ) -> Result<
+        (
+            linear_relation::Parameters<C>,
+            linear_relation::Statement<C>,
+        ),
+        CryptoError,
+    > {
+        if public_keys.is_empty() || public_keys.len() != ciphertext.len() {
+            return Err(CryptoError::InvalidMultiRecipientCiphertext);
+        }
+
+        let first_key = public_keys[0].into_projective();
+        let first_payload = ciphertext.payloads[0].into_projective();
+
+        let key_differences = C::batch_normalization_into_affine(
+            &public_keys[1..]
+                .iter()
+                .map(|pk| pk.into_projective() - first_key)
+                .collect::<Vec<_>>(),
+        );
+        let payload_differences = C::batch_normalization_into_affine(
+            &ciphertext.payloads[1..]
+                .iter()
+                .map(|payload| payload.into_projective() - first_payload)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut equations = vec![Equation::new(vec![Term::new(0, pp.generator)])];
+        equations.extend(
+            key_differences
+                .into_iter()
+                .map(|base| Equation::new(vec![Term::new(0, base)])),
+        );
+
+        let mut images = vec![ciphertext.ephemeral_key];
+        images.extend(payload_differences);
+
+        Ok((linear_relation::Parameters::new(1, equations)?, images))
+    }
+}
@@ -0,0 +1,74 @@
+use super::{Ciphertext, Parameters, Plaintext, PublicKey};
+use crate::utils::fixed_base::{FixedBaseTable, Precompute};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+
+/// Fixed-base tables for the generator and a public key, for encrypting or re-randomizing many ciphertexts under the
+/// same key. Both halves of a ciphertext then take one table lookup per window instead of a scalar multiplication.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrecomputedKey<C: ProjectiveCurve> {
+    generator: FixedBaseTable<C>,
+    public_key: FixedBaseTable<C>,
+}
+
+impl<C: ProjectiveCurve> PrecomputedKey<C> {
+    /// Tables for long-lived parameters and key, see [`Precompute`].
+    pub fn new(pp: &Parameters<C>, pk: &PublicKey<C>) -> Self {
+        Self {
+            generator: pp.generator.precompute(),
+            public_key: pk.precompute(),
+        }
+    }
+
+    /// Smaller tables for a batch of about `num_scalars` encryptions.
+    pub fn for_scalars(pp: &Parameters<C>, pk: &PublicKey<C>, num_scalars: usize) -> Self {
+        Self {
+            generator: FixedBaseTable::for_scalars(&pp.generator, num_scalars),
+            public_key: FixedBaseTable::for_scalars(pk, num_scalars),
+        }
+    }
+
+    pub fn parameters(&self) -> Parameters<C> {
+        Parameters {
+            generator: *self.generator.base(),
+        }
+    }
+
+    pub fn public_key(&self) -> &PublicKey<C> {
+        self.public_key.base()
+    }
+
+    /// Same ciphertext as [`super::ElGamal::encrypt`] under the key the tables were built from.
+    pub fn encrypt(&self, message: &Plaintext<C>, r: &C::ScalarField) -> Ciphertext<C> {
+        let (c1, c2) = self.encrypt_projective(message, r);
+
+        Ciphertext(c1.into_affine(), c2.into_affine())
+    }
+
+    /// Same ciphertext as [`Ciphertext::rerandomize`] under the key the tables were built from.
+    pub fn rerandomize(&self, ciphertext: &Ciphertext<C>, r: &C::ScalarField) -> Ciphertext<C> {
+        let (c1, c2) = self.rerandomize_projective(ciphertext, r);
+
+        Ciphertext(c1.into_affine(), c2.into_affine())
+    }
+
+    /// Components of [`PrecomputedKey::encrypt`] before their conversion to affine coordinates.
+    pub(crate) fn encrypt_projective(&self, message: &Plaintext<C>, r: &C::ScalarField) -> (C, C) {
+        (
+            self.generator.mul(r),
+            message.0.into_projective() + self.public_key.mul(r),
+        )
+    }
+
+    /// Components of [`PrecomputedKey::rerandomize`] before their conversion to affine coordinates.
+    pub(crate) fn rerandomize_projective(
+        &self,
+        ciphertext: &Ciphertext<C>,
+        r: &C::ScalarField,
+    ) -> (C, C) {
+        (
+            ciphertext.0.into_projective() + self.generator.mul(r),
+            ciphertext.1.into_projective() + self.public_key.mul(r),
+        )
+    }
+}
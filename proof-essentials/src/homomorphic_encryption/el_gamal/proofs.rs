@@ -0,0 +1,206 @@
+use super::{Ciphertext, ElGamal, Parameters, Plaintext, PublicKey, SecretKey, Validate};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::zkp::proofs::chaum_pedersen_dl_equality as dl_equality;
+use crate::zkp::proofs::schnorr_identification;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::ArgumentOfKnowledge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+/// Proof that a plaintext is the decryption of a ciphertext under the secret key matching a public key.
+pub type DecryptionProof<C> = dl_equality::proof::Proof<C>;
+
+/// Proof of knowledge of the plaintext and randomness of a ciphertext.
+pub type EncryptionProof<C> = schnorr_identification::proof::Proof<C>;
+
+/// Proof that a ciphertext is a re-randomization of another one under a known public key.
+pub type ReencryptionProof<C> = dl_equality::proof::Proof<C>;
+
+impl<C: ProjectiveCurve> Ciphertext<C> {
+    /// Re-randomize the ciphertext under `pk` with the factor `r` and prove that the result is a re-randomization of
+    /// it, see [`ElGamal::verify_reencryption`].
+    pub fn rerandomize_with_proof<R: Rng, T: Transcript>(
+        &self,
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        r: &C::ScalarField,
+        fs_rng: &mut T,
+    ) -> Result<(Self, ReencryptionProof<C>), CryptoError> {
+        let rerandomized = self.rerandomize(pp, pk, r);
+        let proof = ElGamal::prove_reencryption(rng, pp, pk, self, &rerandomized, r, fs_rng)?;
+
+        Ok((rerandomized, proof))
+    }
+}
+
+impl<C: ProjectiveCurve> ElGamal<C> {
+    /// Decrypt `ciphertext` and prove that the decryption is correct. For $c = (c_1, c_2)$ and plaintext $m$ this
+    /// is a proof that $\log_G(pk) = \log_{c_1}(c_2 - m)$.
+    pub fn prove_decryption<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        sk: &SecretKey<C>,
+        ciphertext: &Ciphertext<C>,
+        fs_rng: &mut T,
+    ) -> Result<(Plaintext<C>, DecryptionProof<C>), CryptoError> {
+        let plaintext = Self::decrypt(pp, sk, ciphertext)?;
+        let shared_secret: C::Affine =
+            (ciphertext.1.into_projective() - plaintext.0.into_projective()).into();
+
+        fs_rng.absorb(&to_bytes![b"el_gamal_decryption", ciphertext, plaintext.0]?);
+
+        let parameters = dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+        let statement = dl_equality::Statement::new(pk, &shared_secret);
+        let proof = dl_equality::DLEquality::prove(
+            rng,
+            &parameters,
+            &statement,
+            sk,
+            b"el_gamal_decryption",
+            fs_rng,
+        )?;
+
+        Ok((plaintext, proof))
+    }
+
+    /// Verify that `plaintext` is the decryption of `ciphertext` under the secret key matching `pk`.
+    pub fn verify_decryption<T: Transcript>(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C>,
+        plaintext: &Plaintext<C>,
+        proof: &DecryptionProof<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        pk.validate()?;
+        ciphertext.validate()?;
+
+        let shared_secret: C::Affine =
+            (ciphertext.1.into_projective() - plaintext.0.into_projective()).into();
+
+        fs_rng.absorb(&to_bytes![b"el_gamal_decryption", ciphertext, plaintext.0]?);
+
+        let parameters = dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+        let statement = dl_equality::Statement::new(pk, &shared_secret);
+
+        dl_equality::DLEquality::verify(
+            &parameters,
+            &statement,
+            proof,
+            b"el_gamal_decryption",
+            fs_rng,
+        )
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("ElGamal Decryption")))
+    }
+
+    /// Prove knowledge of the randomness $r$ of `ciphertext` $= (rG, m + r \cdot pk)$, and hence of its plaintext
+    /// $m = c_2 - r \cdot pk$. The public key and the ciphertext are bound to the transcript.
+    pub fn prove_encryption<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C>,
+        r: &C::ScalarField,
+        fs_rng: &mut T,
+    ) -> Result<EncryptionProof<C>, CryptoError> {
+        fs_rng.absorb(&to_bytes![b"el_gamal_encryption", pk, ciphertext]?);
+
+        schnorr_identification::SchnorrIdentification::prove(
+            rng,
+            &pp.generator,
+            &ciphertext.0,
+            r,
+            b"el_gamal_encryption",
+            fs_rng,
+        )
+    }
+
+    /// Verify that the creator of `ciphertext` knows its plaintext and randomness.
+    pub fn verify_encryption<T: Transcript>(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C>,
+        proof: &EncryptionProof<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![b"el_gamal_encryption", pk, ciphertext]?);
+
+        schnorr_identification::SchnorrIdentification::verify(
+            &pp.generator,
+            &ciphertext.0,
+            proof,
+            b"el_gamal_encryption",
+            fs_rng,
+        )
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("ElGamal Encryption")))
+    }
+
+    /// Re-randomize `ciphertext` under `pk` by adding an encryption of zero with randomness `r`.
+    pub fn reencrypt(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C>,
+        r: &C::ScalarField,
+    ) -> Result<Ciphertext<C>, CryptoError> {
+        Ok(ciphertext.rerandomize(pp, pk, r))
+    }
+
+    /// Prove that `reencrypted` is `original` re-randomized under `pk` with the re-encryption factor `r`, that is
+    /// $c' - c = (rG, r \cdot pk)$.
+    pub fn prove_reencryption<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        original: &Ciphertext<C>,
+        reencrypted: &Ciphertext<C>,
+        r: &C::ScalarField,
+        fs_rng: &mut T,
+    ) -> Result<ReencryptionProof<C>, CryptoError> {
+        let difference = *reencrypted - *original;
+
+        fs_rng.absorb(&to_bytes![b"el_gamal_reencryption", original, reencrypted]?);
+
+        let parameters = dl_equality::Parameters::new(&pp.generator, pk);
+        let statement = dl_equality::Statement::new(&difference.0, &difference.1);
+
+        dl_equality::DLEquality::prove(
+            rng,
+            &parameters,
+            &statement,
+            r,
+            b"el_gamal_reencryption",
+            fs_rng,
+        )
+    }
+
+    /// Verify that `reencrypted` is a re-randomization of `original` under `pk`.
+    pub fn verify_reencryption<T: Transcript>(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        original: &Ciphertext<C>,
+        reencrypted: &Ciphertext<C>,
+        proof: &ReencryptionProof<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let difference = *reencrypted - *original;
+
+        fs_rng.absorb(&to_bytes![b"el_gamal_reencryption", original, reencrypted]?);
+
+        let parameters = dl_equality::Parameters::new(&pp.generator, pk);
+        let statement = dl_equality::Statement::new(&difference.0, &difference.1);
+
+        dl_equality::DLEquality::verify(
+            &parameters,
+            &statement,
+            proof,
+            b"el_gamal_reencryption",
+            fs_rng,
+        )
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("ElGamal Re-encryption")))
+    }
+}
@@ -0,0 +1,164 @@
+use super::{Ciphertext, Parameters, PublicKey, SecretKey};
+use crate::error::CryptoError;
+use crate::zkp::proofs::linear_relation::{self, Equation, LinearRelation, Term};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::ArgumentOfKnowledge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proxy re-encryption for key rotation: ciphertexts under an old key $pk_a = sk_a G$ are moved to a new key
+/// $pk_b = sk_b G$ without being decrypted.
+///
+/// The re-encryption key is $rk = sk_b - sk_a$ and a ciphertext $(c_1, c_2) = (rG, m + r \cdot pk_a)$ becomes
+/// $(c_1, c_2 + rk \cdot c_1) = (rG, m + r \cdot pk_b)$. The transformation is proven correct by showing knowledge of
+/// $rk$ such that $pk_b - pk_a = rk \cdot G$ and $c'_{i,2} - c_{i,2} = rk \cdot c_{i,1}$ for every ciphertext.
+///
+/// Together with either secret key, $rk$ reveals the other one, so it must only be handed to a party trusted with
+/// the old key, e.g. when a long-running game rotates the deck key.
+pub struct ProxyReEncryption<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ReEncryptionKey<C: ProjectiveCurve>(pub(crate) C::ScalarField);
+
+/// Proof that a batch of ciphertexts was re-encrypted from one key to another.
+pub type ReEncryptionProof<C> = linear_relation::proof::Proof<C>;
+
+impl<C: ProjectiveCurve> ProxyReEncryption<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"ElGamal Proxy Re-Encryption";
+
+    /// The key that moves ciphertexts from the key of `old_sk` to the key of `new_sk`.
+    pub fn rekey(old_sk: &SecretKey<C>, new_sk: &SecretKey<C>) -> ReEncryptionKey<C> {
+        ReEncryptionKey(*new_sk - old_sk)
+    }
+
+    /// Re-encrypt `ciphertexts` with `rk`. The plaintexts and the first components are left unchanged.
+    pub fn reencrypt(rk: &ReEncryptionKey<C>, ciphertexts: &[Ciphertext<C>]) -> Vec<Ciphertext<C>> {
+        let payloads = C::batch_normalization_into_affine(
+            &ciphertexts
+                .iter()
+                .map(|ciphertext| ciphertext.0.mul(rk.0.into_repr()).add_mixed(&ciphertext.1))
+                .collect::<Vec<_>>(),
+        );
+
+        ciphertexts
+            .iter()
+            .zip(payloads)
+            .map(|(ciphertext, payload)| Ciphertext(ciphertext.0, payload))
+            .collect()
+    }
+
+    /// Re-encrypt `ciphertexts` from `old_pk` to `new_pk` and prove that the transformation is correct.
+    pub fn reencrypt_and_prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        old_pk: &PublicKey<C>,
+        new_pk: &PublicKey<C>,
+        rk: &ReEncryptionKey<C>,
+        ciphertexts: &[Ciphertext<C>],
+        fs_rng: &mut T,
+    ) -> Result<(Vec<Ciphertext<C>>, ReEncryptionProof<C>), CryptoError> {
+        let reencrypted = Self::reencrypt(rk, ciphertexts);
+
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            old_pk,
+            new_pk,
+            ciphertexts,
+            reencrypted
+        ]?);
+
+        let (relation, images) = Self::relation(pp, old_pk, new_pk, ciphertexts, &reencrypted)?;
+        let proof = LinearRelation::prove(
+            rng,
+            &relation,
+            &images,
+            &vec![rk.0],
+            Self::PROTOCOL_NAME,
+            fs_rng,
+        )?;
+
+        Ok((reencrypted, proof))
+    }
+
+    /// Verify that `reencrypted` holds the plaintexts of `ciphertexts`, moved from `old_pk` to `new_pk`.
+    pub fn verify<T: Transcript>(
+        pp: &Parameters<C>,
+        old_pk: &PublicKey<C>,
+        new_pk: &PublicKey<C>,
+        ciphertexts: &[Ciphertext<C>],
+        reencrypted: &[Ciphertext<C>],
+        proof: &ReEncryptionProof<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            old_pk,
+            new_pk,
+            ciphertexts,
+            reencrypted
+        ]?);
+
+        let (relation, images) = Self::relation(pp, old_pk, new_pk, ciphertexts, reencrypted)?;
+
+        LinearRelation::verify(&relation, &images, proof, Self::PROTOCOL_NAME, fs_rng)
+            .map_err(|_| CryptoError::ProofVerificationError(String::from("Proxy Re-Encryption")))
+    }
+
+    /// The linear relation over $rk$: $pk_b - pk_a = rk \cdot G$ and $c'_{i,2} - c_{i,2} = rk \cdot c_{i,1}$, where
+    /// the first components of the re-encrypted ciphertexts must be unchanged.
+    fn relation(
+        pp: &Parameters<C>,
+        old_pk: &PublicKey<C>,
+        new_pk: &PublicKey<C>,
+        ciphertexts: &[Ciphertext<C>],
+        reencrypted: &[Ciphertext<C>],
+    ) -> Result<
+        (
+            linear_relation::Parameters<C>,
+            linear_relation::Statement<C>,
+        ),
+        CryptoError,
+    > {
+        if ciphertexts.len() != reencrypted.len() {
+            return Err(CryptoError::CiphertextVectorLengthError(
+                ciphertexts.len(),
+                reencrypted.len(),
+            ));
+        }
+        if ciphertexts
+            .iter()
+            .zip(reencrypted.iter())
+            .any(|(ciphertext, reencrypted)| ciphertext.0 != reencrypted.0)
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Proxy Re-Encryption",
+            )));
+        }
+
+        let mut equations = vec![Equation::new(vec![Term::new(0, pp.generator)])];
+        equations.extend(
+            ciphertexts
+                .iter()
+                .map(|ciphertext| Equation::new(vec![Term::new(0, ciphertext.0)])),
+        );
+
+        let mut images = vec![new_pk.into_projective() - old_pk.into_projective()];
+        images.extend(ciphertexts.iter().zip(reencrypted.iter()).map(
+            |(ciphertext, reencrypted)| {
+                reencrypted.1.into_projective() - ciphertext.1.into_projective()
+            },
+        ));
+
+        Ok((
+            linear_relation::Parameters::new(1, equations)?,
+            C::batch_normalization_into_affine(&images),
+        ))
+    }
+}
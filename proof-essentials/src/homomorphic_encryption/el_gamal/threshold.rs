@@ -0,0 +1,291 @@
+use super::proofs::DecryptionProof;
+use super::{Ciphertext, Plaintext, PublicKey, SecretKey, Validate};
+use crate::error::CryptoError;
+use crate::utils::secret_sharing::{lagrange_coefficients_at_zero, share};
+use crate::zkp::proofs::chaum_pedersen_dl_equality as dl_equality;
+use crate::zkp::ArgumentOfKnowledge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+/// Threshold ElGamal: the secret key is split into `n` Shamir shares so that any `t` share holders can decrypt
+/// together, while fewer learn nothing about the plaintext. Ciphertexts are ordinary ElGamal ciphertexts under the
+/// shared public key.
+///
+/// The key can be set up by a trusted dealer, either from a fresh key ([`ThresholdElGamal::keygen`]) or by splitting
+/// an existing one ([`ThresholdElGamal::split_secret_key`]), or without a dealer by running the distributed key
+/// generation of [`crate::secret_sharing::dkg`].
+///
+/// Each share holder publishes a decryption share $D_i = x_i c_1$ along with a Chaum-Pedersen proof that it used the
+/// same key share as in its published verification key $X_i = x_i G$, so that a combiner can discard bad shares.
+pub struct ThresholdElGamal<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+    pub threshold: usize,
+    pub participants: usize,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    /// Threshold parameters sharing the generator of existing ElGamal parameters, so that keys of that scheme can be
+    /// split between the parties.
+    pub fn new(
+        encryption_parameters: &super::Parameters<C>,
+        threshold: usize,
+        participants: usize,
+    ) -> Result<Self, CryptoError> {
+        if threshold == 0 || threshold > participants {
+            return Err(CryptoError::InvalidThresholdParameters);
+        }
+
+        Ok(Self {
+            generator: encryption_parameters.generator,
+            threshold,
+            participants,
+        })
+    }
+
+    /// Parameters of the underlying ElGamal scheme, used to encrypt under the shared public key.
+    pub fn encryption_parameters(&self) -> super::Parameters<C> {
+        super::Parameters {
+            generator: self.generator,
+        }
+    }
+}
+
+/// Share of the secret key held by the party with identifier `index`, in `1..=n`.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KeyShare<C: ProjectiveCurve> {
+    pub(crate) index: u64,
+    pub(crate) secret_share: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> KeyShare<C> {
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+/// Output of the dealer: the shared public key, the verification key $X_i = x_i G$ of every party (the key of
+/// party `i` being at position `i - 1`) and one key share per party.
+pub struct KeyGeneration<C: ProjectiveCurve> {
+    pub public_key: PublicKey<C>,
+    pub verification_keys: Vec<C::Affine>,
+    pub shares: Vec<KeyShare<C>>,
+}
+
+/// Decryption share $D_i = x_i c_1$ of party `index` with a proof that $\log_G(X_i) = \log_{c_1}(D_i)$.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DecryptionShare<C: ProjectiveCurve> {
+    pub index: u64,
+    pub share: C::Affine,
+    pub(crate) proof: DecryptionProof<C>,
+}
+
+impl<C: ProjectiveCurve> ThresholdElGamal<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Threshold ElGamal";
+
+    pub fn setup<R: Rng>(
+        rng: &mut R,
+        threshold: usize,
+        participants: usize,
+    ) -> Result<Parameters<C>, CryptoError> {
+        let encryption_parameters = super::Parameters {
+            generator: C::rand(rng).into_affine(),
+        };
+
+        Parameters::new(&encryption_parameters, threshold, participants)
+    }
+
+    /// Sample a secret key and deal it to the parties with a random polynomial of degree `t - 1`.
+    pub fn keygen<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+    ) -> Result<KeyGeneration<C>, CryptoError> {
+        let secret_key = C::ScalarField::rand(rng);
+
+        Self::split_secret_key(rng, pp, &secret_key)
+    }
+
+    /// Deal an existing secret key to the parties with a random polynomial of degree `t - 1`.
+    pub fn split_secret_key<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        secret_key: &SecretKey<C>,
+    ) -> Result<KeyGeneration<C>, CryptoError> {
+        let shares: Vec<KeyShare<C>> = share(rng, secret_key, pp.threshold, pp.participants)?
+            .into_iter()
+            .map(|share| KeyShare {
+                index: share.index,
+                secret_share: share.value,
+            })
+            .collect();
+
+        let verification_keys = C::batch_normalization_into_affine(
+            &shares
+                .iter()
+                .map(|share| pp.generator.mul(share.secret_share.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(KeyGeneration {
+            public_key: pp.generator.mul(secret_key.into_repr()).into_affine(),
+            verification_keys,
+            shares,
+        })
+    }
+
+    /// Compute the decryption share of `key_share` for `ciphertext` and prove that it is well formed.
+    pub fn decryption_share<R: Rng, D: Digest>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        key_share: &KeyShare<C>,
+        ciphertext: &Ciphertext<C>,
+    ) -> Result<DecryptionShare<C>, CryptoError> {
+        let verification_key = pp
+            .generator
+            .mul(key_share.secret_share.into_repr())
+            .into_affine();
+        let share = ciphertext
+            .0
+            .mul(key_share.secret_share.into_repr())
+            .into_affine();
+
+        let mut fs_rng =
+            Self::transcript::<D>(key_share.index, &verification_key, ciphertext, &share)?;
+
+        let parameters = dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+        let statement = dl_equality::Statement::new(&verification_key, &share);
+        let proof = dl_equality::DLEquality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &key_share.secret_share,
+            Self::PROTOCOL_NAME,
+            &mut fs_rng,
+        )?;
+
+        Ok(DecryptionShare {
+            index: key_share.index,
+            share,
+            proof,
+        })
+    }
+
+    /// Check a decryption share against the verification key of the party that produced it.
+    pub fn verify_decryption_share<D: Digest>(
+        pp: &Parameters<C>,
+        verification_key: &C::Affine,
+        ciphertext: &Ciphertext<C>,
+        decryption_share: &DecryptionShare<C>,
+    ) -> Result<(), CryptoError> {
+        verification_key.validate()?;
+        ciphertext.validate()?;
+
+        let mut fs_rng = Self::transcript::<D>(
+            decryption_share.index,
+            verification_key,
+            ciphertext,
+            &decryption_share.share,
+        )?;
+
+        let parameters = dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+        let statement = dl_equality::Statement::new(verification_key, &decryption_share.share);
+
+        dl_equality::DLEquality::verify(
+            &parameters,
+            &statement,
+            &decryption_share.proof,
+            Self::PROTOCOL_NAME,
+            &mut fs_rng,
+        )
+        .map_err(|_| {
+            CryptoError::ProofVerificationError(String::from("Threshold ElGamal Decryption Share"))
+        })
+    }
+
+    /// Recover the plaintext of `ciphertext` from decryption shares. Shares with an unknown or repeated index, or
+    /// with an invalid proof, are ignored; decryption fails if fewer than `t` valid shares remain.
+    pub fn combine<D: Digest>(
+        pp: &Parameters<C>,
+        verification_keys: &[C::Affine],
+        ciphertext: &Ciphertext<C>,
+        decryption_shares: &Vec<DecryptionShare<C>>,
+    ) -> Result<Plaintext<C>, CryptoError> {
+        let mut valid_shares: Vec<&DecryptionShare<C>> = Vec::with_capacity(pp.threshold);
+        for decryption_share in decryption_shares {
+            if valid_shares.len() == pp.threshold {
+                break;
+            }
+
+            let index = decryption_share.index;
+            if index == 0
+                || index as usize > verification_keys.len()
+                || valid_shares.iter().any(|share| share.index == index)
+            {
+                continue;
+            }
+
+            if Self::verify_decryption_share::<D>(
+                pp,
+                &verification_keys[index as usize - 1],
+                ciphertext,
+                decryption_share,
+            )
+            .is_ok()
+            {
+                valid_shares.push(decryption_share);
+            }
+        }
+
+        if valid_shares.len() < pp.threshold {
+            return Err(CryptoError::InsufficientValidShares);
+        }
+
+        Self::interpolate(ciphertext, &valid_shares)
+    }
+
+    /// Remove from `ciphertext` the shared secret interpolated in the exponent from already verified shares with
+    /// distinct indices.
+    pub(crate) fn interpolate(
+        ciphertext: &Ciphertext<C>,
+        decryption_shares: &[&DecryptionShare<C>],
+    ) -> Result<Plaintext<C>, CryptoError> {
+        let indices: Vec<u64> = decryption_shares.iter().map(|share| share.index).collect();
+        let shared_secret = decryption_shares
+            .iter()
+            .zip(lagrange_coefficients_at_zero::<C::ScalarField>(&indices)?)
+            .fold(C::zero(), |acc, (share, lambda)| {
+                acc + share.share.mul(lambda.into_repr())
+            });
+
+        Ok(Plaintext(
+            (ciphertext.1.into_projective() - shared_secret).into_affine(),
+        ))
+    }
+
+    fn transcript<D: Digest>(
+        index: u64,
+        verification_key: &C::Affine,
+        ciphertext: &Ciphertext<C>,
+        share: &C::Affine,
+    ) -> Result<FiatShamirRng<D>, CryptoError> {
+        Ok(FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            index,
+            verification_key,
+            ciphertext,
+            share
+        ]?))
+    }
+}
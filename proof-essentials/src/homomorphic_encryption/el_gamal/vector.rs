@@ -0,0 +1,163 @@
+use super::precomputed::PrecomputedKey;
+use super::{Ciphertext, ElGamal, Parameters, Plaintext, PublicKey, SecretKey};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::cfg_iter;
+use ark_std::io::{Read, Write};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A vector of ElGamal ciphertexts, such as a masked deck, with element-wise operations that run in parallel when
+/// the `parallel` feature is enabled.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CiphertextVector<C: ProjectiveCurve>(pub Vec<Ciphertext<C>>);
+
+impl<C: ProjectiveCurve> CiphertextVector<C> {
+    /// Encrypt every plaintext under `pk`, the i-th one with the i-th randomness. The generator and `pk` are turned
+    /// into fixed-base tables sized for the number of plaintexts.
+    pub fn encrypt(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        plaintexts: &[Plaintext<C>],
+        randomness: &[C::ScalarField],
+    ) -> Result<Self, CryptoError> {
+        check_length(plaintexts.len(), randomness.len())?;
+
+        let key = PrecomputedKey::for_scalars(pp, pk, plaintexts.len());
+        let ciphertexts = cfg_iter!(plaintexts)
+            .zip(cfg_iter!(randomness))
+            .map(|(m, r)| key.encrypt_projective(m, r))
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_projective(&ciphertexts))
+    }
+
+    /// Decrypt every ciphertext with `sk`.
+    pub fn decrypt(
+        &self,
+        pp: &Parameters<C>,
+        sk: &SecretKey<C>,
+    ) -> Result<Vec<Plaintext<C>>, CryptoError> {
+        cfg_iter!(self.0)
+            .map(|c| ElGamal::<C>::decrypt(pp, sk, c))
+            .collect()
+    }
+
+    /// Re-randomize every ciphertext under `pk`, the i-th one with the i-th randomness.
+    pub fn rerandomize(
+        &self,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        randomness: &[C::ScalarField],
+    ) -> Result<Self, CryptoError> {
+        check_length(self.0.len(), randomness.len())?;
+
+        let key = PrecomputedKey::for_scalars(pp, pk, self.0.len());
+        let ciphertexts = cfg_iter!(self.0)
+            .zip(cfg_iter!(randomness))
+            .map(|(c, r)| key.rerandomize_projective(c, r))
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_projective(&ciphertexts))
+    }
+
+    /// Multiply every ciphertext by the same scalar.
+    pub fn scale(&self, scalar: &C::ScalarField) -> Self {
+        let scalar = scalar.into_repr();
+        let ciphertexts = cfg_iter!(self.0)
+            .map(|c| (c.0.mul(scalar), c.1.mul(scalar)))
+            .collect::<Vec<_>>();
+
+        Self::from_projective(&ciphertexts)
+    }
+
+    /// Multiply the i-th ciphertext by the i-th scalar.
+    pub fn scale_elementwise(&self, scalars: &[C::ScalarField]) -> Result<Self, CryptoError> {
+        check_length(self.0.len(), scalars.len())?;
+
+        let ciphertexts = cfg_iter!(self.0)
+            .zip(cfg_iter!(scalars))
+            .map(|(c, x)| (c.0.mul(x.into_repr()), c.1.mul(x.into_repr())))
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_projective(&ciphertexts))
+    }
+
+    /// Add the ciphertexts of both vectors element-wise.
+    pub fn add(&self, other: &Self) -> Result<Self, CryptoError> {
+        check_length(self.0.len(), other.0.len())?;
+
+        let ciphertexts = cfg_iter!(self.0)
+            .zip(cfg_iter!(other.0))
+            .map(|(a, b)| {
+                (
+                    a.0.into_projective() + b.0.into_projective(),
+                    a.1.into_projective() + b.1.into_projective(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_projective(&ciphertexts))
+    }
+
+    /// Ciphertexts from their components in projective coordinates, all brought to affine form with a single field
+    /// inversion instead of one per point.
+    pub fn from_projective(ciphertexts: &[(C, C)]) -> Self {
+        let points = ciphertexts
+            .iter()
+            .flat_map(|&(c1, c2)| [c1, c2])
+            .collect::<Vec<_>>();
+
+        Self(
+            C::batch_normalization_into_affine(&points)
+                .chunks(2)
+                .map(|c| Ciphertext(c[0], c[1]))
+                .collect(),
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn into_inner(self) -> Vec<Ciphertext<C>> {
+        self.0
+    }
+}
+
+impl<C: ProjectiveCurve> From<Vec<Ciphertext<C>>> for CiphertextVector<C> {
+    fn from(ciphertexts: Vec<Ciphertext<C>>) -> Self {
+        Self(ciphertexts)
+    }
+}
+
+impl<C: ProjectiveCurve> FromIterator<Ciphertext<C>> for CiphertextVector<C> {
+    fn from_iter<I: IntoIterator<Item = Ciphertext<C>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<C: ProjectiveCurve> IntoIterator for CiphertextVector<C> {
+    type Item = Ciphertext<C>;
+    type IntoIter = std::vec::IntoIter<Ciphertext<C>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+fn check_length(left: usize, right: usize) -> Result<(), CryptoError> {
+    if left != right {
+        return Err(CryptoError::CiphertextVectorLengthError(left, right));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,326 @@
+use super::exponential::DiscreteLogTable;
+use super::{Ciphertext, ElGamal, PublicKey, SecretKey};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::vector_commitment::pedersen;
+use crate::zkp::proofs::linear_relation::{self, Equation, LinearRelation, Term};
+use crate::zkp::proofs::range::{self, AggregatedRangeProof};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::ArgumentOfKnowledge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, BigInteger, Field, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// Verifiable encryption of a discrete logarithm: the prover encrypts the witness $x$ of a public statement
+/// $Y = xB$ under the ElGamal key of a recipient and proves that the recipient can recover $x$, without revealing it.
+///
+/// The witness is split into $k$-bit chunks $x = \sum_i 2^{ki} m_i$, each encrypted in the exponent as
+/// $c_i = (r_i G, m_i G + r_i pk)$. A linear relation proof shows that every ciphertext is well formed and that
+/// $Y = \sum_i m_i 2^{ki} B$, and an aggregated range proof, reading each $c_{i,2}$ as a Pedersen commitment to $m_i$
+/// with bases $(G, pk)$, shows that every chunk is in $[0, 2^k)$. The recipient decrypts each $m_i G$ and finds
+/// $m_i$ by a discrete logarithm search over $2^k$ values.
+pub struct VerifiableEncryption<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// Public parameters: the ElGamal generator, the size of the chunks and the generators of the range proof.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+    pub(crate) chunk_bits: usize,
+    pub(crate) chunks: usize,
+    pub(crate) g: Vec<C::Affine>,
+    pub(crate) h: Vec<C::Affine>,
+    pub(crate) u: C::Affine,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    /// Parameters of the underlying ElGamal scheme, used to generate the recipient's keys.
+    pub fn encryption_parameters(&self) -> super::Parameters<C> {
+        super::Parameters {
+            generator: self.generator,
+        }
+    }
+
+    pub fn chunk_bits(&self) -> usize {
+        self.chunk_bits
+    }
+
+    /// Number of ciphertexts in an encryption.
+    pub fn chunks(&self) -> usize {
+        self.chunks
+    }
+}
+
+/// Public statement $Y = xB$ whose witness is encrypted.
+#[derive(Copy, Clone)]
+pub struct Statement<C: ProjectiveCurve> {
+    pub base: C::Affine,
+    pub image: C::Affine,
+}
+
+impl<C: ProjectiveCurve> Statement<C> {
+    pub fn new(base: &C::Affine, image: &C::Affine) -> Self {
+        Self {
+            base: *base,
+            image: *image,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: ProjectiveCurve> {
+    pub(crate) relation_proof: linear_relation::proof::Proof<C>,
+    pub(crate) range_proof: range::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> VerifiableEncryption<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Verifiable Encryption of a Discrete Logarithm";
+
+    /// Parameters for chunks of `chunk_bits` bits. `chunk_bits` must be a power of two of at most 32, as the
+    /// recipient solves discrete logarithms in a range of $2^k$ values.
+    pub fn setup<R: Rng>(rng: &mut R, chunk_bits: usize) -> Result<Parameters<C>, CryptoError> {
+        if !chunk_bits.is_power_of_two() || chunk_bits > 32 {
+            return Err(CryptoError::InvalidRangeParameters);
+        }
+
+        let field_bits = C::ScalarField::size_in_bits();
+        let chunks = field_bits.div_ceil(chunk_bits).next_power_of_two();
+
+        let mut generators = |len: usize| {
+            C::batch_normalization_into_affine(&(0..len).map(|_| C::rand(rng)).collect::<Vec<_>>())
+        };
+
+        Ok(Parameters {
+            generator: generators(1)[0],
+            chunk_bits,
+            chunks,
+            g: generators(chunk_bits * chunks),
+            h: generators(chunk_bits * chunks),
+            u: generators(1)[0],
+        })
+    }
+
+    /// Encrypt the witness `x` of `statement` under `pk` and prove that the ciphertexts decrypt to it.
+    pub fn encrypt_and_prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        statement: &Statement<C>,
+        x: &C::ScalarField,
+        fs_rng: &mut T,
+    ) -> Result<(Vec<Ciphertext<C>>, Proof<C>), CryptoError> {
+        let bits = x.into_repr().to_bits_le();
+        let chunks: Vec<C::ScalarField> = (0..pp.chunks)
+            .map(|i| {
+                let chunk = bits
+                    .iter()
+                    .skip(i * pp.chunk_bits)
+                    .take(pp.chunk_bits)
+                    .rev()
+                    .fold(0u64, |acc, &bit| (acc << 1) | bit as u64);
+                C::ScalarField::from(chunk)
+            })
+            .collect();
+        let randomness: Vec<C::ScalarField> =
+            (0..pp.chunks).map(|_| C::ScalarField::rand(rng)).collect();
+
+        let encryption_parameters = pp.encryption_parameters();
+        let ciphertexts = chunks
+            .iter()
+            .zip(randomness.iter())
+            .map(|(m, r)| {
+                let plaintext = super::Plaintext(pp.generator.mul(m.into_repr()).into_affine());
+                ElGamal::<C>::encrypt(&encryption_parameters, pk, &plaintext, r)
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            pk,
+            statement.base,
+            statement.image,
+            ciphertexts
+        ]?);
+
+        let (relation, images) = Self::relation(pp, pk, statement, &ciphertexts)?;
+        let relation_proof = LinearRelation::prove(
+            rng,
+            &relation,
+            &images,
+            &[chunks.clone(), randomness.clone()].concat(),
+            Self::PROTOCOL_NAME,
+            fs_rng,
+        )?;
+
+        let (range_parameters, commitments) = Self::range_statement(pp, pk, &ciphertexts)?;
+        let range_witness = chunks
+            .iter()
+            .zip(randomness.iter())
+            .map(|(&m, &r)| range::Witness::new(m, r))
+            .collect();
+        let range_proof = AggregatedRangeProof::prove(
+            rng,
+            &range_parameters,
+            &commitments,
+            &range_witness,
+            Self::PROTOCOL_NAME,
+            fs_rng,
+        )?;
+
+        Ok((
+            ciphertexts,
+            Proof {
+                relation_proof,
+                range_proof,
+            },
+        ))
+    }
+
+    /// Verify that `ciphertexts` decrypt, under the secret key matching `pk`, to the witness of `statement`.
+    pub fn verify<T: Transcript>(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        statement: &Statement<C>,
+        ciphertexts: &Vec<Ciphertext<C>>,
+        proof: &Proof<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        if ciphertexts.len() != pp.chunks {
+            return Err(CryptoError::InvalidVerifiableEncryption);
+        }
+
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            pk,
+            statement.base,
+            statement.image,
+            ciphertexts
+        ]?);
+
+        let (relation, images) = Self::relation(pp, pk, statement, ciphertexts)?;
+        let (range_parameters, commitments) = Self::range_statement(pp, pk, ciphertexts)?;
+
+        LinearRelation::verify(
+            &relation,
+            &images,
+            &proof.relation_proof,
+            Self::PROTOCOL_NAME,
+            fs_rng,
+        )
+        .and_then(|_| {
+            AggregatedRangeProof::verify(
+                &range_parameters,
+                &commitments,
+                &proof.range_proof,
+                Self::PROTOCOL_NAME,
+                fs_rng,
+            )
+        })
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("Verifiable Encryption")))
+    }
+
+    /// Recover the witness of `statement` from `ciphertexts` with the recipient's secret key.
+    pub fn decrypt(
+        pp: &Parameters<C>,
+        sk: &SecretKey<C>,
+        statement: &Statement<C>,
+        ciphertexts: &[Ciphertext<C>],
+    ) -> Result<C::ScalarField, CryptoError> {
+        if ciphertexts.len() != pp.chunks {
+            return Err(CryptoError::InvalidVerifiableEncryption);
+        }
+
+        let table = DiscreteLogTable::<C>::new(&pp.generator, 1u64 << pp.chunk_bits)?;
+
+        let encryption_parameters = pp.encryption_parameters();
+        let shift = C::ScalarField::from(2u64).pow([pp.chunk_bits as u64]);
+
+        let mut x = C::ScalarField::zero();
+        for ciphertext in ciphertexts.iter().rev() {
+            let plaintext = ElGamal::<C>::decrypt(&encryption_parameters, sk, ciphertext)?;
+            let chunk = table
+                .solve(&plaintext.0)
+                .ok_or(CryptoError::InvalidVerifiableEncryption)?;
+
+            x = x * shift + C::ScalarField::from(chunk);
+        }
+
+        if statement.base.mul(x.into_repr()) != statement.image.into_projective() {
+            return Err(CryptoError::InvalidVerifiableEncryption);
+        }
+
+        Ok(x)
+    }
+
+    /// The linear relation over the chunks $m_i$ and the randomness $r_i$:
+    /// $c_{i,1} = r_i G \wedge c_{i,2} = m_i G + r_i pk$ for every $i$, and $Y = \sum_i m_i 2^{ki} B$.
+    fn relation(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        statement: &Statement<C>,
+        ciphertexts: &[Ciphertext<C>],
+    ) -> Result<
+        (
+            linear_relation::Parameters<C>,
+            linear_relation::Statement<C>,
+        ),
+        CryptoError,
+    > {
+        let n = pp.chunks;
+
+        let mut equations = Vec::with_capacity(2 * n + 1);
+        let mut images = Vec::with_capacity(2 * n + 1);
+        for (i, ciphertext) in ciphertexts.iter().enumerate() {
+            equations.push(Equation::new(vec![Term::new(n + i, pp.generator)]));
+            equations.push(Equation::new(vec![
+                Term::new(i, pp.generator),
+                Term::new(n + i, *pk),
+            ]));
+            images.push(ciphertext.0);
+            images.push(ciphertext.1);
+        }
+
+        let shift = C::ScalarField::from(2u64).pow([pp.chunk_bits as u64]);
+        let mut weight = C::ScalarField::from(1u64);
+        let mut shifted_bases = Vec::with_capacity(n);
+        for _ in 0..n {
+            shifted_bases.push(statement.base.mul(weight.into_repr()));
+            weight *= shift;
+        }
+        let shifted_bases = C::batch_normalization_into_affine(&shifted_bases);
+
+        equations.push(Equation::new(
+            shifted_bases
+                .into_iter()
+                .enumerate()
+                .map(|(i, base)| Term::new(i, base))
+                .collect(),
+        ));
+        images.push(statement.image);
+
+        Ok((linear_relation::Parameters::new(2 * n, equations)?, images))
+    }
+
+    /// The second components of the ciphertexts, read as Pedersen commitments to the chunks with bases $(G, pk)$.
+    fn range_statement(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        ciphertexts: &[Ciphertext<C>],
+    ) -> Result<(range::Parameters<C>, range::AggregatedStatement<C>), CryptoError> {
+        let commit_key = pedersen::CommitKey::new(vec![pp.generator], *pk);
+        let parameters = range::Parameters::new(pp.chunk_bits, &commit_key, &pp.g, &pp.h, pp.u)?;
+
+        let commitments = ciphertexts
+            .iter()
+            .map(|ciphertext| pedersen::Commitment(ciphertext.1))
+            .collect();
+
+        Ok((parameters, commitments))
+    }
+}
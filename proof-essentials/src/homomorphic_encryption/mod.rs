@@ -1,23 +1,27 @@
 use crate::error::CryptoError;
-use ark_ff::{Field, ToBytes, Zero};
+use ark_ff::{ToBytes, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::rand::Rng;
 use std::iter::Sum;
 use std::ops;
 
 pub mod el_gamal;
+pub mod paillier;
 
 /// Trait defining the types and functions needed for an additively homomorphic encryption scheme.
-/// The scheme is defined with respect to a finite field `F` for which scalar multiplication is preserved.
-pub trait HomomorphicEncryptionScheme<Scalar: Field> {
-    type Parameters: CanonicalSerialize + CanonicalDeserialize;
-    type PublicKey: CanonicalSerialize + CanonicalDeserialize + ToBytes;
+/// The scheme is defined with respect to a ring of scalars `Scalar` for which scalar multiplication is preserved:
+/// the scalar field of the group for curve-based schemes, or integers for schemes such as Paillier.
+pub trait HomomorphicEncryptionScheme<Scalar> {
+    type Parameters: CanonicalSerialize + CanonicalDeserialize + Send + Sync;
+    type PublicKey: CanonicalSerialize + CanonicalDeserialize + ToBytes + Send + Sync;
     type SecretKey: CanonicalSerialize + CanonicalDeserialize;
     type Generator: Copy
         + ops::Add
         + ops::Mul<Scalar, Output = Self::Plaintext>
         + CanonicalSerialize
-        + CanonicalDeserialize;
+        + CanonicalDeserialize
+        + Send
+        + Sync;
 
     /// Represent a plaintext from a generic homomorphic encryption scheme. To manifest the homomorphic
     /// property of the scheme, we require that some arithmetic operations (add and multiply by scalar) are implemented.
@@ -26,7 +30,9 @@ pub trait HomomorphicEncryptionScheme<Scalar: Field> {
         + ops::Mul<Scalar, Output = Self::Plaintext>
         + CanonicalSerialize
         + CanonicalDeserialize
-        + Zero;
+        + Zero
+        + Send
+        + Sync;
 
     /// Represent a ciphertext from a generic homomorphic encryption scheme. To manifest the homomorphic
     /// property of the scheme, we require that some arithmetic operations (add and multiply by scalar) are implemented.
@@ -38,7 +44,9 @@ pub trait HomomorphicEncryptionScheme<Scalar: Field> {
         + CanonicalDeserialize
         + Sum
         + Zero
-        + ToBytes;
+        + ToBytes
+        + Send
+        + Sync;
 
     /// Generate the scheme's parameters.
     fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, CryptoError>;
@@ -66,4 +74,41 @@ pub trait HomomorphicEncryptionScheme<Scalar: Field> {
         sk: &Self::SecretKey,
         ciphertext: &Self::Ciphertext,
     ) -> Result<Self::Plaintext, CryptoError>;
+
+    /// Check a public key received from another party before using it. By default every key is accepted.
+    fn validate_public_key(_pk: &Self::PublicKey) -> Result<(), CryptoError> {
+        Ok(())
+    }
+
+    /// Check a ciphertext received from another party before using it under the public key `pk`. By default every
+    /// ciphertext is accepted.
+    fn validate_ciphertext(
+        _pk: &Self::PublicKey,
+        _ciphertext: &Self::Ciphertext,
+    ) -> Result<(), CryptoError> {
+        Ok(())
+    }
+
+    /// Compute $\sum_i s_i C_i$ for the `scalars` $s_i$ and the `ciphertexts` $C_i$. The default adds up the
+    /// products one by one; curve-based schemes override it with multi-scalar multiplications.
+    fn multi_scalar_mul(
+        scalars: &[Scalar],
+        ciphertexts: &[Self::Ciphertext],
+    ) -> Result<Self::Ciphertext, CryptoError>
+    where
+        Scalar: Clone,
+    {
+        if scalars.len() != ciphertexts.len() {
+            return Err(CryptoError::DotProductLengthError(
+                scalars.len(),
+                ciphertexts.len(),
+            ));
+        }
+
+        Ok(ciphertexts
+            .iter()
+            .zip(scalars.iter())
+            .map(|(&ciphertext, scalar)| ciphertext * scalar.clone())
+            .sum())
+    }
 }
@@ -0,0 +1,121 @@
+use super::{Binding, Ciphertext, Integer, Plaintext};
+
+use ark_ff::Zero;
+use crypto_bigint::U4096;
+use std::ops::{Add, Mul};
+
+/// Gives an invalid plaintext where [`Plaintext::checked_add`] fails.
+impl Add for Plaintext {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(&rhs).unwrap_or(Self::INVALID)
+    }
+}
+
+/// Gives an invalid plaintext where [`Plaintext::checked_mul`] fails.
+impl Mul<Integer> for Plaintext {
+    type Output = Self;
+
+    fn mul(self, x: Integer) -> Self {
+        self.checked_mul(&x).unwrap_or(Self::INVALID)
+    }
+}
+
+/// Invalid plaintexts are equal to no plaintext.
+impl PartialEq for Plaintext {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.binding, other.binding) {
+            (Binding::Invalid, _) | (_, Binding::Invalid) => false,
+            (Binding::Bound(a), Binding::Bound(b)) => a == b && self.value == other.value,
+            (Binding::Bound(params), Binding::Unbound)
+            | (Binding::Unbound, Binding::Bound(params)) => {
+                self.reduce(&params) == other.reduce(&params)
+            }
+            (Binding::Unbound, Binding::Unbound) => self.value == other.value,
+        }
+    }
+}
+
+impl Zero for Plaintext {
+    fn zero() -> Self {
+        Self::new(Integer::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_valid() && self.value == Integer::ZERO
+    }
+}
+
+/// Adding the plaintexts multiplies the ciphertexts. An unbound ciphertext is bound to the key of the other one;
+/// ciphertexts that cannot be combined, under different keys or both unbound, give an invalid ciphertext.
+impl Add for Ciphertext {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        if self.is_zero() {
+            return rhs;
+        }
+        if rhs.is_zero() {
+            return self;
+        }
+
+        let product = match (self.binding, rhs.binding) {
+            (Binding::Bound(params), _) => rhs.bind_to(&params).map(|rhs| (self, rhs)),
+            (_, Binding::Bound(params)) => self.bind_to(&params).map(|lhs| (lhs, rhs)),
+            _ => None,
+        }
+        .and_then(|(lhs, rhs)| Some(lhs.residue()? * rhs.residue()?));
+
+        product.map_or(Self::INVALID, Self::bound)
+    }
+}
+
+/// Multiplying the plaintext by a scalar raises the ciphertext to its power. Ciphertexts other than the identity
+/// must be bound to a key.
+impl Mul<Integer> for Ciphertext {
+    type Output = Self;
+
+    fn mul(self, x: Integer) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+
+        self.residue()
+            .map_or(Self::INVALID, |c| Self::bound(c.pow(&x)))
+    }
+}
+
+impl std::iter::Sum for Ciphertext {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+/// Invalid ciphertexts are equal to no ciphertext, and ciphertexts bound to different keys differ.
+impl PartialEq for Ciphertext {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.binding, other.binding) {
+            (Binding::Invalid, _) | (_, Binding::Invalid) => false,
+            (Binding::Bound(a), Binding::Bound(b)) => a == b && self.value == other.value,
+            _ => self.value == other.value,
+        }
+    }
+}
+
+impl Zero for Ciphertext {
+    fn zero() -> Self {
+        Self {
+            value: U4096::ONE,
+            binding: Binding::Unbound,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_valid() && self.value == U4096::ONE
+    }
+}
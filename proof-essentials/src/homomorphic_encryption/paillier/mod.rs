@@ -0,0 +1,572 @@
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+
+use ark_ff::ToBytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    rand::{CryptoRng, Rng, RngCore},
+};
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::{CheckedAdd, CheckedMul, Encoding, Integer as _, Uint, U1024, U2048, U4096};
+use crypto_primes::generate_prime_with_rng;
+
+pub mod arithmetic_definitions;
+mod tests;
+
+/// Integers used as plaintexts, scalars and encryption randomness, large enough for a 2048-bit modulus.
+pub type Integer = U2048;
+
+/// Paillier encryption (Paillier, EUROCRYPT 1999), additively homomorphic over the integers modulo an RSA modulus
+/// $N = pq$ whose factorization is the secret key.
+///
+/// With the generator $g = N + 1$, a message $m$ is encrypted with randomness $r \in \mathbb{Z}_N^*$ as
+/// $c = (1 + mN) \cdot r^N \bmod N^2$. The product of two ciphertexts encrypts the sum of their plaintexts, and
+/// raising a ciphertext to the power $k$ multiplies its plaintext by $k$. The holder of $\varphi(N)$ decrypts with
+/// $m = L(c^{\varphi(N)} \bmod N^2) \cdot \varphi(N)^{-1} \bmod N$, where $L(x) = (x - 1) / N$.
+///
+/// Plaintexts and ciphertexts carry the modulus of the key they belong to. [`Plaintext::new`] and the identity
+/// elements are not bound to a key yet: they are reduced modulo $N$ when combined with bound values, and behave as
+/// 2048-bit unsigned integers when combined with each other. [`Plaintext::checked_add`] and
+/// [`Plaintext::checked_mul`] fail on plaintexts modulo different moduli and on overflow, where the operators give
+/// an invalid plaintext that encryption rejects.
+///
+/// Ciphertexts are serialized without their modulus, and deserialized unbound: they must be bound to the public key
+/// they are meant for with [`Ciphertext::bind`], which [`Paillier::validate_ciphertext`] and decryption do, before
+/// any arithmetic. Combining ciphertexts under different keys, or unbound ciphertexts with each other, gives an
+/// invalid ciphertext, which binding, validation, decryption and serialization reject with an error.
+pub struct Paillier;
+
+/// Size in bits of the modulus $N$.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters {
+    pub(crate) modulus_bits: u64,
+}
+
+impl Parameters {
+    /// Smallest supported modulus size, only suitable for tests.
+    pub const MIN_MODULUS_BITS: u64 = 256;
+    pub const MAX_MODULUS_BITS: u64 = Integer::BITS as u64;
+
+    /// Parameters for a modulus of `modulus_bits` bits, which must be even and at most 2048.
+    pub fn new(modulus_bits: u64) -> Result<Self, CryptoError> {
+        if !modulus_bits.is_multiple_of(2)
+            || !(Self::MIN_MODULUS_BITS..=Self::MAX_MODULUS_BITS).contains(&modulus_bits)
+        {
+            return Err(CryptoError::InvalidPaillierParameters);
+        }
+
+        Ok(Self { modulus_bits })
+    }
+
+    pub fn modulus_bits(&self) -> u64 {
+        self.modulus_bits
+    }
+}
+
+/// The modulus $N$, with the precomputations for arithmetic modulo $N$ and $N^2$.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PublicKey {
+    pub(crate) modulus: Integer,
+    pub(crate) params: DynResidueParams<{ Integer::LIMBS }>,
+    pub(crate) square_params: DynResidueParams<{ U4096::LIMBS }>,
+}
+
+impl PublicKey {
+    /// Public key with the odd modulus `modulus`.
+    pub fn new(modulus: &Integer) -> Result<Self, CryptoError> {
+        if !bool::from(modulus.is_odd()) || *modulus == Integer::ONE {
+            return Err(CryptoError::InvalidPaillierParameters);
+        }
+
+        Ok(Self {
+            modulus: *modulus,
+            params: DynResidueParams::new(modulus),
+            square_params: DynResidueParams::new(&wide_product(modulus, modulus)),
+        })
+    }
+
+    pub fn modulus(&self) -> &Integer {
+        &self.modulus
+    }
+}
+
+/// The public key with $\varphi(N)$ and its inverse modulo $N$.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SecretKey {
+    pub(crate) public_key: PublicKey,
+    pub(crate) phi: Integer,
+    pub(crate) phi_inverse: Integer,
+}
+
+impl SecretKey {
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+}
+
+/// An integer, reduced modulo $N$ once bound to a key.
+#[derive(Copy, Clone, Debug)]
+pub struct Plaintext {
+    pub(crate) value: Integer,
+    pub(crate) binding: Binding<DynResidueParams<{ Integer::LIMBS }>>,
+}
+
+impl Plaintext {
+    pub(crate) const INVALID: Self = Self {
+        value: Integer::ZERO,
+        binding: Binding::Invalid,
+    };
+
+    /// Plaintext of `value`, not yet bound to a key.
+    pub fn new(value: Integer) -> Self {
+        Self {
+            value,
+            binding: Binding::Unbound,
+        }
+    }
+
+    /// Whether the plaintext is not the invalid result of an operator that [`Plaintext::checked_add`] or
+    /// [`Plaintext::checked_mul`] would have refused.
+    pub fn is_valid(&self) -> bool {
+        self.binding != Binding::Invalid
+    }
+
+    /// Add two plaintexts, modulo $N$ if either is bound to a key. Fails if they are bound to different keys, if
+    /// either is invalid, or if unbound plaintexts overflow.
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, CryptoError> {
+        let params = match (self.binding, rhs.binding) {
+            (Binding::Invalid, _) | (_, Binding::Invalid) => None,
+            (Binding::Bound(a), Binding::Bound(b)) if a != b => None,
+            (Binding::Bound(params), _) | (_, Binding::Bound(params)) => Some(Some(params)),
+            (Binding::Unbound, Binding::Unbound) => Some(None),
+        }
+        .ok_or(CryptoError::InvalidPaillierPlaintext)?;
+
+        match params {
+            Some(params) => {
+                let sum =
+                    DynResidue::new(&self.value, params) + DynResidue::new(&rhs.value, params);
+
+                Ok(Self {
+                    value: sum.retrieve(),
+                    binding: Binding::Bound(params),
+                })
+            }
+            None => Option::from(self.value.checked_add(&rhs.value))
+                .map(Self::new)
+                .ok_or(CryptoError::InvalidPaillierPlaintext),
+        }
+    }
+
+    /// Multiply the plaintext by `x`, modulo $N$ if it is bound to a key. Fails if the plaintext is invalid, or if
+    /// it is unbound and the product overflows.
+    pub fn checked_mul(&self, x: &Integer) -> Result<Self, CryptoError> {
+        match self.binding {
+            Binding::Bound(params) => {
+                let product = DynResidue::new(&self.value, params) * DynResidue::new(x, params);
+
+                Ok(Self {
+                    value: product.retrieve(),
+                    binding: Binding::Bound(params),
+                })
+            }
+            Binding::Unbound => Option::from(self.value.checked_mul(x))
+                .map(Self::new)
+                .ok_or(CryptoError::InvalidPaillierPlaintext),
+            Binding::Invalid => Err(CryptoError::InvalidPaillierPlaintext),
+        }
+    }
+
+    /// The integer value, reduced modulo $N$ if the plaintext is bound to a key.
+    pub fn value(&self) -> Integer {
+        self.value
+    }
+
+    /// The value of the plaintext modulo the modulus of `params`.
+    pub(crate) fn reduce(&self, params: &DynResidueParams<{ Integer::LIMBS }>) -> Integer {
+        DynResidue::new(&self.value, *params).retrieve()
+    }
+}
+
+pub type Generator = Plaintext;
+
+/// Key a value is reduced by, if any.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Binding<P> {
+    Unbound,
+    Bound(P),
+    /// Result of an operation between values that cannot be combined.
+    Invalid,
+}
+
+/// An element of $\mathbb{Z}_{N^2}^*$. The identity, an encryption of zero under any key, is not bound to a key,
+/// and neither are deserialized ciphertexts until they are bound with [`Ciphertext::bind`].
+#[derive(Copy, Clone, Debug)]
+pub struct Ciphertext {
+    pub(crate) value: U4096,
+    pub(crate) binding: Binding<DynResidueParams<{ U4096::LIMBS }>>,
+}
+
+impl Ciphertext {
+    pub(crate) const INVALID: Self = Self {
+        value: U4096::ZERO,
+        binding: Binding::Invalid,
+    };
+
+    pub(crate) fn bound(residue: DynResidue<{ U4096::LIMBS }>) -> Self {
+        Self {
+            value: residue.retrieve(),
+            binding: Binding::Bound(*residue.params()),
+        }
+    }
+
+    /// The ciphertext as an integer modulo $N^2$.
+    pub fn value(&self) -> U4096 {
+        self.value
+    }
+
+    /// Whether the ciphertext is not the invalid result of combining ciphertexts that cannot be combined.
+    pub fn is_valid(&self) -> bool {
+        self.binding != Binding::Invalid
+    }
+
+    /// Bind the ciphertext to `pk`, checking that it is an element of $\mathbb{Z}_{N^2}^*$ for the modulus $N$ of
+    /// `pk`. Fails if the ciphertext is invalid, bound to another key, or not a unit modulo $N^2$.
+    pub fn bind(&self, pk: &PublicKey) -> Result<Self, CryptoError> {
+        self.bind_to(&pk.square_params)
+            .ok_or(CryptoError::InvalidPaillierCiphertext)
+    }
+
+    /// The ciphertext as a residue modulo the modulus of `params`, if it belongs there.
+    pub(crate) fn bind_to(&self, params: &DynResidueParams<{ U4096::LIMBS }>) -> Option<Self> {
+        match self.binding {
+            Binding::Bound(bound) if bound == *params => Some(*self),
+            Binding::Unbound if is_unit(&self.value, params.modulus()) => {
+                Some(Self::bound(DynResidue::new(&self.value, *params)))
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn residue(&self) -> Option<DynResidue<{ U4096::LIMBS }>> {
+        match self.binding {
+            Binding::Bound(params) => Some(DynResidue::new(&self.value, params)),
+            _ => None,
+        }
+    }
+}
+
+impl HomomorphicEncryptionScheme<Integer> for Paillier {
+    type Parameters = Parameters;
+    type Generator = Generator;
+    type PublicKey = PublicKey;
+    type SecretKey = SecretKey;
+    type Plaintext = Plaintext;
+    type Ciphertext = Ciphertext;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, CryptoError> {
+        Parameters::new(Parameters::MAX_MODULUS_BITS)
+    }
+
+    fn generator<R: Rng>(_rng: &mut R) -> Result<Self::Generator, CryptoError> {
+        Ok(Plaintext::new(Integer::ONE))
+    }
+
+    fn keygen<R: Rng>(
+        pp: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), CryptoError> {
+        let prime_bits = Some(pp.modulus_bits as usize / 2);
+        let mut rng = CallerRng(rng);
+
+        let (p, q) = loop {
+            let p: U1024 = generate_prime_with_rng(&mut rng, prime_bits);
+            let q: U1024 = generate_prime_with_rng(&mut rng, prime_bits);
+            if p != q {
+                break (p, q);
+            }
+        };
+
+        let modulus = wide_product(&p.resize(), &q.resize()).resize();
+        let phi = wide_product(
+            &p.wrapping_sub(&U1024::ONE).resize(),
+            &q.wrapping_sub(&U1024::ONE).resize(),
+        )
+        .resize();
+
+        let (phi_inverse, invertible) = phi.inv_odd_mod(&modulus);
+        if !bool::from(invertible) {
+            return Err(CryptoError::InvalidPaillierParameters);
+        }
+
+        let public_key = PublicKey::new(&modulus)?;
+        let secret_key = SecretKey {
+            public_key,
+            phi,
+            phi_inverse,
+        };
+
+        Ok((public_key, secret_key))
+    }
+
+    /// Accept moduli of at least [`Parameters::MIN_MODULUS_BITS`] bits, rounded down by one for the product of two
+    /// primes of half that size.
+    fn validate_public_key(pk: &Self::PublicKey) -> Result<(), CryptoError> {
+        if (pk.modulus.bits() as u64) < Parameters::MIN_MODULUS_BITS - 1 {
+            return Err(CryptoError::InvalidPaillierParameters);
+        }
+
+        Ok(())
+    }
+
+    /// Accept the ciphertexts that bind to `pk`, see [`Ciphertext::bind`].
+    fn validate_ciphertext(
+        pk: &Self::PublicKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<(), CryptoError> {
+        ciphertext.bind(pk).map(|_| ())
+    }
+
+    fn encrypt(
+        _pp: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &Self::Plaintext,
+        r: &Integer,
+    ) -> Result<Self::Ciphertext, CryptoError> {
+        // the randomness must be invertible modulo N
+        let r = DynResidue::new(r, pk.params).retrieve();
+        let (_, invertible) = r.inv_odd_mod(&pk.modulus);
+        if !bool::from(invertible) {
+            return Err(CryptoError::InvalidPaillierRandomness);
+        }
+
+        // the message must be valid, and bound to this key if bound to any
+        match message.binding {
+            Binding::Bound(params) if params == pk.params => {}
+            Binding::Unbound => {}
+            _ => return Err(CryptoError::InvalidPaillierPlaintext),
+        }
+
+        // compute g^m = 1 + mN, which is already reduced modulo N^2
+        let m = message.reduce(&pk.params);
+        let g_m = U4096::ONE.wrapping_add(&wide_product(&m, &pk.modulus));
+
+        // compute c = g^m * r^N
+        let mask = DynResidue::new(&r.resize(), pk.square_params).pow(&pk.modulus);
+
+        Ok(Ciphertext::bound(
+            DynResidue::new(&g_m, pk.square_params) * mask,
+        ))
+    }
+
+    fn decrypt(
+        _pp: &Self::Parameters,
+        sk: &Self::SecretKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Self::Plaintext, CryptoError> {
+        let pk = &sk.public_key;
+        let ciphertext = ciphertext.bind(pk)?;
+
+        // compute L(c^phi mod N^2)
+        let x = ciphertext
+            .residue()
+            .map_or(U4096::ONE, |c| c.pow(&sk.phi).retrieve());
+        let l: Integer = x
+            .wrapping_sub(&U4096::ONE)
+            .wrapping_div(&pk.modulus.resize())
+            .resize();
+
+        // compute m = L(c^phi mod N^2) * phi^-1 mod N
+        let m = DynResidue::new(&l, pk.params) * DynResidue::new(&sk.phi_inverse, pk.params);
+
+        Ok(Plaintext {
+            value: m.retrieve(),
+            binding: Binding::Bound(pk.params),
+        })
+    }
+}
+
+/// Product of two integers, which does not overflow.
+fn wide_product(a: &Integer, b: &Integer) -> U4096 {
+    a.resize::<{ U4096::LIMBS }>()
+        .wrapping_mul(&b.resize::<{ U4096::LIMBS }>())
+}
+
+/// Whether `value` is an element of $\mathbb{Z}_{N^2}^*$ for the odd modulus $N^2$.
+fn is_unit(value: &U4096, square_modulus: &U4096) -> bool {
+    value < square_modulus && bool::from(value.inv_odd_mod(square_modulus).1)
+}
+
+/// Key generation needs a [`CryptoRng`], while the trait accepts any [`Rng`]: the randomness of the caller is trusted
+/// as everywhere else in the crate.
+struct CallerRng<'a, R: Rng>(&'a mut R);
+
+impl<R: Rng> RngCore for CallerRng<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R: Rng> CryptoRng for CallerRng<'_, R> {}
+
+fn write_integer<W: Write, const LIMBS: usize>(
+    value: &Uint<LIMBS>,
+    mut writer: W,
+) -> Result<(), SerializationError>
+where
+    Uint<LIMBS>: Encoding,
+{
+    writer.write_all(value.to_be_bytes().as_ref())?;
+
+    Ok(())
+}
+
+fn read_integer<R: Read, const LIMBS: usize>(
+    mut reader: R,
+) -> Result<Uint<LIMBS>, SerializationError> {
+    let mut bytes = vec![0u8; Uint::<LIMBS>::BYTES];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(Uint::from_be_slice(&bytes))
+}
+
+impl CanonicalSerialize for PublicKey {
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        write_integer(&self.modulus, writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        Integer::BYTES
+    }
+}
+
+impl CanonicalDeserialize for PublicKey {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let modulus = read_integer(reader)?;
+
+        Self::new(&modulus).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+impl ToBytes for PublicKey {
+    fn write<W: Write>(&self, writer: W) -> ark_std::io::Result<()> {
+        writer_result(write_integer(&self.modulus, writer))
+    }
+}
+
+impl CanonicalSerialize for SecretKey {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.public_key.serialize(&mut writer)?;
+        write_integer(&self.phi, &mut writer)?;
+        write_integer(&self.phi_inverse, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        3 * Integer::BYTES
+    }
+}
+
+impl CanonicalDeserialize for SecretKey {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Ok(Self {
+            public_key: PublicKey::deserialize(&mut reader)?,
+            phi: read_integer(&mut reader)?,
+            phi_inverse: read_integer(&mut reader)?,
+        })
+    }
+}
+
+impl CanonicalSerialize for Plaintext {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        write_integer(&self.value, &mut writer)?;
+        match &self.binding {
+            Binding::Bound(params) => {
+                true.serialize(&mut writer)?;
+                write_integer(params.modulus(), &mut writer)
+            }
+            Binding::Unbound => false.serialize(&mut writer),
+            Binding::Invalid => Err(SerializationError::InvalidData),
+        }
+    }
+
+    fn serialized_size(&self) -> usize {
+        let modulus_size = match self.binding {
+            Binding::Bound(_) => Integer::BYTES,
+            _ => 0,
+        };
+
+        Integer::BYTES + 1 + modulus_size
+    }
+}
+
+impl CanonicalDeserialize for Plaintext {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let value = read_integer(&mut reader)?;
+        let binding = if bool::deserialize(&mut reader)? {
+            let modulus = read_integer(&mut reader)?;
+            let params = PublicKey::new(&modulus)
+                .map_err(|_| SerializationError::InvalidData)?
+                .params;
+            if value >= modulus {
+                return Err(SerializationError::InvalidData);
+            }
+            Binding::Bound(params)
+        } else {
+            Binding::Unbound
+        };
+
+        Ok(Self { value, binding })
+    }
+}
+
+/// Only the value is serialized: the ciphertext is deserialized unbound, see [`Ciphertext::bind`].
+impl CanonicalSerialize for Ciphertext {
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        if !self.is_valid() {
+            return Err(SerializationError::InvalidData);
+        }
+
+        write_integer(&self.value, writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        U4096::BYTES
+    }
+}
+
+impl CanonicalDeserialize for Ciphertext {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let value: U4096 = read_integer(reader)?;
+        if value == U4096::ZERO {
+            return Err(SerializationError::InvalidData);
+        }
+
+        Ok(Self {
+            value,
+            binding: Binding::Unbound,
+        })
+    }
+}
+
+impl ToBytes for Ciphertext {
+    fn write<W: Write>(&self, writer: W) -> ark_std::io::Result<()> {
+        writer_result(self.serialize(writer))
+    }
+}
+
+fn writer_result(result: Result<(), SerializationError>) -> ark_std::io::Result<()> {
+    result.map_err(|e| ark_std::io::Error::other(e.to_string()))
+}
@@ -0,0 +1,347 @@
+#[cfg(test)]
+mod test {
+    use super::super::{Ciphertext, Integer, Paillier, Parameters, Plaintext, PublicKey};
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::{thread_rng, CryptoRng, Rng};
+    use ark_std::{UniformRand, Zero};
+    use crypto_bigint::{Encoding, Random, U4096};
+    use starknet_curve;
+
+    // Keep keys small so that tests run quickly
+    const MODULUS_BITS: u64 = 512;
+
+    fn randomness<R: Rng + CryptoRng>(rng: &mut R) -> Integer {
+        Integer::random(&mut *rng)
+    }
+
+    /// Encrypt every vote with its randomness and add the ciphertexts, the same way for any backend.
+    fn encrypted_tally<S, Enc: HomomorphicEncryptionScheme<S>>(
+        pp: &Enc::Parameters,
+        pk: &Enc::PublicKey,
+        votes: &[Enc::Plaintext],
+        randomness: Vec<S>,
+    ) -> Enc::Ciphertext {
+        votes
+            .iter()
+            .zip(randomness.iter())
+            .map(|(vote, r)| Enc::encrypt(pp, pk, vote, r).unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn valid_encrypt_decrypt() {
+        let rng = &mut thread_rng();
+        let pp = Parameters::new(MODULUS_BITS).unwrap();
+        let (pk, sk) = Paillier::keygen(&pp, rng).unwrap();
+        assert!(pk.modulus().bits() >= MODULUS_BITS as usize - 1);
+
+        for value in [0u64, 1, 42, u64::MAX] {
+            let m = Plaintext::new(Integer::from(value));
+            let c = Paillier::encrypt(&pp, &pk, &m, &randomness(rng)).unwrap();
+            assert_eq!(Paillier::decrypt(&pp, &sk, &c).unwrap(), m);
+        }
+
+        // Messages are reduced modulo N
+        let m = Plaintext::new(pk.modulus().wrapping_add(&Integer::from(7u64)));
+        let c = Paillier::encrypt(&pp, &pk, &m, &randomness(rng)).unwrap();
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &c).unwrap().value(),
+            Integer::from(7u64)
+        );
+
+        // The randomness must be invertible modulo N
+        let m = Plaintext::new(Integer::from(3u64));
+        assert_eq!(
+            Paillier::encrypt(&pp, &pk, &m, &Integer::ZERO),
+            Err(CryptoError::InvalidPaillierRandomness)
+        );
+        assert_eq!(
+            Paillier::encrypt(&pp, &pk, &m, pk.modulus()),
+            Err(CryptoError::InvalidPaillierRandomness)
+        );
+
+        // Ciphertexts under another key are rejected
+        let (other_pk, _) = Paillier::keygen(&pp, rng).unwrap();
+        let c = Paillier::encrypt(&pp, &other_pk, &m, &randomness(rng)).unwrap();
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &c),
+            Err(CryptoError::InvalidPaillierCiphertext)
+        );
+
+        assert_eq!(
+            Parameters::new(511),
+            Err(CryptoError::InvalidPaillierParameters)
+        );
+        assert_eq!(
+            Parameters::new(4096),
+            Err(CryptoError::InvalidPaillierParameters)
+        );
+    }
+
+    #[test]
+    fn homomorphic_property() {
+        let rng = &mut thread_rng();
+        let pp = Parameters::new(MODULUS_BITS).unwrap();
+        let (pk, sk) = Paillier::keygen(&pp, rng).unwrap();
+
+        let a = Plaintext::new(Integer::from(1234u64));
+        let b = Plaintext::new(Integer::from(5678u64));
+        let x = Integer::from(1000u64);
+
+        let c_a = Paillier::encrypt(&pp, &pk, &a, &randomness(rng)).unwrap();
+        let c_b = Paillier::encrypt(&pp, &pk, &b, &randomness(rng)).unwrap();
+
+        assert_eq!(Paillier::decrypt(&pp, &sk, &(c_a + c_b)).unwrap(), a + b);
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &(c_a * x)).unwrap(),
+            Plaintext::new(Integer::from(1234000u64))
+        );
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &(c_a + Ciphertext::zero())).unwrap(),
+            a
+        );
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &Ciphertext::zero()).unwrap(),
+            Plaintext::zero()
+        );
+
+        // Arithmetic on decrypted plaintexts is modulo N
+        let minus_one = Paillier::decrypt(
+            &pp,
+            &sk,
+            &Paillier::encrypt(
+                &pp,
+                &pk,
+                &Plaintext::new(pk.modulus().wrapping_sub(&Integer::ONE)),
+                &randomness(rng),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(minus_one + Plaintext::new(Integer::ONE), Plaintext::zero());
+    }
+
+    #[test]
+    fn plaintexts_under_different_keys() {
+        let rng = &mut thread_rng();
+        let pp = Parameters::new(MODULUS_BITS).unwrap();
+        let (pk_1, sk_1) = Paillier::keygen(&pp, rng).unwrap();
+        let (pk_2, sk_2) = Paillier::keygen(&pp, rng).unwrap();
+
+        let m = Plaintext::new(Integer::from(3u64));
+        let a = Paillier::decrypt(
+            &pp,
+            &sk_1,
+            &Paillier::encrypt(&pp, &pk_1, &m, &randomness(rng)).unwrap(),
+        )
+        .unwrap();
+        let b = Paillier::decrypt(
+            &pp,
+            &sk_2,
+            &Paillier::encrypt(&pp, &pk_2, &m, &randomness(rng)).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            a.checked_add(&b),
+            Err(CryptoError::InvalidPaillierPlaintext)
+        );
+        assert_eq!(a.checked_add(&m), Ok(m + m));
+
+        // The operator gives an invalid plaintext, which cannot be encrypted
+        let sum = a + b;
+        assert!(!sum.is_valid());
+        assert!(!(sum + a).is_valid());
+        assert!(!(sum * Integer::ONE).is_valid());
+        assert_eq!(
+            Paillier::encrypt(&pp, &pk_1, &sum, &randomness(rng)),
+            Err(CryptoError::InvalidPaillierPlaintext)
+        );
+        assert!(sum.serialize(&mut Vec::new()).is_err());
+
+        // A plaintext bound to a key is not encrypted under another one
+        assert_eq!(
+            Paillier::encrypt(&pp, &pk_2, &a, &randomness(rng)),
+            Err(CryptoError::InvalidPaillierPlaintext)
+        );
+    }
+
+    #[test]
+    fn unbound_plaintext_overflow() {
+        let max = Plaintext::new(Integer::MAX);
+        let one = Plaintext::new(Integer::ONE);
+
+        assert_eq!(
+            max.checked_add(&one),
+            Err(CryptoError::InvalidPaillierPlaintext)
+        );
+        assert_eq!(
+            max.checked_mul(&Integer::from(2u64)),
+            Err(CryptoError::InvalidPaillierPlaintext)
+        );
+        assert_eq!(max.checked_mul(&Integer::ONE), Ok(max));
+        assert!(!(max + one).is_valid());
+        assert!(!(max * Integer::from(2u64)).is_valid());
+    }
+
+    #[test]
+    fn ciphertexts_under_different_keys() {
+        let rng = &mut thread_rng();
+        let pp = Parameters::new(MODULUS_BITS).unwrap();
+        let (pk_1, sk_1) = Paillier::keygen(&pp, rng).unwrap();
+        let (pk_2, _) = Paillier::keygen(&pp, rng).unwrap();
+
+        let m = Plaintext::new(Integer::from(3u64));
+        let c_1 = Paillier::encrypt(&pp, &pk_1, &m, &randomness(rng)).unwrap();
+        let c_2 = Paillier::encrypt(&pp, &pk_2, &m, &randomness(rng)).unwrap();
+
+        assert_eq!(Paillier::validate_ciphertext(&pk_1, &c_1), Ok(()));
+        assert_eq!(
+            Paillier::validate_ciphertext(&pk_1, &c_2),
+            Err(CryptoError::InvalidPaillierCiphertext)
+        );
+
+        // Combining them gives an invalid ciphertext instead of panicking
+        let sum: Ciphertext = vec![c_1, c_2, c_1].into_iter().sum();
+        assert!(!sum.is_valid());
+        assert_ne!(sum, sum);
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk_1, &sum),
+            Err(CryptoError::InvalidPaillierCiphertext)
+        );
+        assert_eq!(
+            Paillier::validate_ciphertext(&pk_1, &sum),
+            Err(CryptoError::InvalidPaillierCiphertext)
+        );
+        assert!(sum.serialize(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn validate_deserialized_ciphertexts() {
+        let rng = &mut thread_rng();
+        let pp = Parameters::new(MODULUS_BITS).unwrap();
+        let (pk, sk) = Paillier::keygen(&pp, rng).unwrap();
+        let (other_pk, _) = Paillier::keygen(&pp, rng).unwrap();
+        assert_eq!(Paillier::validate_public_key(&pk), Ok(()));
+
+        let deserialize =
+            |value: &U4096| Ciphertext::deserialize(&value.to_be_bytes()[..]).unwrap();
+
+        let m = Plaintext::new(Integer::from(5u64));
+        let c = Paillier::encrypt(&pp, &pk, &m, &randomness(rng)).unwrap();
+        let received = deserialize(&c.value());
+        assert_eq!(Paillier::validate_ciphertext(&pk, &received), Ok(()));
+        assert_eq!(Paillier::decrypt(&pp, &sk, &received).unwrap(), m);
+
+        // Unbound ciphertexts are bound by the bound ones they are combined with, but not by each other
+        assert_eq!(Paillier::decrypt(&pp, &sk, &(c + received)).unwrap(), m + m);
+        assert!(!(received + received).is_valid());
+        assert!(!(received * Integer::from(2u64)).is_valid());
+        let bound = received.bind(&pk).unwrap();
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &(bound * Integer::from(2u64))).unwrap(),
+            m + m
+        );
+
+        // Values that are not units modulo N^2 of the key are rejected
+        let modulus: U4096 = pk.modulus().resize();
+        let square = modulus.wrapping_mul(&modulus);
+        for value in [modulus, square, square.wrapping_add(&U4096::ONE)] {
+            assert_eq!(
+                Paillier::validate_ciphertext(&pk, &deserialize(&value)),
+                Err(CryptoError::InvalidPaillierCiphertext)
+            );
+        }
+        assert_eq!(
+            received.bind(&other_pk).and_then(|c| c.bind(&pk)),
+            Err(CryptoError::InvalidPaillierCiphertext)
+        );
+        assert!(Ciphertext::deserialize(&U4096::ZERO.to_be_bytes()[..]).is_err());
+
+        // Moduli too small to be secure are rejected
+        let small_pk = PublicKey::new(&Integer::from(3u64 * 5)).unwrap();
+        assert_eq!(
+            Paillier::validate_public_key(&small_pk),
+            Err(CryptoError::InvalidPaillierParameters)
+        );
+    }
+
+    #[test]
+    fn swap_backends() {
+        let rng = &mut thread_rng();
+        let votes = [1u64, 0, 1, 1, 0, 1];
+
+        // Paillier tallies integers directly
+        let pp = Parameters::new(MODULUS_BITS).unwrap();
+        let (pk, sk) = Paillier::keygen(&pp, rng).unwrap();
+        let generator = Paillier::generator(rng).unwrap();
+        let plaintexts = votes
+            .iter()
+            .map(|&vote| generator * Integer::from(vote))
+            .collect::<Vec<_>>();
+        let tally = encrypted_tally::<Integer, Paillier>(
+            &pp,
+            &pk,
+            &plaintexts,
+            (0..votes.len()).map(|_| randomness(rng)).collect(),
+        );
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &tally).unwrap().value(),
+            Integer::from(4u64)
+        );
+
+        // ElGamal tallies in the exponent of a generator
+        type Curve = starknet_curve::Projective;
+        type Scalar = starknet_curve::Fr;
+        type ElGamal = el_gamal::ElGamal<Curve>;
+
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&pp, rng).unwrap();
+        let generator = ElGamal::generator(rng).unwrap();
+        let plaintexts = votes
+            .iter()
+            .map(|&vote| generator * Scalar::from(vote))
+            .collect::<Vec<_>>();
+        let tally = encrypted_tally::<Scalar, ElGamal>(
+            &pp,
+            &pk,
+            &plaintexts,
+            (0..votes.len()).map(|_| Scalar::rand(rng)).collect(),
+        );
+        assert_eq!(
+            ElGamal::decrypt(&pp, &sk, &tally).unwrap(),
+            generator * Scalar::from(4u64)
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let rng = &mut thread_rng();
+        let pp = Parameters::new(MODULUS_BITS).unwrap();
+        let (pk, sk) = Paillier::keygen(&pp, rng).unwrap();
+
+        let m = Plaintext::new(Integer::from(99u64));
+        let c = Paillier::encrypt(&pp, &pk, &m, &randomness(rng)).unwrap();
+
+        fn roundtrip<T: CanonicalSerialize + CanonicalDeserialize>(value: &T) -> T {
+            let mut bytes = Vec::new();
+            value.serialize(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), value.serialized_size());
+            T::deserialize(&bytes[..]).unwrap()
+        }
+
+        assert_eq!(roundtrip(&pk), pk);
+        assert_eq!(roundtrip(&sk), sk);
+        assert_eq!(roundtrip(&c), c);
+        assert_eq!(roundtrip(&Ciphertext::zero()), Ciphertext::zero());
+        let decrypted = Paillier::decrypt(&pp, &sk, &c).unwrap();
+        assert_eq!(roundtrip(&decrypted), decrypted);
+        assert_eq!(
+            Paillier::decrypt(&pp, &roundtrip(&sk), &roundtrip(&c)).unwrap(),
+            m
+        );
+    }
+}
@@ -1,5 +1,8 @@
 pub mod error;
 pub mod homomorphic_encryption;
+pub mod secret_sharing;
+pub mod signatures;
 pub mod utils;
 pub mod vector_commitment;
+pub mod vrf;
 pub mod zkp;
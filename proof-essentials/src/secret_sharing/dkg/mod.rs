@@ -0,0 +1,342 @@
+use super::feldman::{self, FeldmanVSS};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal::threshold::{KeyShare, Parameters};
+use crate::homomorphic_encryption::el_gamal::PublicKey;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+mod tests;
+
+/// Pedersen's distributed key generation (Pedersen, EUROCRYPT 1991), producing a threshold ElGamal key that no single
+/// party ever knows. Every participant acts as a dealer of a Feldman sharing of a random secret; the shared secret
+/// key is the sum of the secrets of the qualified dealers, and each party's key share is the sum of the shares it
+/// received from them.
+///
+/// The protocol runs in three broadcast rounds:
+/// 1. every participant broadcasts the commitments of its dealing and sends one share privately to every other party;
+/// 2. every participant broadcasts a complaint against each dealer whose share is missing or invalid;
+/// 3. every accused dealer answers each complaint by broadcasting the disputed share.
+///
+/// A dealer is disqualified if it did not deal, or left a complaint unanswered or answered it with an invalid share.
+/// The broadcast messages form a public [`Transcript`] from which anyone can derive the public key and the
+/// verification keys of the parties.
+pub struct Participant<C: ProjectiveCurve> {
+    pp: Parameters<C>,
+    index: u64,
+    dealing: feldman::Dealing<C>,
+    received: Vec<Option<C::ScalarField>>,
+    transcript: Transcript<C>,
+    round: Round,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Round {
+    Sharing,
+    Complaints,
+    Responses,
+}
+
+/// First round broadcast: the Feldman commitments to the sharing polynomial of `dealer`.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DealingBroadcast<C: ProjectiveCurve> {
+    pub dealer: u64,
+    pub commitments: Vec<C::Affine>,
+}
+
+/// First round private message: the share of `share.index` in the dealing of `dealer`.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SharePacket<C: ProjectiveCurve> {
+    pub dealer: u64,
+    pub share: feldman::Share<C>,
+}
+
+/// Second round broadcast: `accuser` did not receive a valid share from `dealer`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Complaint {
+    pub accuser: u64,
+    pub dealer: u64,
+}
+
+/// Third round broadcast: `dealer` reveals the share of the accuser, `share.index`, to answer a complaint.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ComplaintResponse<C: ProjectiveCurve> {
+    pub dealer: u64,
+    pub share: feldman::Share<C>,
+}
+
+/// Every broadcast message of a key generation, in the order of the rounds.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Transcript<C: ProjectiveCurve> {
+    pub dealings: Vec<DealingBroadcast<C>>,
+    pub complaints: Vec<Complaint>,
+    pub responses: Vec<ComplaintResponse<C>>,
+}
+
+/// Public outcome of a key generation: the qualified dealers, the aggregated Feldman commitments to the shared
+/// polynomial, the public key and the verification key $X_i = x_i G$ of every party (the key of party `i` being at
+/// position `i - 1`).
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicOutput<C: ProjectiveCurve> {
+    pub qualified: Vec<u64>,
+    pub commitments: Vec<C::Affine>,
+    pub public_key: PublicKey<C>,
+    pub verification_keys: Vec<C::Affine>,
+}
+
+impl<C: ProjectiveCurve> Participant<C> {
+    /// Sample a random secret for the participant with identifier `index` and deal it.
+    pub fn new<R: Rng>(rng: &mut R, pp: &Parameters<C>, index: u64) -> Result<Self, CryptoError> {
+        if index == 0 || index > pp.participants as u64 {
+            return Err(CryptoError::InvalidDkgMessage);
+        }
+
+        let secret = C::ScalarField::rand(rng);
+        let dealing = FeldmanVSS::deal(rng, &Self::vss_parameters(pp), &secret)?;
+
+        Ok(Self {
+            pp: *pp,
+            index,
+            dealing,
+            received: vec![None; pp.participants],
+            transcript: Transcript {
+                dealings: Vec::new(),
+                complaints: Vec::new(),
+                responses: Vec::new(),
+            },
+            round: Round::Sharing,
+        })
+    }
+
+    /// First round messages: the commitments to broadcast, and the share packets to send privately, each to the
+    /// party `packet.share.index`.
+    pub fn dealing(&self) -> (DealingBroadcast<C>, Vec<SharePacket<C>>) {
+        let broadcast = DealingBroadcast {
+            dealer: self.index,
+            commitments: self.dealing.commitments.clone(),
+        };
+        let packets = self
+            .dealing
+            .shares
+            .iter()
+            .map(|&share| SharePacket {
+                dealer: self.index,
+                share,
+            })
+            .collect();
+
+        (broadcast, packets)
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Broadcast messages processed so far.
+    pub fn transcript(&self) -> &Transcript<C> {
+        &self.transcript
+    }
+
+    /// Second round: process the dealing broadcasts of all parties, including this one, and the share packets
+    /// addressed to this participant. Returns a complaint against every dealer whose share is missing or does not
+    /// match its commitments.
+    pub fn receive_dealings(
+        &mut self,
+        dealings: &Vec<DealingBroadcast<C>>,
+        packets: &[SharePacket<C>],
+    ) -> Result<Vec<Complaint>, CryptoError> {
+        self.advance(Round::Sharing, Round::Complaints)?;
+
+        let vss_parameters = Self::vss_parameters(&self.pp);
+        let mut complaints = Vec::new();
+
+        for dealing in dealings {
+            if !self.is_participant(dealing.dealer)
+                || self
+                    .transcript
+                    .dealings
+                    .iter()
+                    .any(|other| other.dealer == dealing.dealer)
+            {
+                return Err(CryptoError::InvalidDkgMessage);
+            }
+            self.transcript.dealings.push(dealing.clone());
+
+            let share = packets
+                .iter()
+                .find(|packet| packet.dealer == dealing.dealer && packet.share.index == self.index);
+
+            match share {
+                Some(packet)
+                    if FeldmanVSS::verify_share(
+                        &vss_parameters,
+                        &dealing.commitments,
+                        &packet.share,
+                    )
+                    .is_ok() =>
+                {
+                    self.received[dealing.dealer as usize - 1] = Some(packet.share.value)
+                }
+                _ => complaints.push(Complaint {
+                    accuser: self.index,
+                    dealer: dealing.dealer,
+                }),
+            }
+        }
+
+        Ok(complaints)
+    }
+
+    /// Third round: process the complaints of all parties and answer those made against this participant by
+    /// revealing the disputed shares.
+    pub fn respond(
+        &mut self,
+        complaints: &[Complaint],
+    ) -> Result<Vec<ComplaintResponse<C>>, CryptoError> {
+        self.advance(Round::Complaints, Round::Responses)?;
+
+        if complaints
+            .iter()
+            .any(|complaint| !self.is_participant(complaint.accuser))
+        {
+            return Err(CryptoError::InvalidDkgMessage);
+        }
+        self.transcript.complaints.extend_from_slice(complaints);
+
+        Ok(complaints
+            .iter()
+            .filter(|complaint| complaint.dealer == self.index)
+            .map(|complaint| ComplaintResponse {
+                dealer: self.index,
+                share: self.dealing.shares[complaint.accuser as usize - 1],
+            })
+            .collect())
+    }
+
+    /// Process the answers to the complaints and compute the key share of this participant along with the public
+    /// outcome of the key generation.
+    pub fn finalize(
+        mut self,
+        responses: &[ComplaintResponse<C>],
+    ) -> Result<(KeyShare<C>, PublicOutput<C>), CryptoError> {
+        if self.round != Round::Responses {
+            return Err(CryptoError::InvalidDkgRound);
+        }
+
+        if responses
+            .iter()
+            .any(|response| !self.is_participant(response.dealer))
+        {
+            return Err(CryptoError::InvalidDkgMessage);
+        }
+        self.transcript.responses.extend_from_slice(responses);
+
+        let output = Self::public_output(&self.pp, &self.transcript)?;
+
+        // Shares this participant complained about are taken from the dealers' answers
+        for response in self.transcript.responses.iter() {
+            if response.share.index == self.index
+                && self.received[response.dealer as usize - 1].is_none()
+            {
+                self.received[response.dealer as usize - 1] = Some(response.share.value);
+            }
+        }
+
+        let secret_share = output
+            .qualified
+            .iter()
+            .map(|&dealer| self.received[dealer as usize - 1].ok_or(CryptoError::InvalidDkgMessage))
+            .sum::<Result<C::ScalarField, CryptoError>>()?;
+
+        let key_share = KeyShare {
+            index: self.index,
+            secret_share,
+        };
+
+        Ok((key_share, output))
+    }
+
+    /// Replay a transcript to find the qualified dealers and derive the public key and the verification keys.
+    pub fn public_output(
+        pp: &Parameters<C>,
+        transcript: &Transcript<C>,
+    ) -> Result<PublicOutput<C>, CryptoError> {
+        let vss_parameters = Self::vss_parameters(pp);
+
+        let qualified_dealings: Vec<&DealingBroadcast<C>> = transcript
+            .dealings
+            .iter()
+            .filter(|dealing| {
+                dealing.commitments.len() == pp.threshold
+                    && transcript
+                        .complaints
+                        .iter()
+                        .filter(|complaint| complaint.dealer == dealing.dealer)
+                        .all(|complaint| {
+                            transcript.responses.iter().any(|response| {
+                                response.dealer == dealing.dealer
+                                    && response.share.index == complaint.accuser
+                                    && FeldmanVSS::verify_share(
+                                        &vss_parameters,
+                                        &dealing.commitments,
+                                        &response.share,
+                                    )
+                                    .is_ok()
+                            })
+                        })
+            })
+            .collect();
+
+        if qualified_dealings.is_empty() {
+            return Err(CryptoError::NoQualifiedDealers);
+        }
+
+        let commitments = C::batch_normalization_into_affine(
+            &(0..pp.threshold)
+                .map(|k| {
+                    qualified_dealings.iter().fold(C::zero(), |acc, dealing| {
+                        acc + dealing.commitments[k].into_projective()
+                    })
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let verification_keys = (1..=pp.participants as u64)
+            .map(|index| FeldmanVSS::<C>::public_share(&commitments, index))
+            .collect();
+
+        Ok(PublicOutput {
+            qualified: qualified_dealings
+                .iter()
+                .map(|dealing| dealing.dealer)
+                .collect(),
+            public_key: commitments[0],
+            commitments,
+            verification_keys,
+        })
+    }
+
+    fn advance(&mut self, expected: Round, next: Round) -> Result<(), CryptoError> {
+        if self.round != expected {
+            return Err(CryptoError::InvalidDkgRound);
+        }
+        self.round = next;
+
+        Ok(())
+    }
+
+    fn is_participant(&self, index: u64) -> bool {
+        index != 0 && index <= self.pp.participants as u64
+    }
+
+    fn vss_parameters(pp: &Parameters<C>) -> feldman::Parameters<C> {
+        feldman::Parameters {
+            generator: pp.generator,
+            threshold: pp.threshold,
+            participants: pp.participants,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::el_gamal::threshold::{self, KeyShare, ThresholdElGamal};
+    use crate::homomorphic_encryption::el_gamal::{ElGamal, Plaintext};
+    use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+    use crate::secret_sharing::dkg::{self, PublicOutput};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Participant = dkg::Participant<Curve>;
+    type Threshold = ThresholdElGamal<Curve>;
+
+    /// Run the three rounds among all parties. `tamper` may alter the share packets of the first round and `censor`
+    /// drops the complaint responses of some dealers.
+    fn run(
+        pp: &threshold::Parameters<Curve>,
+        tamper: impl Fn(&mut Vec<dkg::SharePacket<Curve>>),
+        censor: impl Fn(&dkg::ComplaintResponse<Curve>) -> bool,
+    ) -> Vec<(KeyShare<Curve>, PublicOutput<Curve>)> {
+        let rng = &mut thread_rng();
+
+        let mut participants = Vec::new();
+        let mut dealings = Vec::new();
+        let mut packets = Vec::new();
+        for index in 1..=pp.participants as u64 {
+            let participant = Participant::new(rng, pp, index).unwrap();
+            let (dealing, shares) = participant.dealing();
+            participants.push(participant);
+            dealings.push(dealing);
+            packets.extend(shares);
+        }
+        tamper(&mut packets);
+
+        let complaints = participants
+            .iter_mut()
+            .flat_map(|participant| participant.receive_dealings(&dealings, &packets).unwrap())
+            .collect::<Vec<_>>();
+
+        let responses = participants
+            .iter_mut()
+            .flat_map(|participant| participant.respond(&complaints).unwrap())
+            .filter(|response| !censor(response))
+            .collect::<Vec<_>>();
+
+        participants
+            .into_iter()
+            .map(|participant| participant.finalize(&responses).unwrap())
+            .collect()
+    }
+
+    fn assert_consistent(
+        pp: &threshold::Parameters<Curve>,
+        outputs: &[(KeyShare<Curve>, PublicOutput<Curve>)],
+    ) {
+        let output = &outputs[0].1;
+        for (key_share, other) in outputs.iter() {
+            assert_eq!(other, output);
+            assert_eq!(
+                pp.generator
+                    .mul(key_share.secret_share.into_repr())
+                    .into_affine(),
+                output.verification_keys[key_share.index() as usize - 1]
+            );
+        }
+
+        // Any `t` parties decrypt under the jointly generated key
+        let rng = &mut thread_rng();
+        let message = Plaintext::rand(rng);
+        let ciphertext = ElGamal::<Curve>::encrypt(
+            &pp.encryption_parameters(),
+            &output.public_key,
+            &message,
+            &Scalar::rand(rng),
+        )
+        .unwrap();
+
+        let shares = outputs[outputs.len() - pp.threshold..]
+            .iter()
+            .map(|(key_share, _)| {
+                Threshold::decryption_share::<_, Blake2s>(rng, pp, key_share, &ciphertext).unwrap()
+            })
+            .collect();
+
+        assert_eq!(
+            Threshold::combine::<Blake2s>(pp, &output.verification_keys, &ciphertext, &shares),
+            Ok(message)
+        );
+    }
+
+    #[test]
+    fn test_honest_parties() {
+        let rng = &mut thread_rng();
+        let pp = Threshold::setup(rng, 3, 5).unwrap();
+
+        let outputs = run(&pp, |_| {}, |_| false);
+
+        assert_eq!(outputs[0].1.qualified, vec![1, 2, 3, 4, 5]);
+        assert_consistent(&pp, &outputs);
+    }
+
+    #[test]
+    fn test_answered_complaint() {
+        let rng = &mut thread_rng();
+        let pp = Threshold::setup(rng, 3, 5).unwrap();
+
+        // Dealer 2 sends a bad share to party 4, then reveals the correct one when accused
+        let outputs = run(
+            &pp,
+            |packets| {
+                let packet = packets
+                    .iter_mut()
+                    .find(|packet| packet.dealer == 2 && packet.share.index == 4)
+                    .unwrap();
+                packet.share.value += Scalar::from(1u64);
+            },
+            |_| false,
+        );
+
+        assert_eq!(outputs[0].1.qualified, vec![1, 2, 3, 4, 5],);
+        assert_consistent(&pp, &outputs);
+    }
+
+    #[test]
+    fn test_disqualified_dealer() {
+        let rng = &mut thread_rng();
+        let pp = Threshold::setup(rng, 3, 5).unwrap();
+
+        // Dealer 3 withholds the share of party 1 and ignores the complaint
+        let outputs = run(
+            &pp,
+            |packets| packets.retain(|packet| !(packet.dealer == 3 && packet.share.index == 1)),
+            |response| response.dealer == 3,
+        );
+
+        assert_eq!(outputs[0].1.qualified, vec![1, 2, 4, 5]);
+        assert_consistent(&pp, &outputs);
+    }
+
+    #[test]
+    fn test_transcript() {
+        let rng = &mut thread_rng();
+        let pp = Threshold::setup(rng, 2, 3).unwrap();
+
+        let mut participants = Vec::new();
+        let mut dealings = Vec::new();
+        let mut packets = Vec::new();
+        for index in 1..=3 {
+            let participant = Participant::new(rng, &pp, index).unwrap();
+            let (dealing, shares) = participant.dealing();
+            participants.push(participant);
+            dealings.push(dealing);
+            packets.extend(shares);
+        }
+        packets.retain(|packet| !(packet.dealer == 1 && packet.share.index == 2));
+
+        // Rounds must be played in order
+        assert_eq!(
+            participants[0].respond(&[]),
+            Err(CryptoError::InvalidDkgRound)
+        );
+
+        let complaints = participants
+            .iter_mut()
+            .flat_map(|participant| participant.receive_dealings(&dealings, &packets).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            complaints,
+            vec![dkg::Complaint {
+                accuser: 2,
+                dealer: 1
+            }]
+        );
+        assert_eq!(
+            participants[0].receive_dealings(&dealings, &packets),
+            Err(CryptoError::InvalidDkgRound)
+        );
+
+        let responses = participants
+            .iter_mut()
+            .flat_map(|participant| participant.respond(&complaints).unwrap())
+            .collect::<Vec<_>>();
+
+        let transcript = participants[2].transcript().clone();
+        let (_, output) = participants.pop().unwrap().finalize(&responses).unwrap();
+
+        // Anyone can replay the serialized broadcasts
+        let mut serialized = vec![0; transcript.serialized_size()];
+        transcript.serialize(&mut serialized[..]).unwrap();
+        let mut deserialized = dkg::Transcript::<Curve>::deserialize(&serialized[..]).unwrap();
+        deserialized.responses = responses;
+
+        assert_eq!(Participant::public_output(&pp, &deserialized), Ok(output));
+
+        deserialized.responses.clear();
+        assert_eq!(
+            Participant::public_output(&pp, &deserialized)
+                .unwrap()
+                .qualified,
+            vec![2, 3]
+        );
+
+        assert_eq!(
+            Participant::new(rng, &pp, 4).err(),
+            Some(CryptoError::InvalidDkgMessage)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use super::evaluate_commitments;
+use crate::error::CryptoError;
+use crate::utils::secret_sharing::{evaluate_polynomial, sample_polynomial};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+mod tests;
+
+/// Feldman verifiable secret sharing (Feldman, FOCS 1987). A dealer splits a secret into `n` Shamir shares with a
+/// polynomial of degree `t - 1` and publishes commitments $C_k = a_k G$ to its coefficients, so that every
+/// participant can check its share against them.
+///
+/// The commitment $C_0 = sG$ reveals $sG$, so the secret is only computationally hidden, and only if it is
+/// uniformly distributed.
+pub struct FeldmanVSS<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+    pub threshold: usize,
+    pub participants: usize,
+}
+
+/// Share $s_i = f(i)$ of the participant with identifier `index`, in `1..=n`.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Share<C: ProjectiveCurve> {
+    pub index: u64,
+    pub value: C::ScalarField,
+}
+
+/// Output of the dealer: the commitments to the coefficients of the sharing polynomial, published to everyone, and
+/// one share per participant, sent privately.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Dealing<C: ProjectiveCurve> {
+    pub commitments: Vec<C::Affine>,
+    pub shares: Vec<Share<C>>,
+}
+
+impl<C: ProjectiveCurve> FeldmanVSS<C> {
+    pub fn setup<R: Rng>(
+        rng: &mut R,
+        threshold: usize,
+        participants: usize,
+    ) -> Result<Parameters<C>, CryptoError> {
+        if threshold == 0 || threshold > participants {
+            return Err(CryptoError::InvalidThresholdParameters);
+        }
+
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+            threshold,
+            participants,
+        })
+    }
+
+    /// Share `secret` among the participants and commit to the sharing polynomial.
+    pub fn deal<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        secret: &C::ScalarField,
+    ) -> Result<Dealing<C>, CryptoError> {
+        let coefficients = sample_polynomial(rng, *secret, pp.threshold);
+
+        let commitments = C::batch_normalization_into_affine(
+            &coefficients
+                .iter()
+                .map(|coefficient| pp.generator.mul(coefficient.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+
+        let shares = (1..=pp.participants as u64)
+            .map(|index| Share {
+                index,
+                value: evaluate_polynomial(&coefficients, index),
+            })
+            .collect();
+
+        Ok(Dealing {
+            commitments,
+            shares,
+        })
+    }
+
+    /// Check a share against the dealer's commitments: $s_i G = \sum_k C_k \cdot i^k$.
+    pub fn verify_share(
+        pp: &Parameters<C>,
+        commitments: &[C::Affine],
+        share: &Share<C>,
+    ) -> Result<(), CryptoError> {
+        if commitments.len() != pp.threshold
+            || share.index == 0
+            || share.index > pp.participants as u64
+            || pp.generator.mul(share.value.into_repr())
+                != evaluate_commitments::<C>(commitments, share.index)
+        {
+            return Err(CryptoError::InvalidShare);
+        }
+
+        Ok(())
+    }
+
+    /// Public counterpart $s_i G$ of the share of participant `index`, computed from the dealer's commitments alone.
+    pub fn public_share(commitments: &[C::Affine], index: u64) -> C::Affine {
+        evaluate_commitments::<C>(commitments, index).into_affine()
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::secret_sharing::feldman;
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Feldman = feldman::FeldmanVSS<Curve>;
+
+    #[test]
+    fn test_verify_shares() {
+        let rng = &mut thread_rng();
+        let pp = Feldman::setup(rng, 3, 5).unwrap();
+        let secret = Scalar::rand(rng);
+        let dealing = Feldman::deal(rng, &pp, &secret).unwrap();
+
+        assert_eq!(dealing.commitments.len(), 3);
+        assert_eq!(dealing.shares.len(), 5);
+        assert_eq!(
+            dealing.commitments[0],
+            pp.generator.mul(secret.into_repr()).into_affine()
+        );
+
+        for share in dealing.shares.iter() {
+            assert_eq!(
+                Feldman::verify_share(&pp, &dealing.commitments, share),
+                Ok(())
+            );
+            assert_eq!(
+                Feldman::public_share(&dealing.commitments, share.index),
+                pp.generator.mul(share.value.into_repr()).into_affine()
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_shares() {
+        let rng = &mut thread_rng();
+        let pp = Feldman::setup(rng, 3, 5).unwrap();
+        let secret = Scalar::rand(rng);
+        let dealing = Feldman::deal(rng, &pp, &secret).unwrap();
+
+        let mut tampered = dealing.shares[0];
+        tampered.value = Scalar::rand(rng);
+        assert_eq!(
+            Feldman::verify_share(&pp, &dealing.commitments, &tampered),
+            Err(CryptoError::InvalidShare)
+        );
+
+        // A valid share presented under another identifier
+        let mut moved = dealing.shares[0];
+        moved.index = 2;
+        assert_eq!(
+            Feldman::verify_share(&pp, &dealing.commitments, &moved),
+            Err(CryptoError::InvalidShare)
+        );
+
+        // Commitments to a polynomial of the wrong degree
+        let truncated = dealing.commitments[..2].to_vec();
+        assert_eq!(
+            Feldman::verify_share(&pp, &truncated, &dealing.shares[0]),
+            Err(CryptoError::InvalidShare)
+        );
+
+        assert!(Feldman::setup(rng, 0, 5).is_err());
+        assert_eq!(
+            Feldman::setup(rng, 6, 5).err(),
+            Some(CryptoError::InvalidThresholdParameters)
+        );
+    }
+}
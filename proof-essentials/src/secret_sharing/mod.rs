@@ -0,0 +1,15 @@
+pub mod dkg;
+pub mod feldman;
+pub mod pedersen;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+
+/// Evaluate committed coefficients in the exponent, $\sum_k C_k \cdot i^k$, at the participant identifier `index`.
+fn evaluate_commitments<C: ProjectiveCurve>(commitments: &[C::Affine], index: u64) -> C {
+    let x = C::ScalarField::from(index);
+
+    commitments.iter().rev().fold(C::zero(), |acc, commitment| {
+        acc.mul(x.into_repr()) + commitment.into_projective()
+    })
+}
@@ -0,0 +1,119 @@
+use super::evaluate_commitments;
+use crate::error::CryptoError;
+use crate::utils::secret_sharing::{evaluate_polynomial, sample_polynomial};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+mod tests;
+
+/// Pedersen verifiable secret sharing (Pedersen, CRYPTO 1991). Alongside the sharing polynomial $f$ the dealer samples
+/// a blinding polynomial $f'$ of the same degree and publishes Pedersen commitments $C_k = a_k G + b_k H$ to their
+/// coefficients. Each participant receives $(f(i), f'(i))$ and checks it against the commitments.
+///
+/// Unlike Feldman's scheme, the commitments hide the secret unconditionally, provided nobody knows $\log_G(H)$.
+pub struct PedersenVSS<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+    pub blinding_generator: C::Affine,
+    pub threshold: usize,
+    pub participants: usize,
+}
+
+/// Share $(s_i, s'_i) = (f(i), f'(i))$ of the participant with identifier `index`, in `1..=n`.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Share<C: ProjectiveCurve> {
+    pub index: u64,
+    pub value: C::ScalarField,
+    pub blinding: C::ScalarField,
+}
+
+/// Output of the dealer: the commitments to the coefficients of both polynomials, published to everyone, and one
+/// share per participant, sent privately.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Dealing<C: ProjectiveCurve> {
+    pub commitments: Vec<C::Affine>,
+    pub shares: Vec<Share<C>>,
+}
+
+impl<C: ProjectiveCurve> PedersenVSS<C> {
+    pub fn setup<R: Rng>(
+        rng: &mut R,
+        threshold: usize,
+        participants: usize,
+    ) -> Result<Parameters<C>, CryptoError> {
+        if threshold == 0 || threshold > participants {
+            return Err(CryptoError::InvalidThresholdParameters);
+        }
+
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+            blinding_generator: C::rand(rng).into_affine(),
+            threshold,
+            participants,
+        })
+    }
+
+    /// Share `secret` among the participants and commit to the sharing and blinding polynomials.
+    pub fn deal<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        secret: &C::ScalarField,
+    ) -> Result<Dealing<C>, CryptoError> {
+        let coefficients = sample_polynomial(rng, *secret, pp.threshold);
+        let blinding = C::ScalarField::rand(rng);
+        let blinding_coefficients = sample_polynomial(rng, blinding, pp.threshold);
+
+        let commitments = C::batch_normalization_into_affine(
+            &coefficients
+                .iter()
+                .zip(blinding_coefficients.iter())
+                .map(|(coefficient, blinding)| {
+                    pp.generator.mul(coefficient.into_repr())
+                        + pp.blinding_generator.mul(blinding.into_repr())
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let shares = (1..=pp.participants as u64)
+            .map(|index| Share {
+                index,
+                value: evaluate_polynomial(&coefficients, index),
+                blinding: evaluate_polynomial(&blinding_coefficients, index),
+            })
+            .collect();
+
+        Ok(Dealing {
+            commitments,
+            shares,
+        })
+    }
+
+    /// Check a share against the dealer's commitments: $s_i G + s'_i H = \sum_k C_k \cdot i^k$.
+    pub fn verify_share(
+        pp: &Parameters<C>,
+        commitments: &[C::Affine],
+        share: &Share<C>,
+    ) -> Result<(), CryptoError> {
+        if commitments.len() != pp.threshold
+            || share.index == 0
+            || share.index > pp.participants as u64
+            || pp.generator.mul(share.value.into_repr())
+                + pp.blinding_generator.mul(share.blinding.into_repr())
+                != evaluate_commitments::<C>(commitments, share.index)
+        {
+            return Err(CryptoError::InvalidShare);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::secret_sharing::pedersen;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenVSS<Curve>;
+
+    #[test]
+    fn test_verify_shares() {
+        let rng = &mut thread_rng();
+        let pp = Pedersen::setup(rng, 3, 5).unwrap();
+        let secret = Scalar::rand(rng);
+        let dealing = Pedersen::deal(rng, &pp, &secret).unwrap();
+
+        assert_eq!(dealing.commitments.len(), 3);
+        assert_eq!(dealing.shares.len(), 5);
+
+        for share in dealing.shares.iter() {
+            assert_eq!(
+                Pedersen::verify_share(&pp, &dealing.commitments, share),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_shares() {
+        let rng = &mut thread_rng();
+        let pp = Pedersen::setup(rng, 3, 5).unwrap();
+        let secret = Scalar::rand(rng);
+        let dealing = Pedersen::deal(rng, &pp, &secret).unwrap();
+
+        let mut tampered = dealing.shares[1];
+        tampered.value = Scalar::rand(rng);
+        assert_eq!(
+            Pedersen::verify_share(&pp, &dealing.commitments, &tampered),
+            Err(CryptoError::InvalidShare)
+        );
+
+        let mut tampered = dealing.shares[1];
+        tampered.blinding = Scalar::rand(rng);
+        assert_eq!(
+            Pedersen::verify_share(&pp, &dealing.commitments, &tampered),
+            Err(CryptoError::InvalidShare)
+        );
+
+        let mut moved = dealing.shares[1];
+        moved.index = 6;
+        assert_eq!(
+            Pedersen::verify_share(&pp, &dealing.commitments, &moved),
+            Err(CryptoError::InvalidShare)
+        );
+
+        assert_eq!(
+            Pedersen::setup(rng, 4, 3).err(),
+            Some(CryptoError::InvalidThresholdParameters)
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::derive_challenge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+mod tests;
+
+/// Schnorr adaptor signatures. A pre-signature is a Schnorr signature encrypted to an adaptor point `T = t·G`:
+/// anyone can check it against `T`, only the holder of `t` can complete it, and the completed signature together with
+/// the pre-signature reveals `t`.
+///
+/// The nonce commitment of the final signature is `R + T`, so the challenge is bound to the adaptor point from the
+/// start and the response only needs to be shifted by `t`.
+pub struct AdaptorSignature<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+}
+
+pub type PublicKey<C> = <C as ProjectiveCurve>::Affine;
+
+pub type SecretKey<C> = <C as ProjectiveCurve>::ScalarField;
+
+/// Adaptor point `T = t·G`.
+pub type AdaptorPoint<C> = <C as ProjectiveCurve>::Affine;
+
+/// Discrete logarithm `t` of the adaptor point.
+pub type AdaptorSecret<C> = <C as ProjectiveCurve>::ScalarField;
+
+/// Pre-signature `(R, ŝ)` with `ŝ·G = R + c·X` and `c` derived from `R + T`.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PreSignature<C: ProjectiveCurve> {
+    pub(crate) random_commit: C::Affine,
+    pub(crate) response: C::ScalarField,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Signature<C: ProjectiveCurve> {
+    pub(crate) random_commit: C::Affine,
+    pub(crate) response: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> AdaptorSignature<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Schnorr Adaptor Signature";
+
+    pub fn setup<R: Rng>(rng: &mut R) -> Result<Parameters<C>, CryptoError> {
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+        })
+    }
+
+    pub fn keygen<R: Rng>(
+        pp: &Parameters<C>,
+        rng: &mut R,
+    ) -> Result<(PublicKey<C>, SecretKey<C>), CryptoError> {
+        let secret_key = C::ScalarField::rand(rng);
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        Ok((public_key, secret_key))
+    }
+
+    /// Pre-sign `message`, encrypting the signature to `adaptor_point`.
+    pub fn pre_sign<R: Rng, D: Digest>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        secret_key: &SecretKey<C>,
+        adaptor_point: &AdaptorPoint<C>,
+        message: &[u8],
+    ) -> Result<PreSignature<C>, CryptoError> {
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        let nonce = C::ScalarField::rand(rng);
+        let random_commit = pp.generator.mul(nonce.into_repr()).into_affine();
+
+        let challenge =
+            Self::challenge::<D>(pp, &public_key, &(random_commit + *adaptor_point), message)?;
+
+        Ok(PreSignature {
+            random_commit,
+            response: nonce + challenge * secret_key,
+        })
+    }
+
+    pub fn verify_pre_sig<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        adaptor_point: &AdaptorPoint<C>,
+        message: &[u8],
+        pre_signature: &PreSignature<C>,
+    ) -> Result<(), CryptoError> {
+        let challenge = Self::challenge::<D>(
+            pp,
+            public_key,
+            &(pre_signature.random_commit + *adaptor_point),
+            message,
+        )?;
+
+        if pp.generator.mul(pre_signature.response.into_repr())
+            != pre_signature.random_commit.into_projective() + public_key.mul(challenge.into_repr())
+        {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "Adaptor Pre-Signature",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Complete a pre-signature with the discrete logarithm of its adaptor point.
+    pub fn adapt(
+        pp: &Parameters<C>,
+        pre_signature: &PreSignature<C>,
+        adaptor_secret: &AdaptorSecret<C>,
+    ) -> Result<Signature<C>, CryptoError> {
+        let adaptor_point = pp.generator.mul(adaptor_secret.into_repr()).into_affine();
+
+        Ok(Signature {
+            random_commit: pre_signature.random_commit + adaptor_point,
+            response: pre_signature.response + adaptor_secret,
+        })
+    }
+
+    /// Recover the discrete logarithm of the adaptor point from a pre-signature and its completion. Fails if
+    /// `signature` was not obtained from `pre_signature` for `adaptor_point`.
+    pub fn extract_secret(
+        pp: &Parameters<C>,
+        adaptor_point: &AdaptorPoint<C>,
+        pre_signature: &PreSignature<C>,
+        signature: &Signature<C>,
+    ) -> Result<AdaptorSecret<C>, CryptoError> {
+        let adaptor_secret = signature.response - pre_signature.response;
+
+        if pp.generator.mul(adaptor_secret.into_repr()) != adaptor_point.into_projective()
+            || signature.random_commit != pre_signature.random_commit + *adaptor_point
+        {
+            return Err(CryptoError::InvalidAdaptorSignature);
+        }
+
+        Ok(adaptor_secret)
+    }
+
+    pub fn verify<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), CryptoError> {
+        let challenge = Self::challenge::<D>(pp, public_key, &signature.random_commit, message)?;
+
+        if pp.generator.mul(signature.response.into_repr())
+            != signature.random_commit.into_projective() + public_key.mul(challenge.into_repr())
+        {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "Adaptor",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn challenge<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        random_commit: &C::Affine,
+        message: &[u8],
+    ) -> Result<C::ScalarField, CryptoError> {
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            pp.generator,
+            public_key,
+            random_commit,
+            message
+        ]?);
+
+        Ok(derive_challenge::<C::ScalarField>(&mut fs_rng))
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::signatures::adaptor;
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type AdaptorSignature = adaptor::AdaptorSignature<Curve>;
+
+    #[test]
+    fn test_adapt_and_extract() {
+        let rng = &mut thread_rng();
+        let pp = AdaptorSignature::setup(rng).unwrap();
+        let (pk, sk) = AdaptorSignature::keygen(&pp, rng).unwrap();
+        let message = b"Atomic swap";
+
+        let adaptor_secret = Scalar::rand(rng);
+        let adaptor_point = pp.generator.mul(adaptor_secret.into_repr()).into_affine();
+
+        let pre_signature =
+            AdaptorSignature::pre_sign::<_, Blake2s>(rng, &pp, &sk, &adaptor_point, message)
+                .unwrap();
+        assert_eq!(
+            AdaptorSignature::verify_pre_sig::<Blake2s>(
+                &pp,
+                &pk,
+                &adaptor_point,
+                message,
+                &pre_signature
+            ),
+            Ok(())
+        );
+
+        let signature = AdaptorSignature::adapt(&pp, &pre_signature, &adaptor_secret).unwrap();
+        assert_eq!(
+            AdaptorSignature::verify::<Blake2s>(&pp, &pk, message, &signature),
+            Ok(())
+        );
+
+        assert_eq!(
+            AdaptorSignature::extract_secret(&pp, &adaptor_point, &pre_signature, &signature),
+            Ok(adaptor_secret)
+        );
+
+        // Pre-signatures and signatures survive a serialization round trip
+        let mut serialized = Vec::new();
+        pre_signature.serialize(&mut serialized).unwrap();
+        assert_eq!(
+            adaptor::PreSignature::<Curve>::deserialize(&serialized[..]).unwrap(),
+            pre_signature
+        );
+
+        let mut serialized = Vec::new();
+        signature.serialize(&mut serialized).unwrap();
+        assert_eq!(
+            adaptor::Signature::<Curve>::deserialize(&serialized[..]).unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn test_invalid_pre_signature() {
+        let rng = &mut thread_rng();
+        let pp = AdaptorSignature::setup(rng).unwrap();
+        let (pk, sk) = AdaptorSignature::keygen(&pp, rng).unwrap();
+        let message = b"Atomic swap";
+
+        let adaptor_secret = Scalar::rand(rng);
+        let adaptor_point = pp.generator.mul(adaptor_secret.into_repr()).into_affine();
+        let pre_signature =
+            AdaptorSignature::pre_sign::<_, Blake2s>(rng, &pp, &sk, &adaptor_point, message)
+                .unwrap();
+
+        // The pre-signature is bound to its adaptor point
+        let other_point = pp
+            .generator
+            .mul(Scalar::rand(rng).into_repr())
+            .into_affine();
+        assert_eq!(
+            AdaptorSignature::verify_pre_sig::<Blake2s>(
+                &pp,
+                &pk,
+                &other_point,
+                message,
+                &pre_signature
+            ),
+            Err(CryptoError::SignatureVerificationError(String::from(
+                "Adaptor Pre-Signature"
+            )))
+        );
+
+        // A pre-signature is not a valid signature, and completing it with the wrong secret does not help
+        let incomplete = adaptor::Signature::<Curve> {
+            random_commit: pre_signature.random_commit,
+            response: pre_signature.response,
+        };
+        assert!(AdaptorSignature::verify::<Blake2s>(&pp, &pk, message, &incomplete).is_err());
+
+        let wrong = AdaptorSignature::adapt(&pp, &pre_signature, &Scalar::rand(rng)).unwrap();
+        assert_eq!(
+            AdaptorSignature::verify::<Blake2s>(&pp, &pk, message, &wrong),
+            Err(CryptoError::SignatureVerificationError(String::from(
+                "Adaptor"
+            )))
+        );
+        assert_eq!(
+            AdaptorSignature::extract_secret(&pp, &adaptor_point, &pre_signature, &wrong),
+            Err(CryptoError::InvalidAdaptorSignature)
+        );
+    }
+}
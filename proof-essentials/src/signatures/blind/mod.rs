@@ -0,0 +1,194 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::derive_challenge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+mod tests;
+
+/// Blind Schnorr signatures. An issuer signs a message chosen by a user without learning the message, and the
+/// resulting signature cannot be linked to the signing session that produced it.
+///
+/// The user shifts the issuer's commitment by `α·G + β·X` and the challenge by `β`, then shifts the issuer's response
+/// by `α`. An issuer running many sessions concurrently is exposed to the ROS attack (Benhamouda et al., Eurocrypt
+/// 2021), so sessions should be run sequentially.
+pub struct BlindSchnorr<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+}
+
+pub type PublicKey<C> = <C as ProjectiveCurve>::Affine;
+
+pub type SecretKey<C> = <C as ProjectiveCurve>::ScalarField;
+
+/// Secret nonce of the issuer for a single signing session. It is consumed by [`BlindSchnorr::sign`] and must never
+/// be reused.
+pub struct IssuerNonce<C: ProjectiveCurve> {
+    nonce: C::ScalarField,
+}
+
+/// Commitment `R = k·G` sent by the issuer to open a signing session.
+pub type IssuerCommitment<C> = <C as ProjectiveCurve>::Affine;
+
+/// Blinding factors `(α, β)`, together with the blinded commitment `R'` and the unblinded challenge `c'`, kept by
+/// the user until the signature is unblinded.
+pub struct BlindingFactors<C: ProjectiveCurve> {
+    alpha: C::ScalarField,
+    beta: C::ScalarField,
+    random_commit: C::Affine,
+    challenge: C::ScalarField,
+}
+
+/// Blinded challenge `c = c' + β` sent by the user to the issuer.
+pub type BlindedChallenge<C> = <C as ProjectiveCurve>::ScalarField;
+
+/// Response `s = k + c·x` of the issuer to a blinded challenge.
+pub type BlindSignature<C> = <C as ProjectiveCurve>::ScalarField;
+
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Signature<C: ProjectiveCurve> {
+    pub(crate) random_commit: C::Affine,
+    pub(crate) response: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> BlindSchnorr<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Blind Schnorr Signature";
+
+    pub fn setup<R: Rng>(rng: &mut R) -> Result<Parameters<C>, CryptoError> {
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+        })
+    }
+
+    pub fn keygen<R: Rng>(
+        pp: &Parameters<C>,
+        rng: &mut R,
+    ) -> Result<(PublicKey<C>, SecretKey<C>), CryptoError> {
+        let secret_key = C::ScalarField::rand(rng);
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        Ok((public_key, secret_key))
+    }
+
+    /// Issuer, first move: commit to a fresh nonce.
+    pub fn commit<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+    ) -> Result<(IssuerNonce<C>, IssuerCommitment<C>), CryptoError> {
+        let nonce = C::ScalarField::rand(rng);
+
+        Ok((
+            IssuerNonce { nonce },
+            pp.generator.mul(nonce.into_repr()).into_affine(),
+        ))
+    }
+
+    /// User: blind the issuer's commitment and derive the challenge for `message` that is sent back to the issuer.
+    pub fn blind<R: Rng, D: Digest>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        commitment: &IssuerCommitment<C>,
+        message: &[u8],
+    ) -> Result<(BlindingFactors<C>, BlindedChallenge<C>), CryptoError> {
+        let alpha = C::ScalarField::rand(rng);
+        let beta = C::ScalarField::rand(rng);
+
+        let random_commit = (commitment.into_projective()
+            + pp.generator.mul(alpha.into_repr())
+            + public_key.mul(beta.into_repr()))
+        .into_affine();
+
+        let challenge = Self::challenge::<D>(pp, public_key, &random_commit, message)?;
+
+        Ok((
+            BlindingFactors {
+                alpha,
+                beta,
+                random_commit,
+                challenge,
+            },
+            challenge + beta,
+        ))
+    }
+
+    /// Issuer, second move: answer the blinded challenge.
+    pub fn sign(
+        secret_key: &SecretKey<C>,
+        nonce: IssuerNonce<C>,
+        challenge: &BlindedChallenge<C>,
+    ) -> Result<BlindSignature<C>, CryptoError> {
+        Ok(nonce.nonce + *challenge * secret_key)
+    }
+
+    /// User: check the issuer's answer and turn it into a signature on the message passed to [`BlindSchnorr::blind`].
+    pub fn unblind(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        commitment: &IssuerCommitment<C>,
+        blinding_factors: &BlindingFactors<C>,
+        blind_signature: &BlindSignature<C>,
+    ) -> Result<Signature<C>, CryptoError> {
+        let blinded_challenge = blinding_factors.challenge + blinding_factors.beta;
+
+        if pp.generator.mul(blind_signature.into_repr())
+            != commitment.into_projective() + public_key.mul(blinded_challenge.into_repr())
+        {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "Blind Schnorr",
+            )));
+        }
+
+        Ok(Signature {
+            random_commit: blinding_factors.random_commit,
+            response: *blind_signature + blinding_factors.alpha,
+        })
+    }
+
+    pub fn verify<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), CryptoError> {
+        let challenge = Self::challenge::<D>(pp, public_key, &signature.random_commit, message)?;
+
+        if pp.generator.mul(signature.response.into_repr())
+            != signature.random_commit.into_projective() + public_key.mul(challenge.into_repr())
+        {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "Blind Schnorr",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn challenge<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        random_commit: &C::Affine,
+        message: &[u8],
+    ) -> Result<C::ScalarField, CryptoError> {
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            pp.generator,
+            public_key,
+            random_commit,
+            message
+        ]?);
+
+        Ok(derive_challenge::<C::ScalarField>(&mut fs_rng))
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::signatures::blind;
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type BlindSchnorr = blind::BlindSchnorr<Curve>;
+
+    #[test]
+    fn test_blind_sign_and_verify() {
+        let rng = &mut thread_rng();
+        let pp = BlindSchnorr::setup(rng).unwrap();
+        let (pk, sk) = BlindSchnorr::keygen(&pp, rng).unwrap();
+        let message = b"Anonymous token";
+
+        let (nonce, commitment) = BlindSchnorr::commit(rng, &pp).unwrap();
+        let (blinding_factors, challenge) =
+            BlindSchnorr::blind::<_, Blake2s>(rng, &pp, &pk, &commitment, message).unwrap();
+        let blind_signature = BlindSchnorr::sign(&sk, nonce, &challenge).unwrap();
+        let signature =
+            BlindSchnorr::unblind(&pp, &pk, &commitment, &blinding_factors, &blind_signature)
+                .unwrap();
+
+        assert_eq!(
+            BlindSchnorr::verify::<Blake2s>(&pp, &pk, message, &signature),
+            Ok(())
+        );
+
+        // The issuer's view of the session does not appear in the signature
+        assert_ne!(signature.random_commit, commitment);
+        assert_ne!(signature.response, blind_signature);
+
+        assert_eq!(
+            BlindSchnorr::verify::<Blake2s>(&pp, &pk, b"Another token", &signature),
+            Err(CryptoError::SignatureVerificationError(String::from(
+                "Blind Schnorr"
+            )))
+        );
+
+        let (other_pk, _) = BlindSchnorr::keygen(&pp, rng).unwrap();
+        assert!(BlindSchnorr::verify::<Blake2s>(&pp, &other_pk, message, &signature).is_err());
+    }
+
+    #[test]
+    fn test_invalid_issuer_response() {
+        let rng = &mut thread_rng();
+        let pp = BlindSchnorr::setup(rng).unwrap();
+        let (pk, sk) = BlindSchnorr::keygen(&pp, rng).unwrap();
+        let message = b"Anonymous token";
+
+        let (nonce, commitment) = BlindSchnorr::commit(rng, &pp).unwrap();
+        let (blinding_factors, challenge) =
+            BlindSchnorr::blind::<_, Blake2s>(rng, &pp, &pk, &commitment, message).unwrap();
+
+        // An issuer answering with the wrong key is caught before unblinding
+        let blind_signature = BlindSchnorr::sign(&Scalar::rand(rng), nonce, &challenge).unwrap();
+        assert_eq!(
+            BlindSchnorr::unblind(&pp, &pk, &commitment, &blinding_factors, &blind_signature),
+            Err(CryptoError::SignatureVerificationError(String::from(
+                "Blind Schnorr"
+            )))
+        );
+
+        // A commitment substituted after blinding is caught as well
+        let other_commitment = pp
+            .generator
+            .mul(Scalar::rand(rng).into_repr())
+            .into_affine();
+        let (nonce, commitment) = BlindSchnorr::commit(rng, &pp).unwrap();
+        let (blinding_factors, challenge) =
+            BlindSchnorr::blind::<_, Blake2s>(rng, &pp, &pk, &commitment, message).unwrap();
+        let blind_signature = BlindSchnorr::sign(&sk, nonce, &challenge).unwrap();
+        assert!(BlindSchnorr::unblind(
+            &pp,
+            &pk,
+            &other_commitment,
+            &blinding_factors,
+            &blind_signature
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,383 @@
+use crate::error::CryptoError;
+use crate::utils::secret_sharing::{evaluate_polynomial, lagrange_coefficients_at_zero};
+use crate::zkp::transcript::derive_challenge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, ToBytes};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+mod tests;
+
+/// FROST threshold Schnorr signatures (Komlo and Goldberg, SAC 2020). The signing key is split into `n` Shamir
+/// shares, and any `t` share holders can jointly produce a Schnorr signature under the group public key.
+///
+/// Shares are dealt by a trusted dealer together with Feldman commitments to the sharing polynomial, so every
+/// participant can check its share. Signing takes two rounds: nonce commitments, then partial signatures.
+pub struct Frost<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+    pub threshold: usize,
+    pub participants: usize,
+}
+
+pub type PublicKey<C> = <C as ProjectiveCurve>::Affine;
+
+/// Share of the signing key held by the participant with identifier `index`, in `1..=n`.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KeyShare<C: ProjectiveCurve> {
+    pub(crate) index: u64,
+    pub(crate) secret_share: C::ScalarField,
+    pub(crate) public_share: C::Affine,
+}
+
+impl<C: ProjectiveCurve> KeyShare<C> {
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn public_share(&self) -> C::Affine {
+        self.public_share
+    }
+}
+
+/// Output of the dealer: the group public key, Feldman commitments to the coefficients of the sharing polynomial
+/// and one key share per participant.
+pub struct KeyGeneration<C: ProjectiveCurve> {
+    pub public_key: PublicKey<C>,
+    pub commitments: Vec<C::Affine>,
+    pub shares: Vec<KeyShare<C>>,
+}
+
+/// Secret nonces of a participant for a single signing session. They are consumed by [`Frost::sign`] and must
+/// never be reused.
+pub struct SigningNonce<C: ProjectiveCurve> {
+    hiding: C::ScalarField,
+    binding: C::ScalarField,
+}
+
+/// Nonce commitments `(D_i, E_i)` published by participant `index` in the first round.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct NonceCommitment<C: ProjectiveCurve> {
+    pub index: u64,
+    pub hiding: C::Affine,
+    pub binding: C::Affine,
+}
+
+impl<C: ProjectiveCurve> ToBytes for NonceCommitment<C> {
+    fn write<W: Write>(&self, mut w: W) -> Result<(), std::io::Error> {
+        self.index.write(&mut w)?;
+        self.hiding.write(&mut w)?;
+        self.binding.write(&mut w)
+    }
+}
+
+pub type PartialSignature<C> = <C as ProjectiveCurve>::ScalarField;
+
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Signature<C: ProjectiveCurve> {
+    pub(crate) random_commit: C::Affine,
+    pub(crate) response: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Frost<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"FROST";
+
+    pub fn setup<R: Rng>(
+        rng: &mut R,
+        threshold: usize,
+        participants: usize,
+    ) -> Result<Parameters<C>, CryptoError> {
+        if threshold == 0 || threshold > participants {
+            return Err(CryptoError::InvalidThresholdParameters);
+        }
+
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+            threshold,
+            participants,
+        })
+    }
+
+    /// Sample a signing key and deal it to the participants with a random polynomial of degree `t - 1`.
+    pub fn keygen<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+    ) -> Result<KeyGeneration<C>, CryptoError> {
+        let coefficients: Vec<C::ScalarField> = (0..pp.threshold)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect();
+
+        let commitments: Vec<C::Affine> = coefficients
+            .iter()
+            .map(|coefficient| pp.generator.mul(coefficient.into_repr()).into_affine())
+            .collect();
+
+        let shares = (1..=pp.participants as u64)
+            .map(|index| {
+                let secret_share = evaluate_polynomial(&coefficients, index);
+
+                KeyShare {
+                    index,
+                    secret_share,
+                    public_share: pp.generator.mul(secret_share.into_repr()).into_affine(),
+                }
+            })
+            .collect();
+
+        Ok(KeyGeneration {
+            public_key: commitments[0],
+            commitments,
+            shares,
+        })
+    }
+
+    /// Check a key share against the dealer's commitments: `s_i·G = Σ_k C_k·i^k`.
+    pub fn verify_key_share(
+        pp: &Parameters<C>,
+        commitments: &[C::Affine],
+        share: &KeyShare<C>,
+    ) -> Result<(), CryptoError> {
+        let x = C::ScalarField::from(share.index);
+        let expected = commitments.iter().rev().fold(C::zero(), |acc, commitment| {
+            acc.mul(x.into_repr()) + commitment.into_projective()
+        });
+
+        if commitments.len() != pp.threshold
+            || pp.generator.mul(share.secret_share.into_repr()) != expected
+            || share.public_share.into_projective() != expected
+        {
+            return Err(CryptoError::InvalidKeyShare);
+        }
+
+        Ok(())
+    }
+
+    /// First round: sample the hiding and binding nonces of a participant and the commitments to publish.
+    pub fn commit<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        share: &KeyShare<C>,
+    ) -> Result<(SigningNonce<C>, NonceCommitment<C>), CryptoError> {
+        let hiding = C::ScalarField::rand(rng);
+        let binding = C::ScalarField::rand(rng);
+
+        let commitment = NonceCommitment {
+            index: share.index,
+            hiding: pp.generator.mul(hiding.into_repr()).into_affine(),
+            binding: pp.generator.mul(binding.into_repr()).into_affine(),
+        };
+
+        Ok((SigningNonce { hiding, binding }, commitment))
+    }
+
+    /// Second round: the partial signature `z_i = d_i + ρ_i·e_i + λ_i·s_i·c` of `share` over the signing set
+    /// described by `commitments`. Fails if the commitment of the participant in the set is not the commitment of
+    /// `nonce`, so that a coordinator cannot obtain a partial signature over commitments it substituted.
+    pub fn sign<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        share: &KeyShare<C>,
+        nonce: SigningNonce<C>,
+        commitments: &Vec<NonceCommitment<C>>,
+        message: &[u8],
+    ) -> Result<PartialSignature<C>, CryptoError> {
+        let session = Self::session::<D>(pp, public_key, commitments, message)?;
+        let position = session.position(share.index)?;
+
+        let commitment = &commitments[position];
+        if commitment.hiding != pp.generator.mul(nonce.hiding.into_repr()).into_affine()
+            || commitment.binding != pp.generator.mul(nonce.binding.into_repr()).into_affine()
+        {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        Ok(nonce.hiding
+            + session.binding_factors[position] * nonce.binding
+            + session.lagrange_coefficients[position] * share.secret_share * session.challenge)
+    }
+
+    /// Check the partial signature of the participant with identifier `index` against its public share, so that a
+    /// misbehaving signer can be identified.
+    pub fn verify_partial<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        public_share: &C::Affine,
+        index: u64,
+        commitments: &Vec<NonceCommitment<C>>,
+        message: &[u8],
+        partial_signature: &PartialSignature<C>,
+    ) -> Result<(), CryptoError> {
+        let session = Self::session::<D>(pp, public_key, commitments, message)?;
+        let position = session.position(index)?;
+        let commitment = &commitments[position];
+
+        let expected = commitment.hiding.into_projective()
+            + commitment
+                .binding
+                .mul(session.binding_factors[position].into_repr())
+            + public_share
+                .mul((session.lagrange_coefficients[position] * session.challenge).into_repr());
+
+        if pp.generator.mul(partial_signature.into_repr()) != expected {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "FROST Partial",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Combine the partial signatures, given in the order of `commitments`, into a Schnorr signature.
+    pub fn aggregate<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        commitments: &Vec<NonceCommitment<C>>,
+        message: &[u8],
+        partial_signatures: &[PartialSignature<C>],
+    ) -> Result<Signature<C>, CryptoError> {
+        if partial_signatures.len() != commitments.len() {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        let session = Self::session::<D>(pp, public_key, commitments, message)?;
+
+        Ok(Signature {
+            random_commit: session.random_commit,
+            response: partial_signatures.iter().sum(),
+        })
+    }
+
+    /// Verify `signature` as a plain Schnorr signature under the group public key.
+    pub fn verify<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), CryptoError> {
+        let challenge = Self::challenge::<D>(pp, public_key, &signature.random_commit, message)?;
+
+        if pp.generator.mul(signature.response.into_repr())
+            != signature.random_commit.into_projective() + public_key.mul(challenge.into_repr())
+        {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "FROST",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn session<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        commitments: &Vec<NonceCommitment<C>>,
+        message: &[u8],
+    ) -> Result<Session<C>, CryptoError> {
+        let indices: Vec<u64> = commitments
+            .iter()
+            .map(|commitment| commitment.index)
+            .collect();
+
+        // The signing set needs at least `t` distinct, valid identifiers
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        if sorted.len() != indices.len()
+            || indices.len() < pp.threshold
+            || indices
+                .iter()
+                .any(|&index| index == 0 || index > pp.participants as u64)
+        {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        // As in RFC 9591, the message and the commitment list are hashed to a fixed length first, so that no other
+        // message and signing set encode to the same binding factor seed
+        let message_digest = D::digest(message).to_vec();
+        let commitments_digest = D::digest(&to_bytes![commitments]?).to_vec();
+        let binding_factors = indices
+            .iter()
+            .map(|index| {
+                let mut fs_rng = FiatShamirRng::<D>::from_seed(&to_bytes![
+                    Self::PROTOCOL_NAME,
+                    b"binding",
+                    pp.generator,
+                    public_key,
+                    *index,
+                    message_digest,
+                    commitments_digest
+                ]?);
+
+                Ok(derive_challenge::<C::ScalarField>(&mut fs_rng))
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        let random_commit = commitments
+            .iter()
+            .zip(binding_factors.iter())
+            .fold(C::zero(), |acc, (commitment, binding_factor)| {
+                acc + commitment.hiding.into_projective()
+                    + commitment.binding.mul(binding_factor.into_repr())
+            })
+            .into_affine();
+
+        let challenge = Self::challenge::<D>(pp, public_key, &random_commit, message)?;
+
+        let lagrange_coefficients = lagrange_coefficients_at_zero::<C::ScalarField>(&indices)?;
+
+        Ok(Session {
+            indices,
+            binding_factors,
+            lagrange_coefficients,
+            random_commit,
+            challenge,
+        })
+    }
+
+    fn challenge<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        random_commit: &C::Affine,
+        message: &[u8],
+    ) -> Result<C::ScalarField, CryptoError> {
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            b"challenge",
+            pp.generator,
+            public_key,
+            random_commit,
+            message
+        ]?);
+
+        Ok(derive_challenge::<C::ScalarField>(&mut fs_rng))
+    }
+}
+
+/// Binding factors `ρ_i`, Lagrange coefficients `λ_i`, group commitment `R` and challenge `c` of a signing
+/// session, indexed like the nonce commitments of the signing set.
+struct Session<C: ProjectiveCurve> {
+    indices: Vec<u64>,
+    binding_factors: Vec<C::ScalarField>,
+    lagrange_coefficients: Vec<C::ScalarField>,
+    random_commit: C::Affine,
+    challenge: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Session<C> {
+    fn position(&self, index: u64) -> Result<usize, CryptoError> {
+        self.indices
+            .iter()
+            .position(|&i| i == index)
+            .ok_or(CryptoError::InvalidSigningSession)
+    }
+}
@@ -0,0 +1,243 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::signatures::frost;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Frost = frost::Frost<Curve>;
+
+    /// Run both signing rounds among the participants at `signers` and return their nonce commitments and partial
+    /// signatures.
+    fn sign_with(
+        pp: &frost::Parameters<Curve>,
+        keys: &frost::KeyGeneration<Curve>,
+        signers: &[usize],
+        message: &[u8],
+    ) -> (
+        Vec<frost::NonceCommitment<Curve>>,
+        Vec<frost::PartialSignature<Curve>>,
+    ) {
+        let rng = &mut thread_rng();
+
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+            .iter()
+            .map(|&i| Frost::commit(rng, pp, &keys.shares[i]).unwrap())
+            .unzip();
+
+        let partial_signatures = signers
+            .iter()
+            .zip(nonces)
+            .map(|(&i, nonce)| {
+                Frost::sign::<Blake2s>(
+                    pp,
+                    &keys.public_key,
+                    &keys.shares[i],
+                    nonce,
+                    &commitments,
+                    message,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        (commitments, partial_signatures)
+    }
+
+    #[test]
+    fn test_key_shares() {
+        let rng = &mut thread_rng();
+        let pp = Frost::setup(rng, 3, 5).unwrap();
+        let keys = Frost::keygen(rng, &pp).unwrap();
+
+        for share in keys.shares.iter() {
+            assert_eq!(
+                Frost::verify_key_share(&pp, &keys.commitments, share),
+                Ok(())
+            );
+        }
+
+        let mut tampered = keys.shares[0];
+        tampered.secret_share = Scalar::rand(rng);
+        assert_eq!(
+            Frost::verify_key_share(&pp, &keys.commitments, &tampered),
+            Err(CryptoError::InvalidKeyShare)
+        );
+
+        assert!(Frost::setup(rng, 0, 5).is_err());
+        assert_eq!(
+            Frost::setup(rng, 6, 5).err(),
+            Some(CryptoError::InvalidThresholdParameters)
+        );
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut thread_rng();
+        let pp = Frost::setup(rng, 3, 5).unwrap();
+        let keys = Frost::keygen(rng, &pp).unwrap();
+        let message = b"Three out of five";
+
+        // Any set of at least `t` participants can sign
+        for signers in [vec![0, 1, 2], vec![1, 3, 4], vec![0, 1, 2, 3, 4]] {
+            let (commitments, partial_signatures) = sign_with(&pp, &keys, &signers, message);
+
+            for (&i, partial_signature) in signers.iter().zip(partial_signatures.iter()) {
+                assert_eq!(
+                    Frost::verify_partial::<Blake2s>(
+                        &pp,
+                        &keys.public_key,
+                        &keys.shares[i].public_share(),
+                        keys.shares[i].index(),
+                        &commitments,
+                        message,
+                        partial_signature,
+                    ),
+                    Ok(())
+                );
+            }
+
+            let signature = Frost::aggregate::<Blake2s>(
+                &pp,
+                &keys.public_key,
+                &commitments,
+                message,
+                &partial_signatures,
+            )
+            .unwrap();
+
+            assert_eq!(
+                Frost::verify::<Blake2s>(&pp, &keys.public_key, message, &signature),
+                Ok(())
+            );
+            assert_eq!(
+                Frost::verify::<Blake2s>(&pp, &keys.public_key, b"Another message", &signature),
+                Err(CryptoError::SignatureVerificationError(String::from(
+                    "FROST"
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_misbehaving_signer() {
+        let rng = &mut thread_rng();
+        let pp = Frost::setup(rng, 2, 3).unwrap();
+        let keys = Frost::keygen(rng, &pp).unwrap();
+        let message = b"Two out of three";
+
+        let (commitments, mut partial_signatures) = sign_with(&pp, &keys, &[0, 2], message);
+        partial_signatures[1] = Scalar::rand(rng);
+
+        assert_eq!(
+            Frost::verify_partial::<Blake2s>(
+                &pp,
+                &keys.public_key,
+                &keys.shares[2].public_share(),
+                keys.shares[2].index(),
+                &commitments,
+                message,
+                &partial_signatures[1],
+            ),
+            Err(CryptoError::SignatureVerificationError(String::from(
+                "FROST Partial"
+            )))
+        );
+
+        let signature = Frost::aggregate::<Blake2s>(
+            &pp,
+            &keys.public_key,
+            &commitments,
+            message,
+            &partial_signatures,
+        )
+        .unwrap();
+        assert!(Frost::verify::<Blake2s>(&pp, &keys.public_key, message, &signature).is_err());
+    }
+
+    #[test]
+    fn test_binding_factors_separate_message_and_commitments() {
+        let rng = &mut thread_rng();
+        let pp = Frost::setup(rng, 3, 5).unwrap();
+        let keys = Frost::keygen(rng, &pp).unwrap();
+        let message = b"Three out of five".to_vec();
+
+        let commitments = (0..4)
+            .map(|i| Frost::commit(rng, &pp, &keys.shares[i]).unwrap().1)
+            .collect::<Vec<_>>();
+        let session =
+            Frost::session::<Blake2s>(&pp, &keys.public_key, &commitments, &message).unwrap();
+
+        // Moving the first commitment into the message keeps the concatenation of both
+        let mut shifted_message = message.clone();
+        shifted_message.extend(ark_ff::to_bytes![commitments[0]].unwrap());
+        let shifted_commitments = commitments[1..].to_vec();
+        let shifted_session = Frost::session::<Blake2s>(
+            &pp,
+            &keys.public_key,
+            &shifted_commitments,
+            &shifted_message,
+        )
+        .unwrap();
+
+        assert_ne!(
+            session.binding_factors[1],
+            shifted_session.binding_factors[0]
+        );
+    }
+
+    #[test]
+    fn test_invalid_signing_set() {
+        let rng = &mut thread_rng();
+        let pp = Frost::setup(rng, 3, 5).unwrap();
+        let keys = Frost::keygen(rng, &pp).unwrap();
+        let message = b"Three out of five";
+
+        // Fewer than `t` participants
+        let (nonce, commitment) = Frost::commit(rng, &pp, &keys.shares[0]).unwrap();
+        let (_, other_commitment) = Frost::commit(rng, &pp, &keys.shares[1]).unwrap();
+        assert_eq!(
+            Frost::sign::<Blake2s>(
+                &pp,
+                &keys.public_key,
+                &keys.shares[0],
+                nonce,
+                &vec![commitment, other_commitment],
+                message,
+            ),
+            Err(CryptoError::InvalidSigningSession)
+        );
+
+        // A commitment other than the one of the nonce
+        let (nonce, _) = Frost::commit(rng, &pp, &keys.shares[0]).unwrap();
+        let (_, third_commitment) = Frost::commit(rng, &pp, &keys.shares[2]).unwrap();
+        assert_eq!(
+            Frost::sign::<Blake2s>(
+                &pp,
+                &keys.public_key,
+                &keys.shares[0],
+                nonce,
+                &vec![commitment, other_commitment, third_commitment],
+                message,
+            ),
+            Err(CryptoError::InvalidSigningSession)
+        );
+
+        // The same participant twice
+        let (nonce, commitment) = Frost::commit(rng, &pp, &keys.shares[0]).unwrap();
+        assert_eq!(
+            Frost::sign::<Blake2s>(
+                &pp,
+                &keys.public_key,
+                &keys.shares[0],
+                nonce,
+                &vec![commitment, commitment, other_commitment],
+                message,
+            ),
+            Err(CryptoError::InvalidSigningSession)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod adaptor;
+pub mod blind;
+pub mod frost;
+pub mod musig2;
+pub mod ring;
@@ -0,0 +1,316 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::derive_challenge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, ToBytes};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+mod tests;
+
+/// MuSig2 two-round Schnorr multi-signatures (Nick, Ruffing and Seurin, Crypto 2021). A group of signers produces a
+/// single Schnorr signature that verifies under the aggregate of their public keys.
+///
+/// In the first round every signer publishes two nonce commitments, which can be done before the message is known.
+/// In the second round every signer sends a partial signature, and the partial signatures add up to the final one.
+pub struct MuSig2<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+}
+
+pub type PublicKey<C> = <C as ProjectiveCurve>::Affine;
+
+pub type SecretKey<C> = <C as ProjectiveCurve>::ScalarField;
+
+/// Aggregate public key `X = Σ a_i·X_i` together with the public keys `X_i` and key aggregation coefficients `a_i`
+/// of every signer.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct AggregateKey<C: ProjectiveCurve> {
+    pub(crate) key: PublicKey<C>,
+    pub(crate) public_keys: Vec<PublicKey<C>>,
+    pub(crate) coefficients: Vec<C::ScalarField>,
+}
+
+impl<C: ProjectiveCurve> AggregateKey<C> {
+    pub fn key(&self) -> PublicKey<C> {
+        self.key
+    }
+}
+
+/// Secret nonces of a signer for a single signing session. They are consumed by [`MuSig2::sign`] and must never be
+/// reused.
+pub struct SecretNonce<C: ProjectiveCurve> {
+    r_1: C::ScalarField,
+    r_2: C::ScalarField,
+}
+
+/// Nonce commitments `(R_1, R_2)` of a signer, or their sum over all signers.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicNonce<C: ProjectiveCurve>(pub C::Affine, pub C::Affine);
+
+impl<C: ProjectiveCurve> ToBytes for PublicNonce<C> {
+    fn write<W: Write>(&self, mut w: W) -> Result<(), std::io::Error> {
+        self.0.write(&mut w)?;
+        self.1.write(&mut w)
+    }
+}
+
+/// Nonce coefficient `b`, effective nonce `R = R_1 + b·R_2` and challenge `c` of a signing session.
+struct Session<C: ProjectiveCurve> {
+    nonce_coefficient: C::ScalarField,
+    random_commit: C::Affine,
+    challenge: C::ScalarField,
+}
+
+pub type PartialSignature<C> = <C as ProjectiveCurve>::ScalarField;
+
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Signature<C: ProjectiveCurve> {
+    pub(crate) random_commit: C::Affine,
+    pub(crate) response: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> MuSig2<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"MuSig2";
+
+    pub fn setup<R: Rng>(rng: &mut R) -> Result<Parameters<C>, CryptoError> {
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+        })
+    }
+
+    pub fn keygen<R: Rng>(
+        pp: &Parameters<C>,
+        rng: &mut R,
+    ) -> Result<(PublicKey<C>, SecretKey<C>), CryptoError> {
+        let secret_key = C::ScalarField::rand(rng);
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        Ok((public_key, secret_key))
+    }
+
+    /// Aggregate the public keys of all signers. Each key is weighted by a coefficient bound to the whole list,
+    /// which prevents rogue-key attacks.
+    pub fn aggregate_keys<D: Digest>(
+        pp: &Parameters<C>,
+        public_keys: &Vec<PublicKey<C>>,
+    ) -> Result<AggregateKey<C>, CryptoError> {
+        if public_keys.is_empty() {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        let coefficients = public_keys
+            .iter()
+            .map(|public_key| {
+                Ok(Self::hash::<D>(&to_bytes![
+                    Self::PROTOCOL_NAME,
+                    b"key aggregation",
+                    pp.generator,
+                    public_keys,
+                    public_key
+                ]?))
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        let key = public_keys
+            .iter()
+            .zip(coefficients.iter())
+            .fold(C::zero(), |acc, (public_key, coefficient)| {
+                acc + public_key.mul(coefficient.into_repr())
+            })
+            .into_affine();
+
+        Ok(AggregateKey {
+            key,
+            public_keys: public_keys.clone(),
+            coefficients,
+        })
+    }
+
+    /// First round: sample the secret nonces of a signer and the commitments to send to the other signers.
+    pub fn generate_nonce<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+    ) -> Result<(SecretNonce<C>, PublicNonce<C>), CryptoError> {
+        let r_1 = C::ScalarField::rand(rng);
+        let r_2 = C::ScalarField::rand(rng);
+
+        let public_nonce = PublicNonce(
+            pp.generator.mul(r_1.into_repr()).into_affine(),
+            pp.generator.mul(r_2.into_repr()).into_affine(),
+        );
+
+        Ok((SecretNonce { r_1, r_2 }, public_nonce))
+    }
+
+    pub fn aggregate_nonces(
+        public_nonces: &[PublicNonce<C>],
+    ) -> Result<PublicNonce<C>, CryptoError> {
+        if public_nonces.is_empty() {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        let (r_1, r_2) = public_nonces
+            .iter()
+            .fold((C::zero(), C::zero()), |(r_1, r_2), nonce| {
+                (
+                    r_1 + nonce.0.into_projective(),
+                    r_2 + nonce.1.into_projective(),
+                )
+            });
+
+        Ok(PublicNonce(r_1.into_affine(), r_2.into_affine()))
+    }
+
+    /// Second round: the partial signature `s_i = r_1 + b·r_2 + c·a_i·x_i` of the signer at `signer_index`.
+    pub fn sign<D: Digest>(
+        pp: &Parameters<C>,
+        aggregate_key: &AggregateKey<C>,
+        signer_index: usize,
+        secret_key: &SecretKey<C>,
+        secret_nonce: SecretNonce<C>,
+        aggregate_nonce: &PublicNonce<C>,
+        message: &[u8],
+    ) -> Result<PartialSignature<C>, CryptoError> {
+        if signer_index >= aggregate_key.public_keys.len()
+            || pp.generator.mul(secret_key.into_repr()).into_affine()
+                != aggregate_key.public_keys[signer_index]
+        {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        let session = Self::session::<D>(pp, aggregate_key, aggregate_nonce, message)?;
+
+        Ok(secret_nonce.r_1
+            + session.nonce_coefficient * secret_nonce.r_2
+            + session.challenge * aggregate_key.coefficients[signer_index] * secret_key)
+    }
+
+    /// Check the partial signature of the signer at `signer_index` against its public key and nonce commitments,
+    /// so that a misbehaving signer can be identified.
+    pub fn verify_partial<D: Digest>(
+        pp: &Parameters<C>,
+        aggregate_key: &AggregateKey<C>,
+        signer_index: usize,
+        public_nonce: &PublicNonce<C>,
+        aggregate_nonce: &PublicNonce<C>,
+        message: &[u8],
+        partial_signature: &PartialSignature<C>,
+    ) -> Result<(), CryptoError> {
+        if signer_index >= aggregate_key.public_keys.len() {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        let session = Self::session::<D>(pp, aggregate_key, aggregate_nonce, message)?;
+
+        let expected = public_nonce.0.into_projective()
+            + public_nonce.1.mul(session.nonce_coefficient.into_repr())
+            + aggregate_key.public_keys[signer_index]
+                .mul((session.challenge * aggregate_key.coefficients[signer_index]).into_repr());
+
+        if pp.generator.mul(partial_signature.into_repr()) != expected {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "MuSig2 Partial",
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn aggregate_signatures<D: Digest>(
+        pp: &Parameters<C>,
+        aggregate_key: &AggregateKey<C>,
+        aggregate_nonce: &PublicNonce<C>,
+        message: &[u8],
+        partial_signatures: &[PartialSignature<C>],
+    ) -> Result<Signature<C>, CryptoError> {
+        if partial_signatures.len() != aggregate_key.public_keys.len() {
+            return Err(CryptoError::InvalidSigningSession);
+        }
+
+        let session = Self::session::<D>(pp, aggregate_key, aggregate_nonce, message)?;
+
+        Ok(Signature {
+            random_commit: session.random_commit,
+            response: partial_signatures.iter().sum(),
+        })
+    }
+
+    /// Verify `signature` as a plain Schnorr signature under the aggregate public key.
+    pub fn verify<D: Digest>(
+        pp: &Parameters<C>,
+        aggregate_key: &PublicKey<C>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), CryptoError> {
+        let c = Self::challenge::<D>(pp, aggregate_key, &signature.random_commit, message)?;
+
+        if pp.generator.mul(signature.response.into_repr())
+            != signature.random_commit.into_projective() + aggregate_key.mul(c.into_repr())
+        {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "MuSig2",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn session<D: Digest>(
+        pp: &Parameters<C>,
+        aggregate_key: &AggregateKey<C>,
+        aggregate_nonce: &PublicNonce<C>,
+        message: &[u8],
+    ) -> Result<Session<C>, CryptoError> {
+        let b = Self::hash::<D>(&to_bytes![
+            Self::PROTOCOL_NAME,
+            b"nonce",
+            pp.generator,
+            aggregate_key.key,
+            aggregate_nonce,
+            message
+        ]?);
+
+        let random_commit = (aggregate_nonce.0.into_projective()
+            + aggregate_nonce.1.mul(b.into_repr()))
+        .into_affine();
+        let c = Self::challenge::<D>(pp, &aggregate_key.key, &random_commit, message)?;
+
+        Ok(Session {
+            nonce_coefficient: b,
+            random_commit,
+            challenge: c,
+        })
+    }
+
+    fn challenge<D: Digest>(
+        pp: &Parameters<C>,
+        aggregate_key: &PublicKey<C>,
+        random_commit: &C::Affine,
+        message: &[u8],
+    ) -> Result<C::ScalarField, CryptoError> {
+        Ok(Self::hash::<D>(&to_bytes![
+            Self::PROTOCOL_NAME,
+            b"challenge",
+            pp.generator,
+            aggregate_key,
+            random_commit,
+            message
+        ]?))
+    }
+
+    fn hash<D: Digest>(seed: &[u8]) -> C::ScalarField {
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(&seed);
+
+        derive_challenge::<C::ScalarField>(&mut fs_rng)
+    }
+}
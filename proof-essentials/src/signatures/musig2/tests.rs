@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::signatures::musig2;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type MuSig2 = musig2::MuSig2<Curve>;
+
+    struct Session {
+        pp: musig2::Parameters<Curve>,
+        secret_keys: Vec<musig2::SecretKey<Curve>>,
+        aggregate_key: musig2::AggregateKey<Curve>,
+        public_nonces: Vec<musig2::PublicNonce<Curve>>,
+        aggregate_nonce: musig2::PublicNonce<Curve>,
+        partial_signatures: Vec<musig2::PartialSignature<Curve>>,
+    }
+
+    fn run_session(signers: usize, message: &[u8]) -> Session {
+        let rng = &mut thread_rng();
+        let pp = MuSig2::setup(rng).unwrap();
+
+        let (public_keys, secret_keys): (Vec<_>, Vec<_>) = (0..signers)
+            .map(|_| MuSig2::keygen(&pp, rng).unwrap())
+            .unzip();
+        let aggregate_key = MuSig2::aggregate_keys::<Blake2s>(&pp, &public_keys).unwrap();
+
+        // First round
+        let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) = (0..signers)
+            .map(|_| MuSig2::generate_nonce(rng, &pp).unwrap())
+            .unzip();
+        let aggregate_nonce = MuSig2::aggregate_nonces(&public_nonces).unwrap();
+
+        // Second round
+        let partial_signatures = secret_nonces
+            .into_iter()
+            .enumerate()
+            .map(|(i, secret_nonce)| {
+                MuSig2::sign::<Blake2s>(
+                    &pp,
+                    &aggregate_key,
+                    i,
+                    &secret_keys[i],
+                    secret_nonce,
+                    &aggregate_nonce,
+                    message,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        Session {
+            pp,
+            secret_keys,
+            aggregate_key,
+            public_nonces,
+            aggregate_nonce,
+            partial_signatures,
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let message = b"Let's sign together";
+
+        for signers in [1, 2, 5] {
+            let session = run_session(signers, message);
+
+            for i in 0..signers {
+                assert_eq!(
+                    MuSig2::verify_partial::<Blake2s>(
+                        &session.pp,
+                        &session.aggregate_key,
+                        i,
+                        &session.public_nonces[i],
+                        &session.aggregate_nonce,
+                        message,
+                        &session.partial_signatures[i],
+                    ),
+                    Ok(())
+                );
+            }
+
+            let signature = MuSig2::aggregate_signatures::<Blake2s>(
+                &session.pp,
+                &session.aggregate_key,
+                &session.aggregate_nonce,
+                message,
+                &session.partial_signatures,
+            )
+            .unwrap();
+
+            assert_eq!(
+                MuSig2::verify::<Blake2s>(
+                    &session.pp,
+                    &session.aggregate_key.key(),
+                    message,
+                    &signature
+                ),
+                Ok(())
+            );
+            assert_eq!(
+                MuSig2::verify::<Blake2s>(
+                    &session.pp,
+                    &session.aggregate_key.key(),
+                    b"Another message",
+                    &signature
+                ),
+                Err(CryptoError::SignatureVerificationError(String::from(
+                    "MuSig2"
+                )))
+            );
+
+            let mut serialized = Vec::new();
+            signature.serialize(&mut serialized).unwrap();
+            let deserialized = musig2::Signature::<Curve>::deserialize(&serialized[..]).unwrap();
+            assert_eq!(deserialized, signature);
+        }
+    }
+
+    #[test]
+    fn test_misbehaving_signer() {
+        let message = b"Let's sign together";
+        let mut session = run_session(3, message);
+
+        session.partial_signatures[1] = Scalar::rand(&mut thread_rng());
+
+        // The faulty partial signature is pinned on its signer
+        assert_eq!(
+            MuSig2::verify_partial::<Blake2s>(
+                &session.pp,
+                &session.aggregate_key,
+                1,
+                &session.public_nonces[1],
+                &session.aggregate_nonce,
+                message,
+                &session.partial_signatures[1],
+            ),
+            Err(CryptoError::SignatureVerificationError(String::from(
+                "MuSig2 Partial"
+            )))
+        );
+
+        let signature = MuSig2::aggregate_signatures::<Blake2s>(
+            &session.pp,
+            &session.aggregate_key,
+            &session.aggregate_nonce,
+            message,
+            &session.partial_signatures,
+        )
+        .unwrap();
+        assert!(MuSig2::verify::<Blake2s>(
+            &session.pp,
+            &session.aggregate_key.key(),
+            message,
+            &signature
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_invalid_session() {
+        let rng = &mut thread_rng();
+        let message = b"Let's sign together";
+        let session = run_session(3, message);
+
+        // A secret key that does not match the public key at the given position
+        let (secret_nonce, _) = MuSig2::generate_nonce(rng, &session.pp).unwrap();
+        assert_eq!(
+            MuSig2::sign::<Blake2s>(
+                &session.pp,
+                &session.aggregate_key,
+                0,
+                &session.secret_keys[1],
+                secret_nonce,
+                &session.aggregate_nonce,
+                message,
+            ),
+            Err(CryptoError::InvalidSigningSession)
+        );
+
+        assert_eq!(
+            MuSig2::aggregate_signatures::<Blake2s>(
+                &session.pp,
+                &session.aggregate_key,
+                &session.aggregate_nonce,
+                message,
+                &session.partial_signatures[..2],
+            ),
+            Err(CryptoError::InvalidSigningSession)
+        );
+
+        assert_eq!(
+            MuSig2::aggregate_keys::<Blake2s>(&session.pp, &vec![]),
+            Err(CryptoError::InvalidSigningSession)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::error::CryptoError;
+use crate::zkp::proofs::schnorr_identification::SchnorrIdentification;
+use crate::zkp::transcript::derive_challenge;
+use crate::zkp::SigmaProtocol;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, Zero};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+mod tests;
+
+/// Abe-Ohkubo-Suzuki ring signatures (Asiacrypt 2002) over Schnorr identification. A member of a ring of public
+/// keys signs a message in a way that shows some ring member signed, without revealing which one.
+///
+/// Every ring member contributes one Schnorr transcript. The challenge of each transcript is derived from the
+/// commitment of the previous one, closing the ring at the signer's position.
+pub struct RingSignature<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+}
+
+pub type PublicKey<C> = <C as ProjectiveCurve>::Affine;
+
+pub type SecretKey<C> = <C as ProjectiveCurve>::ScalarField;
+
+/// A ring signature: the challenge of the first ring member and one Schnorr response per ring member.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Signature<C: ProjectiveCurve> {
+    pub(crate) challenge: C::ScalarField,
+    pub(crate) responses: Vec<C::ScalarField>,
+}
+
+impl<C: ProjectiveCurve> RingSignature<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"AOS Ring Signature";
+
+    pub fn setup<R: Rng>(rng: &mut R) -> Result<Parameters<C>, CryptoError> {
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+        })
+    }
+
+    pub fn keygen<R: Rng>(
+        pp: &Parameters<C>,
+        rng: &mut R,
+    ) -> Result<(PublicKey<C>, SecretKey<C>), CryptoError> {
+        let secret_key = C::ScalarField::rand(rng);
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        Ok((public_key, secret_key))
+    }
+
+    /// Sign `message` on behalf of `ring`. `signer_index` is the position of the signer's public key in the ring.
+    pub fn sign<R: Rng, D: Digest>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        ring: &Vec<PublicKey<C>>,
+        signer_index: usize,
+        secret_key: &SecretKey<C>,
+        message: &[u8],
+    ) -> Result<Signature<C>, CryptoError> {
+        let ring_size = ring.len();
+        if signer_index >= ring_size
+            || pp.generator.mul(secret_key.into_repr()).into_affine() != ring[signer_index]
+        {
+            return Err(CryptoError::InvalidRing);
+        }
+
+        let mut challenges = vec![C::ScalarField::zero(); ring_size];
+        let mut responses = vec![C::ScalarField::zero(); ring_size];
+
+        let (commitment, randomness) = SchnorrIdentification::<C>::commit(
+            rng,
+            &pp.generator,
+            &ring[signer_index],
+            secret_key,
+        )?;
+
+        // Walk around the ring from the signer, simulating a transcript for every other member
+        let mut next = (signer_index + 1) % ring_size;
+        challenges[next] = Self::challenge::<D>(pp, ring, message, &commitment)?;
+        while next != signer_index {
+            let (commitment, response) = SchnorrIdentification::<C>::simulate(
+                rng,
+                &pp.generator,
+                &ring[next],
+                &challenges[next],
+            )?;
+            responses[next] = response;
+
+            let following = (next + 1) % ring_size;
+            challenges[following] = Self::challenge::<D>(pp, ring, message, &commitment)?;
+            next = following;
+        }
+
+        responses[signer_index] = SchnorrIdentification::<C>::respond(
+            &pp.generator,
+            &ring[signer_index],
+            secret_key,
+            &randomness,
+            &challenges[signer_index],
+        )?;
+
+        Ok(Signature {
+            challenge: challenges[0],
+            responses,
+        })
+    }
+
+    pub fn verify<D: Digest>(
+        pp: &Parameters<C>,
+        ring: &Vec<PublicKey<C>>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), CryptoError> {
+        if ring.is_empty() || signature.responses.len() != ring.len() {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "AOS Ring",
+            )));
+        }
+
+        let mut challenge = signature.challenge;
+        for (public_key, response) in ring.iter().zip(signature.responses.iter()) {
+            let commitment = (pp.generator.mul(response.into_repr())
+                + public_key.mul(challenge.into_repr()))
+            .into_affine();
+            challenge = Self::challenge::<D>(pp, ring, message, &commitment)?;
+        }
+
+        if challenge != signature.challenge {
+            return Err(CryptoError::SignatureVerificationError(String::from(
+                "AOS Ring",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Challenge of the next ring member, bound to the parameters, the ring, the message and the commitment of
+    /// the current member.
+    fn challenge<D: Digest>(
+        pp: &Parameters<C>,
+        ring: &Vec<PublicKey<C>>,
+        message: &[u8],
+        commitment: &C::Affine,
+    ) -> Result<C::ScalarField, CryptoError> {
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            pp.generator,
+            ring,
+            message,
+            commitment
+        ]?);
+
+        Ok(derive_challenge::<C::ScalarField>(&mut fs_rng))
+    }
+}
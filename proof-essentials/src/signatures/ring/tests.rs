@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::signatures::ring;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type RingSignature = ring::RingSignature<Curve>;
+
+    fn ring_of_keys(
+        pp: &ring::Parameters<Curve>,
+        size: usize,
+    ) -> (Vec<ring::PublicKey<Curve>>, Vec<ring::SecretKey<Curve>>) {
+        let rng = &mut thread_rng();
+        (0..size)
+            .map(|_| RingSignature::keygen(pp, rng).unwrap())
+            .unzip()
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut thread_rng();
+        let pp = RingSignature::setup(rng).unwrap();
+        let (ring, secret_keys) = ring_of_keys(&pp, 5);
+        let message = b"All in";
+
+        for (signer, secret_key) in secret_keys.iter().enumerate() {
+            let signature =
+                RingSignature::sign::<_, Blake2s>(rng, &pp, &ring, signer, secret_key, message)
+                    .unwrap();
+
+            assert_eq!(
+                RingSignature::verify::<Blake2s>(&pp, &ring, message, &signature),
+                Ok(())
+            );
+        }
+
+        // A ring of a single key is a plain Schnorr signature
+        let signature = RingSignature::sign::<_, Blake2s>(
+            rng,
+            &pp,
+            &ring[..1].to_vec(),
+            0,
+            &secret_keys[0],
+            message,
+        )
+        .unwrap();
+        assert_eq!(
+            RingSignature::verify::<Blake2s>(&pp, &ring[..1].to_vec(), message, &signature),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_invalid_signature() {
+        let rng = &mut thread_rng();
+        let pp = RingSignature::setup(rng).unwrap();
+        let (ring, secret_keys) = ring_of_keys(&pp, 4);
+
+        let signature =
+            RingSignature::sign::<_, Blake2s>(rng, &pp, &ring, 2, &secret_keys[2], b"Fold")
+                .unwrap();
+
+        let expected_error = Err(CryptoError::SignatureVerificationError(String::from(
+            "AOS Ring",
+        )));
+
+        // Different message
+        assert_eq!(
+            RingSignature::verify::<Blake2s>(&pp, &ring, b"Call", &signature),
+            expected_error
+        );
+
+        // Different ring
+        let mut other_ring = ring.clone();
+        other_ring.swap(0, 1);
+        assert_eq!(
+            RingSignature::verify::<Blake2s>(&pp, &other_ring, b"Fold", &signature),
+            expected_error
+        );
+
+        // Signing with a key that does not belong to the claimed position
+        let wrong_key = Scalar::rand(rng);
+        assert_eq!(
+            RingSignature::sign::<_, Blake2s>(rng, &pp, &ring, 1, &wrong_key, b"Fold"),
+            Err(CryptoError::InvalidRing)
+        );
+        assert_eq!(
+            RingSignature::sign::<_, Blake2s>(rng, &pp, &ring, 4, &secret_keys[2], b"Fold"),
+            Err(CryptoError::InvalidRing)
+        );
+    }
+
+    #[test]
+    fn test_serialize_deserialize_signature() {
+        let rng = &mut thread_rng();
+        let pp = RingSignature::setup(rng).unwrap();
+        let (ring, secret_keys) = ring_of_keys(&pp, 3);
+
+        let signature =
+            RingSignature::sign::<_, Blake2s>(rng, &pp, &ring, 0, &secret_keys[0], b"Raise")
+                .unwrap();
+
+        let mut serialized = vec![0; signature.serialized_size()];
+        signature.serialize(&mut serialized[..]).unwrap();
+        let deserialized = ring::Signature::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(signature, deserialized);
+
+        assert_eq!(
+            RingSignature::verify::<Blake2s>(&pp, &ring, b"Raise", &deserialized),
+            Ok(())
+        );
+    }
+}
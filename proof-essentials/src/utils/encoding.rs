@@ -0,0 +1,194 @@
+use crate::error::CryptoError;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, Field, FpParameters, FromBytes, PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+
+/// Number of candidate points tried for a message before giving up. Each candidate is a valid encoding with
+/// probability about 1/2, so exhausting them all happens with negligible probability.
+const MAX_ATTEMPTS: usize = 256;
+
+/// Maximum length in bytes of a message that [`encode_bytes`] can embed into a point of `C`.
+///
+/// The x-coordinate of the point holds a counter byte, a length byte and the message, and must stay below the
+/// modulus of the base field. Curves over extension fields are not supported and have a capacity of zero.
+pub fn max_message_length<C: ProjectiveCurve>() -> usize {
+    if C::BaseField::extension_degree() != 1 {
+        return 0;
+    }
+
+    let capacity = (<C::BaseField as Field>::BasePrimeField::size_in_bits() - 1) / 8;
+
+    capacity.saturating_sub(2)
+}
+
+/// Reversibly encode `message` as a point of the prime-order subgroup of `C` by try-and-increment: the
+/// x-coordinate is set to `counter || length || message`, and the counter is increased until it lies on the curve
+/// and the point is in the subgroup. Decode with [`decode_bytes`].
+///
+/// Contrary to $mG$-style encodings, the message is recovered without solving a discrete logarithm, and the
+/// discrete logarithm of the point is unknown to everyone.
+pub fn encode_bytes<C: ProjectiveCurve>(message: &[u8]) -> Result<C::Affine, CryptoError> {
+    let max_length = max_message_length::<C>();
+    if message.len() > max_length {
+        return Err(CryptoError::EncodingLengthError(message.len(), max_length));
+    }
+
+    let mut bytes = vec![0u8; C::Affine::zero().serialized_size()];
+    bytes[1] = message.len() as u8;
+    bytes[2..2 + message.len()].copy_from_slice(message);
+
+    let order = <<C::ScalarField as PrimeField>::Params as FpParameters>::MODULUS;
+    for counter in 0..MAX_ATTEMPTS {
+        bytes[0] = counter as u8;
+
+        if let Some(point) = C::Affine::from_random_bytes(&bytes) {
+            if !point.is_zero() && point.mul(order).is_zero() {
+                return Ok(point);
+            }
+        }
+    }
+
+    Err(CryptoError::InvalidEncoding)
+}
+
+/// Recover the message embedded in `point` by [`encode_bytes`]. Fails if the point is not such an encoding.
+pub fn decode_bytes<C: ProjectiveCurve>(point: &C::Affine) -> Result<Vec<u8>, CryptoError> {
+    if point.is_zero() {
+        return Err(CryptoError::InvalidEncoding);
+    }
+
+    let mut bytes = Vec::with_capacity(point.serialized_size());
+    point
+        .serialize(&mut bytes)
+        .map_err(|e| CryptoError::IoError(e.to_string()))?;
+
+    let length = bytes[1] as usize;
+    let max_length = max_message_length::<C>();
+    if length > max_length || bytes[2 + length..2 + max_length].iter().any(|&b| b != 0) {
+        return Err(CryptoError::InvalidEncoding);
+    }
+
+    Ok(bytes[2..2 + length].to_vec())
+}
+
+/// Encode an integer as a point with [`encode_bytes`].
+pub fn encode_u64<C: ProjectiveCurve>(value: u64) -> Result<C::Affine, CryptoError> {
+    encode_bytes::<C>(&value.to_le_bytes())
+}
+
+/// Recover an integer encoded with [`encode_u64`].
+pub fn decode_u64<C: ProjectiveCurve>(point: &C::Affine) -> Result<u64, CryptoError> {
+    let bytes: [u8; 8] = decode_bytes::<C>(point)?
+        .try_into()
+        .map_err(|_| CryptoError::InvalidEncoding)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Byte length of the big-endian encoding of the elements of `F`, the shortest that holds the modulus.
+pub fn field_length<F: PrimeField>() -> usize {
+    F::size_in_bits().div_ceil(8)
+}
+
+/// Big-endian encoding of `x` on [`field_length`] bytes, as used by SEC 1 and the standards built on it.
+pub fn field_to_be_bytes<F: PrimeField>(x: &F) -> Vec<u8> {
+    let bytes = x.into_repr().to_bytes_be();
+
+    bytes[bytes.len() - field_length::<F>()..].to_vec()
+}
+
+/// Decode an element of `F` encoded by [`field_to_be_bytes`], rejecting other lengths and values that are not
+/// reduced, so that every element has a single encoding.
+pub fn field_from_be_bytes<F: PrimeField>(bytes: &[u8]) -> Result<F, CryptoError> {
+    if bytes.len() != field_length::<F>() {
+        return Err(CryptoError::InvalidEncoding);
+    }
+
+    let mut little_endian: Vec<u8> = bytes.iter().rev().cloned().collect();
+    little_endian.resize(F::zero().into_repr().to_bytes_le().len(), 0);
+
+    F::BigInt::read(&little_endian[..])
+        .ok()
+        .and_then(F::from_repr)
+        .ok_or(CryptoError::InvalidEncoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+
+    #[test]
+    fn encode_and_decode_test() {
+        let max_length = max_message_length::<Curve>();
+        assert_eq!(max_length, 29);
+
+        for message in [&b""[..], b"Ace of spades", &[0xffu8; 29]] {
+            let point = encode_bytes::<Curve>(message).unwrap();
+            assert_eq!(decode_bytes::<Curve>(&point).unwrap(), message);
+        }
+
+        for value in [0, 42, u64::MAX] {
+            let point = encode_u64::<Curve>(value).unwrap();
+            assert_eq!(decode_u64::<Curve>(&point), Ok(value));
+        }
+
+        assert_eq!(
+            encode_bytes::<Curve>(&[0u8; 30]),
+            Err(CryptoError::EncodingLengthError(30, 29))
+        );
+
+        // A random point is almost surely not an encoding
+        let rng = &mut thread_rng();
+        let point = Curve::rand(rng).into_affine();
+        assert_eq!(
+            decode_bytes::<Curve>(&point),
+            Err(CryptoError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn encrypt_encoded_message_test() {
+        let rng = &mut thread_rng();
+
+        let parameters = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&parameters, rng).unwrap();
+
+        let message = b"Queen of hearts";
+        let plaintext = el_gamal::Plaintext(encode_bytes::<Curve>(message).unwrap());
+        let ciphertext =
+            ElGamal::encrypt(&parameters, &pk, &plaintext, &Scalar::rand(rng)).unwrap();
+
+        let decrypted = ElGamal::decrypt(&parameters, &sk, &ciphertext).unwrap();
+        assert_eq!(decode_bytes::<Curve>(&decrypted.0).unwrap(), message);
+    }
+
+    #[test]
+    fn field_encoding_test() {
+        let rng = &mut thread_rng();
+
+        assert_eq!(field_length::<Scalar>(), 32);
+        assert_eq!(field_length::<ark_bls12_381::Fq>(), 48);
+
+        let x = Scalar::rand(rng);
+        let bytes = field_to_be_bytes(&x);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(field_from_be_bytes::<Scalar>(&bytes), Ok(x));
+
+        let y = ark_bls12_381::Fq::rand(rng);
+        assert_eq!(field_from_be_bytes(&field_to_be_bytes(&y)), Ok(y));
+
+        // Wrong lengths and unreduced values
+        assert!(field_from_be_bytes::<Scalar>(&bytes[1..]).is_err());
+        assert!(field_from_be_bytes::<Scalar>(&[&[0u8][..], &bytes].concat()).is_err());
+        assert!(field_from_be_bytes::<Scalar>(&[0xff; 32]).is_err());
+        let modulus = <Scalar as PrimeField>::Params::MODULUS.to_bytes_be();
+        assert!(field_from_be_bytes::<Scalar>(&modulus[modulus.len() - 32..]).is_err());
+    }
+}
@@ -0,0 +1,273 @@
+use crate::error::CryptoError;
+use crate::utils::msm::ScalarMul;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::cfg_into_iter;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Curve with an efficiently computable endomorphism $\phi$ that acts on its prime-order subgroup as the
+/// multiplication by some scalar $\lambda$, such as $(x, y) \mapsto (\beta x, y)$ on curves with $a = 0$.
+///
+/// The Starknet curve has $a = 1$ and no such endomorphism, so it keeps the [`Plain`](crate::utils::msm::Plain)
+/// multiplications.
+///
+/// The crate implements it for BLS12-381 $\mathbb{G}_1$ with the `ark-bls12-381` feature, which is on by default.
+pub trait GlvParameters: ProjectiveCurve {
+    /// Compute $\phi(P) = \lambda P$.
+    fn endomorphism(point: &Self::Affine) -> Self::Affine;
+
+    /// Split $k$ into $(s_1, k_1)$ and $(s_2, k_2)$ such that $k = \pm k_1 \pm k_2 \lambda$, where $s_i$ tells
+    /// whether the sign of $k_i$ is negative and both $k_i$ have about half the bits of the scalar field.
+    fn decompose(scalar: &Self::ScalarField) -> [(bool, Self::ScalarField); 2];
+}
+
+/// GLV multiplications: $kP = k_1 P + k_2 \phi(P)$ with half-size $k_1, k_2$, so a multiplication takes half the
+/// doublings, and a multi-scalar multiplication of $n$ points becomes one of $2n$ points with half-size scalars.
+pub struct Glv;
+
+impl Glv {
+    /// The two half-size terms $(\pm P, k_1)$ and $(\pm\phi(P), k_2)$ of $kP$.
+    fn split<C: GlvParameters>(
+        base: &C::Affine,
+        scalar: &C::ScalarField,
+    ) -> [(C::Affine, <C::ScalarField as PrimeField>::BigInt); 2] {
+        let [(negative_1, k_1), (negative_2, k_2)] = C::decompose(scalar);
+        let endomorphism = C::endomorphism(base);
+
+        [
+            (if negative_1 { -*base } else { *base }, k_1.into_repr()),
+            (
+                if negative_2 {
+                    -endomorphism
+                } else {
+                    endomorphism
+                },
+                k_2.into_repr(),
+            ),
+        ]
+    }
+}
+
+impl<C: GlvParameters> ScalarMul<C> for Glv {
+    /// Simultaneous double-and-add over the bits of $k_1$ and $k_2$ with the precomputed sum $P + \phi(P)$.
+    fn mul(base: &C::Affine, scalar: &C::ScalarField) -> C {
+        let [(p_1, k_1), (p_2, k_2)] = Self::split::<C>(base, scalar);
+        let mut sum = p_1.into_projective();
+        sum.add_assign_mixed(&p_2);
+
+        let mut acc = C::zero();
+        for i in (0..k_1.num_bits().max(k_2.num_bits()) as usize).rev() {
+            acc.double_in_place();
+            match (k_1.get_bit(i), k_2.get_bit(i)) {
+                (true, true) => acc += &sum,
+                (true, false) => acc.add_assign_mixed(&p_1),
+                (false, true) => acc.add_assign_mixed(&p_2),
+                (false, false) => {}
+            }
+        }
+
+        acc
+    }
+
+    fn multi_scalar_mul(bases: &[C::Affine], scalars: &[C::ScalarField]) -> Result<C, CryptoError> {
+        if scalars.len() != bases.len() {
+            return Err(CryptoError::DotProductLengthError(
+                scalars.len(),
+                bases.len(),
+            ));
+        }
+
+        let (split_bases, split_scalars): (Vec<_>, Vec<_>) = bases
+            .iter()
+            .zip(scalars.iter())
+            .flat_map(|(base, scalar)| Self::split::<C>(base, scalar))
+            .unzip();
+        let num_bits = split_scalars
+            .iter()
+            .map(|k| k.num_bits() as usize)
+            .max()
+            .unwrap_or(0);
+
+        Ok(short_scalar_msm(&split_bases, &split_scalars, num_bits))
+    }
+}
+
+/// Pippenger's bucket method, like arkworks' variable-base multi-scalar multiplication, but over only the first
+/// `num_bits` bits of the scalars instead of the full scalar size.
+fn short_scalar_msm<C: ProjectiveCurve>(
+    bases: &[C::Affine],
+    scalars: &[<C::ScalarField as PrimeField>::BigInt],
+    num_bits: usize,
+) -> C {
+    let c = if bases.len() < 32 {
+        3
+    } else {
+        (ark_std::log2(bases.len()) * 69 / 100) as usize + 2
+    };
+
+    let window_sums: Vec<C> = cfg_into_iter!((0..num_bits).step_by(c).collect::<Vec<_>>())
+        .map(|w_start| {
+            let mut buckets = vec![C::zero(); (1 << c) - 1];
+            for (base, scalar) in bases.iter().zip(scalars.iter()) {
+                let mut scalar = *scalar;
+                scalar.divn(w_start as u32);
+                let index = (scalar.as_ref()[0] % (1 << c)) as usize;
+                if index != 0 {
+                    buckets[index - 1].add_assign_mixed(base);
+                }
+            }
+
+            // sum_i i * bucket_i, as the sum of the running sums from the highest bucket down
+            let mut running_sum = C::zero();
+            let mut window_sum = C::zero();
+            for bucket in buckets.into_iter().rev() {
+                running_sum += &bucket;
+                window_sum += &running_sum;
+            }
+            window_sum
+        })
+        .collect();
+
+    window_sums
+        .iter()
+        .rev()
+        .fold(C::zero(), |mut total, window_sum| {
+            for _ in 0..c {
+                total.double_in_place();
+            }
+            total + window_sum
+        })
+}
+
+/// BLS12-381 $\mathbb{G}_1$, where $\phi(x, y) = (\beta x, y)$ for a cube root of unity $\beta$ is the
+/// multiplication by $\lambda = z^2 - 1$ for the curve parameter $z$.
+#[cfg(feature = "ark-bls12-381")]
+mod bls12_381 {
+    use super::GlvParameters;
+
+    use ark_bls12_381::{Fq, Fr, G1Affine, G1Projective};
+    use ark_ff::{field_new, BigInteger, BigInteger256, PrimeField};
+
+    const BETA: Fq = field_new!(Fq, "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436");
+
+    pub(super) const LAMBDA: u128 = 228988810152649578064853576960394133503;
+
+    impl GlvParameters for G1Projective {
+        fn endomorphism(point: &G1Affine) -> G1Affine {
+            G1Affine::new(point.x * BETA, point.y, point.infinity)
+        }
+
+        /// $k_1 = k \bmod \lambda$ and $k_2 = \lfloor k / \lambda \rfloor$, by long division since $\lambda$ has
+        /// 128 bits. The group order is $\lambda^2 + \lambda + 1$, so both fit in 128 bits.
+        fn decompose(scalar: &Fr) -> [(bool, Fr); 2] {
+            let lambda = BigInteger256::new([LAMBDA as u64, (LAMBDA >> 64) as u64, 0, 0]);
+            let k = scalar.into_repr();
+
+            let (mut quotient, mut remainder) = (BigInteger256::from(0), BigInteger256::from(0));
+            for i in (0..256).rev() {
+                remainder.mul2();
+                quotient.mul2();
+                if k.get_bit(i) {
+                    remainder.add_nocarry(&BigInteger256::from(1));
+                }
+                if remainder >= lambda {
+                    remainder.sub_noborrow(&lambda);
+                    quotient.add_nocarry(&BigInteger256::from(1));
+                }
+            }
+
+            [
+                (false, low_128_bits(&remainder)),
+                (false, low_128_bits(&quotient)),
+            ]
+        }
+    }
+
+    fn low_128_bits(value: &BigInteger256) -> Fr {
+        let limbs = value.as_ref();
+        Fr::from(limbs[0] as u128 | (limbs[1] as u128) << 64)
+    }
+}
+
+#[cfg(all(test, feature = "ark-bls12-381"))]
+mod tests {
+    use super::{Glv, GlvParameters};
+    use crate::homomorphic_encryption::{el_gamal::ElGamal, HomomorphicEncryptionScheme};
+    use crate::utils::msm::{Plain, ScalarMul};
+    use crate::utils::rand::sample_vector;
+    use crate::vector_commitment::{pedersen::PedersenCommitment, HomomorphicCommitmentScheme};
+
+    use ark_bls12_381::{Fr, G1Projective};
+    use ark_ec::ProjectiveCurve;
+    use ark_ff::{BigInteger, One, PrimeField, Zero};
+    use ark_std::{rand::thread_rng, UniformRand};
+
+    #[test]
+    fn glv_multiplication() {
+        let rng = &mut thread_rng();
+        let base = G1Projective::rand(rng).into_affine();
+
+        let mut scalars = vec![Fr::zero(), Fr::one(), -Fr::one()];
+        scalars.extend(sample_vector::<Fr, _>(rng, 5));
+        for scalar in &scalars {
+            let [(_, k_1), (_, k_2)] = G1Projective::decompose(scalar);
+            assert_eq!(k_1 + k_2 * Fr::from(super::bls12_381::LAMBDA), *scalar);
+            assert!(k_1.into_repr().num_bits() <= 128 && k_2.into_repr().num_bits() <= 128);
+            assert_eq!(
+                <Glv as ScalarMul<G1Projective>>::mul(&base, scalar),
+                <Plain as ScalarMul<G1Projective>>::mul(&base, scalar)
+            );
+        }
+
+        for n in [0, 1, 5, 40] {
+            let bases = sample_vector::<G1Projective, _>(rng, n)
+                .into_iter()
+                .map(|p| p.into_affine())
+                .collect::<Vec<_>>();
+            let scalars: Vec<Fr> = sample_vector(rng, n);
+            assert_eq!(
+                <Glv as ScalarMul<G1Projective>>::multi_scalar_mul(&bases, &scalars),
+                <Plain as ScalarMul<G1Projective>>::multi_scalar_mul(&bases, &scalars)
+            );
+        }
+        assert!(<Glv as ScalarMul<G1Projective>>::multi_scalar_mul(&[base], &[]).is_err());
+    }
+
+    #[test]
+    fn glv_schemes() {
+        let rng = &mut thread_rng();
+
+        let pp = ElGamal::<G1Projective>::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::<G1Projective>::keygen(&pp, rng).unwrap();
+        let message = ElGamal::<G1Projective>::generator(rng).unwrap();
+        let r = Fr::rand(rng);
+
+        let cipher = ElGamal::<G1Projective, Glv>::encrypt(&pp, &pk, &message, &r).unwrap();
+        assert_eq!(
+            cipher,
+            ElGamal::<G1Projective>::encrypt(&pp, &pk, &message, &r).unwrap()
+        );
+        assert_eq!(
+            ElGamal::<G1Projective, Glv>::decrypt(&pp, &sk, &cipher),
+            Ok(message)
+        );
+
+        let ciphers = (0..4)
+            .map(|_| ElGamal::<G1Projective>::encrypt(&pp, &pk, &message, &Fr::rand(rng)).unwrap())
+            .collect::<Vec<_>>();
+        let scalars: Vec<Fr> = sample_vector(rng, 4);
+        assert_eq!(
+            ElGamal::<G1Projective, Glv>::multi_scalar_mul(&scalars, &ciphers),
+            ElGamal::<G1Projective>::multi_scalar_mul(&scalars, &ciphers)
+        );
+
+        let commit_key = PedersenCommitment::<G1Projective>::setup(rng, 6);
+        let values: Vec<Fr> = sample_vector(rng, 4);
+        assert_eq!(
+            PedersenCommitment::<G1Projective, Glv>::commit(&commit_key, &values, r),
+            PedersenCommitment::<G1Projective>::commit(&commit_key, &values, r)
+        );
+    }
+}
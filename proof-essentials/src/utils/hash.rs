@@ -0,0 +1,154 @@
+use crate::error::CryptoError;
+use crate::utils::hash_to_curve::hash_to_curve;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use digest::Digest;
+
+/// Windowed Pedersen hash of byte strings to points of `C`.
+///
+/// The input, prefixed with its length, is split into windows of [`PedersenHash::WINDOW_BITS`] bits and window $j$
+/// holding the value $v_j$ contributes $v_j G_j$, so the hash is $\sum_j v_j G_j$. The generators are hashed to the
+/// curve from a seed, and a collision would give a discrete logarithm relation between them. Every multiple
+/// $v G_j$ is precomputed, so hashing only takes one addition per window.
+///
+/// The hash is collision resistant but not a random oracle: it is homomorphic in the input windows.
+pub struct PedersenHash<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    max_input_length: u64,
+    /// The multiples $0, G_j, 2G_j, \dots$ of the generator of every window.
+    tables: Vec<Vec<C::Affine>>,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    /// Maximum length in bytes of the inputs.
+    pub fn max_input_length(&self) -> usize {
+        self.max_input_length as usize
+    }
+}
+
+impl<C: ProjectiveCurve> PedersenHash<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Pedersen Hash";
+
+    /// Number of input bits in a window.
+    pub const WINDOW_BITS: usize = 4;
+
+    /// Derive the generators for inputs of up to `max_input_length` bytes from `seed`.
+    pub fn setup<D: Digest>(
+        seed: &[u8],
+        max_input_length: usize,
+    ) -> Result<Parameters<C>, CryptoError> {
+        let windows = (8 + max_input_length) * 8 / Self::WINDOW_BITS;
+
+        let tables = (0..windows as u64)
+            .map(|window| {
+                let generator = hash_to_curve::<C, D>(
+                    Self::PROTOCOL_NAME,
+                    &to_bytes![seed.len() as u64, seed, window]?,
+                )?;
+
+                let mut multiples = vec![C::zero(); 1 << Self::WINDOW_BITS];
+                for v in 1..multiples.len() {
+                    multiples[v] = multiples[v - 1].add_mixed(&generator);
+                }
+
+                Ok(C::batch_normalization_into_affine(&multiples))
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(Parameters {
+            max_input_length: max_input_length as u64,
+            tables,
+        })
+    }
+
+    /// Hash `input`. Fails if it is longer than the maximum length of the parameters.
+    pub fn hash(pp: &Parameters<C>, input: &[u8]) -> Result<C::Affine, CryptoError> {
+        if input.len() > pp.max_input_length() {
+            return Err(CryptoError::HashInputLengthError(
+                input.len(),
+                pp.max_input_length(),
+            ));
+        }
+
+        let encoded = [&(input.len() as u64).to_le_bytes()[..], input].concat();
+        let mask = (1u8 << Self::WINDOW_BITS) - 1;
+
+        let hash = encoded
+            .iter()
+            .flat_map(|byte| {
+                (0..8 / Self::WINDOW_BITS).map(move |i| (byte >> (i * Self::WINDOW_BITS)) & mask)
+            })
+            .zip(pp.tables.iter())
+            .fold(C::zero(), |acc, (value, table)| {
+                acc.add_mixed(&table[value as usize])
+            });
+
+        Ok(hash.into_affine())
+    }
+
+    /// Hash two points, e.g. the children of a node in a Merkle tree. The parameters must accept inputs of two
+    /// serialized points.
+    pub fn compress(
+        pp: &Parameters<C>,
+        left: &C::Affine,
+        right: &C::Affine,
+    ) -> Result<C::Affine, CryptoError> {
+        Self::hash(pp, &to_bytes![left, right]?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PedersenHash;
+    use crate::error::CryptoError;
+    use ark_ec::ProjectiveCurve;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Hash = PedersenHash<Curve>;
+
+    #[test]
+    fn pedersen_hash() {
+        let pp = Hash::setup::<Blake2s>(b"merkle tree of the deck", 160).unwrap();
+        assert_eq!(
+            Hash::setup::<Blake2s>(b"merkle tree of the deck", 160).unwrap(),
+            pp
+        );
+
+        let hash = Hash::hash(&pp, b"ace of spades").unwrap();
+        assert_eq!(Hash::hash(&pp, b"ace of spades").unwrap(), hash);
+        assert_ne!(Hash::hash(&pp, b"ace of hearts").unwrap(), hash);
+
+        // Trailing zeros change the length prefix
+        assert_ne!(
+            Hash::hash(&pp, b"").unwrap(),
+            Hash::hash(&pp, &[0u8]).unwrap()
+        );
+        assert_ne!(
+            Hash::hash(&pp, b"ace of spades").unwrap(),
+            Hash::hash(&pp, b"ace of spades\0").unwrap()
+        );
+
+        assert_eq!(
+            Hash::hash(&pp, &[1u8; 161]),
+            Err(CryptoError::HashInputLengthError(161, 160))
+        );
+
+        let rng = &mut thread_rng();
+        let left = Curve::rand(rng).into_affine();
+        let right = Curve::rand(rng).into_affine();
+        assert_ne!(
+            Hash::compress(&pp, &left, &right).unwrap(),
+            Hash::compress(&pp, &right, &left).unwrap()
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::CryptoError;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, FpParameters, PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+use digest::Digest;
+
+/// Number of candidate points tried before giving up. Each candidate is on the curve with probability about 1/2,
+/// so exhausting them all happens with negligible probability.
+const MAX_ATTEMPTS: u32 = 256;
+
+/// Hash `message` to a point of the prime-order subgroup of `C` whose discrete logarithm is unknown to everyone.
+///
+/// Try-and-increment: candidate x-coordinates are expanded with `D` from `domain`, `message` and a counter, and the
+/// first one that lies on the curve is cleared of its cofactor. The output only depends on the inputs, so anyone
+/// can recompute it.
+pub fn hash_to_curve<C: ProjectiveCurve, D: Digest>(
+    domain: &[u8],
+    message: &[u8],
+) -> Result<C::Affine, CryptoError> {
+    let length = C::Affine::zero().serialized_size();
+    let order = <<C::ScalarField as PrimeField>::Params as FpParameters>::MODULUS;
+
+    for counter in 0..MAX_ATTEMPTS {
+        let mut bytes = Vec::with_capacity(length);
+        let mut block = 0u32;
+        while bytes.len() < length {
+            bytes.extend(D::digest(&to_bytes![domain, message, counter, block]?));
+            block += 1;
+        }
+        bytes.truncate(length);
+
+        if let Some(point) = C::Affine::from_random_bytes(&bytes) {
+            let point = point.mul_by_cofactor();
+            if !point.is_zero() && point.mul(order).is_zero() {
+                return Ok(point);
+            }
+        }
+    }
+
+    Err(CryptoError::HashToCurveError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hash_to_curve;
+    use ark_ec::AffineCurve;
+    use ark_ff::Zero;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+
+    #[test]
+    fn hash_to_curve_is_deterministic() {
+        let point = hash_to_curve::<Curve, Blake2s>(b"domain", b"message").unwrap();
+
+        assert!(!point.is_zero());
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(
+            hash_to_curve::<Curve, Blake2s>(b"domain", b"message").unwrap(),
+            point
+        );
+
+        assert_ne!(
+            hash_to_curve::<Curve, Blake2s>(b"domain", b"other message").unwrap(),
+            point
+        );
+        assert_ne!(
+            hash_to_curve::<Curve, Blake2s>(b"other domain", b"message").unwrap(),
+            point
+        );
+        assert_ne!(point, starknet_curve::Affine::prime_subgroup_generator());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::error::CryptoError;
+
+use ark_ff::{Field, PrimeField};
+use digest::generic_array::typenum::Unsigned;
+use digest::{BlockInput, Digest};
+
+/// Computational security in bits of [`hash_to_field`]: every coordinate is reduced from this many more bits than
+/// the modulus has, so the bias of the output is at most $2^{-128}$.
+pub const SECURITY_BITS: usize = 128;
+
+/// Prefix of the hashed domain separation tag replacing a tag longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// `expand_message_xmd` of RFC 9380 (section 5.3.1): expand `message` into `length` pseudo-random bytes with the
+/// Merkle-Damgård hash `D`, under the domain separation tag `dst`.
+///
+/// At most 255 digests are produced, which bounds `length` to 8160 bytes for SHA-256.
+pub fn expand_message_xmd<D: Digest + BlockInput>(
+    message: &[u8],
+    dst: &[u8],
+    length: usize,
+) -> Result<Vec<u8>, CryptoError> {
+    let output_size = D::OutputSize::to_usize();
+    let block_size = D::BlockSize::to_usize();
+
+    let blocks = length.div_ceil(output_size);
+    let max_length = (255 * output_size).min(u16::MAX as usize);
+    if blocks > 255 || length > max_length {
+        return Err(CryptoError::ExpansionLengthError(length, max_length));
+    }
+
+    let mut dst_prime = if dst.len() > 255 {
+        D::new()
+            .chain(OVERSIZE_DST_PREFIX)
+            .chain(dst)
+            .finalize()
+            .to_vec()
+    } else {
+        dst.to_vec()
+    };
+    dst_prime.push(dst_prime.len() as u8);
+
+    let b_0 = D::new()
+        .chain(vec![0u8; block_size])
+        .chain(message)
+        .chain((length as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(&dst_prime)
+        .finalize();
+
+    let mut b_i = D::new()
+        .chain(&b_0)
+        .chain([1u8])
+        .chain(&dst_prime)
+        .finalize();
+    let mut uniform_bytes = b_i.to_vec();
+    for i in 2..=blocks {
+        let chained: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(x, y)| x ^ y).collect();
+        b_i = D::new()
+            .chain(chained)
+            .chain([i as u8])
+            .chain(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(length);
+
+    Ok(uniform_bytes)
+}
+
+/// `hash_to_field` of RFC 9380 (section 5.2): hash `message` to `count` elements of `F` under the domain separation
+/// tag `dst`, with [`expand_message_xmd`] over `D`.
+///
+/// Each coordinate over the prime field is read big-endian from $L = \lceil (\lceil \log_2 p \rceil + 128) / 8
+/// \rceil$ bytes and reduced modulo $p$, so any implementation of the RFC recomputes the same elements.
+pub fn hash_to_field<F: Field, D: Digest + BlockInput>(
+    message: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<F>, CryptoError> {
+    let degree = F::extension_degree() as usize;
+    let chunk = (F::BasePrimeField::size_in_bits() + SECURITY_BITS).div_ceil(8);
+
+    let uniform_bytes = expand_message_xmd::<D>(message, dst, count * degree * chunk)?;
+
+    Ok(uniform_bytes
+        .chunks(degree * chunk)
+        .map(|element| {
+            let coordinates: Vec<F::BasePrimeField> = element
+                .chunks(chunk)
+                .map(F::BasePrimeField::from_be_bytes_mod_order)
+                .collect();
+
+            F::from_base_prime_field_elems(&coordinates)
+                .expect("one coordinate per degree of the extension")
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_message_xmd, hash_to_field};
+    use crate::error::CryptoError;
+
+    use sha2::Sha256;
+    use starknet_curve::Fr;
+    use std::str::FromStr;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    // Test vectors of RFC 9380, appendix K.1
+    #[test]
+    fn expand_message_xmd_sha256() {
+        let vectors: [(&[u8], usize, &str); 4] = [
+            (b"", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            (b"abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            (b"abcdef0123456789", 0x20, "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd\
+                 103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d349118\
+                 4472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+
+        for (message, length, expected) in vectors {
+            let uniform_bytes = expand_message_xmd::<Sha256>(message, DST, length).unwrap();
+            assert_eq!(hex(&uniform_bytes), expected);
+        }
+
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", DST, 8161),
+            Err(CryptoError::ExpansionLengthError(8161, 8160))
+        );
+    }
+
+    #[test]
+    fn hash_to_scalar_field() {
+        let elements = hash_to_field::<Fr, Sha256>(b"abc", DST, 2).unwrap();
+
+        assert_eq!(
+            elements,
+            vec![
+                Fr::from_str(
+                    "307458894654498308792624884969053818899583505401471110159290213481975140885"
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "2702048224817642059879719738199936677247562720008150318585254264332315560579"
+                )
+                .unwrap(),
+            ]
+        );
+        assert_ne!(
+            hash_to_field::<Fr, Sha256>(b"abc", b"other tag", 2).unwrap(),
+            elements
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::UniformRand;
+use hkdf::Hkdf;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use sha2::Sha256;
+
+/// Minimum length in bytes of a master seed.
+pub const MIN_SEED_LENGTH: usize = 16;
+
+/// Deterministic derivation of keys from a master seed, so that they can be restored from a backup of the seed.
+///
+/// The seed is expanded with HKDF-SHA256. Every key is derived for a label, and [`KeyDerivation::path`] moves down
+/// a hierarchy of labels such as `game/42/deck`, so that the keys of a game can be handed out without revealing the
+/// keys of other games. Keys are sampled by the key generation of each scheme from a ChaCha20 stream seeded by HKDF
+/// with the label, so the same seed, path and label always give the same key.
+///
+/// Commitment keys are only binding if nobody knows discrete logarithms between their generators: derive those
+/// shared between parties from a public seed.
+#[derive(Clone)]
+pub struct KeyDerivation {
+    hkdf: Hkdf<Sha256>,
+}
+
+impl KeyDerivation {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Key Derivation";
+
+    /// Root of the derivation for `seed`, which must be at least [`MIN_SEED_LENGTH`] bytes long.
+    pub fn new(seed: &[u8]) -> Result<Self, CryptoError> {
+        if seed.len() < MIN_SEED_LENGTH {
+            return Err(CryptoError::InvalidSeed);
+        }
+
+        Ok(Self {
+            hkdf: Hkdf::new(Some(Self::PROTOCOL_NAME), seed),
+        })
+    }
+
+    /// Derivation below the child `label` of this one.
+    pub fn child(&self, label: &str) -> Self {
+        let seed = self.expand(b"child", label);
+
+        Self {
+            hkdf: Hkdf::new(Some(Self::PROTOCOL_NAME), &seed),
+        }
+    }
+
+    /// Derivation below the `/`-separated `path` of labels, i.e. `path("a/b")` is `child("a").child("b")`.
+    pub fn path(&self, path: &str) -> Self {
+        path.split('/')
+            .filter(|label| !label.is_empty())
+            .fold(self.clone(), |derivation, label| derivation.child(label))
+    }
+
+    /// Deterministic random number generator for `label`, to derive keys of other schemes.
+    pub fn rng(&self, label: &str) -> ChaCha20Rng {
+        ChaCha20Rng::from_seed(self.expand(b"rng", label))
+    }
+
+    /// ElGamal key pair for `label`.
+    pub fn el_gamal_keypair<C: ProjectiveCurve>(
+        &self,
+        pp: &el_gamal::Parameters<C>,
+        label: &str,
+    ) -> Result<(el_gamal::PublicKey<C>, el_gamal::SecretKey<C>), CryptoError> {
+        el_gamal::ElGamal::<C>::keygen(pp, &mut self.rng(label))
+    }
+
+    /// Schnorr key pair $(xG, x)$ for `label`, as used by the signature schemes and identification proofs.
+    pub fn schnorr_keypair<C: ProjectiveCurve>(
+        &self,
+        generator: &C::Affine,
+        label: &str,
+    ) -> (C::Affine, C::ScalarField) {
+        let secret_key = C::ScalarField::rand(&mut self.rng(label));
+        let public_key = generator.mul(secret_key.into_repr()).into_affine();
+
+        (public_key, secret_key)
+    }
+
+    /// Pedersen commit key for vectors of length `len` for `label`.
+    pub fn pedersen_commit_key<C: ProjectiveCurve>(
+        &self,
+        len: usize,
+        label: &str,
+    ) -> pedersen::CommitKey<C> {
+        pedersen::PedersenCommitment::<C>::setup(&mut self.rng(label), len)
+    }
+
+    fn expand(&self, purpose: &[u8], label: &str) -> [u8; 32] {
+        let info = [
+            purpose,
+            &(label.len() as u64).to_le_bytes(),
+            label.as_bytes(),
+        ]
+        .concat();
+
+        let mut output = [0u8; 32];
+        self.hkdf
+            .expand(&info, &mut output)
+            .expect("32 bytes is a valid length for HKDF-SHA256");
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+
+    const SEED: &[u8] = b"correct horse battery staple";
+
+    #[test]
+    fn deterministic_derivation_test() {
+        let root = KeyDerivation::new(SEED).unwrap();
+        let restored = KeyDerivation::new(SEED).unwrap();
+
+        let pp = el_gamal::Parameters::<Curve> {
+            generator: Curve::prime_subgroup_generator().into_affine(),
+        };
+
+        let game = root.path("games/42");
+        let (pk, sk) = game.el_gamal_keypair(&pp, "deck").unwrap();
+        assert_eq!(pp.generator.mul(sk.into_repr()).into_affine(), pk);
+        assert_eq!(
+            restored
+                .child("games")
+                .child("42")
+                .el_gamal_keypair(&pp, "deck"),
+            Ok((pk, sk))
+        );
+        assert_eq!(
+            restored.path("/games/42/").el_gamal_keypair(&pp, "deck"),
+            Ok((pk, sk))
+        );
+
+        // Other labels, paths and seeds give unrelated keys
+        assert_ne!(game.el_gamal_keypair(&pp, "chat").unwrap().1, sk);
+        assert_ne!(
+            root.path("games/43")
+                .el_gamal_keypair(&pp, "deck")
+                .unwrap()
+                .1,
+            sk
+        );
+        let other = KeyDerivation::new(b"another sufficiently long seed").unwrap();
+        assert_ne!(
+            other
+                .path("games/42")
+                .el_gamal_keypair(&pp, "deck")
+                .unwrap()
+                .1,
+            sk
+        );
+
+        let (schnorr_pk, schnorr_sk) = game.schnorr_keypair::<Curve>(&pp.generator, "signing");
+        assert_eq!(
+            restored
+                .path("games/42")
+                .schnorr_keypair::<Curve>(&pp.generator, "signing"),
+            (schnorr_pk, schnorr_sk)
+        );
+        assert_ne!(schnorr_sk, sk);
+
+        let commit_key = game.pedersen_commit_key::<Curve>(4, "commitments");
+        assert_eq!(commit_key.len(), 4);
+        assert_eq!(
+            restored
+                .path("games/42")
+                .pedersen_commit_key::<Curve>(4, "commitments")
+                .g,
+            commit_key.g
+        );
+
+        assert!(matches!(
+            KeyDerivation::new(b"too short"),
+            Err(CryptoError::InvalidSeed)
+        ));
+    }
+}
@@ -1,3 +1,14 @@
+pub mod encoding;
+pub mod fixed_base;
+pub mod glv;
+pub mod hash;
+pub mod hash_to_curve;
+pub mod hash_to_field;
+pub mod key_derivation;
+pub mod msm;
 pub mod permutation;
+pub mod poseidon;
 pub mod rand;
+pub mod scratch;
+pub mod secret_sharing;
 pub mod vector_arithmetic;
@@ -0,0 +1,136 @@
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+
+/// Backend of the scalar multiplications and multi-scalar multiplications of the encryption and commitment schemes.
+///
+/// [`ElGamal`](crate::homomorphic_encryption::el_gamal::ElGamal),
+/// [`PedersenCommitment`](crate::vector_commitment::pedersen::PedersenCommitment) and
+/// [`ElGamalCommitment`](crate::vector_commitment::el_gamal::ElGamalCommitment) take the backend as a type parameter
+/// that defaults to [`Plain`]. The arguments only multiply points through these schemes, so provers and verifiers
+/// run on another backend, such as [`Glv`](crate::utils::glv::Glv) or a GPU implementation, by instantiating the
+/// schemes with it, e.g. `ElGamal<C, Gpu>` and `PedersenCommitment<C, Gpu>`, without any change to the arguments.
+///
+/// A backend must compute exactly the same points as [`Plain`], since provers and verifiers may use different ones.
+pub trait ScalarMul<C: ProjectiveCurve> {
+    /// Compute $kP$.
+    fn mul(base: &C::Affine, scalar: &C::ScalarField) -> C;
+
+    /// Compute $\sum_i k_i P_i$ for the `scalars` $k_i$ and the points `bases` $P_i$, which must have the same
+    /// length.
+    fn multi_scalar_mul(bases: &[C::Affine], scalars: &[C::ScalarField]) -> Result<C, CryptoError>;
+}
+
+/// Double-and-add multiplications and arkworks' Pippenger multi-scalar multiplication over the full scalar size.
+pub struct Plain;
+
+impl<C: ProjectiveCurve> ScalarMul<C> for Plain {
+    fn mul(base: &C::Affine, scalar: &C::ScalarField) -> C {
+        base.mul(scalar.into_repr())
+    }
+
+    fn multi_scalar_mul(bases: &[C::Affine], scalars: &[C::ScalarField]) -> Result<C, CryptoError> {
+        vector_arithmetic::multi_scalar_mul(scalars, bases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Plain, ScalarMul};
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::utils::permutation::Permutation;
+    use crate::utils::rand::sample_vector;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{arguments::shuffle, ArgumentOfKnowledge};
+
+    use ark_ff::Zero;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Affine = starknet_curve::Affine;
+
+    static MULTIPLICATIONS: AtomicUsize = AtomicUsize::new(0);
+    static MULTI_SCALAR_MULTIPLICATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Stand-in for an accelerator: counts the calls and forwards them to the default backend.
+    struct Counting;
+
+    impl ScalarMul<Curve> for Counting {
+        fn mul(base: &Affine, scalar: &Scalar) -> Curve {
+            MULTIPLICATIONS.fetch_add(1, Ordering::Relaxed);
+            <Plain as ScalarMul<Curve>>::mul(base, scalar)
+        }
+
+        fn multi_scalar_mul(bases: &[Affine], scalars: &[Scalar]) -> Result<Curve, CryptoError> {
+            MULTI_SCALAR_MULTIPLICATIONS.fetch_add(1, Ordering::Relaxed);
+            <Plain as ScalarMul<Curve>>::multi_scalar_mul(bases, scalars)
+        }
+    }
+
+    type Enc = el_gamal::ElGamal<Curve, Counting>;
+    type Comm = pedersen::PedersenCommitment<Curve, Counting>;
+    type ShuffleArgument<'a> = shuffle::ShuffleArgument<'a, Scalar, Enc, Comm>;
+
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn shuffle_on_custom_backend() {
+        let (m, n) = (2, 4);
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = el_gamal::Generator::rand(rng);
+
+        let ciphers: Vec<el_gamal::Ciphertext<Curve>> = sample_vector(rng, m * n);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, m * n);
+        let permutation = Permutation::new(rng, m * n);
+        let shuffled = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, r)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &el_gamal::Plaintext::zero(), r)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters =
+            shuffle::Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = shuffle::Statement::new(&ciphers, &shuffled, m, n);
+        let witness = shuffle::Witness::new(&permutation, &masking_factors);
+
+        let proof = ShuffleArgument::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut FS::from_seed(b"Initialised with some input"),
+        )
+        .unwrap();
+        // Every multiplication of the prover went through the backend
+        assert!(MULTIPLICATIONS.load(Ordering::Relaxed) > 0);
+        assert!(MULTI_SCALAR_MULTIPLICATIONS.load(Ordering::Relaxed) > 0);
+
+        assert_eq!(
+            ShuffleArgument::verify(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            ),
+            Ok(())
+        );
+    }
+}
@@ -16,6 +16,7 @@ impl Permutation {
         Self { mapping, size }
     }
 
+    #[allow(clippy::ptr_arg)]
     pub fn from(permutation_vec: &Vec<usize>) -> Self {
         Self {
             mapping: permutation_vec[..].to_vec(),
@@ -26,10 +27,11 @@ impl Permutation {
     pub fn identity(size: usize) -> Self {
         Self {
             mapping: (0..size).collect(),
-            size: size,
+            size,
         }
     }
 
+    #[allow(clippy::ptr_arg)]
     pub fn permute_array<T: Copy>(&self, input_vector: &Vec<T>) -> Vec<T> {
         self.mapping
             .iter()
@@ -0,0 +1,325 @@
+use crate::error::CryptoError;
+
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+
+/// Parameters of the Poseidon permutation (Grassi et al., USENIX Security 2021) over `F` for a state of
+/// `rate + 1` elements.
+///
+/// The S-box is $x^\alpha$ for the smallest $\alpha$ in [`ALPHAS`] coprime with $p - 1$, the MDS matrix is the Cauchy
+/// matrix $M_{i,j} = 1 / (i + t + j)$ and the round constants are sampled from ChaCha20 seeded with a hash of the
+/// instance, so every party derives the same permutation. The round numbers are those of the reference instances
+/// for 128-bit security over fields of about 255 bits. The constants differ from other implementations, so
+/// circuits verifying these hashes must use the same derivation.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<F: PrimeField> {
+    rate: u64,
+    alpha: u64,
+    full_rounds: u64,
+    partial_rounds: u64,
+    round_constants: Vec<Vec<F>>,
+    mds: Vec<Vec<F>>,
+}
+
+/// Candidate exponents of the S-box, by increasing cost.
+pub const ALPHAS: [u64; 4] = [3, 5, 7, 11];
+
+/// Largest supported rate.
+pub const MAX_RATE: usize = 8;
+
+impl<F: PrimeField> Parameters<F> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Poseidon";
+
+    /// Parameters for a sponge absorbing `rate` field elements per permutation, with a capacity of one element.
+    pub fn new(rate: usize) -> Result<Self, CryptoError> {
+        if rate == 0 || rate > MAX_RATE {
+            return Err(CryptoError::InvalidPoseidonParameters);
+        }
+
+        let alpha = ALPHAS
+            .iter()
+            .copied()
+            .find(|&alpha| Self::modulus_minus_one_mod(alpha) != 0)
+            .ok_or(CryptoError::InvalidPoseidonParameters)?;
+
+        let width = rate + 1;
+        let full_rounds = 8;
+        let partial_rounds = match alpha {
+            3 => 84,
+            _ if width <= 5 => 60,
+            _ => 64,
+        };
+
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&Sha256::digest(
+            &[
+                Self::PROTOCOL_NAME,
+                &(F::size_in_bits() as u64).to_le_bytes(),
+                &(width as u64).to_le_bytes(),
+                &alpha.to_le_bytes(),
+            ]
+            .concat(),
+        ));
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        let round_constants = (0..full_rounds + partial_rounds)
+            .map(|_| (0..width).map(|_| F::rand(&mut rng)).collect())
+            .collect();
+
+        let mds = (0..width)
+            .map(|i| {
+                (0..width)
+                    .map(|j| {
+                        F::from((i + width + j) as u64)
+                            .inverse()
+                            .expect("small non-zero integers are invertible")
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            rate: rate as u64,
+            alpha,
+            full_rounds: full_rounds as u64,
+            partial_rounds: partial_rounds as u64,
+            round_constants,
+            mds,
+        })
+    }
+
+    pub fn rate(&self) -> usize {
+        self.rate as usize
+    }
+
+    pub fn width(&self) -> usize {
+        self.rate as usize + 1
+    }
+
+    /// Apply the permutation to `state`, which must hold [`Parameters::width`] elements.
+    pub fn permute(&self, state: &mut [F]) {
+        debug_assert_eq!(state.len(), self.width());
+
+        let half_full_rounds = self.full_rounds as usize / 2;
+        let partial_rounds = self.partial_rounds as usize;
+
+        for (round, constants) in self.round_constants.iter().enumerate() {
+            for (x, c) in state.iter_mut().zip(constants.iter()) {
+                *x += c;
+            }
+
+            let is_full_round =
+                round < half_full_rounds || round >= half_full_rounds + partial_rounds;
+            if is_full_round {
+                state.iter_mut().for_each(|x| *x = x.pow([self.alpha]));
+            } else {
+                state[0] = state[0].pow([self.alpha]);
+            }
+
+            let mixed: Vec<F> = self
+                .mds
+                .iter()
+                .map(|row| row.iter().zip(state.iter()).map(|(m, x)| *m * x).sum())
+                .collect();
+            state.copy_from_slice(&mixed);
+        }
+    }
+
+    /// $(p - 1) \bmod a$ for the characteristic $p$ of `F`.
+    fn modulus_minus_one_mod(a: u64) -> u64 {
+        let remainder = F::characteristic()
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &limb| ((acc << 64) + limb as u128) % a as u128);
+
+        ((remainder + a as u128 - 1) % a as u128) as u64
+    }
+}
+
+/// Duplex sponge over the Poseidon permutation. Elements are absorbed into the rate part of the state, which is
+/// permuted whenever it is full or before squeezing, and squeezed from it.
+#[derive(Clone, Debug)]
+pub struct PoseidonSponge<F: PrimeField> {
+    parameters: Parameters<F>,
+    state: Vec<F>,
+    position: usize,
+    squeezing: bool,
+}
+
+impl<F: PrimeField> PoseidonSponge<F> {
+    /// A sponge whose capacity element is set to `domain`, so that sponges for different purposes never agree.
+    pub fn new(parameters: &Parameters<F>, domain: u64) -> Self {
+        let mut state = vec![F::zero(); parameters.width()];
+        state[parameters.rate()] = F::from(domain);
+
+        Self {
+            parameters: parameters.clone(),
+            state,
+            position: 0,
+            squeezing: false,
+        }
+    }
+
+    pub fn absorb(&mut self, elements: &[F]) {
+        if self.squeezing {
+            self.squeezing = false;
+            self.position = 0;
+        }
+
+        for element in elements {
+            if self.position == self.parameters.rate() {
+                self.parameters.permute(&mut self.state);
+                self.position = 0;
+            }
+            self.state[self.position] += element;
+            self.position += 1;
+        }
+    }
+
+    pub fn squeeze(&mut self, n: usize) -> Vec<F> {
+        if !self.squeezing {
+            self.parameters.permute(&mut self.state);
+            self.squeezing = true;
+            self.position = 0;
+        }
+
+        let mut output = Vec::with_capacity(n);
+        for _ in 0..n {
+            if self.position == self.parameters.rate() {
+                self.parameters.permute(&mut self.state);
+                self.position = 0;
+            }
+            output.push(self.state[self.position]);
+            self.position += 1;
+        }
+
+        output
+    }
+}
+
+/// Poseidon hash of a sequence of field elements. The length of the input is bound through the domain, so inputs
+/// that only differ by trailing zeros have different hashes.
+pub fn hash<F: PrimeField>(parameters: &Parameters<F>, input: &[F]) -> F {
+    let mut sponge = PoseidonSponge::new(parameters, HASH_DOMAIN + input.len() as u64);
+    sponge.absorb(input);
+
+    sponge.squeeze(1)[0]
+}
+
+const HASH_DOMAIN: u64 = 1 << 32;
+const COMMITMENT_DOMAIN: u64 = 2 << 32;
+pub(crate) const TRANSCRIPT_DOMAIN: u64 = 3 << 32;
+
+/// Commitments to field elements as $c = \mathrm{Poseidon}(r, x_1, \dots, x_n)$ with a random $r$, cheap to open
+/// inside an arithmetic circuit over `F`. They are binding and hiding in the random oracle model, but not
+/// homomorphic.
+pub struct PoseidonCommitment;
+
+impl PoseidonCommitment {
+    /// Commit to `values` with fresh randomness, returned as the opening.
+    pub fn commit<F: PrimeField, R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<F>,
+        values: &[F],
+    ) -> (F, F) {
+        let randomness = F::rand(rng);
+
+        (
+            Self::commit_with(parameters, values, &randomness),
+            randomness,
+        )
+    }
+
+    /// Commit to `values` with the given randomness.
+    pub fn commit_with<F: PrimeField>(
+        parameters: &Parameters<F>,
+        values: &[F],
+        randomness: &F,
+    ) -> F {
+        let mut sponge = PoseidonSponge::new(parameters, COMMITMENT_DOMAIN + values.len() as u64);
+        sponge.absorb(&[*randomness]);
+        sponge.absorb(values);
+
+        sponge.squeeze(1)[0]
+    }
+
+    /// Check that `randomness` opens `commitment` to `values`.
+    pub fn verify<F: PrimeField>(
+        parameters: &Parameters<F>,
+        commitment: &F,
+        values: &[F],
+        randomness: &F,
+    ) -> Result<(), CryptoError> {
+        if Self::commit_with(parameters, values, randomness) != *commitment {
+            return Err(CryptoError::InvalidOpening);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash, Parameters, PoseidonCommitment, PoseidonSponge};
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use ark_ff::Zero;
+    use ark_std::rand::thread_rng;
+
+    type Scalar = starknet_curve::Fr;
+
+    #[test]
+    fn poseidon_sponge_and_hash() {
+        let rng = &mut thread_rng();
+        let parameters = Parameters::<Scalar>::new(2).unwrap();
+        assert_eq!(Parameters::<Scalar>::new(2).unwrap(), parameters);
+        assert_eq!(
+            Parameters::<Scalar>::new(0),
+            Err(CryptoError::InvalidPoseidonParameters)
+        );
+
+        // The permutation is not the identity and is deterministic
+        let mut state = vec![Scalar::zero(); 3];
+        parameters.permute(&mut state);
+        assert_ne!(state, vec![Scalar::zero(); 3]);
+
+        let input: Vec<Scalar> = sample_vector(rng, 5);
+        assert_eq!(hash(&parameters, &input), hash(&parameters, &input));
+        assert_ne!(hash(&parameters, &input), hash(&parameters, &input[..4]));
+        assert_ne!(hash(&parameters, &[]), hash(&parameters, &[Scalar::zero()]));
+
+        // Absorbing in several calls is the same as absorbing at once
+        let mut sponge = PoseidonSponge::new(&parameters, 7);
+        sponge.absorb(&input[..3]);
+        sponge.absorb(&input[3..]);
+        let mut other = PoseidonSponge::new(&parameters, 7);
+        other.absorb(&input);
+        assert_eq!(sponge.squeeze(5), other.squeeze(5));
+
+        let mut other_domain = PoseidonSponge::new(&parameters, 8);
+        other_domain.absorb(&input);
+        assert_ne!(sponge.squeeze(1), other_domain.squeeze(1));
+    }
+
+    #[test]
+    fn poseidon_commitment() {
+        let rng = &mut thread_rng();
+        let parameters = Parameters::<Scalar>::new(4).unwrap();
+
+        let values: Vec<Scalar> = sample_vector(rng, 3);
+        let (commitment, randomness) = PoseidonCommitment::commit(rng, &parameters, &values);
+        assert_eq!(
+            PoseidonCommitment::verify(&parameters, &commitment, &values, &randomness),
+            Ok(())
+        );
+        assert_ne!(hash(&parameters, &values), commitment);
+
+        assert_eq!(
+            PoseidonCommitment::verify(&parameters, &commitment, &values[..2], &randomness),
+            Err(CryptoError::InvalidOpening)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+/// Pool of vectors for the temporaries of a prover, owned by the caller so that it outlives a single proof: a prover
+/// takes its temporary vectors from the pool and gives them back once done, so that proving many times, e.g. a
+/// shuffle in every round of a game, reuses the same buffers instead of allocating and freeing them each time.
+#[derive(Debug)]
+pub struct Scratch<T> {
+    buffers: Vec<Vec<T>>,
+}
+
+impl<T> Scratch<T> {
+    pub fn new() -> Self {
+        Self {
+            buffers: Vec::new(),
+        }
+    }
+
+    /// An empty vector with room for `capacity` elements: the smallest buffer of the pool that is large enough, or a
+    /// new one.
+    pub fn take(&mut self, capacity: usize) -> Vec<T> {
+        match self
+            .buffers
+            .iter()
+            .enumerate()
+            .filter(|(_, buffer)| buffer.capacity() >= capacity)
+            .min_by_key(|(_, buffer)| buffer.capacity())
+            .map(|(i, _)| i)
+        {
+            Some(i) => self.buffers.swap_remove(i),
+            None => Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Give `buffer` back to the pool, for a later [`Scratch::take`].
+    pub fn recycle(&mut self, mut buffer: Vec<T>) {
+        buffer.clear();
+        self.buffers.push(buffer);
+    }
+
+    /// Give every vector of `buffers` back to the pool.
+    pub fn recycle_all<I: IntoIterator<Item = Vec<T>>>(&mut self, buffers: I) {
+        for buffer in buffers {
+            self.recycle(buffer);
+        }
+    }
+
+    /// Number of buffers in the pool.
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+}
+
+impl<T> Default for Scratch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scratch;
+
+    #[test]
+    fn buffer_reuse() {
+        let mut scratch = Scratch::<u64>::new();
+        assert!(scratch.is_empty());
+
+        let mut buffer = scratch.take(10);
+        assert!(buffer.capacity() >= 10);
+        buffer.extend(0..10);
+        let address = buffer.as_ptr();
+
+        scratch.recycle(buffer);
+        assert_eq!(scratch.len(), 1);
+
+        // A large enough buffer comes back empty, without a new allocation
+        let buffer = scratch.take(4);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.as_ptr(), address);
+        assert!(scratch.is_empty());
+
+        // A larger one is allocated, and the pool keeps the small one
+        scratch.recycle(buffer);
+        let large = scratch.take(100);
+        assert!(large.capacity() >= 100);
+        assert_eq!(scratch.len(), 1);
+
+        // The smallest buffer that fits is taken
+        scratch.recycle_all(vec![large, Vec::new()]);
+        assert_eq!(scratch.len(), 3);
+        let small = scratch.take(5);
+        assert_eq!(small.as_ptr(), address);
+        assert!(scratch.take(50).capacity() >= 100);
+    }
+}
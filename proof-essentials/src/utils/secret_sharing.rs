@@ -0,0 +1,210 @@
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::batch_inverse;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use rand::Rng;
+
+/// Shamir share $f(i)$ of the participant with identifier `index`, in `1..=n`
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Share<F: Field> {
+    pub index: u64,
+    pub value: F,
+}
+
+/// Sample the coefficients of a random polynomial of degree `threshold - 1` whose constant term is `secret`
+pub fn sample_polynomial<F: Field, R: Rng>(rng: &mut R, secret: F, threshold: usize) -> Vec<F> {
+    let mut coefficients = Vec::with_capacity(threshold);
+    coefficients.push(secret);
+    coefficients.extend((1..threshold).map(|_| F::rand(rng)));
+
+    coefficients
+}
+
+/// Evaluate the polynomial with coefficients `coefficients` (constant term first) at the identifier `index`
+pub fn evaluate_polynomial<F: Field>(coefficients: &[F], index: u64) -> F {
+    let x = F::from(index);
+
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// Split `secret` into `participants` shares such that any `threshold` of them reconstruct it
+pub fn share<F: Field, R: Rng>(
+    rng: &mut R,
+    secret: &F,
+    threshold: usize,
+    participants: usize,
+) -> Result<Vec<Share<F>>, CryptoError> {
+    if threshold == 0 || threshold > participants {
+        return Err(CryptoError::InvalidThresholdParameters);
+    }
+
+    let coefficients = sample_polynomial(rng, *secret, threshold);
+
+    Ok((1..=participants as u64)
+        .map(|index| Share {
+            index,
+            value: evaluate_polynomial(&coefficients, index),
+        })
+        .collect())
+}
+
+/// Interpolate the secret $f(0)$ from `shares`. The result is only meaningful if at least `threshold` shares are given
+pub fn reconstruct<F: Field>(shares: &[Share<F>]) -> Result<F, CryptoError> {
+    let indices: Vec<u64> = shares.iter().map(|share| share.index).collect();
+
+    Ok(shares
+        .iter()
+        .zip(lagrange_coefficients_at_zero::<F>(&indices)?)
+        .map(|(share, lambda)| lambda * share.value)
+        .sum())
+}
+
+/// $\lambda_i = \prod_{j \neq i} j / (j - i)$, the weight of share `index` when interpolating at zero from the
+/// shares at `indices`. Fails unless the identifiers in `indices` are distinct and non-zero.
+pub fn lagrange_coefficient_at_zero<F: Field>(
+    index: u64,
+    indices: &[u64],
+) -> Result<F, CryptoError> {
+    if !are_valid_indices(indices) {
+        return Err(CryptoError::InvalidShare);
+    }
+
+    let x_i = F::from(index);
+
+    let (numerator, denominator) = indices.iter().filter(|&&j| j != index).fold(
+        (F::one(), F::one()),
+        |(numerator, denominator), &j| {
+            let x_j = F::from(j);
+            (numerator * x_j, denominator * (x_j - x_i))
+        },
+    );
+
+    Ok(numerator * denominator.inverse().ok_or(CryptoError::InvalidShare)?)
+}
+
+/// The weights $\lambda_i$ of all the shares at `indices`, in the same order, with a single batch inversion of
+/// the denominators. Fails unless the identifiers in `indices` are distinct and non-zero.
+pub fn lagrange_coefficients_at_zero<F: Field>(indices: &[u64]) -> Result<Vec<F>, CryptoError> {
+    if !are_valid_indices(indices) {
+        return Err(CryptoError::InvalidShare);
+    }
+
+    let (numerators, denominators): (Vec<F>, Vec<F>) = indices
+        .iter()
+        .map(|&index| {
+            let x_i = F::from(index);
+            indices.iter().filter(|&&j| j != index).fold(
+                (F::one(), F::one()),
+                |(numerator, denominator), &j| {
+                    let x_j = F::from(j);
+                    (numerator * x_j, denominator * (x_j - x_i))
+                },
+            )
+        })
+        .unzip();
+
+    Ok(numerators
+        .into_iter()
+        .zip(batch_inverse(&denominators)?)
+        .map(|(numerator, denominator_inverse)| numerator * denominator_inverse)
+        .collect())
+}
+
+/// Check that `indices` is a non-empty set of distinct, non-zero identifiers
+fn are_valid_indices(indices: &[u64]) -> bool {
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    !indices.is_empty() && sorted.len() == indices.len() && sorted[0] != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use starknet_curve;
+
+    type Scalar = starknet_curve::Fr;
+
+    #[test]
+    fn share_and_reconstruct_test() {
+        let rng = &mut thread_rng();
+        let secret = Scalar::rand(rng);
+
+        let shares = share(rng, &secret, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        for subset in [vec![0, 1, 2], vec![4, 2, 0], vec![0, 1, 2, 3, 4]] {
+            let subset_shares = subset.iter().map(|&i| shares[i]).collect::<Vec<_>>();
+            assert_eq!(reconstruct(&subset_shares), Ok(secret));
+        }
+
+        // Too few shares do not determine the secret
+        assert_ne!(reconstruct(&shares[..2]), Ok(secret));
+
+        assert!(share(rng, &secret, 0, 5).is_err());
+        assert_eq!(
+            share(rng, &secret, 6, 5),
+            Err(CryptoError::InvalidThresholdParameters)
+        );
+    }
+
+    #[test]
+    fn invalid_indices_test() {
+        let rng = &mut thread_rng();
+        let secret = Scalar::rand(rng);
+        let shares = share(rng, &secret, 2, 3).unwrap();
+
+        assert_eq!(
+            reconstruct(&[shares[0], shares[0]]),
+            Err(CryptoError::InvalidShare)
+        );
+        assert_eq!(
+            reconstruct(&[Share {
+                index: 0,
+                value: Scalar::rand(rng)
+            }]),
+            Err(CryptoError::InvalidShare)
+        );
+        assert_eq!(reconstruct::<Scalar>(&[]), Err(CryptoError::InvalidShare));
+    }
+
+    #[test]
+    fn lagrange_coefficients_test() {
+        // The coefficients of any set of points sum to one, as they interpolate the constant polynomial
+        let indices = [1, 3, 4, 7];
+        let sum: Scalar = indices
+            .iter()
+            .map(|&i| lagrange_coefficient_at_zero::<Scalar>(i, &indices).unwrap())
+            .sum();
+
+        assert_eq!(sum, Scalar::from(1u64));
+
+        let expected: Vec<Scalar> = indices
+            .iter()
+            .map(|&i| lagrange_coefficient_at_zero::<Scalar>(i, &indices).unwrap())
+            .collect();
+        assert_eq!(
+            lagrange_coefficients_at_zero::<Scalar>(&indices),
+            Ok(expected)
+        );
+
+        assert_eq!(
+            lagrange_coefficient_at_zero::<Scalar>(1, &[1, 3, 3]),
+            Err(CryptoError::InvalidShare)
+        );
+        assert_eq!(
+            lagrange_coefficient_at_zero::<Scalar>(1, &[0, 1]),
+            Err(CryptoError::InvalidShare)
+        );
+        assert_eq!(
+            lagrange_coefficients_at_zero::<Scalar>(&[1, 3, 3]),
+            Err(CryptoError::InvalidShare)
+        );
+    }
+}
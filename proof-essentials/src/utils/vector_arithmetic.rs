@@ -1,9 +1,11 @@
 use crate::error::CryptoError;
-use ark_ff::Field;
+use ark_ec::{msm::VariableBaseMSM, AffineCurve};
+use ark_ff::{Field, PrimeField};
 use std::iter::Sum;
 use std::ops::Mul;
 
 /// Compute the dot product (inner product) of two vectors
+#[allow(clippy::ptr_arg)]
 pub fn dot_product<S, T>(scalars: &Vec<S>, rhs: &Vec<T>) -> Result<T, CryptoError>
 where
     S: Field,
@@ -20,7 +22,26 @@ where
         .sum())
 }
 
+/// Compute $\sum_i s_i P_i$ for the `scalars` $s_i$ and the points `bases` $P_i$ with a single variable-base
+/// multi-scalar multiplication (Pippenger), much faster than adding up the products one by one
+pub fn multi_scalar_mul<G: AffineCurve>(
+    scalars: &[G::ScalarField],
+    bases: &[G],
+) -> Result<G::Projective, CryptoError> {
+    if scalars.len() != bases.len() {
+        return Err(CryptoError::DotProductLengthError(
+            scalars.len(),
+            bases.len(),
+        ));
+    }
+
+    let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+
+    Ok(VariableBaseMSM::multi_scalar_mul(bases, &scalars))
+}
+
 // Compute the Hadamard product (elemet-wise multiplication) of two vectors
+#[allow(clippy::ptr_arg)]
 pub fn hadamard_product<S: Field>(scalars: &Vec<S>, rhs: &Vec<S>) -> Result<Vec<S>, CryptoError> {
     if scalars.len() != rhs.len() {
         return Err(CryptoError::HadamardProductLengthError(
@@ -36,7 +57,35 @@ pub fn hadamard_product<S: Field>(scalars: &Vec<S>, rhs: &Vec<S>) -> Result<Vec<
         .collect())
 }
 
+/// Invert every element of `values` with Montgomery's trick: a single field inversion of the product of all the
+/// elements, followed by three multiplications per element, instead of one inversion per element. Fails if any
+/// element is zero.
+pub fn batch_inverse<F: Field>(values: &[F]) -> Result<Vec<F>, CryptoError> {
+    // prefix_products[i] is the product of the elements before i
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut product = F::one();
+    for value in values {
+        prefix_products.push(product);
+        product *= value;
+    }
+
+    let mut inverse = product.inverse().ok_or(CryptoError::ZeroInversion)?;
+    let mut inverses = vec![F::zero(); values.len()];
+    for ((value, prefix_product), value_inverse) in values
+        .iter()
+        .zip(prefix_products)
+        .zip(inverses.iter_mut())
+        .rev()
+    {
+        *value_inverse = inverse * prefix_product;
+        inverse *= value;
+    }
+
+    Ok(inverses)
+}
+
 /// Reshape a vector of length N into a matrix of m-by-n (m chunks of length n). Requires that N = m*n
+#[allow(clippy::ptr_arg)]
 pub fn reshape<T: Clone>(
     in_vector: &Vec<T>,
     m: usize,
@@ -73,6 +122,51 @@ mod tests {
         dot_product(&scalars, &ciphers).unwrap();
     }
 
+    #[test]
+    fn multi_scalar_mul_test() {
+        use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+        use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+
+        let rng = &mut thread_rng();
+        let n = 9;
+
+        let scalars: Vec<Scalar> = sample_vector(rng, n);
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, n);
+        assert_eq!(
+            el_gamal::ElGamal::<Curve>::multi_scalar_mul(&scalars, &ciphers).unwrap(),
+            dot_product(&scalars, &ciphers).unwrap()
+        );
+
+        let commitments: Vec<pedersen::Commitment<Curve>> = sample_vector(rng, n);
+        assert_eq!(
+            pedersen::PedersenCommitment::<Curve>::multi_scalar_mul(&scalars, &commitments)
+                .unwrap(),
+            dot_product(&scalars, &commitments).unwrap()
+        );
+
+        assert_eq!(
+            el_gamal::ElGamal::<Curve>::multi_scalar_mul(&scalars[1..], &ciphers),
+            Err(CryptoError::DotProductLengthError(n - 1, n))
+        );
+    }
+
+    #[test]
+    fn batch_inverse_test() {
+        let rng = &mut thread_rng();
+
+        let scalars: Vec<Scalar> = sample_vector(rng, 7);
+        let inverses = batch_inverse(&scalars).unwrap();
+        for (scalar, inverse) in scalars.iter().zip(inverses.iter()) {
+            assert_eq!(*inverse, scalar.inverse().unwrap());
+        }
+
+        assert_eq!(batch_inverse::<Scalar>(&[]), Ok(vec![]));
+        assert_eq!(
+            batch_inverse(&[Scalar::one(), Scalar::zero()]),
+            Err(CryptoError::ZeroInversion)
+        );
+    }
+
     #[test]
     fn hadamard_product_test() {
         let rng = &mut thread_rng();
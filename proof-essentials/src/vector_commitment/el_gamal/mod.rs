@@ -0,0 +1,164 @@
+use crate::error::CryptoError;
+use crate::utils::msm::{Plain, ScalarMul};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, ToBytes, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    marker::PhantomData,
+};
+use rand::Rng;
+use std::ops::{Add, Mul};
+
+mod tests;
+
+/// ElGamal commitments: a vector $x$ is committed to as $(rG, rH + \sum_i x_i G_i)$, i.e. as an ElGamal encryption
+/// of $\sum_i x_i G_i$ under the key $H$.
+///
+/// The trade-off is the opposite of Pedersen: the first component fixes $r$, so a commitment to a single scalar is
+/// perfectly binding, while hiding is only computational (DDH). For longer vectors, binding to the individual
+/// entries additionally relies on the discrete logarithm problem, as with Pedersen. Anyone who learns $\log_G H$
+/// can recover $\sum_i x_i G_i$, so the key must come from a setup that nobody can trapdoor.
+///
+/// The scalar multiplications go through the backend `M`, see [`ScalarMul`].
+pub struct ElGamalCommitment<C: ProjectiveCurve, M: ScalarMul<C> = Plain> {
+    _curve: PhantomData<C>,
+    _scalar_mul: PhantomData<M>,
+}
+
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq)]
+pub struct CommitKey<C: ProjectiveCurve> {
+    pub(crate) generator: C::Affine,
+    pub(crate) h: C::Affine,
+    pub(crate) g: Vec<C::Affine>,
+}
+
+impl<C: ProjectiveCurve> CommitKey<C> {
+    pub fn new(generator: C::Affine, h: C::Affine, g: Vec<C::Affine>) -> Self {
+        Self { generator, h, g }
+    }
+
+    /// Maximum number of scalars that can be committed to with this key
+    pub fn len(&self) -> usize {
+        self.g.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.g.is_empty()
+    }
+}
+
+impl<C: ProjectiveCurve> ToBytes for CommitKey<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.generator.write(&mut w)?;
+        self.h.write(&mut w)?;
+        self.g.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<C: ProjectiveCurve>(pub C::Affine, pub C::Affine);
+
+impl<C: ProjectiveCurve> ToBytes for Commitment<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0.write(&mut w)?;
+        self.1.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> Add for Commitment<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<C: ProjectiveCurve> Mul<C::ScalarField> for Commitment<C> {
+    type Output = Self;
+
+    fn mul(self, x: C::ScalarField) -> Self {
+        Self(
+            self.0.mul(x.into_repr()).into_affine(),
+            self.1.mul(x.into_repr()).into_affine(),
+        )
+    }
+}
+
+impl<C: ProjectiveCurve> std::iter::Sum for Commitment<C> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<C: ProjectiveCurve> Zero for Commitment<C> {
+    fn zero() -> Self {
+        Self(C::Affine::zero(), C::Affine::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero() && self.1.is_zero()
+    }
+}
+
+impl<C, M> HomomorphicCommitmentScheme<C::ScalarField> for ElGamalCommitment<C, M>
+where
+    C: ProjectiveCurve,
+    M: ScalarMul<C>,
+{
+    type CommitKey = CommitKey<C>;
+    type Commitment = Commitment<C>;
+
+    fn setup<R: Rng>(public_randomess: &mut R, len: usize) -> CommitKey<C> {
+        let points = (0..len + 2)
+            .map(|_| C::rand(public_randomess))
+            .collect::<Vec<_>>();
+        let points = C::batch_normalization_into_affine(&points);
+
+        CommitKey {
+            generator: points[0],
+            h: points[1],
+            g: points[2..].to_vec(),
+        }
+    }
+
+    fn commit(
+        commit_key: &CommitKey<C>,
+        x: &Vec<C::ScalarField>,
+        r: C::ScalarField,
+    ) -> Result<Self::Commitment, CryptoError> {
+        if x.len() > commit_key.g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("ElGamal"),
+                x.len(),
+                commit_key.g.len(),
+            ));
+        }
+
+        let scalars = [&[r], x.as_slice()].concat();
+        let bases = [&[commit_key.h], &commit_key.g[..x.len()]].concat();
+
+        Ok(Commitment(
+            M::mul(&commit_key.generator, &r).into_affine(),
+            M::multi_scalar_mul(&bases, &scalars)?.into_affine(),
+        ))
+    }
+
+    fn multi_scalar_mul(
+        scalars: &[C::ScalarField],
+        commitments: &[Self::Commitment],
+    ) -> Result<Self::Commitment, CryptoError> {
+        let (first, second): (Vec<_>, Vec<_>) = commitments.iter().map(|c| (c.0, c.1)).unzip();
+
+        Ok(Commitment(
+            M::multi_scalar_mul(&first, scalars)?.into_affine(),
+            M::multi_scalar_mul(&second, scalars)?.into_affine(),
+        ))
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::vector_commitment::{el_gamal, HomomorphicCommitmentScheme};
+    use crate::zkp::{arguments::single_value_product, ArgumentOfKnowledge};
+
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Comm = el_gamal::ElGamalCommitment<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn additive_homomorphism() {
+        let rng = &mut thread_rng();
+        let n = 10;
+
+        let commit_key = Comm::setup(rng, n);
+
+        let v1: Vec<Scalar> = sample_vector(rng, n);
+        let v2: Vec<Scalar> = sample_vector(rng, n);
+        let r1 = Scalar::rand(rng);
+        let r2 = Scalar::rand(rng);
+        let alpha = Scalar::rand(rng);
+
+        let v3 = v1
+            .iter()
+            .zip(v2.iter())
+            .map(|(&a, &b)| a * alpha + b)
+            .collect::<Vec<_>>();
+
+        let c1 = Comm::commit(&commit_key, &v1, r1).unwrap();
+        let c2 = Comm::commit(&commit_key, &v2, r2).unwrap();
+        assert_eq!(
+            c1 * alpha + c2,
+            Comm::commit(&commit_key, &v3, r1 * alpha + r2).unwrap()
+        );
+
+        // Short vectors are padded with zeros
+        assert_eq!(
+            Comm::commit(&commit_key, &v1[..1].to_vec(), r1).unwrap(),
+            Comm::commit(
+                &commit_key,
+                &[&v1[..1], &[Scalar::from(0u64); 3]].concat(),
+                r1
+            )
+            .unwrap()
+        );
+
+        assert_eq!(
+            Comm::commit(&commit_key, &sample_vector(rng, n + 1), r1),
+            Err(CryptoError::CommitmentLengthError(
+                String::from("ElGamal"),
+                n + 1,
+                n
+            ))
+        );
+    }
+
+    #[test]
+    fn usable_in_arguments() {
+        type Witness<'a> = single_value_product::Witness<'a, Scalar>;
+        type Statement<'a> = single_value_product::Statement<'a, Scalar, Comm>;
+        type Parameters<'a> = single_value_product::Parameters<'a, Scalar, Comm>;
+        type SingleValueProd<'a> =
+            single_value_product::SingleValueProductArgument<'a, Scalar, Comm>;
+
+        let rng = &mut thread_rng();
+        let n = 8;
+        let commit_key = Comm::setup(rng, n);
+
+        let a: Vec<Scalar> = sample_vector(rng, n);
+        let b: Scalar = a.iter().product();
+        let r = Scalar::rand(rng);
+        let a_commit = Comm::commit(&commit_key, &a, r).unwrap();
+
+        let parameters = Parameters::new(n, &commit_key);
+        let witness = Witness::new(&a, &r);
+        let statement = Statement::new(&a_commit, b);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = SingleValueProd::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SingleValueProd::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+}
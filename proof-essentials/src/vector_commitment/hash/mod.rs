@@ -0,0 +1,65 @@
+mod tests;
+
+use crate::error::CryptoError;
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use digest::Digest;
+use rand::Rng;
+use subtle::ConstantTimeEq;
+
+/// Hash-based commitment to arbitrary byte strings, $c = H(m \| r)$ for a random $r$ of
+/// [`HashCommitment::RANDOMNESS_LENGTH`] bytes. It is binding as long as `D` is collision resistant and hiding if
+/// `D` behaves as a random oracle, but, unlike the algebraic commitments, it has no homomorphic property.
+pub struct HashCommitment<D: Digest> {
+    _hash: PhantomData<D>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment(pub Vec<u8>);
+
+/// The randomness of a commitment, revealed with the message to open it.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Opening(pub Vec<u8>);
+
+impl<D: Digest> HashCommitment<D> {
+    /// Length in bytes of the randomness. The randomness comes last and has a fixed length, so $m \| r$ is
+    /// unambiguous.
+    pub const RANDOMNESS_LENGTH: usize = 32;
+
+    /// Commit to `message` with fresh randomness.
+    pub fn commit<R: Rng>(rng: &mut R, message: &[u8]) -> (Commitment, Opening) {
+        let mut randomness = vec![0u8; Self::RANDOMNESS_LENGTH];
+        rng.fill_bytes(&mut randomness);
+        let opening = Opening(randomness);
+
+        (Self::digest(message, &opening), opening)
+    }
+
+    /// Check that `opening` opens `commitment` to `message`. The digests are compared in constant time.
+    pub fn verify(
+        commitment: &Commitment,
+        message: &[u8],
+        opening: &Opening,
+    ) -> Result<(), CryptoError> {
+        if opening.0.len() != Self::RANDOMNESS_LENGTH {
+            return Err(CryptoError::InvalidOpening);
+        }
+
+        let expected = Self::digest(message, opening);
+        if !bool::from(expected.0.ct_eq(&commitment.0)) {
+            return Err(CryptoError::InvalidOpening);
+        }
+
+        Ok(())
+    }
+
+    fn digest(message: &[u8], opening: &Opening) -> Commitment {
+        let mut hasher = D::new();
+        hasher.update(message);
+        hasher.update(&opening.0);
+
+        Commitment(hasher.finalize().to_vec())
+    }
+}
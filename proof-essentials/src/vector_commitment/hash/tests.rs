@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::vector_commitment::hash::{HashCommitment, Opening};
+
+    use ark_std::rand::thread_rng;
+    use sha2::Sha256;
+
+    type Commit = HashCommitment<Sha256>;
+
+    #[test]
+    fn commit_and_open() {
+        let rng = &mut thread_rng();
+        let message = b"{\"players\": 6, \"deck\": 52}";
+
+        let (commitment, opening) = Commit::commit(rng, message);
+        assert_eq!(Commit::verify(&commitment, message, &opening), Ok(()));
+
+        // Hiding: committing twice to the same message gives unrelated commitments
+        let (other, _) = Commit::commit(rng, message);
+        assert_ne!(commitment, other);
+
+        assert_eq!(
+            Commit::verify(&commitment, b"{\"players\": 7, \"deck\": 52}", &opening),
+            Err(CryptoError::InvalidOpening)
+        );
+
+        let mut wrong_opening = opening.clone();
+        wrong_opening.0[0] ^= 1;
+        assert_eq!(
+            Commit::verify(&commitment, message, &wrong_opening),
+            Err(CryptoError::InvalidOpening)
+        );
+
+        // Moving bytes between the message and the randomness is rejected
+        let shifted = Opening(opening.0[1..].to_vec());
+        let mut longer_message = message.to_vec();
+        longer_message.push(opening.0[0]);
+        assert_eq!(
+            Commit::verify(&commitment, &longer_message, &shifted),
+            Err(CryptoError::InvalidOpening)
+        );
+    }
+}
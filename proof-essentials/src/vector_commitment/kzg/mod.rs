@@ -0,0 +1,269 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{to_bytes, One, PrimeField, ToBytes, UniformRand, Zero};
+use ark_poly::{univariate::DensePolynomial, Polynomial, UVPolynomial};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    marker::PhantomData,
+};
+use rand::Rng;
+
+mod tests;
+
+/// KZG polynomial commitments (Kate, Zaverucha and Goldberg, Asiacrypt 2010). A polynomial of degree at most `d`
+/// is committed to as a single group element, and its evaluation at any point is proven with a single group element.
+pub struct Kzg<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+/// Structured reference string `(g, β·g, ..., β^d·g)` in G1 and `(h, β·h)` in G2.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+pub struct Parameters<E: PairingEngine> {
+    pub(crate) powers_of_g: Vec<E::G1Affine>,
+    pub(crate) h: E::G2Affine,
+    pub(crate) beta_h: E::G2Affine,
+}
+
+impl<E: PairingEngine> Parameters<E> {
+    /// Load a reference string produced by an external trusted-setup ceremony and check that it is well formed, i.e.
+    /// that consecutive powers in G1 differ by the same secret as the two elements in G2. Rather than one pairing
+    /// check per power, the powers are combined with powers of a random `ρ` and checked with a single equation
+    /// `e(Σ ρ^i·g_{i+1}, h) = e(Σ ρ^i·g_i, β·h)`.
+    pub fn load<R: Rng, I: Read>(rng: &mut R, reader: I) -> Result<Self, CryptoError> {
+        let parameters =
+            Self::deserialize(reader).map_err(|_| CryptoError::InvalidKzgParameters)?;
+
+        if parameters.powers_of_g.is_empty()
+            || parameters.powers_of_g[0].is_zero()
+            || parameters.h.is_zero()
+            || parameters.beta_h.is_zero()
+        {
+            return Err(CryptoError::InvalidKzgParameters);
+        }
+
+        let max_degree = parameters.max_degree();
+        if max_degree > 0 {
+            let rho = E::Fr::rand(rng);
+            let scalars: Vec<_> =
+                ark_std::iter::successors(Some(E::Fr::one()), |power| Some(*power * rho))
+                    .take(max_degree)
+                    .map(|power| power.into_repr())
+                    .collect();
+
+            let shifted = VariableBaseMSM::multi_scalar_mul(&parameters.powers_of_g[1..], &scalars);
+            let unshifted =
+                VariableBaseMSM::multi_scalar_mul(&parameters.powers_of_g[..max_degree], &scalars);
+
+            if E::pairing(shifted, parameters.h) != E::pairing(unshifted, parameters.beta_h) {
+                return Err(CryptoError::InvalidKzgParameters);
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    /// Largest degree of a polynomial that can be committed to with these parameters.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len() - 1
+    }
+
+    /// Parameters restricted to polynomials of degree at most `max_degree`.
+    pub fn trim(&self, max_degree: usize) -> Result<Self, CryptoError> {
+        if max_degree > self.max_degree() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("KZG"),
+                max_degree + 1,
+                self.powers_of_g.len(),
+            ));
+        }
+
+        Ok(Self {
+            powers_of_g: self.powers_of_g[..=max_degree].to_vec(),
+            h: self.h,
+            beta_h: self.beta_h,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<E: PairingEngine>(pub E::G1Affine);
+
+impl<E: PairingEngine> ToBytes for Commitment<E> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// Commitment to the quotient `(p(X) - p(z)) / (X - z)`.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: PairingEngine>(pub E::G1Affine);
+
+impl<E: PairingEngine> Kzg<E> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"KZG";
+
+    /// Sample a reference string for polynomials of degree at most `max_degree`. Whoever runs this learns the secret
+    /// `β` and can forge openings, so it is only suitable for testing; production parameters should be loaded with
+    /// [`Parameters::load`].
+    pub fn setup<R: Rng>(rng: &mut R, max_degree: usize) -> Result<Parameters<E>, CryptoError> {
+        let beta = E::Fr::rand(rng);
+        let g = E::G1Projective::rand(rng);
+        let h = E::G2Projective::rand(rng);
+
+        let mut powers_of_g = Vec::with_capacity(max_degree + 1);
+        let mut power = E::Fr::one();
+        for _ in 0..=max_degree {
+            powers_of_g.push(g.mul(power.into_repr()).into_affine());
+            power *= beta;
+        }
+
+        Ok(Parameters {
+            powers_of_g,
+            h: h.into_affine(),
+            beta_h: h.mul(beta.into_repr()).into_affine(),
+        })
+    }
+
+    pub fn commit(
+        pp: &Parameters<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+    ) -> Result<Commitment<E>, CryptoError> {
+        let coefficients = &polynomial.coeffs;
+        if coefficients.len() > pp.powers_of_g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("KZG"),
+                coefficients.len(),
+                pp.powers_of_g.len(),
+            ));
+        }
+
+        let scalars: Vec<_> = coefficients.iter().map(|c| c.into_repr()).collect();
+        let commitment =
+            VariableBaseMSM::multi_scalar_mul(&pp.powers_of_g[..coefficients.len()], &scalars);
+
+        Ok(Commitment(commitment.into_affine()))
+    }
+
+    /// Evaluate `polynomial` at `point` and prove the evaluation.
+    pub fn open(
+        pp: &Parameters<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        point: &E::Fr,
+    ) -> Result<(E::Fr, Proof<E>), CryptoError> {
+        let value = polynomial.evaluate(point);
+        let quotient = divide_by_linear(polynomial, point);
+
+        Ok((value, Proof(Self::commit(pp, &quotient)?.0)))
+    }
+
+    /// Check that the polynomial committed to in `commitment` evaluates to `value` at `point`:
+    /// `e(C - v·g, h) = e(π, β·h - z·h)`.
+    pub fn verify(
+        pp: &Parameters<E>,
+        commitment: &Commitment<E>,
+        point: &E::Fr,
+        value: &E::Fr,
+        proof: &Proof<E>,
+    ) -> Result<(), CryptoError> {
+        let lhs = commitment.0.into_projective() - pp.powers_of_g[0].mul(value.into_repr());
+        let rhs = pp.beta_h.into_projective() - pp.h.mul(point.into_repr());
+
+        if E::pairing(lhs, pp.h) != E::pairing(proof.0, rhs) {
+            return Err(CryptoError::ProofVerificationError(String::from("KZG")));
+        }
+
+        Ok(())
+    }
+
+    /// Open several polynomials at the same point with a single proof. The polynomials are combined with powers of a
+    /// challenge drawn from `fs_rng` after absorbing their commitments and evaluations.
+    pub fn batch_open<T: Transcript>(
+        pp: &Parameters<E>,
+        polynomials: &Vec<DensePolynomial<E::Fr>>,
+        point: &E::Fr,
+        fs_rng: &mut T,
+    ) -> Result<(Vec<E::Fr>, Proof<E>), CryptoError> {
+        let commitments = polynomials
+            .iter()
+            .map(|polynomial| Self::commit(pp, polynomial))
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+        let values: Vec<E::Fr> = polynomials
+            .iter()
+            .map(|polynomial| polynomial.evaluate(point))
+            .collect();
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME, commitments, point, values]?);
+        let gamma = derive_challenge::<E::Fr>(fs_rng);
+
+        let mut combined = DensePolynomial::zero();
+        let mut power = E::Fr::one();
+        for polynomial in polynomials {
+            combined += (power, polynomial);
+            power *= gamma;
+        }
+
+        let (_, proof) = Self::open(pp, &combined, point)?;
+
+        Ok((values, proof))
+    }
+
+    pub fn batch_verify<T: Transcript>(
+        pp: &Parameters<E>,
+        commitments: &Vec<Commitment<E>>,
+        point: &E::Fr,
+        values: &Vec<E::Fr>,
+        proof: &Proof<E>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        if commitments.len() != values.len() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "KZG Batch",
+            )));
+        }
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME, commitments, point, values]?);
+        let gamma = derive_challenge::<E::Fr>(fs_rng);
+
+        let mut combined_commitment = E::G1Projective::zero();
+        let mut combined_value = E::Fr::zero();
+        let mut power = E::Fr::one();
+        for (commitment, value) in commitments.iter().zip(values.iter()) {
+            combined_commitment += commitment.0.mul(power.into_repr());
+            combined_value += power * value;
+            power *= gamma;
+        }
+
+        Self::verify(
+            pp,
+            &Commitment(combined_commitment.into_affine()),
+            point,
+            &combined_value,
+            proof,
+        )
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("KZG Batch")))
+    }
+}
+
+/// Quotient of `p(X) - p(z)` by `X - z`, computed by synthetic division.
+fn divide_by_linear<F: PrimeField>(
+    polynomial: &DensePolynomial<F>,
+    point: &F,
+) -> DensePolynomial<F> {
+    let coefficients = &polynomial.coeffs;
+    if coefficients.len() < 2 {
+        return DensePolynomial::zero();
+    }
+
+    let mut quotient = vec![F::zero(); coefficients.len() - 1];
+    let mut carry = F::zero();
+    for i in (1..coefficients.len()).rev() {
+        carry = coefficients[i] + carry * point;
+        quotient[i - 1] = carry;
+    }
+
+    DensePolynomial::from_coefficients_vec(quotient)
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::vector_commitment::kzg;
+    use ark_bls12_381::{Bls12_381, Fr, G1Affine};
+    use ark_ff::Zero;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_poly::{univariate::DensePolynomial, UVPolynomial};
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Kzg = kzg::Kzg<Bls12_381>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_open_and_verify() {
+        let rng = &mut thread_rng();
+        let pp = Kzg::setup(rng, 15).unwrap();
+
+        let polynomial = DensePolynomial::<Fr>::rand(15, rng);
+        let commitment = Kzg::commit(&pp, &polynomial).unwrap();
+
+        let point = Fr::rand(rng);
+        let (value, proof) = Kzg::open(&pp, &polynomial, &point).unwrap();
+        assert_eq!(
+            Kzg::verify(&pp, &commitment, &point, &value, &proof),
+            Ok(())
+        );
+
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from("KZG")));
+        assert_eq!(
+            Kzg::verify(&pp, &commitment, &point, &Fr::rand(rng), &proof),
+            expected_error
+        );
+        assert_eq!(
+            Kzg::verify(&pp, &commitment, &Fr::rand(rng), &value, &proof),
+            expected_error
+        );
+
+        // Constant polynomials open with the identity as proof
+        let constant = DensePolynomial::from_coefficients_vec(vec![Fr::rand(rng)]);
+        let commitment = Kzg::commit(&pp, &constant).unwrap();
+        let (value, proof) = Kzg::open(&pp, &constant, &point).unwrap();
+        assert_eq!(value, constant.coeffs[0]);
+        assert_eq!(
+            Kzg::verify(&pp, &commitment, &point, &value, &proof),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_degree_bound() {
+        let rng = &mut thread_rng();
+        let pp = Kzg::setup(rng, 7).unwrap();
+
+        let polynomial = DensePolynomial::<Fr>::rand(8, rng);
+        assert_eq!(
+            Kzg::commit(&pp, &polynomial),
+            Err(CryptoError::CommitmentLengthError(
+                String::from("KZG"),
+                9,
+                8
+            ))
+        );
+
+        let trimmed = pp.trim(3).unwrap();
+        assert_eq!(trimmed.max_degree(), 3);
+        assert!(pp.trim(8).is_err());
+    }
+
+    #[test]
+    fn test_batch_open() {
+        let rng = &mut thread_rng();
+        let pp = Kzg::setup(rng, 15).unwrap();
+
+        let polynomials: Vec<_> = (0..4)
+            .map(|degree| DensePolynomial::<Fr>::rand(4 * degree + 3, rng))
+            .collect();
+        let commitments: Vec<_> = polynomials
+            .iter()
+            .map(|polynomial| Kzg::commit(&pp, polynomial).unwrap())
+            .collect();
+        let point = Fr::rand(rng);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let (values, proof) = Kzg::batch_open(&pp, &polynomials, &point, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Kzg::batch_verify(&pp, &commitments, &point, &values, &proof, &mut fs_rng),
+            Ok(())
+        );
+
+        let mut wrong_values = values.clone();
+        wrong_values[2] = Fr::rand(rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Kzg::batch_verify(
+                &pp,
+                &commitments,
+                &point,
+                &wrong_values,
+                &proof,
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "KZG Batch"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_load_parameters() {
+        let rng = &mut thread_rng();
+        let pp = Kzg::setup(rng, 7).unwrap();
+
+        let mut serialized = Vec::new();
+        pp.serialize(&mut serialized).unwrap();
+        let loaded = kzg::Parameters::<Bls12_381>::load(rng, &serialized[..]).unwrap();
+
+        let polynomial = DensePolynomial::<Fr>::rand(7, rng);
+        let commitment = Kzg::commit(&loaded, &polynomial).unwrap();
+        assert_eq!(commitment, Kzg::commit(&pp, &polynomial).unwrap());
+
+        // Parameters whose powers do not follow a single secret are rejected
+        let mut tampered = pp.clone();
+        tampered.powers_of_g.swap(2, 3);
+        let mut serialized = Vec::new();
+        tampered.serialize(&mut serialized).unwrap();
+        assert_eq!(
+            kzg::Parameters::<Bls12_381>::load(rng, &serialized[..]).err(),
+            Some(CryptoError::InvalidKzgParameters)
+        );
+
+        assert_eq!(
+            kzg::Parameters::<Bls12_381>::load(rng, &serialized[..10]).err(),
+            Some(CryptoError::InvalidKzgParameters)
+        );
+
+        // A zero generator trivially satisfies every pairing check
+        let mut degenerate = pp.clone();
+        degenerate
+            .powers_of_g
+            .iter_mut()
+            .for_each(|power| *power = G1Affine::zero());
+        let mut serialized = Vec::new();
+        degenerate.serialize(&mut serialized).unwrap();
+        assert_eq!(
+            kzg::Parameters::<Bls12_381>::load(rng, &serialized[..]).err(),
+            Some(CryptoError::InvalidKzgParameters)
+        );
+    }
+}
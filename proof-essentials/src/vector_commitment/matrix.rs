@@ -0,0 +1,262 @@
+use super::HomomorphicCommitmentScheme;
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::{dot_product, reshape};
+
+use ark_ff::Field;
+use ark_std::cfg_into_iter;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::marker::PhantomData;
+
+/// A matrix of scalars stored as its `m` columns of `n` entries each, which is how the shuffle, product and
+/// Hadamard product arguments split a vector of length $N = mn$ before committing to it column by column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix<Scalar: Field> {
+    columns: Vec<Vec<Scalar>>,
+}
+
+impl<Scalar: Field> Matrix<Scalar> {
+    /// Build a matrix from its columns. Fails if there are no columns or if they do not all have the same length.
+    pub fn from_columns(columns: Vec<Vec<Scalar>>) -> Result<Self, CryptoError> {
+        let n = columns.first().map_or(0, |column| column.len());
+        if let Some(column) = columns.iter().find(|column| column.len() != n) {
+            return Err(CryptoError::VectorCastingError(
+                column.len(),
+                columns.len(),
+                n,
+            ));
+        }
+        if n == 0 {
+            return Err(CryptoError::VectorCastingError(0, columns.len(), n));
+        }
+
+        Ok(Self { columns })
+    }
+
+    /// Split `vector` of length $mn$ into `m` consecutive columns of length `n`.
+    pub fn reshape(vector: &Vec<Scalar>, m: usize, n: usize) -> Result<Self, CryptoError> {
+        Self::from_columns(reshape(vector, m, n)?)
+    }
+
+    /// Number of columns.
+    pub fn m(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Number of entries in every column.
+    pub fn n(&self) -> usize {
+        self.columns[0].len()
+    }
+
+    pub fn columns(&self) -> &Vec<Vec<Scalar>> {
+        &self.columns
+    }
+
+    pub fn into_columns(self) -> Vec<Vec<Scalar>> {
+        self.columns
+    }
+
+    pub fn column(&self, j: usize) -> Option<&Vec<Scalar>> {
+        self.columns.get(j)
+    }
+
+    pub fn row(&self, i: usize) -> Option<Vec<Scalar>> {
+        if i >= self.n() {
+            return None;
+        }
+
+        Some(self.columns.iter().map(|column| column[i]).collect())
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            columns: (0..self.n())
+                .map(|i| self.columns.iter().map(|column| column[i]).collect())
+                .collect(),
+        }
+    }
+
+    /// The column $\sum_j w_j a_j$. Its commitment is [`MatrixCommitment::combine_columns`] of the column commitments
+    /// with the same weights.
+    pub fn combine_columns(&self, weights: &[Scalar]) -> Result<Vec<Scalar>, CryptoError> {
+        if weights.len() != self.m() {
+            return Err(CryptoError::DotProductLengthError(weights.len(), self.m()));
+        }
+
+        Ok((0..self.n())
+            .map(|i| {
+                self.columns
+                    .iter()
+                    .zip(weights.iter())
+                    .map(|(column, weight)| column[i] * weight)
+                    .sum()
+            })
+            .collect())
+    }
+
+    /// The column holding the product of every row, i.e. the Hadamard product of all the columns.
+    pub fn product_along_rows(&self) -> Result<Vec<Scalar>, CryptoError> {
+        Ok(cfg_into_iter!(0..self.n())
+            .map(|i| self.columns.iter().map(|column| column[i]).product())
+            .collect())
+    }
+
+    /// Entry-wise sum of two matrices of the same shape.
+    pub fn add(&self, other: &Self) -> Result<Self, CryptoError> {
+        if self.m() != other.m() || self.n() != other.n() {
+            return Err(CryptoError::VectorCastingError(
+                other.m() * other.n(),
+                self.m(),
+                self.n(),
+            ));
+        }
+
+        Ok(Self {
+            columns: self
+                .columns
+                .iter()
+                .zip(other.columns.iter())
+                .map(|(a, b)| a.iter().zip(b.iter()).map(|(&a, &b)| a + b).collect())
+                .collect(),
+        })
+    }
+
+    pub fn scale(&self, x: Scalar) -> Self {
+        Self {
+            columns: self
+                .columns
+                .iter()
+                .map(|column| column.iter().map(|&a| a * x).collect())
+                .collect(),
+        }
+    }
+}
+
+/// Column-wise commitments to a [`Matrix`]: column $j$ is committed with the vector commitment scheme `Comm` and
+/// randomness $r_j$. The operations on commitments mirror the operations on matrices, with the randomness combined
+/// the same way as the columns.
+pub struct MatrixCommitment<Scalar: Field, Comm: HomomorphicCommitmentScheme<Scalar>> {
+    _scalar: PhantomData<Scalar>,
+    _commitment_scheme: PhantomData<Comm>,
+}
+
+impl<Scalar: Field, Comm: HomomorphicCommitmentScheme<Scalar>> MatrixCommitment<Scalar, Comm> {
+    /// Commit to every column of `matrix` with the matching entry of `randomness`, see
+    /// [`HomomorphicCommitmentScheme::batch_commit`].
+    pub fn commit_matrix(
+        commit_key: &Comm::CommitKey,
+        matrix: &Matrix<Scalar>,
+        randomness: &[Scalar],
+    ) -> Result<Vec<Comm::Commitment>, CryptoError> {
+        if randomness.len() != matrix.m() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Matrix"),
+                matrix.m(),
+                randomness.len(),
+            ));
+        }
+
+        Comm::batch_commit(commit_key, &matrix.columns, randomness)
+    }
+
+    /// Commitment to [`Matrix::combine_columns`] with randomness $\sum_j w_j r_j$.
+    pub fn combine_columns(
+        commitments: &Vec<Comm::Commitment>,
+        weights: &Vec<Scalar>,
+    ) -> Result<Comm::Commitment, CryptoError> {
+        dot_product(weights, commitments)
+    }
+
+    /// Commitments to [`Matrix::add`] with randomness $r_j + s_j$.
+    pub fn add(
+        commitments: &[Comm::Commitment],
+        other: &[Comm::Commitment],
+    ) -> Result<Vec<Comm::Commitment>, CryptoError> {
+        if commitments.len() != other.len() {
+            return Err(CryptoError::HadamardProductLengthError(
+                commitments.len(),
+                other.len(),
+            ));
+        }
+
+        Ok(commitments
+            .iter()
+            .zip(other.iter())
+            .map(|(&a, &b)| a + b)
+            .collect())
+    }
+
+    /// Commitments to [`Matrix::scale`] with randomness $x r_j$.
+    pub fn scale(commitments: &[Comm::Commitment], x: Scalar) -> Vec<Comm::Commitment> {
+        commitments.iter().map(|&c| c * x).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Matrix, MatrixCommitment};
+    use crate::utils::rand::sample_vector;
+    use crate::utils::vector_arithmetic::dot_product;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use ark_std::{rand::thread_rng, UniformRand};
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type Commitments = MatrixCommitment<Scalar, Pedersen>;
+
+    #[test]
+    fn matrix_commitment_operations() {
+        let rng = &mut thread_rng();
+        let m = 4;
+        let n = 6;
+
+        let commit_key = Pedersen::setup(rng, n);
+
+        let a = Matrix::reshape(&sample_vector(rng, m * n), m, n).unwrap();
+        let b = Matrix::reshape(&sample_vector(rng, m * n), m, n).unwrap();
+        let r: Vec<Scalar> = sample_vector(rng, m);
+        let s: Vec<Scalar> = sample_vector(rng, m);
+
+        let a_commits = Commitments::commit_matrix(&commit_key, &a, &r).unwrap();
+        let b_commits = Commitments::commit_matrix(&commit_key, &b, &s).unwrap();
+
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(a.row(2).unwrap()[1], a.column(1).unwrap()[2]);
+        assert_eq!(a.transpose().column(2), a.row(2).as_ref());
+        assert!(a.row(n).is_none());
+
+        let weights: Vec<Scalar> = sample_vector(rng, m);
+        assert_eq!(
+            Commitments::combine_columns(&a_commits, &weights).unwrap(),
+            Pedersen::commit(
+                &commit_key,
+                &a.combine_columns(&weights).unwrap(),
+                dot_product(&weights, &r).unwrap()
+            )
+            .unwrap()
+        );
+
+        let sum_randomness = r
+            .iter()
+            .zip(s.iter())
+            .map(|(&r, &s)| r + s)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Commitments::add(&a_commits, &b_commits).unwrap(),
+            Commitments::commit_matrix(&commit_key, &a.add(&b).unwrap(), &sum_randomness).unwrap()
+        );
+
+        let x = Scalar::rand(rng);
+        let scaled_randomness = r.iter().map(|&r| r * x).collect::<Vec<_>>();
+        assert_eq!(
+            Commitments::scale(&a_commits, x),
+            Commitments::commit_matrix(&commit_key, &a.scale(x), &scaled_randomness).unwrap()
+        );
+
+        let products = a.product_along_rows().unwrap();
+        for (i, product) in products.iter().enumerate() {
+            assert_eq!(*product, a.row(i).unwrap().iter().product());
+        }
+    }
+}
@@ -0,0 +1,244 @@
+mod tests;
+
+use crate::error::CryptoError;
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use digest::Digest;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+/// Output of the hash function of the tree.
+pub type Hash = Vec<u8>;
+
+/// Binary Merkle tree over byte strings, hashed with `D`.
+///
+/// Leaves and inner nodes are hashed with distinct prefixes, $H(0 \| x)$ and $H(1 \| l \| r)$, so that an inner
+/// node cannot be passed off as a leaf. The leaves are padded to a power of two with $H(2)$, so every
+/// membership proof has one sibling per level.
+#[derive(Clone)]
+pub struct MerkleTree<D: Digest> {
+    /// Every level of the tree, from the padded leaves up to the root.
+    levels: Vec<Vec<Hash>>,
+    len: usize,
+    _hash: PhantomData<D>,
+}
+
+/// Proof that a leaf sits at `index` in the tree: the hashes of its siblings from the bottom up.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MembershipProof {
+    pub index: usize,
+    pub siblings: Vec<Hash>,
+}
+
+/// Proof that several leaves are in the tree. Siblings that can be computed from the proven leaves are left out,
+/// so the proof is smaller than the separate membership proofs.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultiProof {
+    pub depth: usize,
+    /// Missing siblings, level by level from the bottom up and by increasing index within a level.
+    pub siblings: Vec<Hash>,
+}
+
+impl<D: Digest> MerkleTree<D> {
+    /// Build the tree of `leaves`. Fails if there are none.
+    pub fn new<L: AsRef<[u8]>>(leaves: &[L]) -> Result<Self, CryptoError> {
+        if leaves.is_empty() {
+            return Err(CryptoError::InvalidMerkleTree);
+        }
+
+        let mut level: Vec<Hash> = leaves
+            .iter()
+            .map(|leaf| Self::hash_leaf(leaf.as_ref()))
+            .collect();
+        level.resize(leaves.len().next_power_of_two(), Self::padding());
+
+        let mut levels = vec![level];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| Self::hash_node(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(Self {
+            levels,
+            len: leaves.len(),
+            _hash: PhantomData,
+        })
+    }
+
+    pub fn root(&self) -> Hash {
+        self.levels[self.levels.len() - 1][0].clone()
+    }
+
+    /// Number of leaves, without the padding.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of levels below the root.
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Prove that the leaf at `index` is in the tree.
+    pub fn prove(&self, index: usize) -> Result<MembershipProof, CryptoError> {
+        if index >= self.len {
+            return Err(CryptoError::InvalidMerkleTree);
+        }
+
+        let siblings = self.levels[..self.depth()]
+            .iter()
+            .enumerate()
+            .map(|(height, level)| level[(index >> height) ^ 1].clone())
+            .collect();
+
+        Ok(MembershipProof { index, siblings })
+    }
+
+    /// Verify that `leaf` is in the tree of root `root` at the index given by `proof`.
+    pub fn verify(root: &Hash, leaf: &[u8], proof: &MembershipProof) -> Result<(), CryptoError> {
+        let verification_error =
+            || CryptoError::ProofVerificationError(String::from("Merkle Membership"));
+
+        if proof.siblings.len() >= usize::BITS as usize || proof.index >> proof.siblings.len() != 0
+        {
+            return Err(verification_error());
+        }
+
+        let computed = proof.siblings.iter().enumerate().fold(
+            Self::hash_leaf(leaf),
+            |node, (height, sibling)| {
+                if (proof.index >> height) & 1 == 0 {
+                    Self::hash_node(&node, sibling)
+                } else {
+                    Self::hash_node(sibling, &node)
+                }
+            },
+        );
+
+        if computed != *root {
+            return Err(verification_error());
+        }
+
+        Ok(())
+    }
+
+    /// Prove that the leaves at `indices` are in the tree with a single proof.
+    pub fn prove_batch(&self, indices: &[usize]) -> Result<MultiProof, CryptoError> {
+        if indices.is_empty() || indices.iter().any(|&index| index >= self.len) {
+            return Err(CryptoError::InvalidMerkleTree);
+        }
+
+        let mut known: Vec<usize> = indices.to_vec();
+        known.sort_unstable();
+        known.dedup();
+
+        let mut siblings = Vec::new();
+        for level in &self.levels[..self.depth()] {
+            for (position, &index) in known.iter().enumerate() {
+                let sibling = index ^ 1;
+                let sibling_is_known = if index & 1 == 0 {
+                    known.get(position + 1) == Some(&sibling)
+                } else {
+                    position > 0 && known[position - 1] == sibling
+                };
+                if !sibling_is_known {
+                    siblings.push(level[sibling].clone());
+                }
+            }
+
+            known = known.iter().map(|index| index >> 1).collect();
+            known.dedup();
+        }
+
+        Ok(MultiProof {
+            depth: self.depth(),
+            siblings,
+        })
+    }
+
+    /// Verify that every `(index, leaf)` pair of `leaves` is in the tree of root `root`.
+    pub fn verify_batch<L: AsRef<[u8]>>(
+        root: &Hash,
+        leaves: &[(usize, L)],
+        proof: &MultiProof,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            || CryptoError::ProofVerificationError(String::from("Merkle Multi"));
+
+        if leaves.is_empty()
+            || proof.depth >= usize::BITS as usize
+            || leaves.iter().any(|(index, _)| index >> proof.depth != 0)
+        {
+            return Err(verification_error());
+        }
+
+        let mut known: BTreeMap<usize, Hash> = BTreeMap::new();
+        for (index, leaf) in leaves {
+            let hash = Self::hash_leaf(leaf.as_ref());
+            // the same index cannot be claimed for two different leaves
+            if known
+                .insert(*index, hash.clone())
+                .is_some_and(|previous| previous != hash)
+            {
+                return Err(verification_error());
+            }
+        }
+
+        let mut siblings = proof.siblings.iter();
+        for _ in 0..proof.depth {
+            let mut next = BTreeMap::new();
+            let mut nodes = known.into_iter().peekable();
+            while let Some((index, node)) = nodes.next() {
+                let (left, right) = if index & 1 == 0 {
+                    let right = match nodes.peek() {
+                        Some((next_index, _)) if *next_index == index ^ 1 => {
+                            nodes.next().unwrap().1
+                        }
+                        _ => siblings.next().ok_or_else(verification_error)?.clone(),
+                    };
+                    (node, right)
+                } else {
+                    (
+                        siblings.next().ok_or_else(verification_error)?.clone(),
+                        node,
+                    )
+                };
+                next.insert(index >> 1, Self::hash_node(&left, &right));
+            }
+            known = next;
+        }
+
+        if siblings.next().is_some() || known.get(&0) != Some(root) {
+            return Err(verification_error());
+        }
+
+        Ok(())
+    }
+
+    fn hash_leaf(leaf: &[u8]) -> Hash {
+        let mut hasher = D::new();
+        hasher.update([0u8]);
+        hasher.update(leaf);
+        hasher.finalize().to_vec()
+    }
+
+    fn hash_node(left: &[u8], right: &[u8]) -> Hash {
+        let mut hasher = D::new();
+        hasher.update([1u8]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().to_vec()
+    }
+
+    fn padding() -> Hash {
+        D::digest(&[2u8]).to_vec()
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::vector_commitment::merkle::MerkleTree;
+
+    use sha2::Sha256;
+
+    type Tree = MerkleTree<Sha256>;
+
+    fn leaves(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| format!("card {}", i).into_bytes()).collect()
+    }
+
+    #[test]
+    fn membership_proofs() {
+        let leaves = leaves(52);
+        let tree = Tree::new(&leaves).unwrap();
+        assert_eq!(tree.len(), 52);
+        assert_eq!(tree.depth(), 6);
+
+        let root = tree.root();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.prove(i).unwrap();
+            assert_eq!(Tree::verify(&root, leaf, &proof), Ok(()));
+        }
+
+        let proof = tree.prove(7).unwrap();
+        assert_eq!(
+            Tree::verify(&root, &leaves[8], &proof),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Merkle Membership"
+            )))
+        );
+
+        let mut wrong_index = proof.clone();
+        wrong_index.index = 6;
+        assert!(Tree::verify(&root, &leaves[7], &wrong_index).is_err());
+
+        assert_eq!(tree.prove(52), Err(CryptoError::InvalidMerkleTree));
+        assert_eq!(
+            Tree::new::<Vec<u8>>(&[]).err(),
+            Some(CryptoError::InvalidMerkleTree)
+        );
+
+        // A single leaf is its own tree
+        let single = Tree::new(&leaves[..1]).unwrap();
+        assert_eq!(
+            Tree::verify(&single.root(), &leaves[0], &single.prove(0).unwrap()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn multi_proofs() {
+        let leaves = leaves(20);
+        let tree = Tree::new(&leaves).unwrap();
+        let root = tree.root();
+
+        let indices = [3, 4, 5, 12, 19, 4];
+        let proof = tree.prove_batch(&indices).unwrap();
+        let opened = indices
+            .iter()
+            .map(|&i| (i, leaves[i].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(Tree::verify_batch(&root, &opened, &proof), Ok(()));
+
+        // Smaller than the separate proofs
+        let separate: usize = [3, 4, 5, 12, 19]
+            .iter()
+            .map(|&i| tree.prove(i).unwrap().siblings.len())
+            .sum();
+        assert!(proof.siblings.len() < separate);
+
+        let mut tampered = opened.clone();
+        tampered[3].1 = b"joker".to_vec();
+        assert_eq!(
+            Tree::verify_batch(&root, &tampered, &proof),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Merkle Multi"
+            )))
+        );
+
+        let mut conflicting = opened.clone();
+        conflicting.push((3, leaves[2].clone()));
+        assert!(Tree::verify_batch(&root, &conflicting, &proof).is_err());
+
+        assert!(Tree::verify_batch(&root, &opened[..2], &proof).is_err());
+
+        // Every leaf at once needs no sibling
+        let all = (0..20).collect::<Vec<_>>();
+        let proof = tree.prove_batch(&all).unwrap();
+        let opened = all
+            .iter()
+            .map(|&i| (i, leaves[i].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(Tree::verify_batch(&root, &opened, &proof), Ok(()));
+    }
+}
@@ -1,35 +1,103 @@
+pub mod el_gamal;
+pub mod hash;
+pub mod kzg;
+pub mod matrix;
+pub mod merkle;
 pub mod pedersen;
 
 use crate::error::CryptoError;
 use ark_ff::{Field, ToBytes, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::cfg_iter;
 use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::{iter::Sum, ops};
 
 /// Trait defining the types and functions needed for an additively homomorphic commitment scheme.
 /// The scheme is defined with respect to a finite field `F` for which scalar multiplication is preserved.
+///
+/// The arguments of [`crate::zkp::arguments`] only rely on this trait, so any implementation, such as
+/// [`pedersen::PedersenCommitment`] or [`el_gamal::ElGamalCommitment`], can be used with them.
 pub trait HomomorphicCommitmentScheme<Scalar: Field> {
-    type CommitKey: Clone + CanonicalSerialize + CanonicalDeserialize + ToBytes;
+    type CommitKey: Clone + CanonicalSerialize + CanonicalDeserialize + ToBytes + Send + Sync;
 
-    /// Represent a ciphertext from a generic homomorphic encryption scheme. To manifest the homomorphic
+    /// Represent a commitment from a generic homomorphic commitment scheme. To manifest the homomorphic
     /// property of the scheme, we require that some arithmetic operations (add and multiply by scalar) are implemented.
     type Commitment: PartialEq
         + Copy
-        + ops::Add
+        + ops::Add<Output = Self::Commitment>
         + ops::Mul<Scalar, Output = Self::Commitment>
         + CanonicalSerialize
         + CanonicalDeserialize
         + Zero
         + Sum
-        + ToBytes;
+        + ToBytes
+        + Send
+        + Sync;
 
     /// Generate a commit key using the provided length
     fn setup<R: Rng>(public_randomess: &mut R, len: usize) -> Self::CommitKey;
 
     /// Commit to a vector of scalars using the commit key
+    #[allow(clippy::ptr_arg)]
     fn commit(
         commit_key: &Self::CommitKey,
         x: &Vec<Scalar>,
         r: Scalar,
     ) -> Result<Self::Commitment, CryptoError>;
+
+    /// Commit to every vector of `values` with the matching entry of `randomness`, in parallel when the `parallel`
+    /// feature is enabled. Curve-based schemes override it to bring all the commitments to affine form with a single
+    /// field inversion, instead of one per commitment.
+    fn batch_commit(
+        commit_key: &Self::CommitKey,
+        values: &[Vec<Scalar>],
+        randomness: &[Scalar],
+    ) -> Result<Vec<Self::Commitment>, CryptoError> {
+        if values.len() != randomness.len() {
+            return Err(CryptoError::DotProductLengthError(
+                values.len(),
+                randomness.len(),
+            ));
+        }
+
+        cfg_iter!(values)
+            .zip(cfg_iter!(randomness))
+            .map(|(x, &r)| Self::commit(commit_key, x, r))
+            .collect()
+    }
+
+    /// Compute $\sum_i s_i C_i$ for the `scalars` $s_i$ and the `commitments` $C_i$. The default adds up the
+    /// products one by one; curve-based schemes override it with a multi-scalar multiplication.
+    fn multi_scalar_mul(
+        scalars: &[Scalar],
+        commitments: &[Self::Commitment],
+    ) -> Result<Self::Commitment, CryptoError> {
+        if scalars.len() != commitments.len() {
+            return Err(CryptoError::DotProductLengthError(
+                scalars.len(),
+                commitments.len(),
+            ));
+        }
+
+        Ok(commitments
+            .iter()
+            .zip(scalars.iter())
+            .map(|(&commitment, &scalar)| commitment * scalar)
+            .sum())
+    }
+
+    /// Check that $\sum_i s_i C_i = \mathrm{com}(x; r)$ for the `scalars` $s_i$ and the `commitments` $C_i$. The
+    /// default computes both sides and compares them; curve-based schemes override it with a single multi-scalar
+    /// multiplication compared to the identity.
+    fn verify_combination(
+        commit_key: &Self::CommitKey,
+        scalars: &[Scalar],
+        commitments: &[Self::Commitment],
+        x: &Vec<Scalar>,
+        r: Scalar,
+    ) -> Result<bool, CryptoError> {
+        Ok(Self::multi_scalar_mul(scalars, commitments)? == Self::commit(commit_key, x, r)?)
+    }
 }
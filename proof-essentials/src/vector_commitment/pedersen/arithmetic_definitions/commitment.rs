@@ -20,6 +20,22 @@ impl<C: ProjectiveCurve> std::ops::Add for Commitment<C> {
     }
 }
 
+impl<C: ProjectiveCurve> std::ops::Neg for Commitment<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<C: ProjectiveCurve> std::ops::Sub for Commitment<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self((self.0.into_projective() - rhs.0.into_projective()).into_affine())
+    }
+}
+
 impl<C: ProjectiveCurve> std::iter::Sum for Commitment<C> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(Self::zero(), |a, b| a + b)
@@ -1 +1,2 @@
 pub mod commitment;
+pub mod opening;
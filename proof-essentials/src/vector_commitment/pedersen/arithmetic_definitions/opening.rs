@@ -0,0 +1,55 @@
+use super::super::Opening;
+use ark_ec::ProjectiveCurve;
+use ark_ff::Zero;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Openings of different lengths are combined as if the shorter one was padded with zeros, which is how
+/// [`super::super::PedersenCommitment`] commits to short vectors.
+impl<C: ProjectiveCurve> Add for Opening<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let len = self.values.len().max(rhs.values.len());
+        let value = |values: &Vec<C::ScalarField>, i: usize| {
+            values.get(i).copied().unwrap_or_else(C::ScalarField::zero)
+        };
+
+        Self {
+            values: (0..len)
+                .map(|i| value(&self.values, i) + value(&rhs.values, i))
+                .collect(),
+            randomness: self.randomness + rhs.randomness,
+        }
+    }
+}
+
+impl<C: ProjectiveCurve> Neg for Opening<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            values: self.values.into_iter().map(|x| -x).collect(),
+            randomness: -self.randomness,
+        }
+    }
+}
+
+impl<C: ProjectiveCurve> Sub for Opening<C> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<C: ProjectiveCurve> Mul<C::ScalarField> for Opening<C> {
+    type Output = Self;
+
+    fn mul(self, x: C::ScalarField) -> Self {
+        Self {
+            values: self.values.into_iter().map(|v| v * x).collect(),
+            randomness: self.randomness * x,
+        }
+    }
+}
@@ -1,32 +1,275 @@
 use crate::error::CryptoError;
+use crate::utils::fixed_base::{FixedBaseTable, Precompute};
+use crate::utils::hash_to_curve::hash_to_curve;
+use crate::utils::msm::{Plain, ScalarMul};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 
-use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
-use ark_ff::{PrimeField, ToBytes};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{to_bytes, ToBytes, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::UniformRand;
 use ark_std::{
+    cfg_iter,
     io::{Read, Write},
     marker::PhantomData,
 };
+use digest::Digest;
 use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 pub mod arithmetic_definitions;
 mod tests;
 
-pub struct PedersenCommitment<C: ProjectiveCurve> {
+/// Pedersen commitments over `C`, with the multi-scalar multiplications of the backend `M`, such as
+/// [`Glv`](crate::utils::glv::Glv) on curves with an efficient endomorphism.
+pub struct PedersenCommitment<C: ProjectiveCurve, M: ScalarMul<C> = Plain> {
     _curve: PhantomData<C>,
+    _scalar_mul: PhantomData<M>,
 }
 
-#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq)]
 pub struct CommitKey<C: ProjectiveCurve> {
-    g: Vec<C::Affine>,
-    h: C::Affine,
+    pub(crate) g: Vec<C::Affine>,
+    pub(crate) h: C::Affine,
 }
 
 impl<C: ProjectiveCurve> CommitKey<C> {
     pub fn new(g: Vec<C::Affine>, h: C::Affine) -> Self {
         Self { g, h }
     }
+
+    /// Maximum number of scalars that can be committed to with this key
+    pub fn len(&self) -> usize {
+        self.g.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.g.is_empty()
+    }
+
+    /// Grow the key to `len` generators. The existing generators are kept, so commitments made with the shorter
+    /// key are still valid commitments under the longer one, and every new generator $G_i$ is hashed to the curve
+    /// from $H$ and $i$, so anyone holding the key derives the same extension and no one knows its discrete
+    /// logarithms. The key is returned unchanged if it already has `len` generators.
+    pub fn extend<D: Digest>(&self, len: usize) -> Result<Self, CryptoError> {
+        let mut g = self.g.clone();
+        for index in self.g.len()..len {
+            g.push(hash_to_curve::<C, D>(
+                PedersenCommitment::<C>::PROTOCOL_NAME,
+                &to_bytes![b"extension", self.h, index as u64]?,
+            )?);
+        }
+
+        Ok(Self { g, h: self.h })
+    }
+}
+
+/// Commit key with a fixed-base table for every generator, for keys that commit to many vectors. A commitment then
+/// takes one table lookup per value instead of a multi-scalar multiplication.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrecomputedCommitKey<C: ProjectiveCurve> {
+    g: Vec<FixedBaseTable<C>>,
+    h: FixedBaseTable<C>,
+}
+
+impl<C: ProjectiveCurve> Precompute for CommitKey<C> {
+    type Table = PrecomputedCommitKey<C>;
+
+    fn precompute(&self) -> Self::Table {
+        PrecomputedCommitKey {
+            g: cfg_iter!(self.g).map(|g_i| g_i.precompute()).collect(),
+            h: self.h.precompute(),
+        }
+    }
+}
+
+impl<C: ProjectiveCurve> PrecomputedCommitKey<C> {
+    pub fn commit_key(&self) -> CommitKey<C> {
+        CommitKey {
+            g: self.g.iter().map(|g_i| *g_i.base()).collect(),
+            h: *self.h.base(),
+        }
+    }
+
+    /// Same commitment as [`PedersenCommitment::commit`] with the commit key the tables were built from.
+    pub fn commit(
+        &self,
+        x: &[C::ScalarField],
+        r: C::ScalarField,
+    ) -> Result<Commitment<C>, CryptoError> {
+        if x.len() > self.g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                x.len(),
+                self.g.len(),
+            ));
+        }
+
+        let commitment = self
+            .g
+            .iter()
+            .zip(x.iter())
+            .fold(self.h.mul(&r), |acc, (g_i, x_i)| acc + g_i.mul(x_i));
+
+        Ok(Commitment(commitment.into_affine()))
+    }
+}
+
+/// The committed values and the blinding factor of a commitment. Openings support the same additive and scalar
+/// operations as commitments, so that the opening of a combination of commitments is the same combination of
+/// their openings.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Opening<C: ProjectiveCurve> {
+    pub values: Vec<C::ScalarField>,
+    pub randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Opening<C> {
+    pub fn new(values: Vec<C::ScalarField>, randomness: C::ScalarField) -> Self {
+        Self { values, randomness }
+    }
+}
+
+impl<C: ProjectiveCurve, M: ScalarMul<C>> PedersenCommitment<C, M> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Pedersen Commitment Key";
+
+    /// Nothing-up-my-sleeve commit key for `len` scalars: every generator is hashed to the curve from `seed` and its
+    /// label, so no one knows a discrete logarithm relation between them and verifiers can recompute the key from
+    /// the seed instead of trusting whoever generated it. Keys of different lengths from the same seed share their
+    /// generators.
+    pub fn setup_from_seed<D: Digest>(
+        seed: &[u8],
+        len: usize,
+    ) -> Result<CommitKey<C>, CryptoError> {
+        let g = (0..len as u64)
+            .map(|index| {
+                hash_to_curve::<C, D>(
+                    Self::PROTOCOL_NAME,
+                    &to_bytes![seed.len() as u64, seed, b"g", index]?,
+                )
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+        let h = hash_to_curve::<C, D>(
+            Self::PROTOCOL_NAME,
+            &to_bytes![seed.len() as u64, seed, b"h"]?,
+        )?;
+
+        Ok(CommitKey { g, h })
+    }
+
+    /// Commit to `values` with a fresh blinding factor and return the commitment with its opening.
+    pub fn commit_vector<R: Rng>(
+        rng: &mut R,
+        commit_key: &CommitKey<C>,
+        values: &[C::ScalarField],
+    ) -> Result<(Commitment<C>, Opening<C>), CryptoError> {
+        let opening = Opening::new(values.to_vec(), C::ScalarField::rand(rng));
+        let commitment = Self::commit_opening(commit_key, &opening)?;
+
+        Ok((commitment, opening))
+    }
+
+    /// Commit to the values of `opening` with its blinding factor.
+    pub fn commit_opening(
+        commit_key: &CommitKey<C>,
+        opening: &Opening<C>,
+    ) -> Result<Commitment<C>, CryptoError> {
+        Self::commit(commit_key, &opening.values, opening.randomness)
+    }
+
+    /// Check that `opening` opens `commitment`.
+    pub fn open(
+        commit_key: &CommitKey<C>,
+        commitment: &Commitment<C>,
+        opening: &Opening<C>,
+    ) -> Result<(), CryptoError> {
+        if Self::commit_opening(commit_key, opening)? != *commitment {
+            return Err(CryptoError::InvalidOpening);
+        }
+
+        Ok(())
+    }
+
+    /// Check that every opening opens the commitment at the same position with a single multi-exponentiation.
+    ///
+    /// For random weights $\rho_i$, all openings are valid (except with negligible probability) if
+    /// $\sum_i \rho_i r_i H + \sum_j (\sum_i \rho_i x_{i,j}) G_j - \sum_i \rho_i C_i = 0$. The weights must be
+    /// unpredictable to whoever produced the openings.
+    pub fn batch_open<R: Rng>(
+        rng: &mut R,
+        commit_key: &CommitKey<C>,
+        commitments: &[Commitment<C>],
+        openings: &[Opening<C>],
+    ) -> Result<(), CryptoError> {
+        if commitments.len() != openings.len() {
+            return Err(CryptoError::InvalidBatchStatement);
+        }
+        if let Some(opening) = openings
+            .iter()
+            .find(|o| o.values.len() > commit_key.g.len())
+        {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                opening.values.len(),
+                commit_key.g.len(),
+            ));
+        }
+
+        let weights = (0..openings.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+
+        let mut randomness = C::ScalarField::zero();
+        let mut values = vec![C::ScalarField::zero(); commit_key.g.len()];
+        for (weight, opening) in weights.iter().zip(openings.iter()) {
+            randomness += *weight * opening.randomness;
+            for (acc, x) in values.iter_mut().zip(opening.values.iter()) {
+                *acc += *weight * x;
+            }
+        }
+
+        let bases = [
+            &[commit_key.h],
+            &commit_key.g[..],
+            &commitments.iter().map(|c| c.0).collect::<Vec<_>>()[..],
+        ]
+        .concat();
+        let scalars = [
+            &[randomness],
+            &values[..],
+            &weights.iter().map(|w| -*w).collect::<Vec<_>>()[..],
+        ]
+        .concat();
+
+        if !M::multi_scalar_mul(&bases, &scalars)?.is_zero() {
+            return Err(CryptoError::InvalidOpening);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve, M: ScalarMul<C>> PedersenCommitment<C, M> {
+    /// $rH + \sum_i x_i G_i$, before its conversion to affine coordinates.
+    fn commit_projective(
+        commit_key: &CommitKey<C>,
+        x: &[C::ScalarField],
+        r: C::ScalarField,
+    ) -> Result<C, CryptoError> {
+        if x.len() > commit_key.g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                x.len(),
+                commit_key.g.len(),
+            ));
+        }
+
+        let scalars = [&[r], x].concat();
+        let bases = [&[commit_key.h], &commit_key.g[..x.len()]].concat();
+
+        M::multi_scalar_mul(&bases, &scalars)
+    }
 }
 
 impl<C: ProjectiveCurve> ToBytes for CommitKey<C> {
@@ -49,7 +292,11 @@ impl<C: ProjectiveCurve> ToBytes for Commitment<C> {
     }
 }
 
-impl<C: ProjectiveCurve> HomomorphicCommitmentScheme<C::ScalarField> for PedersenCommitment<C> {
+impl<C, M> HomomorphicCommitmentScheme<C::ScalarField> for PedersenCommitment<C, M>
+where
+    C: ProjectiveCurve,
+    M: ScalarMul<C>,
+{
     type CommitKey = CommitKey<C>;
     type Commitment = Commitment<C>;
 
@@ -67,6 +314,53 @@ impl<C: ProjectiveCurve> HomomorphicCommitmentScheme<C::ScalarField> for Pederse
         x: &Vec<C::ScalarField>,
         r: C::ScalarField,
     ) -> Result<Self::Commitment, CryptoError> {
+        Ok(Commitment(
+            Self::commit_projective(commit_key, x, r)?.into_affine(),
+        ))
+    }
+
+    fn batch_commit(
+        commit_key: &CommitKey<C>,
+        values: &[Vec<C::ScalarField>],
+        randomness: &[C::ScalarField],
+    ) -> Result<Vec<Self::Commitment>, CryptoError> {
+        if values.len() != randomness.len() {
+            return Err(CryptoError::DotProductLengthError(
+                values.len(),
+                randomness.len(),
+            ));
+        }
+
+        let commitments = cfg_iter!(values)
+            .zip(cfg_iter!(randomness))
+            .map(|(x, &r)| Self::commit_projective(commit_key, x, r))
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(C::batch_normalization_into_affine(&commitments)
+            .into_iter()
+            .map(Commitment)
+            .collect())
+    }
+
+    fn multi_scalar_mul(
+        scalars: &[C::ScalarField],
+        commitments: &[Self::Commitment],
+    ) -> Result<Self::Commitment, CryptoError> {
+        let bases = commitments.iter().map(|c| c.0).collect::<Vec<_>>();
+
+        Ok(Commitment(
+            M::multi_scalar_mul(&bases, scalars)?.into_affine(),
+        ))
+    }
+
+    /// $r H + \sum_j x_j G_j - \sum_i s_i C_i = 0$, in one multi-scalar multiplication.
+    fn verify_combination(
+        commit_key: &CommitKey<C>,
+        scalars: &[C::ScalarField],
+        commitments: &[Self::Commitment],
+        x: &Vec<C::ScalarField>,
+        r: C::ScalarField,
+    ) -> Result<bool, CryptoError> {
         if x.len() > commit_key.g.len() {
             return Err(CryptoError::CommitmentLengthError(
                 String::from("Pedersen"),
@@ -74,17 +368,24 @@ impl<C: ProjectiveCurve> HomomorphicCommitmentScheme<C::ScalarField> for Pederse
                 commit_key.g.len(),
             ));
         }
+        if scalars.len() != commitments.len() {
+            return Err(CryptoError::DotProductLengthError(
+                scalars.len(),
+                commitments.len(),
+            ));
+        }
 
+        let bases = [&[commit_key.h], &commit_key.g[..x.len()]]
+            .concat()
+            .into_iter()
+            .chain(commitments.iter().map(|c| c.0))
+            .collect::<Vec<_>>();
         let scalars = [&[r], x.as_slice()]
             .concat()
-            .iter()
-            .map(|x| x.into_repr())
+            .into_iter()
+            .chain(scalars.iter().map(|s| -*s))
             .collect::<Vec<_>>();
 
-        let bases = [&[commit_key.h], &commit_key.g[..]].concat();
-
-        Ok(Commitment(
-            VariableBaseMSM::multi_scalar_mul(&bases, &scalars[..]).into_affine(),
-        ))
+        Ok(M::multi_scalar_mul(&bases, &scalars)?.is_zero())
     }
 }
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod test {
+    use crate::error::CryptoError;
     use crate::utils::rand::sample_vector;
     use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
     use ark_ff::Zero;
@@ -82,4 +83,242 @@ mod test {
 
         let _commit = Pedersen::commit(&commit_key, &too_long, r).unwrap();
     }
+
+    #[test]
+    fn commit_key_from_seed() {
+        use blake2::Blake2s;
+
+        let rng = &mut thread_rng();
+        let n = 8;
+
+        let commit_key = Pedersen::setup_from_seed::<Blake2s>(b"poker table 7", n).unwrap();
+        assert_eq!(commit_key.len(), n);
+
+        // A verifier reproduces the same key from the seed
+        assert_eq!(
+            Pedersen::setup_from_seed::<Blake2s>(b"poker table 7", n).unwrap(),
+            commit_key
+        );
+        assert_ne!(
+            Pedersen::setup_from_seed::<Blake2s>(b"poker table 8", n).unwrap(),
+            commit_key
+        );
+
+        // A longer key extends the shorter one with the same generators
+        let longer = Pedersen::setup_from_seed::<Blake2s>(b"poker table 7", n + 1).unwrap();
+        assert_eq!(longer.g[..n], commit_key.g[..]);
+
+        let v: Vec<Scalar> = sample_vector(rng, n);
+        let r = Scalar::rand(rng);
+        assert_eq!(
+            Pedersen::commit(&commit_key, &v, r).unwrap(),
+            Pedersen::commit(&longer, &v, r).unwrap()
+        );
+    }
+
+    #[test]
+    fn commitment_and_opening_algebra() {
+        let rng = &mut thread_rng();
+        let n = 6;
+
+        let commit_key = Pedersen::setup(rng, n);
+
+        let v1: Vec<Scalar> = sample_vector(rng, n);
+        let v2: Vec<Scalar> = sample_vector(rng, n - 2);
+        let (c1, o1) = Pedersen::commit_vector(rng, &commit_key, &v1).unwrap();
+        let (c2, o2) = Pedersen::commit_vector(rng, &commit_key, &v2).unwrap();
+        assert_eq!(Pedersen::open(&commit_key, &c1, &o1), Ok(()));
+
+        let alpha = Scalar::rand(rng);
+        assert_eq!(
+            Pedersen::open(&commit_key, &(c1 + c2), &(o1.clone() + o2.clone())),
+            Ok(())
+        );
+        assert_eq!(
+            Pedersen::open(&commit_key, &(c1 - c2), &(o1.clone() - o2.clone())),
+            Ok(())
+        );
+        assert_eq!(Pedersen::open(&commit_key, &(-c2), &(-o2.clone())), Ok(()));
+        assert_eq!(
+            Pedersen::open(&commit_key, &(c1 * alpha), &(o1.clone() * alpha)),
+            Ok(())
+        );
+        assert_eq!(c1 - c1, pedersen::Commitment::zero());
+
+        assert_eq!(
+            Pedersen::open(&commit_key, &c1, &o2),
+            Err(CryptoError::InvalidOpening)
+        );
+    }
+
+    #[test]
+    fn batch_opening() {
+        let rng = &mut thread_rng();
+        let n = 10;
+        let number_of_commitments = 20;
+
+        let commit_key = Pedersen::setup(rng, n);
+
+        let (commitments, mut openings): (Vec<_>, Vec<_>) = (0..number_of_commitments)
+            .map(|i| {
+                let values: Vec<Scalar> = sample_vector(rng, 1 + i % n);
+                Pedersen::commit_vector(rng, &commit_key, &values).unwrap()
+            })
+            .unzip();
+
+        assert_eq!(
+            Pedersen::batch_open(rng, &commit_key, &commitments, &openings),
+            Ok(())
+        );
+        assert_eq!(
+            Pedersen::batch_open(rng, &commit_key, &commitments[1..], &openings),
+            Err(CryptoError::InvalidBatchStatement)
+        );
+
+        openings[7].values[0] += Scalar::from(1u64);
+        assert_eq!(
+            Pedersen::batch_open(rng, &commit_key, &commitments, &openings),
+            Err(CryptoError::InvalidOpening)
+        );
+    }
+
+    #[test]
+    fn extend_commit_key() {
+        use blake2::Blake2s;
+
+        let rng = &mut thread_rng();
+        let n = 52;
+        let m = 60;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let v: Vec<Scalar> = sample_vector(rng, n);
+        let (commitment, opening) = Pedersen::commit_vector(rng, &commit_key, &v).unwrap();
+
+        let extended = commit_key.extend::<Blake2s>(m).unwrap();
+        assert_eq!(extended.len(), m);
+        assert_eq!(extended.g[..n], commit_key.g[..]);
+        assert_eq!(Pedersen::open(&extended, &commitment, &opening), Ok(()));
+
+        // The extension is deterministic and does not depend on the intermediate steps
+        assert_eq!(
+            commit_key
+                .extend::<Blake2s>(56)
+                .unwrap()
+                .extend::<Blake2s>(m)
+                .unwrap(),
+            extended
+        );
+        assert_eq!(commit_key.extend::<Blake2s>(n - 1).unwrap(), commit_key);
+
+        let longer: Vec<Scalar> = sample_vector(rng, m);
+        assert!(Pedersen::commit_vector(rng, &extended, &longer).is_ok());
+    }
+
+    #[test]
+    fn precomputed_commit_key() {
+        use crate::utils::fixed_base::Precompute;
+
+        let rng = &mut thread_rng();
+        let n = 52;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let precomputed = commit_key.precompute();
+        assert_eq!(precomputed.commit_key(), commit_key);
+
+        let r = Scalar::rand(rng);
+        for len in [0, 1, n] {
+            let v: Vec<Scalar> = sample_vector(rng, len);
+            assert_eq!(
+                precomputed.commit(&v, r),
+                Pedersen::commit(&commit_key, &v, r)
+            );
+        }
+
+        let too_long: Vec<Scalar> = sample_vector(rng, n + 1);
+        assert_eq!(
+            precomputed.commit(&too_long, r),
+            Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                n + 1,
+                n
+            ))
+        );
+    }
+
+    #[test]
+    fn verify_combination() {
+        let rng = &mut thread_rng();
+        let n = 8;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let openings = (0..3)
+            .map(|_| (sample_vector::<Scalar, _>(rng, n), Scalar::rand(rng)))
+            .collect::<Vec<_>>();
+        let commitments = openings
+            .iter()
+            .map(|(v, r)| Pedersen::commit(&commit_key, v, *r).unwrap())
+            .collect::<Vec<_>>();
+
+        // sum_i s_i C_i opens to sum_i s_i (v_i; r_i)
+        let scalars: Vec<Scalar> = sample_vector(rng, 3);
+        let mut v = vec![Scalar::zero(); n];
+        let mut r = Scalar::zero();
+        for (s, (v_i, r_i)) in scalars.iter().zip(openings.iter()) {
+            v.iter_mut()
+                .zip(v_i.iter())
+                .for_each(|(v, v_i)| *v += *s * v_i);
+            r += *s * r_i;
+        }
+        assert_eq!(
+            Pedersen::verify_combination(&commit_key, &scalars, &commitments, &v, r),
+            Ok(true)
+        );
+        assert_eq!(
+            Pedersen::verify_combination(&commit_key, &scalars, &commitments, &v, r + r),
+            Ok(false)
+        );
+        assert_eq!(
+            Pedersen::verify_combination(&commit_key, &scalars[1..], &commitments, &v, r),
+            Err(CryptoError::DotProductLengthError(2, 3))
+        );
+    }
+
+    #[test]
+    fn batch_commit() {
+        let rng = &mut thread_rng();
+        let n = 6;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let values = (0..5)
+            .map(|len| sample_vector::<Scalar, _>(rng, len))
+            .collect::<Vec<_>>();
+        let randomness: Vec<Scalar> = sample_vector(rng, 5);
+
+        let expected = values
+            .iter()
+            .zip(randomness.iter())
+            .map(|(x, r)| Pedersen::commit(&commit_key, x, *r).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Pedersen::batch_commit(&commit_key, &values, &randomness),
+            Ok(expected)
+        );
+
+        assert_eq!(
+            Pedersen::batch_commit(&commit_key, &values, &randomness[1..]),
+            Err(CryptoError::DotProductLengthError(5, 4))
+        );
+        assert_eq!(
+            Pedersen::batch_commit(
+                &commit_key,
+                &[vec![Scalar::zero(); n + 1]],
+                &randomness[..1]
+            ),
+            Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                n + 1,
+                n
+            ))
+        );
+    }
 }
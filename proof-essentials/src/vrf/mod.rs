@@ -0,0 +1,147 @@
+use crate::error::CryptoError;
+use crate::zkp::proofs::chaum_pedersen_dl_equality::{self, DLEquality};
+use crate::zkp::ArgumentOfKnowledge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+mod tests;
+
+/// Verifiable random function in the style of ECVRF (RFC 9381). The holder of a secret key `x` maps an input
+/// `alpha` to the point `Γ = x·H(alpha)` and hashes it into a pseudorandom output. A Chaum-Pedersen proof that `Γ`
+/// and the public key share the discrete logarithm `x` lets anyone check the output against the public key.
+///
+/// Inputs are hashed to the curve by seeding a Fiat-Shamir RNG with the public key and the input and sampling a
+/// point from it, so the discrete logarithm of `H(alpha)` is unknown to everyone.
+pub struct Ecvrf<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+}
+
+pub type PublicKey<C> = <C as ProjectiveCurve>::Affine;
+
+pub type SecretKey<C> = <C as ProjectiveCurve>::ScalarField;
+
+/// Pseudorandom output of the VRF, as many bytes as the output of the hash function.
+pub type Output = Vec<u8>;
+
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: ProjectiveCurve> {
+    pub(crate) gamma: C::Affine,
+    pub(crate) dleq_proof: chaum_pedersen_dl_equality::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> Ecvrf<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"ECVRF";
+
+    pub fn setup<R: Rng>(rng: &mut R) -> Result<Parameters<C>, CryptoError> {
+        Ok(Parameters {
+            generator: C::rand(rng).into_affine(),
+        })
+    }
+
+    pub fn keygen<R: Rng>(
+        pp: &Parameters<C>,
+        rng: &mut R,
+    ) -> Result<(PublicKey<C>, SecretKey<C>), CryptoError> {
+        let secret_key = C::ScalarField::rand(rng);
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        Ok((public_key, secret_key))
+    }
+
+    /// Evaluate the VRF on `alpha` and prove that the output is correct.
+    pub fn prove<R: Rng, D: Digest>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        secret_key: &SecretKey<C>,
+        alpha: &[u8],
+    ) -> Result<(Output, Proof<C>), CryptoError> {
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        let h = Self::hash_to_curve::<D>(pp, &public_key, alpha)?;
+        let gamma = h.mul(secret_key.into_repr()).into_affine();
+
+        let mut fs_rng = Self::transcript::<D>(pp, &public_key, alpha)?;
+        let dleq_proof = DLEquality::prove(
+            rng,
+            &chaum_pedersen_dl_equality::Parameters::new(&pp.generator, &h),
+            &chaum_pedersen_dl_equality::Statement::new(&public_key, &gamma),
+            secret_key,
+            Self::PROTOCOL_NAME,
+            &mut fs_rng,
+        )?;
+
+        let proof = Proof { gamma, dleq_proof };
+
+        Ok((Self::proof_to_hash::<D>(&proof)?, proof))
+    }
+
+    /// Check `proof` against `public_key` and `alpha` and return the VRF output it certifies.
+    pub fn verify<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        alpha: &[u8],
+        proof: &Proof<C>,
+    ) -> Result<Output, CryptoError> {
+        let h = Self::hash_to_curve::<D>(pp, public_key, alpha)?;
+
+        let mut fs_rng = Self::transcript::<D>(pp, public_key, alpha)?;
+        DLEquality::verify(
+            &chaum_pedersen_dl_equality::Parameters::new(&pp.generator, &h),
+            &chaum_pedersen_dl_equality::Statement::new(public_key, &proof.gamma),
+            &proof.dleq_proof,
+            Self::PROTOCOL_NAME,
+            &mut fs_rng,
+        )
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("ECVRF")))?;
+
+        Self::proof_to_hash::<D>(proof)
+    }
+
+    /// VRF output certified by `proof`. Only meaningful once the proof has been verified.
+    pub fn proof_to_hash<D: Digest>(proof: &Proof<C>) -> Result<Output, CryptoError> {
+        Ok(D::digest(&to_bytes![Self::PROTOCOL_NAME, b"output", proof.gamma]?).to_vec())
+    }
+
+    fn hash_to_curve<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        alpha: &[u8],
+    ) -> Result<C::Affine, CryptoError> {
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            b"hash to curve",
+            pp.generator,
+            public_key,
+            alpha
+        ]?);
+
+        Ok(C::rand(&mut fs_rng).into_affine())
+    }
+
+    fn transcript<D: Digest>(
+        pp: &Parameters<C>,
+        public_key: &PublicKey<C>,
+        alpha: &[u8],
+    ) -> Result<FiatShamirRng<D>, CryptoError> {
+        Ok(FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            b"proof",
+            pp.generator,
+            public_key,
+            alpha
+        ]?))
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::vrf;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Ecvrf = vrf::Ecvrf<Curve>;
+
+    #[test]
+    fn test_prove_and_verify() {
+        let rng = &mut thread_rng();
+        let pp = Ecvrf::setup(rng).unwrap();
+        let (pk, sk) = Ecvrf::keygen(&pp, rng).unwrap();
+
+        let (output, proof) = Ecvrf::prove::<_, Blake2s>(rng, &pp, &sk, b"round 1").unwrap();
+        assert_eq!(
+            Ecvrf::verify::<Blake2s>(&pp, &pk, b"round 1", &proof),
+            Ok(output.clone())
+        );
+
+        // The output is a function of the key and the input only
+        let (same_output, _) = Ecvrf::prove::<_, Blake2s>(rng, &pp, &sk, b"round 1").unwrap();
+        assert_eq!(same_output, output);
+
+        let (other_output, _) = Ecvrf::prove::<_, Blake2s>(rng, &pp, &sk, b"round 2").unwrap();
+        assert_ne!(other_output, output);
+
+        let mut serialized = Vec::new();
+        proof.serialize(&mut serialized).unwrap();
+        let deserialized = vrf::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(Ecvrf::proof_to_hash::<Blake2s>(&deserialized), Ok(output));
+    }
+
+    #[test]
+    fn test_invalid_proof() {
+        let rng = &mut thread_rng();
+        let pp = Ecvrf::setup(rng).unwrap();
+        let (pk, sk) = Ecvrf::keygen(&pp, rng).unwrap();
+
+        let (_, proof) = Ecvrf::prove::<_, Blake2s>(rng, &pp, &sk, b"round 1").unwrap();
+
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from("ECVRF")));
+        assert_eq!(
+            Ecvrf::verify::<Blake2s>(&pp, &pk, b"round 2", &proof),
+            expected_error
+        );
+
+        let (other_pk, _) = Ecvrf::keygen(&pp, rng).unwrap();
+        assert_eq!(
+            Ecvrf::verify::<Blake2s>(&pp, &other_pk, b"round 1", &proof),
+            expected_error
+        );
+
+        // A proof for another input cannot be passed off with a substituted output point
+        let (_, other_proof) = Ecvrf::prove::<_, Blake2s>(rng, &pp, &sk, b"round 2").unwrap();
+        let forged = vrf::Proof {
+            gamma: other_proof.gamma,
+            dleq_proof: proof.dleq_proof,
+        };
+        assert_eq!(
+            Ecvrf::verify::<Blake2s>(&pp, &pk, b"round 1", &forged),
+            expected_error
+        );
+    }
+}
@@ -0,0 +1,92 @@
+pub mod proof;
+pub mod prover;
+mod tests;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Compression layer for sigma protocols (Attema and Cramer, Crypto 2020). Many sigma protocols over vectors end
+/// with the verifier checking a linear relation $\langle z, G \rangle = Q$ on a response $z \in \mathbb{F}^n$, where
+/// $Q$ is computed from the statement, the first message and the challenge. Instead of sending $z$, the prover
+/// proves knowledge of it by recursively folding $z$ and $G$ in half, which takes $2 \lceil \log_2(n) \rceil$
+/// group elements and a single scalar.
+///
+/// The argument only hides what the sigma protocol response already hides, so it must be run on the response of
+/// a zero-knowledge sigma protocol rather than on a secret directly.
+pub struct CompressedSigma<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for CompressedSigma<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<'a, C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        _rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        let proof = prover.prove(fs_rng)?;
+
+        Ok(proof)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+/// Parameters: the generators $G$ of the linear relation. Their number does not need to be a power of two.
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub generators: &'a Vec<C::Affine>,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(generators: &'a Vec<C::Affine>) -> Self {
+        Self { generators }
+    }
+}
+
+/// Statement: the point $Q$.
+pub struct Statement<'a, C: ProjectiveCurve> {
+    pub commitment: &'a C,
+}
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(commitment: &'a C) -> Self {
+        Self { commitment }
+    }
+}
+
+/// Witness: the response $z$ of the sigma protocol.
+pub struct Witness<'a, C: ProjectiveCurve> {
+    pub response: &'a Vec<C::ScalarField>,
+}
+
+impl<'a, C: ProjectiveCurve> Witness<'a, C> {
+    pub fn new(response: &'a Vec<C::ScalarField>) -> Self {
+        Self { response }
+    }
+}
@@ -0,0 +1,96 @@
+use super::{Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::batch_inverse;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+use ark_ff::{to_bytes, Field, One, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C: ProjectiveCurve> {
+    pub(crate) a_vec: Vec<C::Affine>,
+    pub(crate) b_vec: Vec<C::Affine>,
+    pub(crate) response: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    /// The folded generator is expanded as $G^* = \langle s, G \rangle$ and the folded statement as
+    /// $Q^* = (\prod_j c_j) Q + \sum_j t_j (B_j + c_j^2 A_j)$ with $t_j = \prod_{l > j} c_l$, so that the check
+    /// $z G^* = Q^*$ is a single multi-scalar multiplication.
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            CryptoError::ProofVerificationError(String::from("Compressed Sigma"));
+
+        let n = parameters.generators.len();
+        let rounds = self.a_vec.len();
+        if n == 0 || self.b_vec.len() != rounds || n.next_power_of_two() != 1 << rounds {
+            return Err(verification_error);
+        }
+
+        fs_rng.absorb(&to_bytes![b"compressed_sigma"]?);
+
+        //public information
+        fs_rng.absorb(&to_bytes![
+            parameters.generators,
+            statement.commitment.into_affine()
+        ]?);
+
+        let mut challenges = Vec::with_capacity(rounds);
+        for (a, b) in self.a_vec.iter().zip(self.b_vec.iter()) {
+            fs_rng.absorb(&to_bytes![a, b]?);
+            challenges.push(derive_challenge::<C::ScalarField>(fs_rng));
+        }
+        let challenges_inv = batch_inverse(&challenges)?;
+
+        // s_i is the product of the challenges of the rounds in which G_i was in the lower half. Bit k of i is
+        // decided in round rounds - 1 - k, so s_i follows from s_{i - 2^k} for the highest bit k of i.
+        let mut s = Vec::with_capacity(n);
+        s.push(challenges.iter().product::<C::ScalarField>());
+        for i in 1..n {
+            let k = (usize::BITS - 1 - i.leading_zeros()) as usize;
+            let s_i = s[i - (1 << k)] * challenges_inv[rounds - 1 - k];
+            s.push(s_i);
+        }
+
+        let mut suffix_products = vec![C::ScalarField::one(); rounds];
+        for j in (0..rounds.saturating_sub(1)).rev() {
+            suffix_products[j] = suffix_products[j + 1] * challenges[j + 1];
+        }
+
+        let bases = parameters
+            .generators
+            .iter()
+            .chain(self.a_vec.iter())
+            .chain(self.b_vec.iter())
+            .cloned()
+            .chain([statement.commitment.into_affine()])
+            .collect::<Vec<_>>();
+        let scalars = s
+            .iter()
+            .map(|s_i| self.response * s_i)
+            .chain(
+                suffix_products
+                    .iter()
+                    .zip(challenges.iter())
+                    .map(|(t, c)| -(*t * c.square())),
+            )
+            .chain(suffix_products.iter().map(|t| -*t))
+            .chain([-challenges.iter().product::<C::ScalarField>()])
+            .map(|s| s.into_repr())
+            .collect::<Vec<_>>();
+
+        if !VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+            return Err(verification_error);
+        }
+
+        Ok(())
+    }
+}
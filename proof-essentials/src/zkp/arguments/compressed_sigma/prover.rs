@@ -0,0 +1,89 @@
+use super::proof::Proof;
+use super::{Parameters, Statement, Witness};
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, Zero};
+
+pub struct Prover<'a, C: ProjectiveCurve> {
+    parameters: &'a Parameters<'a, C>,
+    statement: &'a Statement<'a, C>,
+    witness: &'a Witness<'a, C>,
+}
+
+impl<'a, C: ProjectiveCurve> Prover<'a, C> {
+    pub fn new(
+        parameters: &'a Parameters<'a, C>,
+        statement: &'a Statement<'a, C>,
+        witness: &'a Witness<'a, C>,
+    ) -> Self {
+        Self {
+            parameters,
+            statement,
+            witness,
+        }
+    }
+
+    pub fn prove<T: Transcript>(&self, fs_rng: &mut T) -> Result<Proof<C>, CryptoError> {
+        let n = self.parameters.generators.len();
+        if n == 0 || self.witness.response.len() != n {
+            return Err(CryptoError::InvalidCompressionStatement);
+        }
+
+        fs_rng.absorb(&to_bytes![b"compressed_sigma"]?);
+
+        //public information
+        fs_rng.absorb(&to_bytes![
+            self.parameters.generators,
+            self.statement.commitment.into_affine()
+        ]?);
+
+        // Pad to a power of two with zero coefficients on the identity
+        let padded_length = n.next_power_of_two();
+        let mut g = self.parameters.generators.clone();
+        g.resize(padded_length, C::Affine::zero());
+        let mut z = self.witness.response.clone();
+        z.resize(padded_length, C::ScalarField::zero());
+
+        let mut a_vec = Vec::new();
+        let mut b_vec = Vec::new();
+
+        let mut n = padded_length;
+        while n > 1 {
+            n /= 2;
+            let (z_lo, z_hi) = z.split_at(n);
+            let (g_lo, g_hi) = g.split_at(n);
+
+            let a = multi_exponentiation::<C>(g_lo, z_hi).into_affine();
+            let b = multi_exponentiation::<C>(g_hi, z_lo).into_affine();
+
+            fs_rng.absorb(&to_bytes![a, b]?);
+            let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+            // z' = z_lo + c z_hi and G' = c G_lo + G_hi, so that <z', G'> = B + c Q + c^2 A
+            let new_z = (0..n).map(|i| z_lo[i] + c * z_hi[i]).collect();
+            let new_g = (0..n)
+                .map(|i| g_lo[i].mul(c.into_repr()) + g_hi[i].into_projective())
+                .collect::<Vec<_>>();
+
+            z = new_z;
+            g = C::batch_normalization_into_affine(&new_g);
+
+            a_vec.push(a);
+            b_vec.push(b);
+        }
+
+        Ok(Proof {
+            a_vec,
+            b_vec,
+            response: z[0],
+        })
+    }
+}
+
+fn multi_exponentiation<C: ProjectiveCurve>(bases: &[C::Affine], scalars: &[C::ScalarField]) -> C {
+    let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::zkp::{arguments::compressed_sigma, ArgumentOfKnowledge};
+
+    use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+
+    type Compressed<'a> = compressed_sigma::CompressedSigma<'a, Curve>;
+    type Parameters<'a> = compressed_sigma::Parameters<'a, Curve>;
+    type Statement<'a> = compressed_sigma::Statement<'a, Curve>;
+    type Witness<'a> = compressed_sigma::Witness<'a, Curve>;
+
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+
+        for n in [1, 2, 5, 16, 33] {
+            let generators = (0..n)
+                .map(|_| Curve::rand(rng).into_affine())
+                .collect::<Vec<_>>();
+            let response: Vec<Scalar> = sample_vector(rng, n);
+            let scalars = response.iter().map(|z| z.into_repr()).collect::<Vec<_>>();
+            let commitment: Curve = VariableBaseMSM::multi_scalar_mul(&generators, &scalars);
+
+            let parameters = Parameters::new(&generators);
+            let statement = Statement::new(&commitment);
+            let witness = Witness::new(&response);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = Compressed::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+            assert_eq!(
+                proof.a_vec.len(),
+                n.next_power_of_two().trailing_zeros() as usize
+            );
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                Compressed::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let n = 11;
+
+        let generators = (0..n)
+            .map(|_| Curve::rand(rng).into_affine())
+            .collect::<Vec<_>>();
+        let response: Vec<Scalar> = sample_vector(rng, n);
+        let scalars = response.iter().map(|z| z.into_repr()).collect::<Vec<_>>();
+        let commitment: Curve = VariableBaseMSM::multi_scalar_mul(&generators, &scalars);
+
+        let parameters = Parameters::new(&generators);
+        let statement = Statement::new(&commitment);
+
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Compressed Sigma",
+        )));
+
+        let mut bad_response = response.clone();
+        bad_response[7] = Scalar::rand(rng);
+        let bad_witness = Witness::new(&bad_response);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = Compressed::prove(
+            rng,
+            &parameters,
+            &statement,
+            &bad_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Compressed::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let witness = Witness::new(&response);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let mut tampered = Compressed::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        tampered.a_vec.swap(1, 2);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Compressed::verify(
+                &parameters,
+                &statement,
+                &tampered,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let short_response = response[..n - 1].to_vec();
+        let short_witness = Witness::new(&short_response);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Compressed::prove(
+                rng,
+                &parameters,
+                &statement,
+                &short_witness,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::InvalidCompressionStatement)
+        );
+    }
+}
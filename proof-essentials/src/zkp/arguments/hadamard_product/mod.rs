@@ -4,11 +4,10 @@ mod tests;
 
 use crate::error::CryptoError;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
 use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::{marker::PhantomData, rand::Rng};
-use digest::Digest;
 
 pub struct HadamardProductArgument<'a, F, Comm>
 where
@@ -29,26 +28,34 @@ where
     type Witness = Witness<'a, Scalar>;
     type Proof = proof::Proof<Scalar, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove_in_context<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
         let prover = prover::Prover::new(common_reference_string, statement, witness);
         let proof = prover.prove(rng, fs_rng)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify_in_context<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
     }
 }
 
@@ -2,14 +2,13 @@ use super::{Parameters, Statement};
 
 use crate::error::CryptoError;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::scalar_powers;
 use crate::zkp::arguments::{zero_value_bilinear_map, zero_value_bilinear_map::YMapping};
-use crate::zkp::{arguments::scalar_powers, ArgumentOfKnowledge};
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
-use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::{to_bytes, Field};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Comm>
@@ -29,11 +28,11 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Comm>,
         statement: &Statement<Scalar, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
         fs_rng.absorb(&to_bytes![b"hadamard_product_argument"]?);
 
@@ -62,8 +61,8 @@ where
         fs_rng.absorb(&to_bytes![self.b_commits]?);
 
         // Extract challenges
-        let x = Scalar::rand(fs_rng);
-        let y = Scalar::rand(fs_rng);
+        let x = derive_challenge::<Scalar>(fs_rng);
+        let y = derive_challenge::<Scalar>(fs_rng);
 
         // Precompute all powers of the x challenge from 0 to m-1
         let x_challenge_powers = scalar_powers(x, proof_parameters.m - 1);
@@ -78,17 +77,14 @@ where
             .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
             .collect::<Vec<Comm::Commitment>>();
 
-        let temp_x_c_d_shifted = self
+        let (x_powers_shifted, b_commits_shifted): (Vec<Scalar>, Vec<Comm::Commitment>) = self
             .b_commits
             .iter()
             .skip(1)
             .zip(x_challenge_powers.iter().skip(1))
-            .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
-            .collect::<Vec<Comm::Commitment>>();
-
-        let final_cd: Comm::Commitment = temp_x_c_d_shifted
-            .iter()
-            .fold(Comm::Commitment::zero(), |acc, &x| acc + x);
+            .map(|(&b_i_commit, &x_power_i)| (x_power_i, b_i_commit))
+            .unzip();
+        let final_cd = Comm::multi_scalar_mul(&x_powers_shifted, &b_commits_shifted)?;
         c_d_i.push(final_cd);
 
         // Engage in zero argument
@@ -110,12 +106,10 @@ where
         let zero_arg_statement =
             zero_value_bilinear_map::Statement::new(&vec_commits_to_a, &c_d_i, &prover_mapping);
 
-        match zero_value_bilinear_map::ZeroValueArgument::verify(
-            &zero_arg_parameters,
-            &zero_arg_statement,
-            &self.zero_arg_proof,
-            fs_rng,
-        ) {
+        match self
+            .zero_arg_proof
+            .verify(&zero_arg_parameters, &zero_arg_statement, fs_rng)
+        {
             Ok(_) => Ok(()),
             Err(_) => Err(CryptoError::ProofVerificationError(String::from(
                 "Hadamard Product (5.1)",
@@ -2,15 +2,16 @@ use super::{proof::Proof, Parameters, Statement, Witness};
 
 use crate::error::CryptoError;
 use crate::utils::rand::sample_vector;
-use crate::utils::vector_arithmetic::{dot_product, hadamard_product};
+use crate::utils::vector_arithmetic::dot_product;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::{zero_value_bilinear_map, zero_value_bilinear_map::YMapping};
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
-use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
-use digest::Digest;
+use ark_ff::{to_bytes, Field};
+use ark_std::{cfg_into_iter, cfg_iter};
 use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::iter;
 
 pub struct Prover<'a, Scalar, Comm>
@@ -40,10 +41,21 @@ where
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
+    pub fn prove<R: Rng, T: Transcript>(
         &self,
         rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        let precomputation = Precomputation::new(rng, self.parameters)?;
+        self.prove_precomputed(precomputation, fs_rng)
+    }
+
+    /// Online phase of [`Prover::prove`], with the randomness of `precomputation` and the one of the zero
+    /// argument, which must have been made for the same parameters.
+    pub(crate) fn prove_precomputed<T: Transcript>(
+        &self,
+        precomputation: Precomputation<Scalar, Comm>,
+        fs_rng: &mut T,
     ) -> Result<Proof<Scalar, Comm>, CryptoError> {
         fs_rng.absorb(&to_bytes![b"hadamard_product_argument"]?);
 
@@ -63,20 +75,16 @@ where
             .chain(iter::once(self.witness.vector_b.to_vec()))
             .collect::<Vec<_>>();
 
-        let mut s: Vec<Scalar> = sample_vector(rng, self.parameters.m - 2);
+        let Precomputation {
+            mut s,
+            zero_argument,
+        } = precomputation;
 
-        let b_commit_middle = b
-            .iter()
-            .take(b.len() - 1)
-            .skip(1)
-            .zip(s.iter())
-            .map(|(b_i, &s_i)| -> Result<Comm::Commitment, CryptoError> {
-                Comm::commit(self.parameters.commit_key, b_i, s_i)
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        let commit_key = self.parameters.commit_key;
+        let b_commit_middle = Comm::batch_commit(commit_key, &b[1..b.len() - 1], &s)?;
 
         let b_commits = iter::once(self.statement.commitment_to_a[0])
-            .chain(b_commit_middle.into_iter())
+            .chain(b_commit_middle)
             .chain(iter::once(self.statement.commitment_to_b))
             .collect::<Vec<Comm::Commitment>>();
 
@@ -94,8 +102,8 @@ where
         fs_rng.absorb(&to_bytes![b_commits]?);
 
         // Challenges
-        let x = Scalar::rand(fs_rng);
-        let y = Scalar::rand(fs_rng);
+        let x = derive_challenge::<Scalar>(fs_rng);
+        let y = derive_challenge::<Scalar>(fs_rng);
 
         // Precompute all powers of the x challenge
         let x_challenge_powers = iter::once(Scalar::one())
@@ -120,22 +128,13 @@ where
                 .concat()
                 .to_vec();
 
-        let mut c_d_i = b_commits
-            .iter()
-            .zip(x_challenge_powers.iter().skip(1))
+        // C_{D_i} = x^{i+1} C_{B_i}, and the last C_D is sum_{i >= 1} x^i C_{B_i}
+        let mut c_d_i = cfg_iter!(b_commits)
+            .zip(cfg_iter!(x_challenge_powers[1..]))
             .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
             .collect::<Vec<_>>();
 
-        let temp_x_c_d_shifted = b_commits
-            .iter()
-            .skip(1)
-            .zip(x_challenge_powers.iter().skip(1))
-            .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
-            .collect::<Vec<_>>();
-
-        let final_cd = temp_x_c_d_shifted
-            .iter()
-            .fold(Comm::Commitment::zero(), |acc, &x| acc + x);
+        let final_cd = Comm::multi_scalar_mul(&x_challenge_powers[1..], &b_commits[1..])?;
         c_d_i.push(final_cd);
 
         // prepare witness
@@ -159,37 +158,22 @@ where
             .chain(iter::once(final_t))
             .collect::<Vec<Scalar>>();
 
-        let temp_x_b = b[1..=self.parameters.m - 1]
-            .to_vec()
-            .iter()
-            .zip(x_challenge_powers.iter().skip(1))
-            .map(|(b_chunk, &x_power_i)| {
-                let x_power_i_vector = vec![x_power_i; self.parameters.n];
-                hadamard_product(b_chunk, &x_power_i_vector)
-            })
-            .collect::<Result<Vec<Vec<Scalar>>, CryptoError>>()?;
-
-        let final_d = temp_x_b
-            .into_iter()
-            .reduce(|x, y| {
-                x.iter()
-                    .zip(y.iter())
-                    .map(|(&s_a, &s_b)| s_a + s_b)
-                    .collect()
+        // sum_{i >= 1} x^i b_i, one entry at a time
+        let final_d = cfg_into_iter!(0..self.parameters.n)
+            .map(|k| {
+                b[1..]
+                    .iter()
+                    .zip(x_challenge_powers[1..].iter())
+                    .map(|(b_i, &x_power_i)| b_i[k] * x_power_i)
+                    .sum()
             })
-            .unwrap();
+            .collect::<Vec<Scalar>>();
 
-        let vec_openings_to_d = b
-            .iter()
-            .zip(x_challenge_powers.iter().skip(1))
-            .map(|(b_chunk, &x_power_i)| {
-                let x_power_i_vector = vec![x_power_i; self.parameters.n];
-                hadamard_product(b_chunk, &x_power_i_vector)
-            })
-            .collect::<Result<Vec<_>, CryptoError>>()?
-            .into_iter()
-            .chain(iter::once(final_d))
-            .collect::<Vec<_>>();
+        let mut vec_openings_to_d = cfg_iter!(b)
+            .zip(cfg_iter!(x_challenge_powers[1..]))
+            .map(|(b_chunk, &x_power_i)| b_chunk.iter().map(|&b| b * x_power_i).collect())
+            .collect::<Vec<Vec<Scalar>>>();
+        vec_openings_to_d.push(final_d);
 
         let vec_commits_to_a_shifted = vec_commits_to_a[1..].to_vec();
         let zero_arg_statement = zero_value_bilinear_map::Statement::new(
@@ -211,13 +195,12 @@ where
             &vec_randoms_for_d,
         );
 
-        let zero_arg_proof = zero_value_bilinear_map::ZeroValueArgument::prove(
-            rng,
+        let zero_arg_prover = zero_value_bilinear_map::prover::Prover::new(
             &zero_arg_params,
             &zero_arg_statement,
             &zero_arg_witness,
-            fs_rng,
-        )?;
+        );
+        let zero_arg_proof = zero_arg_prover.prove_precomputed(zero_argument, fs_rng)?;
 
         let proof = Proof {
             // Round 1
@@ -230,3 +213,37 @@ where
         Ok(proof)
     }
 }
+
+/// Randomness of the commitments to the intermediate products of the Hadamard product argument, and the
+/// precomputation of its zero argument. A precomputation blinds a single proof, so it is consumed by it.
+pub(crate) struct Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    s: Vec<Scalar>,
+    zero_argument: zero_value_bilinear_map::prover::Precomputation<Scalar, Comm>,
+}
+
+impl<Scalar, Comm> Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) fn new<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<Scalar, Comm>,
+    ) -> Result<Self, CryptoError> {
+        let s: Vec<Scalar> = sample_vector(rng, parameters.m - 2);
+
+        let zero_arg_params = zero_value_bilinear_map::Parameters::new(
+            parameters.m,
+            parameters.n,
+            parameters.commit_key,
+        );
+        let zero_argument =
+            zero_value_bilinear_map::prover::Precomputation::new(rng, &zero_arg_params)?;
+
+        Ok(Self { s, zero_argument })
+    }
+}
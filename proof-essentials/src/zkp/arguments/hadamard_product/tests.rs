@@ -1,5 +1,4 @@
 #[cfg(test)]
-
 mod test {
 
     use crate::error::CryptoError;
@@ -50,7 +49,7 @@ mod test {
             .collect::<Vec<_>>();
 
         let b = a_chunks.iter().fold(vec![Scalar::one(); n], |x, y| {
-            hadamard_product(&x, &y).unwrap()
+            hadamard_product(&x, y).unwrap()
         });
 
         let product = b.iter().fold(Scalar::one(), |x, y| x * y);
@@ -73,6 +72,7 @@ mod test {
             &proof_parameters,
             &statement,
             &valid_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -84,7 +84,8 @@ mod test {
                 &proof_parameters,
                 &statement,
                 &valid_proof,
-                &mut fs_rng
+                b"Test Domain",
+                &mut fs_rng,
             )
         );
 
@@ -97,6 +98,7 @@ mod test {
             &proof_parameters,
             &statement,
             &invalid_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -110,7 +112,8 @@ mod test {
                 &proof_parameters,
                 &statement,
                 &invalid_proof,
-                &mut fs_rng
+                b"Test Domain",
+                &mut fs_rng,
             )
         );
     }
@@ -0,0 +1,94 @@
+pub mod proof;
+pub mod prover;
+mod tests;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Bulletproofs inner product argument (Bünz et al., S&P 2018): for generators $G, H \in \mathbb{G}^n$,
+/// $U \in \mathbb{G}$ and a point $P$, proves knowledge of $a, b \in \mathbb{F}^n$ with
+/// $P = \langle a, G \rangle + \langle b, H \rangle + \langle a, b \rangle U$ using $2 \log_2(n)$ group elements
+/// and two scalars. $n$ must be a power of two.
+///
+/// The argument is not zero-knowledge on its own: it leaks $a$ and $b$ in the last round and is meant to be run
+/// on vectors that are already blinded, as in the range proof.
+pub struct InnerProductArgument<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for InnerProductArgument<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<'a, C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        _rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        let proof = prover.prove(fs_rng)?;
+
+        Ok(proof)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+/// Parameters
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub g: &'a Vec<C::Affine>,
+    pub h: &'a Vec<C::Affine>,
+    pub u: &'a C::Affine,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(g: &'a Vec<C::Affine>, h: &'a Vec<C::Affine>, u: &'a C::Affine) -> Self {
+        Self { g, h, u }
+    }
+}
+
+/// Statement: the point $P$.
+pub struct Statement<'a, C: ProjectiveCurve> {
+    pub commitment: &'a C,
+}
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(commitment: &'a C) -> Self {
+        Self { commitment }
+    }
+}
+
+/// Witness
+pub struct Witness<'a, C: ProjectiveCurve> {
+    pub a: &'a Vec<C::ScalarField>,
+    pub b: &'a Vec<C::ScalarField>,
+}
+
+impl<'a, C: ProjectiveCurve> Witness<'a, C> {
+    pub fn new(a: &'a Vec<C::ScalarField>, b: &'a Vec<C::ScalarField>) -> Self {
+        Self { a, b }
+    }
+}
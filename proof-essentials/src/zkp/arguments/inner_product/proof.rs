@@ -0,0 +1,94 @@
+use super::{Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::batch_inverse;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+use ark_ff::{to_bytes, Field, One, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C: ProjectiveCurve> {
+    pub(crate) l_vec: Vec<C::Affine>,
+    pub(crate) r_vec: Vec<C::Affine>,
+    pub(crate) a: C::ScalarField,
+    pub(crate) b: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    /// Instead of folding the generators round by round, the verifier expands the final generators as
+    /// $G' = \langle s, G \rangle$ and $H' = \langle s^{-1}, H \rangle$ and checks
+    /// $\langle a s, G \rangle + \langle b s^{-1}, H \rangle + ab U - \sum_j (x_j^2 L_j + x_j^{-2} R_j) - P = 0$
+    /// with a single multi-scalar multiplication.
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error = CryptoError::ProofVerificationError(String::from("Inner Product"));
+
+        let n = parameters.g.len();
+        let rounds = self.l_vec.len();
+        if parameters.h.len() != n || self.r_vec.len() != rounds || n != 1 << rounds {
+            return Err(verification_error);
+        }
+
+        fs_rng.absorb(&to_bytes![b"inner_product_argument"]?);
+
+        //public information
+        fs_rng.absorb(&to_bytes![
+            parameters.g,
+            parameters.h,
+            parameters.u,
+            statement.commitment.into_affine()
+        ]?);
+
+        let mut challenges = Vec::with_capacity(rounds);
+        for (l, r) in self.l_vec.iter().zip(self.r_vec.iter()) {
+            fs_rng.absorb(&to_bytes![l, r]?);
+            challenges.push(derive_challenge::<C::ScalarField>(fs_rng));
+        }
+        let challenges_inv = batch_inverse(&challenges)?;
+        let challenges_squared = challenges.iter().map(|x| x.square()).collect::<Vec<_>>();
+
+        // s_i is the product over all rounds of x_j or x_j^{-1}, depending on whether G_i ended up in the
+        // upper or lower half of the generators in round j. Bit k of i is decided in round rounds - 1 - k, so
+        // s_i follows from s_{i - 2^k} for the highest bit k of i.
+        let mut s = Vec::with_capacity(n);
+        s.push(challenges_inv.iter().product::<C::ScalarField>());
+        for i in 1..n {
+            let k = (usize::BITS - 1 - i.leading_zeros()) as usize;
+            let s_i = s[i - (1 << k)] * challenges_squared[rounds - 1 - k];
+            s.push(s_i);
+        }
+        let s_inv = batch_inverse(&s)?;
+
+        let bases = parameters
+            .g
+            .iter()
+            .chain(parameters.h.iter())
+            .chain(self.l_vec.iter())
+            .chain(self.r_vec.iter())
+            .cloned()
+            .chain([*parameters.u, statement.commitment.into_affine()])
+            .collect::<Vec<_>>();
+        let scalars = s
+            .iter()
+            .map(|s_i| self.a * s_i)
+            .chain(s_inv.iter().map(|s_i| self.b * s_i))
+            .chain(challenges_squared.iter().map(|x| -*x))
+            .chain(challenges_inv.iter().map(|x_inv| -x_inv.square()))
+            .chain([self.a * self.b, -C::ScalarField::one()])
+            .map(|s| s.into_repr())
+            .collect::<Vec<_>>();
+
+        if !VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+            return Err(verification_error);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use super::proof::Proof;
+use super::{Parameters, Statement, Witness};
+
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::dot_product;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, Field, PrimeField};
+
+pub struct Prover<'a, C: ProjectiveCurve> {
+    parameters: &'a Parameters<'a, C>,
+    statement: &'a Statement<'a, C>,
+    witness: &'a Witness<'a, C>,
+}
+
+impl<'a, C: ProjectiveCurve> Prover<'a, C> {
+    pub fn new(
+        parameters: &'a Parameters<'a, C>,
+        statement: &'a Statement<'a, C>,
+        witness: &'a Witness<'a, C>,
+    ) -> Self {
+        Self {
+            parameters,
+            statement,
+            witness,
+        }
+    }
+
+    pub fn prove<T: Transcript>(&self, fs_rng: &mut T) -> Result<Proof<C>, CryptoError> {
+        let n = self.parameters.g.len();
+        if !n.is_power_of_two()
+            || self.parameters.h.len() != n
+            || self.witness.a.len() != n
+            || self.witness.b.len() != n
+        {
+            return Err(CryptoError::InvalidInnerProductStatement);
+        }
+
+        fs_rng.absorb(&to_bytes![b"inner_product_argument"]?);
+
+        //public information
+        fs_rng.absorb(&to_bytes![
+            self.parameters.g,
+            self.parameters.h,
+            self.parameters.u,
+            self.statement.commitment.into_affine()
+        ]?);
+
+        let mut g = self.parameters.g.clone();
+        let mut h = self.parameters.h.clone();
+        let mut a = self.witness.a.clone();
+        let mut b = self.witness.b.clone();
+        let u = self.parameters.u;
+
+        let mut l_vec = Vec::new();
+        let mut r_vec = Vec::new();
+
+        let mut n = n;
+        while n > 1 {
+            n /= 2;
+            let (a_lo, a_hi) = a.split_at(n);
+            let (b_lo, b_hi) = b.split_at(n);
+            let (g_lo, g_hi) = g.split_at(n);
+            let (h_lo, h_hi) = h.split_at(n);
+
+            let c_l = dot_product(&a_lo.to_vec(), &b_hi.to_vec())?;
+            let c_r = dot_product(&a_hi.to_vec(), &b_lo.to_vec())?;
+
+            let l = multi_exponentiation::<C>(g_hi, a_lo)
+                + multi_exponentiation::<C>(h_lo, b_hi)
+                + u.mul(c_l.into_repr());
+            let r = multi_exponentiation::<C>(g_lo, a_hi)
+                + multi_exponentiation::<C>(h_hi, b_lo)
+                + u.mul(c_r.into_repr());
+            let (l, r) = (l.into_affine(), r.into_affine());
+
+            fs_rng.absorb(&to_bytes![l, r]?);
+            let x = derive_challenge::<C::ScalarField>(fs_rng);
+            let x_inv = x.inverse().unwrap();
+
+            let new_a = (0..n).map(|i| a_lo[i] * x + a_hi[i] * x_inv).collect();
+            let new_b = (0..n).map(|i| b_lo[i] * x_inv + b_hi[i] * x).collect();
+            let new_g = (0..n)
+                .map(|i| g_lo[i].mul(x_inv.into_repr()) + g_hi[i].mul(x.into_repr()))
+                .collect::<Vec<_>>();
+            let new_h = (0..n)
+                .map(|i| h_lo[i].mul(x.into_repr()) + h_hi[i].mul(x_inv.into_repr()))
+                .collect::<Vec<_>>();
+
+            a = new_a;
+            b = new_b;
+            g = C::batch_normalization_into_affine(&new_g);
+            h = C::batch_normalization_into_affine(&new_h);
+
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+
+        Ok(Proof {
+            l_vec,
+            r_vec,
+            a: a[0],
+            b: b[0],
+        })
+    }
+}
+
+fn multi_exponentiation<C: ProjectiveCurve>(bases: &[C::Affine], scalars: &[C::ScalarField]) -> C {
+    let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+}
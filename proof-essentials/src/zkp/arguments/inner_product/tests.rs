@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::{rand::sample_vector, vector_arithmetic::dot_product};
+    use crate::zkp::{arguments::inner_product, ArgumentOfKnowledge};
+
+    use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use rand::Rng;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Affine = starknet_curve::Affine;
+
+    type InnerProduct<'a> = inner_product::InnerProductArgument<'a, Curve>;
+    type Parameters<'a> = inner_product::Parameters<'a, Curve>;
+    type Statement<'a> = inner_product::Statement<'a, Curve>;
+    type Witness<'a> = inner_product::Witness<'a, Curve>;
+
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn generators<R: Rng>(rng: &mut R, n: usize) -> Vec<Affine> {
+        (0..n).map(|_| Curve::rand(rng).into_affine()).collect()
+    }
+
+    fn commit(g: &[Affine], h: &[Affine], u: &Affine, a: &Vec<Scalar>, b: &Vec<Scalar>) -> Curve {
+        let bases = g.iter().chain(h.iter()).cloned().collect::<Vec<_>>();
+        let scalars = a
+            .iter()
+            .chain(b.iter())
+            .map(|s| s.into_repr())
+            .collect::<Vec<_>>();
+
+        VariableBaseMSM::multi_scalar_mul(&bases, &scalars)
+            + u.mul(dot_product(a, b).unwrap().into_repr())
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+
+        for n in [1, 2, 16, 64] {
+            let g = generators(rng, n);
+            let h = generators(rng, n);
+            let u = Curve::rand(rng).into_affine();
+
+            let a: Vec<Scalar> = sample_vector(rng, n);
+            let b: Vec<Scalar> = sample_vector(rng, n);
+            let p = commit(&g, &h, &u, &a, &b);
+
+            let parameters = Parameters::new(&g, &h, &u);
+            let statement = Statement::new(&p);
+            let witness = Witness::new(&a, &b);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = InnerProduct::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+            assert_eq!(proof.l_vec.len(), n.trailing_zeros() as usize);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                InnerProduct::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let n = 16;
+
+        let g = generators(rng, n);
+        let h = generators(rng, n);
+        let u = Curve::rand(rng).into_affine();
+
+        let a: Vec<Scalar> = sample_vector(rng, n);
+        let b: Vec<Scalar> = sample_vector(rng, n);
+        let p = commit(&g, &h, &u, &a, &b);
+
+        let parameters = Parameters::new(&g, &h, &u);
+        let statement = Statement::new(&p);
+
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Inner Product",
+        )));
+
+        // The witness does not open the statement
+        let mut bad_b = b.clone();
+        bad_b[3] = Scalar::rand(rng);
+        let bad_witness = Witness::new(&a, &bad_b);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = InnerProduct::prove(
+            rng,
+            &parameters,
+            &statement,
+            &bad_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            InnerProduct::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // Tampering with an honest proof
+        let witness = Witness::new(&a, &b);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = InnerProduct::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut tampered = proof.clone();
+        tampered.l_vec.swap(0, 1);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            InnerProduct::verify(
+                &parameters,
+                &statement,
+                &tampered,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let mut truncated = proof.clone();
+        truncated.l_vec.pop();
+        truncated.r_vec.pop();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            InnerProduct::verify(
+                &parameters,
+                &statement,
+                &truncated,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // Generators of unsupported length
+        let short_g = g[..n - 1].to_vec();
+        let short_h = h[..n - 1].to_vec();
+        let short_a = a[..n - 1].to_vec();
+        let short_b = b[..n - 1].to_vec();
+        let short_parameters = Parameters::new(&short_g, &short_h, &u);
+        let short_witness = Witness::new(&short_a, &short_b);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            InnerProduct::prove(
+                rng,
+                &short_parameters,
+                &statement,
+                &short_witness,
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Err(CryptoError::InvalidInnerProductStatement)
+        );
+    }
+}
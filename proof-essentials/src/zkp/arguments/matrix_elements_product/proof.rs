@@ -3,12 +3,11 @@ use super::{Parameters, Statement};
 use crate::error::CryptoError;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::{hadamard_product, single_value_product};
+use crate::zkp::transcript::Transcript;
 
 use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Comm>
@@ -26,11 +25,11 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Comm>,
         statement: &Statement<Scalar, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
         statement.is_valid(proof_parameters)?;
         fs_rng.absorb(&to_bytes![b"matrix_elements_product"]?);
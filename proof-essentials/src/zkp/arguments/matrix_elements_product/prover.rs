@@ -1,17 +1,13 @@
 use super::{proof::Proof, Parameters, Statement, Witness};
 
 use crate::error::CryptoError;
-use crate::utils::vector_arithmetic::hadamard_product as compute_hadamard_product;
+use crate::vector_commitment::matrix::Matrix;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::{
-    arguments::{hadamard_product, single_value_product},
-    ArgumentOfKnowledge,
-};
+use crate::zkp::arguments::{hadamard_product, single_value_product};
+use crate::zkp::transcript::Transcript;
 
 use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::rand::Rng;
-use digest::Digest;
 
 pub struct Prover<'a, Scalar, Comm>
 where
@@ -40,19 +36,32 @@ where
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
+    pub fn prove<R: Rng, T: Transcript>(
         &self,
         rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        let precomputation = Precomputation::new(rng, self.parameters)?;
+        self.prove_precomputed(precomputation, fs_rng)
+    }
+
+    /// Online phase of [`Prover::prove`], with the randomness of `precomputation` and the ones of the Hadamard and
+    /// single value product arguments, which must have been made for the same parameters.
+    pub(crate) fn prove_precomputed<T: Transcript>(
+        &self,
+        precomputation: Precomputation<Scalar, Comm>,
+        fs_rng: &mut T,
     ) -> Result<Proof<Scalar, Comm>, CryptoError> {
         fs_rng.absorb(&to_bytes![b"matrix_elements_product"]?);
 
-        let s = Scalar::rand(rng);
+        let Precomputation {
+            s,
+            hadamard_product,
+            single_value_product,
+        } = precomputation;
 
-        let mut product_along_rows = vec![Scalar::one(); self.parameters.n];
-        for x in self.witness.matrix_a {
-            product_along_rows = compute_hadamard_product(&x, &product_along_rows)?;
-        }
+        let product_along_rows =
+            Matrix::from_columns(self.witness.matrix_a.clone())?.product_along_rows()?;
 
         let b_commit = Comm::commit(self.parameters.commit_key, &product_along_rows, s)?;
 
@@ -65,7 +74,7 @@ where
         );
 
         let hadamard_product_statement =
-            hadamard_product::Statement::new(&self.statement.commitments_to_a, b_commit);
+            hadamard_product::Statement::new(self.statement.commitments_to_a, b_commit);
 
         let hadamard_product_witness = hadamard_product::Witness::new(
             self.witness.matrix_a,
@@ -74,13 +83,13 @@ where
             s,
         );
 
-        let hadamard_product_proof = hadamard_product::HadamardProductArgument::prove(
-            rng,
+        let hadamard_product_prover = hadamard_product::prover::Prover::new(
             &hadamard_product_parameters,
             &hadamard_product_statement,
             &hadamard_product_witness,
-            fs_rng,
-        )?;
+        );
+        let hadamard_product_proof =
+            hadamard_product_prover.prove_precomputed(hadamard_product, fs_rng)?;
 
         // Engage in single value product argument for b_commit and b as a statement:
         // This will show that our claimed product b is indeed the product of the values in
@@ -94,13 +103,13 @@ where
         let single_value_product_statement =
             single_value_product::Statement::new(&b_commit, self.statement.b);
 
-        let single_value_proof = single_value_product::SingleValueProductArgument::prove(
-            rng,
+        let single_value_prover = single_value_product::prover::Prover::new(
             &single_value_product_parameters,
             &single_value_product_statement,
             &single_value_product_witness,
-            fs_rng,
-        )?;
+        );
+        let single_value_proof =
+            single_value_prover.prove_precomputed(single_value_product, fs_rng)?;
 
         let proof = Proof {
             b_commit,
@@ -111,3 +120,46 @@ where
         Ok(proof)
     }
 }
+
+/// Randomness of the commitment to the products along the rows of the matrix, and the precomputations of the
+/// Hadamard and single value product arguments, none of which depend on the witness. A precomputation blinds a single
+/// proof, so it is consumed by it.
+pub(crate) struct Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    s: Scalar,
+    hadamard_product: hadamard_product::prover::Precomputation<Scalar, Comm>,
+    single_value_product: single_value_product::prover::Precomputation<Scalar, Comm>,
+}
+
+impl<Scalar, Comm> Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) fn new<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<Scalar, Comm>,
+    ) -> Result<Self, CryptoError> {
+        let s = Scalar::rand(rng);
+
+        let hadamard_product_parameters =
+            hadamard_product::Parameters::new(parameters.m, parameters.n, parameters.commit_key);
+        let single_value_product_parameters =
+            single_value_product::Parameters::new(parameters.n, parameters.commit_key);
+
+        Ok(Self {
+            s,
+            hadamard_product: hadamard_product::prover::Precomputation::new(
+                rng,
+                &hadamard_product_parameters,
+            )?,
+            single_value_product: single_value_product::prover::Precomputation::new(
+                rng,
+                &single_value_product_parameters,
+            )?,
+        })
+    }
+}
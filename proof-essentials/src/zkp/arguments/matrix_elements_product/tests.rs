@@ -1,5 +1,4 @@
 #[cfg(test)]
-
 mod test {
     use crate::utils::rand::sample_vector;
     use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
@@ -63,6 +62,7 @@ mod test {
             &proof_parameters,
             &statement,
             &valid_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -70,7 +70,13 @@ mod test {
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
             Ok(()),
-            valid_proof.verify(&proof_parameters, &statement, &mut fs_rng)
+            ProductArgument::verify(
+                &proof_parameters,
+                &statement,
+                &valid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
 
         let new_random_scalars: Vec<Scalar> = sample_vector(rng, m * n);
@@ -96,6 +102,7 @@ mod test {
             &proof_parameters,
             &statement,
             &invalid_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -103,7 +110,13 @@ mod test {
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_ne!(
             Ok(()),
-            invalid_proof.verify(&proof_parameters, &statement, &mut fs_rng)
+            ProductArgument::verify(
+                &proof_parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
 
         let fake_product = Scalar::rand(rng);
@@ -118,6 +131,7 @@ mod test {
             &proof_parameters,
             &wrong_statement,
             &invalid_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -125,7 +139,13 @@ mod test {
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_ne!(
             Ok(()),
-            invalid_proof.verify(&proof_parameters, &wrong_statement, &mut fs_rng)
+            ProductArgument::verify(
+                &proof_parameters,
+                &wrong_statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
     }
 }
@@ -1,11 +1,20 @@
+pub mod compressed_sigma;
 pub mod hadamard_product;
+pub mod inner_product;
 pub mod matrix_elements_product;
 pub mod multi_exponentiation;
 pub mod shuffle;
 pub mod single_value_product;
+pub mod streaming;
 pub mod zero_value_bilinear_map;
 
+use crate::error::CryptoError;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::transcript::derive_challenge;
+
 use ark_ff::Field;
+use ark_marlin::rng::FiatShamirRng;
+use digest::Digest;
 use std::iter;
 
 /// Compute the powers of a given scalar $x$ from $x^0$ to $x^(n)$ (included)
@@ -19,6 +28,83 @@ pub fn scalar_powers<F: Field>(x: F, n: usize) -> Vec<F> {
         .collect::<Vec<_>>()
 }
 
+/// Commitment equations $\sum_i s_i C_i = \mathrm{com}(x; r)$ of an argument verifier, checked together as their
+/// random linear combination with weights $1, \rho, \rho^2, \dots$ through a single
+/// [`HomomorphicCommitmentScheme::verify_combination`]. If any equation fails, the combination holds with
+/// probability at most $k / |\mathbb{F}|$ for $k$ equations.
+pub(crate) struct CommitmentEquations<Scalar: Field, Comm: HomomorphicCommitmentScheme<Scalar>> {
+    scalars: Vec<Vec<Scalar>>,
+    commitments: Vec<Vec<Comm::Commitment>>,
+    values: Vec<Vec<Scalar>>,
+    randomness: Vec<Scalar>,
+}
+
+impl<Scalar: Field, Comm: HomomorphicCommitmentScheme<Scalar>> CommitmentEquations<Scalar, Comm> {
+    pub(crate) fn new() -> Self {
+        Self {
+            scalars: Vec::new(),
+            commitments: Vec::new(),
+            values: Vec::new(),
+            randomness: Vec::new(),
+        }
+    }
+
+    /// Add the equation $\sum_i s_i C_i = \mathrm{com}(x; r)$.
+    pub(crate) fn add(
+        &mut self,
+        scalars: Vec<Scalar>,
+        commitments: Vec<Comm::Commitment>,
+        x: Vec<Scalar>,
+        r: Scalar,
+    ) -> Result<(), CryptoError> {
+        if scalars.len() != commitments.len() {
+            return Err(CryptoError::DotProductLengthError(
+                scalars.len(),
+                commitments.len(),
+            ));
+        }
+
+        self.scalars.push(scalars);
+        self.commitments.push(commitments);
+        self.values.push(x);
+        self.randomness.push(r);
+
+        Ok(())
+    }
+
+    /// Check every equation at once. $\rho$ is derived from `seed`, which must bind every term of the equations,
+    /// typically the last challenge of the argument followed by the prover's responses, so that the prover cannot
+    /// choose them against the weights.
+    pub(crate) fn verify<D: Digest>(
+        self,
+        commit_key: &Comm::CommitKey,
+        seed: &[u8],
+    ) -> Result<bool, CryptoError> {
+        let rho = derive_challenge::<Scalar>(&mut FiatShamirRng::<D>::from_seed(&seed));
+        let weights = scalar_powers(rho, self.randomness.len());
+
+        let mut values = vec![Scalar::zero(); self.values.iter().map(Vec::len).max().unwrap_or(0)];
+        for (weight, x) in weights.iter().zip(self.values.iter()) {
+            for (acc, x_j) in values.iter_mut().zip(x.iter()) {
+                *acc += *weight * x_j;
+            }
+        }
+        let randomness = weights
+            .iter()
+            .zip(self.randomness.iter())
+            .map(|(&weight, &r)| weight * r)
+            .sum();
+        let scalars = weights
+            .iter()
+            .zip(self.scalars.iter())
+            .flat_map(|(&weight, scalars)| scalars.iter().map(move |&s| weight * s))
+            .collect::<Vec<_>>();
+        let commitments = self.commitments.concat();
+
+        Comm::verify_combination(commit_key, &scalars, &commitments, &values, randomness)
+    }
+}
+
 #[cfg(test)]
 mod scalar_power_test {
     use super::scalar_powers;
@@ -2,13 +2,12 @@ use super::{Parameters, Statement};
 
 use crate::error::CryptoError;
 use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
-use crate::utils::vector_arithmetic::dot_product;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::arguments::scalar_powers;
-use ark_marlin::rng::FiatShamirRng;
-use digest::Digest;
+use crate::zkp::arguments::streaming::{for_each_chunk, CiphertextDigest, CiphertextSource};
+use crate::zkp::arguments::{scalar_powers, CommitmentEquations};
+use crate::zkp::transcript::{absorb_serializable, derive_challenge, Transcript};
 
-use ark_ff::{to_bytes, Field, Zero};
+use ark_ff::{to_bytes, Field};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
 
@@ -38,37 +37,92 @@ where
     Enc: HomomorphicEncryptionScheme<Scalar>,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Enc, Comm>,
         statement: &Statement<Scalar, Enc, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let mut shuffled_digest = CiphertextDigest::<T::Hash>::new();
+        for chunk in statement.shuffled_ciphers {
+            shuffled_digest.update(chunk)?;
+        }
+
+        self.verify_with_digest(
+            proof_parameters,
+            statement,
+            &shuffled_digest.finalize(),
+            fs_rng,
+        )
+    }
+
+    /// Verify with the digest of the shuffled ciphertexts of the statement already computed, such as the one of a
+    /// prepared shuffle statement.
+    pub(crate) fn verify_with_digest<T: Transcript>(
+        &self,
+        proof_parameters: &Parameters<Scalar, Enc, Comm>,
+        statement: &Statement<Scalar, Enc, Comm>,
+        shuffled_digest: &[u8],
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
         let m = statement.shuffled_ciphers.len();
         let n = statement.shuffled_ciphers[0].len();
+        if let Some(chunk) = statement
+            .shuffled_ciphers
+            .iter()
+            .find(|chunk| chunk.len() != n)
+        {
+            return Err(CryptoError::DotProductLengthError(n, chunk.len()));
+        }
+
+        self.verify_from_source(
+            proof_parameters,
+            statement.commitments_to_exponents,
+            statement.product,
+            statement.shuffled_ciphers,
+            (m, n),
+            m * n,
+            shuffled_digest,
+            fs_rng,
+        )
+    }
+
+    /// Verify against the `m` columns of `n` shuffled ciphertexts of `shuffled_ciphers`, whose digest is already
+    /// computed, read `chunk` at a time. The final check is folded into a running sum of one multi-scalar
+    /// multiplication per read, so that the verifier holds at most `chunk` of the ciphertexts.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn verify_from_source<T, S>(
+        &self,
+        proof_parameters: &Parameters<Scalar, Enc, Comm>,
+        commitments_to_exponents: &[Comm::Commitment],
+        product: Enc::Ciphertext,
+        shuffled_ciphers: &S,
+        (m, n): (usize, usize),
+        chunk: usize,
+        shuffled_digest: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError>
+    where
+        T: Transcript,
+        S: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
         let num_of_diagonals = 2 * m - 1;
 
-        fs_rng.absorb(
-            &to_bytes![
-                b"multi-exponentiation",
-                proof_parameters.public_key,
-                proof_parameters.commit_key,
-                statement.commitments_to_exponents,
-                &statement.product,
-                statement.shuffled_ciphers
-            ]
-            .unwrap(),
+        absorb_serializable!(
+            fs_rng,
+            b"multi-exponentiation"[..],
+            proof_parameters.public_key,
+            proof_parameters.commit_key,
+            commitments_to_exponents,
+            product,
+            shuffled_digest
         );
 
         fs_rng.absorb(&to_bytes![m as u32, n as u32, num_of_diagonals as u32]?);
 
-        fs_rng.absorb(&to_bytes![
-            self.a_0_commit,
-            self.commit_b_k,
-            self.vector_e_k
-        ]?);
+        absorb_serializable!(fs_rng, self.a_0_commit, self.commit_b_k, self.vector_e_k);
 
-        let challenge = Scalar::rand(fs_rng);
+        let challenge = derive_challenge::<Scalar>(fs_rng);
 
         // Precompute all powers of the challenge from 0 to number_of_diagonals
         let challenge_powers = scalar_powers(challenge, num_of_diagonals);
@@ -76,92 +130,83 @@ where
         // take vector x: x, x^2, x^3, ..., x^m
         let x_array = challenge_powers[1..m + 1].to_vec();
 
-        let left = self.commit_b_k[m];
-        let right = Comm::commit(
-            proof_parameters.commit_key,
-            &vec![Scalar::zero()],
-            Scalar::zero(),
-        )?;
-
-        if left != right {
+        if self.vector_e_k[m] != product {
             return Err(CryptoError::ProofVerificationError(String::from(
                 "Multi Exponentiation",
             )));
         }
 
-        if self.vector_e_k[m] != statement.product {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Multi Exponentiation",
-            )));
-        }
+        let mut equations = CommitmentEquations::<Scalar, Comm>::new();
 
-        let c_a_x = dot_product(&x_array, &statement.commitments_to_exponents)?;
-        let verifier_commit_a = Comm::commit(
-            &proof_parameters.commit_key,
-            &self.a_blinded,
-            self.r_blinded,
+        // C_{B_m} ==? com(0; 0)
+        equations.add(
+            vec![Scalar::one()],
+            vec![self.commit_b_k[m]],
+            vec![],
+            Scalar::zero(),
         )?;
 
-        let left = c_a_x + self.a_0_commit;
-        if left != verifier_commit_a {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Multi Exponentiation",
-            )));
-        }
+        // A_0 + sum_i x^i C_{A_i} ==? com(a; r)
+        equations.add(
+            [&[Scalar::one()], &x_array[..]].concat(),
+            [&[self.a_0_commit], commitments_to_exponents].concat(),
+            self.a_blinded.clone(),
+            self.r_blinded,
+        )?;
 
-        let c_b_k = dot_product(&challenge_powers, &self.commit_b_k)?;
-        let verif_commit_b = Comm::commit(
-            proof_parameters.commit_key,
-            &vec![self.b_blinded],
+        // sum_k x^k C_{B_k} ==? com(b; s)
+        equations.add(
+            challenge_powers.clone(),
+            self.commit_b_k.clone(),
+            vec![self.b_blinded],
             self.s_blinded,
         )?;
-        if c_b_k != verif_commit_b {
+
+        let seed = to_bytes![
+            challenge,
+            self.a_blinded,
+            self.r_blinded,
+            self.b_blinded,
+            self.s_blinded
+        ]?;
+        if !equations.verify::<T::Hash>(proof_parameters.commit_key, &seed)? {
             return Err(CryptoError::ProofVerificationError(String::from(
                 "Multi Exponentiation",
             )));
         }
 
-        let sum_e_k = dot_product(&challenge_powers, &self.vector_e_k)?;
-
         let message = *proof_parameters.generator * self.b_blinded;
         let aggregate_masking_cipher = Enc::encrypt(
-            &proof_parameters.encrypt_parameters,
-            &proof_parameters.public_key,
+            proof_parameters.encrypt_parameters,
+            proof_parameters.public_key,
             &message,
             &self.tau_blinded,
         )?;
 
         /*
-            c1 * x^m-1; x[m-1]
-            c2 * x^m-2; x[m-2]
-            c3 * x^m-3; x[m-3]
-            ...
-            cm * x^m-m; x[0]
+            sum_k x^k E_k - sum_i x^(m-i) C_i^a ==? E(G^b; tau), where column i of the ciphertexts is multiplied by
+            the exponents -x^(m-1-i) a, in one multi-scalar multiplication per read of the ciphertexts
         */
+        if shuffled_ciphers.len() != m * n {
+            return Err(CryptoError::CiphertextVectorLengthError(
+                shuffled_ciphers.len(),
+                m * n,
+            ));
+        }
+        if self.a_blinded.len() != n {
+            return Err(CryptoError::DotProductLengthError(self.a_blinded.len(), n));
+        }
 
-        let verif_rhs: Result<Vec<Enc::Ciphertext>, CryptoError> = challenge_powers
-            .iter()
-            .take(m)
-            .rev()
-            .zip(statement.shuffled_ciphers.iter())
-            .map(
-                |(power_of_x, cipher_chunk)| -> Result<Enc::Ciphertext, CryptoError> {
-                    // x^m - i * a_vec
-                    let xm_minus_i_times_a = self
-                        .a_blinded
-                        .iter()
-                        .map(|element_of_a| *element_of_a * *power_of_x)
-                        .collect::<_>();
-                    let dot_p = dot_product(&xm_minus_i_times_a, cipher_chunk)?;
-                    Ok(dot_p)
-                },
-            )
-            .collect();
-
-        let verif_rhs = verif_rhs?
-            .iter()
-            .fold(Enc::Ciphertext::zero(), |acc, &x| acc + x);
-        if sum_e_k != aggregate_masking_cipher + verif_rhs {
+        let mut sum = Enc::multi_scalar_mul(&challenge_powers, &self.vector_e_k)?;
+        for_each_chunk(shuffled_ciphers, chunk, |start, ciphers| {
+            let scalars = (start..start + ciphers.len())
+                .map(|index| -(self.a_blinded[index % n] * challenge_powers[m - 1 - index / n]))
+                .collect::<Vec<_>>();
+            sum = sum + Enc::multi_scalar_mul(&scalars, &ciphers)?;
+            Ok(())
+        })?;
+
+        if sum != aggregate_masking_cipher {
             return Err(CryptoError::ProofVerificationError(String::from(
                 "Multi Exponentiation",
             )));
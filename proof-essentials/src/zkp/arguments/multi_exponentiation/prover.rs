@@ -6,11 +6,15 @@ use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
 use crate::utils::{rand::sample_vector, vector_arithmetic::dot_product};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::scalar_powers;
+use crate::zkp::arguments::streaming::{CiphertextDigest, CiphertextSource};
+use crate::zkp::transcript::{absorb_serializable, derive_challenge, Transcript};
 
 use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
+use ark_std::cfg_iter;
 use ark_std::rand::Rng;
-use digest::Digest;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::iter;
 use std::marker::PhantomData;
 
 pub struct Prover<'a, Scalar, Enc, Comm>
@@ -47,85 +51,120 @@ where
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
+    pub fn prove<R: Rng, T: Transcript>(
         &self,
         rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
-        fs_rng.absorb(
-            &to_bytes![
-                b"multi-exponentiation",
-                self.parameters.public_key,
-                self.parameters.commit_key,
-                self.statement.commitments_to_exponents,
-                &self.statement.product,
-                self.statement.shuffled_ciphers
-            ]
-            .unwrap(),
-        );
-
-        let m = self.witness.matrix_a.len();
-        let n = self.witness.matrix_a[0].len();
-        let num_of_diagonals = 2 * m - 1;
-
-        fs_rng.absorb(&to_bytes![m as u32, n as u32, num_of_diagonals as u32]?);
-
-        let a_0: Vec<Scalar> = sample_vector(rng, n);
-        let r_0 = Scalar::rand(rng);
+        Self::prove_from_source(
+            rng,
+            self.parameters,
+            self.statement.commitments_to_exponents,
+            self.statement.product,
+            self.statement.shuffled_ciphers,
+            self.witness,
+            fs_rng,
+        )
+    }
 
-        let mut b: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
-        let mut s: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
-        let mut tau: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
+    /// Prove the statement whose ciphertexts are read from `shuffled_ciphers` one chunk at a time, with the chunk
+    /// length given by the columns of the witness.
+    pub(crate) fn prove_from_source<R, T, S>(
+        rng: &mut R,
+        parameters: &Parameters<Scalar, Enc, Comm>,
+        commitments_to_exponents: &Vec<Comm::Commitment>,
+        product: Enc::Ciphertext,
+        shuffled_ciphers: &S,
+        witness: &Witness<Scalar>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError>
+    where
+        R: Rng,
+        T: Transcript,
+        S: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        let precomputation = Precomputation::new(
+            rng,
+            parameters,
+            witness.matrix_a.len(),
+            witness.matrix_a[0].len(),
+        )?;
 
-        b[m] = Scalar::zero();
-        s[m] = Scalar::zero();
-        tau[m] = self.witness.rho;
+        Self::prove_precomputed_from_source(
+            precomputation,
+            parameters,
+            commitments_to_exponents,
+            product,
+            shuffled_ciphers,
+            witness,
+            fs_rng,
+        )
+    }
 
-        let a_0_commit = Comm::commit(&self.parameters.commit_key, &a_0, r_0)?;
+    /// Online phase of [`Prover::prove_from_source`], with the randomness and the blinding commitments and
+    /// encryptions of `precomputation`, which must have the dimensions of the witness.
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn prove_precomputed_from_source<T, S>(
+        precomputation: Precomputation<Scalar, Enc, Comm>,
+        parameters: &Parameters<Scalar, Enc, Comm>,
+        commitments_to_exponents: &Vec<Comm::Commitment>,
+        product: Enc::Ciphertext,
+        shuffled_ciphers: &S,
+        witness: &Witness<Scalar>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError>
+    where
+        T: Transcript,
+        S: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        let m = witness.matrix_a.len();
+        let n = witness.matrix_a[0].len();
+        let num_of_diagonals = 2 * m - 1;
 
-        let commit_b_k = b
-            .iter()
-            .zip(s.iter())
-            .map(|(&b_k, &s_k)| {
-                let commit = Comm::commit(self.parameters.commit_key, &vec![b_k], s_k)?;
-                Ok(commit)
-            })
-            .collect::<Result<Vec<Comm::Commitment>, CryptoError>>()?;
+        let shuffled_digest = CiphertextDigest::<T::Hash>::of_source(shuffled_ciphers, n)?;
+        absorb_serializable!(
+            fs_rng,
+            b"multi-exponentiation"[..],
+            parameters.public_key,
+            parameters.commit_key,
+            commitments_to_exponents,
+            product,
+            shuffled_digest
+        );
 
-        let diagonals = Self::diagonals_from_chunks(
-            &self.statement.shuffled_ciphers,
-            &self.witness.matrix_a,
-            &a_0,
-        )
-        .unwrap();
+        fs_rng.absorb(&to_bytes![m as u32, n as u32, num_of_diagonals as u32]?);
 
-        let vector_e_k = b
-            .iter()
-            .zip(tau.iter())
-            .zip(diagonals.iter())
-            .map(|((&b_k, tau_k), &d_k)| {
-                let message = *self.parameters.generator * b_k;
-
-                let encrypted_random = Enc::encrypt(
-                    &self.parameters.encrypt_parameters,
-                    self.parameters.public_key,
-                    &message,
-                    tau_k,
-                );
-
-                // let encrypted_random = DiscreteLogVTMF::<C>::mask(
-                //     encryption_parameters,
-                //     self.parameters.public_key,
-                //     &self.parameters.masking_generator.mul(*b_k).into_affine(),
-                //     &Randomness::<C>(*tau_k),
-                // );
-                encrypted_random.unwrap() + d_k
-            })
+        let Precomputation {
+            a_0,
+            r_0,
+            b,
+            s,
+            mut tau,
+            a_0_commit,
+            commit_b_k,
+            mut encrypted_b_k,
+        } = precomputation;
+
+        // The only blinding encryption that depends on the witness is the one of diagonal m, with b_m = 0
+        tau[m] = witness.rho;
+        encrypted_b_k[m] = Enc::encrypt(
+            parameters.encrypt_parameters,
+            parameters.public_key,
+            &(*parameters.generator * Scalar::zero()),
+            &tau[m],
+        )?;
+
+        let diagonals = Self::diagonals_from_source(shuffled_ciphers, witness.matrix_a, &a_0)?;
+
+        let vector_e_k = encrypted_b_k
+            .into_iter()
+            .zip(diagonals)
+            .map(|(encrypted_random, d_k)| encrypted_random + d_k)
             .collect::<Vec<Enc::Ciphertext>>();
 
-        fs_rng.absorb(&to_bytes![a_0_commit, commit_b_k, vector_e_k]?);
+        absorb_serializable!(fs_rng, a_0_commit, commit_b_k, vector_e_k);
 
-        let challenge = Scalar::rand(fs_rng);
+        let challenge = derive_challenge::<Scalar>(fs_rng);
 
         // Precompute all powers of the challenge from 0 to number_of_diagonals
         let challenge_powers = scalar_powers(challenge, num_of_diagonals);
@@ -133,8 +172,7 @@ where
         // take vector x: x, x^2, x^3, ..., x^m
         let x_array = challenge_powers[1..m + 1].to_vec();
 
-        let scalar_products_ax = self
-            .witness
+        let scalar_products_ax = witness
             .matrix_a
             .iter()
             .enumerate()
@@ -157,12 +195,12 @@ where
         for i in 0..n {
             let mut poly = a_0[i];
             for j in 0..m {
-                poly = poly + scalar_products_ax[j][i];
+                poly += scalar_products_ax[j][i];
             }
             a_blinded.push(poly);
         }
 
-        let r_blinded = r_0 + dot_product(&self.witness.matrix_blinders, &x_array)?;
+        let r_blinded = r_0 + dot_product(witness.matrix_blinders, &x_array)?;
         let b_blinded = dot_product(&b, &challenge_powers)?;
         let s_blinded = dot_product(&s, &challenge_powers)?;
         let tau_blinded = dot_product(&tau, &challenge_powers)?;
@@ -184,61 +222,122 @@ where
         Ok(proof)
     }
 
-    fn diagonals_from_chunks(
-        cipher_chunks: &Vec<Vec<Enc::Ciphertext>>,
-        scalar_chunks: &Vec<Vec<Scalar>>,
+    /// The sums $E_k = \sum_{j - i = k - m} a_j \cdot C_i$, for $k$ from 0 to $2m - 1$, of the products of the column
+    /// $a_j$ of the witness with the chunk $C_i$ of ciphertexts, where $a_{-1}$ is the blinding column $a_0$. Every
+    /// chunk is read once.
+    fn diagonals_from_source<S>(
+        cipher_chunks: &S,
+        scalar_chunks: &[Vec<Scalar>],
         a_0_randomness: &Vec<Scalar>,
-    ) -> Result<Vec<Enc::Ciphertext>, CryptoError> {
-        let m = cipher_chunks.len();
-        let num_of_diagonals = 2 * m - 1;
+    ) -> Result<Vec<Enc::Ciphertext>, CryptoError>
+    where
+        S: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        let m = scalar_chunks.len();
+        let n = a_0_randomness.len();
+
+        let columns = iter::once(a_0_randomness)
+            .chain(scalar_chunks.iter())
+            .collect::<Vec<_>>();
+
+        let mut diagonal_sums = vec![Enc::Ciphertext::zero(); 2 * m];
+        for i in 0..m {
+            let chunk = cipher_chunks.read(i * n, n)?;
+            let products = cfg_iter!(columns)
+                .map(|a_j| Enc::multi_scalar_mul(a_j, &chunk))
+                .collect::<Result<Vec<_>, CryptoError>>()?;
+
+            // Column j + 1 of `columns` is a_j
+            for (j, product) in products.into_iter().enumerate() {
+                let k = m + j - 1 - i;
+                diagonal_sums[k] = diagonal_sums[k] + product;
+            }
+        }
 
-        let mut diagonal_sums: Vec<Enc::Ciphertext> =
-            vec![Enc::Ciphertext::zero(); num_of_diagonals];
-        let center = num_of_diagonals / 2 as usize;
+        Ok(diagonal_sums)
+    }
+}
 
-        for d in 1..m {
-            let additional_randomness = dot_product(&a_0_randomness, &cipher_chunks[d - 1])?;
-            let mut tmp_product1 = Enc::Ciphertext::zero();
-            let mut tmp_product2 = Enc::Ciphertext::zero();
-            for i in d..m {
-                let dot = dot_product(&scalar_chunks[i - d], &cipher_chunks[i])?;
-                tmp_product1 = tmp_product1 + dot;
+/// Randomness of the multi-exponentiation argument with the commitments and encryptions that blind its first round,
+/// which do not depend on the witness and can be computed ahead of the proof: the commitments to $a_0$ and to every
+/// $b_k$, and the encryptions of $G^{b_k}$ but the one of diagonal $m$, which uses the aggregate factor $\rho$.
+///
+/// A precomputation blinds a single proof, so it is consumed by it.
+pub(crate) struct Precomputation<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    a_0: Vec<Scalar>,
+    r_0: Scalar,
+    b: Vec<Scalar>,
+    s: Vec<Scalar>,
+    tau: Vec<Scalar>,
+    a_0_commit: Comm::Commitment,
+    commit_b_k: Vec<Comm::Commitment>,
+    encrypted_b_k: Vec<Enc::Ciphertext>,
+}
 
-                let dot = dot_product(&scalar_chunks[i], &cipher_chunks[i - d])?;
-                tmp_product2 = tmp_product2 + dot;
-            }
+impl<Scalar, Enc, Comm> Precomputation<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    /// Precomputation for a witness of `m` columns of `n` exponents.
+    pub(crate) fn new<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<Scalar, Enc, Comm>,
+        m: usize,
+        n: usize,
+    ) -> Result<Self, CryptoError> {
+        let num_of_diagonals = 2 * m - 1;
 
-            diagonal_sums[center - d] = tmp_product1 + additional_randomness;
-            diagonal_sums[center + d] = tmp_product2;
-        }
+        let a_0: Vec<Scalar> = sample_vector(rng, n);
+        let r_0 = Scalar::rand(rng);
 
-        // let commit_b_k: Vec<_> = b
-        // .iter()
-        // .zip(s.iter())
-        // .map(|(&b_k, &s_k)| {
-        //     let commit = Comm::commit(self.parameters.commit_key, vec![b_k], s_k)?;
-        //     Ok(commit)
-        // }).collect()?;
+        let mut b: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
+        let mut s: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
+        let mut tau: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
 
-        let product: Result<Vec<Enc::Ciphertext>, CryptoError> = cipher_chunks
-            .iter()
-            .zip(scalar_chunks.iter())
-            .map(|(c_i, a_i)| {
-                let dot_p = dot_product(a_i, c_i)?;
-                Ok(dot_p)
-            })
-            .collect();
+        b[m] = Scalar::zero();
+        s[m] = Scalar::zero();
+        tau[m] = Scalar::zero();
 
-        let product = product?
-            .iter()
-            .fold(Enc::Ciphertext::zero(), |acc, &x| acc + x);
-        // .sum();
+        let a_0_commit = Comm::commit(parameters.commit_key, &a_0, r_0)?;
 
-        diagonal_sums[center] = product;
+        let commit_key = parameters.commit_key;
+        let commit_b_k = cfg_iter!(b)
+            .zip(cfg_iter!(s))
+            .map(|(&b_k, &s_k)| Comm::commit(commit_key, &vec![b_k], s_k))
+            .collect::<Result<Vec<Comm::Commitment>, CryptoError>>()?;
 
-        let zeroth_diagonal = dot_product(&a_0_randomness, &cipher_chunks.last().unwrap())?;
-        diagonal_sums.insert(0, zeroth_diagonal);
+        let generator = *parameters.generator;
+        let encrypt_parameters = parameters.encrypt_parameters;
+        let public_key = parameters.public_key;
+        let encrypted_b_k = cfg_iter!(b)
+            .zip(cfg_iter!(tau))
+            .enumerate()
+            .map(|(k, (&b_k, tau_k))| {
+                if k == m {
+                    return Ok(Enc::Ciphertext::zero());
+                }
 
-        Ok(diagonal_sums)
+                let message = generator * b_k;
+                Enc::encrypt(encrypt_parameters, public_key, &message, tau_k)
+            })
+            .collect::<Result<Vec<Enc::Ciphertext>, CryptoError>>()?;
+
+        Ok(Self {
+            a_0,
+            r_0,
+            b,
+            s,
+            tau,
+            a_0_commit,
+            commit_b_k,
+            encrypted_b_k,
+        })
     }
 }
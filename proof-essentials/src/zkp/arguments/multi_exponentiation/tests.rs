@@ -83,25 +83,46 @@ mod test {
         let statement = Statement::new(&c_chunks, grand_product, &c_a);
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        let proof =
-            MultiExpArg::prove(rng, &parameters, &statement, &witness, &mut fs_rng).unwrap();
+        let proof = MultiExpArg::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
             (),
-            proof.verify(&parameters, &statement, &mut fs_rng).unwrap()
+            MultiExpArg::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng)
+                .unwrap()
         );
 
         let wrong_rho = Scalar::rand(rng);
         let wrong_witness = Witness::new(&a_chunks, &r, wrong_rho);
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        let invalid_proof =
-            MultiExpArg::prove(rng, &parameters, &statement, &wrong_witness, &mut fs_rng).unwrap();
+        let invalid_proof = MultiExpArg::prove(
+            rng,
+            &parameters,
+            &statement,
+            &wrong_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
-            invalid_proof.verify(&parameters, &statement, &mut fs_rng),
+            MultiExpArg::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
             Err(CryptoError::ProofVerificationError(String::from(
                 "Multi Exponentiation",
             )))
@@ -0,0 +1,92 @@
+use super::ShuffleArgument;
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ff::{Field, Zero};
+use ark_serialize::CanonicalSerialize;
+
+/// Size of the length prefix of a serialized vector.
+const LENGTH_PREFIX_SIZE: usize = 8;
+
+/// Predicted size and work of a shuffle proof of `m` columns of `n` ciphertexts, computed from the dimensions
+/// alone, see [`ShuffleArgument::estimate_cost`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CostEstimate {
+    pub m: usize,
+    pub n: usize,
+    /// Size in bytes of the serialized proof.
+    pub proof_size: usize,
+    pub prover: OperationCount,
+    pub verifier: OperationCount,
+}
+
+/// Group operations of a prover or a verifier, as they reach the [`ScalarMul`](crate::utils::msm::ScalarMul)
+/// backend of the encryption and commitment schemes.
+///
+/// A multi-scalar multiplication of $k$ terms costs much less than $k$ scalar multiplications, so the two counts
+/// are kept apart. The few multiplications of commitments and ciphertexts by a challenge through their `Mul`
+/// operator, $O(m)$ of them, are not counted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OperationCount {
+    /// Single scalar multiplications, such as the ones of an ElGamal encryption.
+    pub scalar_multiplications: usize,
+    /// Points multiplied within multi-scalar multiplications, over all of them.
+    pub multi_scalar_terms: usize,
+}
+
+impl<'a, F, Enc, Comm> ShuffleArgument<'a, F, Enc, Comm>
+where
+    F: Field,
+    Enc: HomomorphicEncryptionScheme<F>,
+    Comm: HomomorphicCommitmentScheme<F>,
+{
+    /// Estimate the cost of a shuffle of `m` columns of `n` ciphertexts, both at least 2, without running anything.
+    ///
+    /// The proof size is exact for the scalar, commitment and ciphertext encodings of the schemes. The operation
+    /// counts are the ones of ElGamal encryption and Pedersen commitments, where a ciphertext term of a
+    /// multi-scalar multiplication counts as two points and a commitment to $n$ values as $n + 1$. The prover's work
+    /// grows as $2m^2 n$ from the products of every column of exponents with every column of ciphertexts in the
+    /// multi-exponentiation argument, and the verifier's as $4mn$, so a split with fewer, longer columns trades
+    /// prover time for a larger proof and more verifier work.
+    pub fn estimate_cost(m: usize, n: usize) -> Result<CostEstimate, CryptoError> {
+        if m < 2 || n < 2 {
+            return Err(CryptoError::InvalidShuffleStatement);
+        }
+
+        let scalar_size = F::zero().serialized_size();
+        let commitment_size = Comm::Commitment::zero().serialized_size();
+        let ciphertext_size = Enc::Ciphertext::zero().serialized_size();
+
+        // The commitments to A and B, the product argument with a zero argument over 2m + 1 diagonals, and the
+        // multi-exponentiation argument with 2m commitments and ciphertexts
+        let proof_size = (7 * m + 8) * commitment_size
+            + 2 * m * ciphertext_size
+            + (5 * n + 9) * scalar_size
+            + 11 * LENGTH_PREFIX_SIZE;
+
+        Ok(CostEstimate {
+            m,
+            n,
+            proof_size,
+            prover: OperationCount {
+                scalar_multiplications: 4 * m + 2,
+                multi_scalar_terms: (2 * m * m + 7 * m + 7) * n + 12 * m + 6,
+            },
+            verifier: OperationCount {
+                scalar_multiplications: 2,
+                multi_scalar_terms: (4 * m + 5) * n + 12 * m + 14,
+            },
+        })
+    }
+
+    /// Estimate the cost of every split of `number_of_ciphers` ciphertexts into `m` columns of `n`, by increasing
+    /// `m`. Empty if the number has no such split, e.g. a prime, in which case the deck has to be padded.
+    pub fn estimate_costs(number_of_ciphers: usize) -> Vec<CostEstimate> {
+        (2..=number_of_ciphers / 2)
+            .filter(|m| number_of_ciphers.is_multiple_of(*m))
+            .filter_map(|m| Self::estimate_cost(m, number_of_ciphers / m).ok())
+            .collect()
+    }
+}
@@ -1,3 +1,4 @@
+pub mod cost;
 pub mod proof;
 pub mod prover;
 mod tests;
@@ -5,11 +6,14 @@ mod tests;
 use crate::error::CryptoError;
 use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
 use crate::utils::permutation::Permutation;
+use crate::utils::scratch::Scratch;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::arguments::streaming::{for_each_chunk, CiphertextDigest, CiphertextSource};
+use crate::zkp::transcript::{absorb_serializable, Transcript};
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
 
 use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::CanonicalSerialize;
 use ark_std::rand::Rng;
 use digest::Digest;
 use std::marker::PhantomData;
@@ -36,26 +40,165 @@ where
     type Witness = Witness<'a, F>;
     type Proof = proof::Proof<F, Enc, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove_in_context<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
-        let prover = prover::Prover::new(&common_reference_string, &statement, &witness);
+        absorb_domain(fs_rng, domain, context)?;
+
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
         let proof = prover.prove(rng, fs_rng)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify_in_context<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<'a, F, Enc, Comm> ShuffleArgument<'a, F, Enc, Comm>
+where
+    F: Field,
+    Enc: HomomorphicEncryptionScheme<F>,
+    Comm: HomomorphicCommitmentScheme<F>,
+{
+    /// Verify `proof` against prepared parameters and statement, which can be reused across the proofs of many
+    /// rounds over the same deck. Accepts exactly the proofs that [`ArgumentOfKnowledge::verify`] accepts.
+    pub fn verify_prepared<T: Transcript>(
+        parameters: &PreparedParameters<'a, F, Enc, Comm>,
+        statement: &PreparedStatement<'a, F, Enc, T::Hash>,
+        proof: &proof::Proof<F, Enc, Comm>,
+        domain: &[u8],
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        absorb_domain(fs_rng, domain, None)?;
+
+        proof.verify_prepared(parameters, statement, fs_rng)
+    }
+}
+
+impl<'a, F, Enc, Comm> ShuffleArgument<'a, F, Enc, Comm>
+where
+    F: Field,
+    Enc: HomomorphicEncryptionScheme<F>,
+    Comm: HomomorphicCommitmentScheme<F>,
+{
+    /// Prove like [`ArgumentOfKnowledge::prove`], with the temporary vectors of the prover taken from `scratch`,
+    /// which the caller keeps across the proofs of many shuffles.
+    pub fn prove_with_scratch<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Parameters<'a, F, Enc, Comm>,
+        statement: &Statement<'a, F, Enc>,
+        witness: &Witness<'a, F>,
+        scratch: &mut Scratch<F>,
+        domain: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<proof::Proof<F, Enc, Comm>, CryptoError> {
+        absorb_domain(fs_rng, domain, None)?;
+
+        prover::Prover::new(common_reference_string, statement, witness)
+            .prove_with_scratch(rng, scratch, fs_rng)
+    }
+
+    /// Online phase of [`ArgumentOfKnowledge::prove`]: prove with the randomness and blinding commitments of a
+    /// [`prover::Precomputation`] made ahead of time for the dimensions of `statement`, so that no randomness is
+    /// drawn once the permutation and the ciphertexts are known. The proof verifies like any other.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_precomputed<T: Transcript>(
+        common_reference_string: &Parameters<'a, F, Enc, Comm>,
+        statement: &Statement<'a, F, Enc>,
+        witness: &Witness<'a, F>,
+        precomputation: prover::Precomputation<F, Enc, Comm>,
+        scratch: &mut Scratch<F>,
+        domain: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<proof::Proof<F, Enc, Comm>, CryptoError> {
+        absorb_domain(fs_rng, domain, None)?;
+
+        prover::Prover::new(common_reference_string, statement, witness).prove_precomputed(
+            precomputation,
+            scratch,
+            fs_rng,
+        )
+    }
+
+    /// Prove the shuffle of `m` columns of `n` ciphertexts read from `input_ciphers` into `shuffled_ciphers`, with
+    /// the [`prover::StreamingProver`]. The proof verifies with [`ArgumentOfKnowledge::verify`] against the
+    /// statement holding the same ciphertexts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_streaming<R, T, I, O>(
+        rng: &mut R,
+        common_reference_string: &Parameters<'a, F, Enc, Comm>,
+        m: usize,
+        n: usize,
+        input_ciphers: &I,
+        shuffled_ciphers: &O,
+        witness: &Witness<'a, F>,
+        domain: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<proof::Proof<F, Enc, Comm>, CryptoError>
+    where
+        R: Rng,
+        T: Transcript,
+        I: CiphertextSource<Enc::Ciphertext> + ?Sized,
+        O: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        absorb_domain(fs_rng, domain, None)?;
+
+        prover::StreamingProver::new(common_reference_string, m, n, witness).prove(
+            rng,
+            input_ciphers,
+            shuffled_ciphers,
+            fs_rng,
+        )
+    }
+
+    /// Verify a shuffle of `m` columns of `n` ciphertexts read from `input_ciphers` and `shuffled_ciphers`, `chunk`
+    /// at a time, with [`proof::Proof::verify_from_source`]. Accepts exactly the proofs that
+    /// [`ArgumentOfKnowledge::verify`] accepts for the statement holding the same ciphertexts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_streaming<T, I, O>(
+        common_reference_string: &Parameters<'a, F, Enc, Comm>,
+        m: usize,
+        n: usize,
+        input_ciphers: &I,
+        shuffled_ciphers: &O,
+        chunk: usize,
+        proof: &proof::Proof<F, Enc, Comm>,
+        domain: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError>
+    where
+        T: Transcript,
+        I: CiphertextSource<Enc::Ciphertext> + ?Sized,
+        O: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        absorb_domain(fs_rng, domain, None)?;
+
+        proof.verify_from_source(
+            common_reference_string,
+            m,
+            n,
+            input_ciphers,
+            shuffled_ciphers,
+            chunk,
+            fs_rng,
+        )
     }
 }
 
@@ -136,6 +279,103 @@ where
     }
 }
 
+/// [`Parameters`] prepared for verifying many proofs: the public key is validated once, and the public data that
+/// every transcript absorbs is serialized once.
+pub struct PreparedParameters<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) parameters: Parameters<'a, Scalar, Enc, Comm>,
+    pub(crate) public_data: Vec<u8>,
+}
+
+impl<'a, Scalar, Enc, Comm> PreparedParameters<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(parameters: &Parameters<'a, Scalar, Enc, Comm>) -> Result<Self, CryptoError> {
+        Enc::validate_public_key(parameters.public_key)?;
+
+        let mut public_data = Vec::new();
+        parameters
+            .public_key
+            .serialize(&mut public_data)
+            .and_then(|_| parameters.commit_key.serialize(&mut public_data))
+            .map_err(|_| CryptoError::InvalidSerialization)?;
+
+        Ok(Self {
+            parameters: Parameters::new(
+                parameters.encrypt_parameters,
+                parameters.public_key,
+                parameters.commit_key,
+                parameters.generator,
+            ),
+            public_data,
+        })
+    }
+
+    pub fn parameters(&self) -> &Parameters<'a, Scalar, Enc, Comm> {
+        &self.parameters
+    }
+}
+
+/// [`Statement`] prepared for verifying many proofs, e.g. a deck that every player of a game shuffles in turn
+/// from the same input: the dimensions and every ciphertext are validated once against the public key, and the digests of the ciphertexts
+/// that the transcripts absorb are computed once with `D`.
+pub struct PreparedStatement<'a, Scalar, Enc, D>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    D: Digest,
+{
+    pub(crate) statement: Statement<'a, Scalar, Enc>,
+    pub(crate) input_digest: Vec<u8>,
+    pub(crate) shuffled_digest: Vec<u8>,
+    _digest: PhantomData<D>,
+}
+
+impl<'a, Scalar, Enc, D> PreparedStatement<'a, Scalar, Enc, D>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    D: Digest,
+{
+    pub fn new(
+        public_key: &Enc::PublicKey,
+        statement: &Statement<'a, Scalar, Enc>,
+    ) -> Result<Self, CryptoError> {
+        statement.is_valid()?;
+
+        Ok(Self {
+            statement: Statement::new(
+                statement.input_ciphers,
+                statement.shuffled_ciphers,
+                statement.m,
+                statement.n,
+            ),
+            input_digest: validated_digest::<Scalar, Enc, D, _>(
+                public_key,
+                statement.input_ciphers,
+                statement.n,
+            )?,
+            shuffled_digest: validated_digest::<Scalar, Enc, D, _>(
+                public_key,
+                statement.shuffled_ciphers,
+                statement.n,
+            )?,
+            _digest: PhantomData,
+        })
+    }
+
+    pub fn statement(&self) -> &Statement<'a, Scalar, Enc> {
+        &self.statement
+    }
+}
+
 /// Witness
 pub struct Witness<'a, Scalar: Field> {
     pub permutation: &'a Permutation,
@@ -147,3 +387,61 @@ impl<'a, Scalar: Field> Witness<'a, Scalar> {
         Self { permutation, rho }
     }
 }
+
+/// Absorb the statement: the ciphertexts enter the transcript through their digests, which are computed one column
+/// of `n` at a time, see [`CiphertextSource`].
+fn absorb_statement<T, I, O, C>(
+    fs_rng: &mut T,
+    input_ciphers: &I,
+    shuffled_ciphers: &O,
+    m: usize,
+    n: usize,
+) -> Result<(), CryptoError>
+where
+    T: Transcript,
+    C: CanonicalSerialize,
+    I: CiphertextSource<C> + ?Sized,
+    O: CiphertextSource<C> + ?Sized,
+{
+    let input_digest = CiphertextDigest::<T::Hash>::of_source(input_ciphers, n)?;
+    let shuffled_digest = CiphertextDigest::<T::Hash>::of_source(shuffled_ciphers, n)?;
+
+    absorb_digests(fs_rng, &input_digest, &shuffled_digest, m, n);
+
+    Ok(())
+}
+
+/// Absorb the statement from the digests of its ciphertexts.
+fn absorb_digests<T: Transcript>(
+    fs_rng: &mut T,
+    input_digest: &[u8],
+    shuffled_digest: &[u8],
+    m: usize,
+    n: usize,
+) {
+    absorb_serializable!(fs_rng, input_digest, shuffled_digest, m as u32, n as u32);
+}
+
+/// Digest of the ciphertexts of `source`, read `chunk` at a time, each of which is validated along under
+/// `public_key`.
+fn validated_digest<Scalar, Enc, D, S>(
+    public_key: &Enc::PublicKey,
+    source: &S,
+    chunk: usize,
+) -> Result<Vec<u8>, CryptoError>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    D: Digest,
+    S: CiphertextSource<Enc::Ciphertext> + ?Sized,
+{
+    let mut digest = CiphertextDigest::<D>::new();
+    for_each_chunk(source, chunk, |_, ciphers| {
+        for ciphertext in &ciphers {
+            Enc::validate_ciphertext(public_key, ciphertext)?;
+        }
+        digest.update(&ciphers)
+    })?;
+
+    Ok(digest.finalize())
+}
@@ -1,17 +1,19 @@
-use super::{Parameters, Statement};
+use super::{
+    absorb_digests, validated_digest, Parameters, PreparedParameters, PreparedStatement, Statement,
+};
 
 use crate::error::CryptoError;
 use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
-use crate::utils::vector_arithmetic::dot_product;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::arguments::scalar_powers;
+use crate::zkp::arguments::streaming::{for_each_chunk, CiphertextSource};
 use crate::zkp::arguments::{matrix_elements_product as product_argument, multi_exponentiation};
+use crate::zkp::transcript::{
+    absorb_serializable, derive_challenge, AbsorbSerializable, Transcript,
+};
 
-use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::{to_bytes, Field, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Enc, Comm>
@@ -32,72 +34,160 @@ where
     Enc: HomomorphicEncryptionScheme<Scalar>,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Enc, Comm>,
         statement: &Statement<Scalar, Enc>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
-        statement.is_valid()?;
+        let statement =
+            PreparedStatement::<Scalar, Enc, T::Hash>::new(proof_parameters.public_key, statement)?;
+        let proof_parameters = PreparedParameters::new(proof_parameters)?;
+
+        self.verify_prepared(&proof_parameters, &statement, fs_rng)
+    }
+
+    /// Verify against parameters and a statement that have already been validated and digested once, see
+    /// [`PreparedStatement`].
+    pub fn verify_prepared<T: Transcript>(
+        &self,
+        prepared_parameters: &PreparedParameters<Scalar, Enc, Comm>,
+        prepared_statement: &PreparedStatement<Scalar, Enc, T::Hash>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let statement = &prepared_statement.statement;
+        let (m, n) = (statement.m, statement.n);
 
         fs_rng.absorb(&to_bytes![b"shuffle_argument"]?);
 
         // Public data
-        fs_rng.absorb(&to_bytes![
+        fs_rng.absorb(&prepared_parameters.public_data);
+
+        absorb_digests(
+            fs_rng,
+            &prepared_statement.input_digest,
+            &prepared_statement.shuffled_digest,
+            m,
+            n,
+        );
+
+        self.verify_rounds(
+            &prepared_parameters.parameters,
+            (m, n),
+            statement.input_ciphers,
+            statement.shuffled_ciphers,
+            m * n,
+            &prepared_statement.shuffled_digest,
+            fs_rng,
+        )
+    }
+
+    /// Verify against the `m` columns of `n` ciphertexts read from [`CiphertextSource`]s `chunk` at a time, for
+    /// verifiers that cannot hold the statement, such as auditors of a very large mix. Besides the proof and one
+    /// read of at most `chunk` ciphertexts, the verifier only keeps running digests, products and sums, so its memory
+    /// does not grow with the number of ciphertexts. The sources are read three times.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_from_source<T, I, O>(
+        &self,
+        proof_parameters: &Parameters<Scalar, Enc, Comm>,
+        m: usize,
+        n: usize,
+        input_ciphers: &I,
+        shuffled_ciphers: &O,
+        chunk: usize,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError>
+    where
+        T: Transcript,
+        I: CiphertextSource<Enc::Ciphertext> + ?Sized,
+        O: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        if m == 0 || n == 0 || input_ciphers.len() != m * n || shuffled_ciphers.len() != m * n {
+            return Err(CryptoError::InvalidShuffleStatement);
+        }
+
+        Enc::validate_public_key(proof_parameters.public_key)?;
+        let input_digest = validated_digest::<Scalar, Enc, T::Hash, _>(
+            proof_parameters.public_key,
+            input_ciphers,
+            chunk,
+        )?;
+        let shuffled_digest = validated_digest::<Scalar, Enc, T::Hash, _>(
+            proof_parameters.public_key,
+            shuffled_ciphers,
+            chunk,
+        )?;
+
+        fs_rng.absorb(&to_bytes![b"shuffle_argument"]?);
+
+        // Public data
+        absorb_serializable!(
+            fs_rng,
             proof_parameters.public_key,
             proof_parameters.commit_key
-        ]?);
-
-        // statement
-        fs_rng.absorb(
-            &to_bytes![
-                statement.input_ciphers,
-                statement.shuffled_ciphers,
-                statement.m as u32,
-                statement.n as u32
-            ]
-            .unwrap(),
         );
 
-        // round 1
-        fs_rng.absorb(&to_bytes![self.a_commits]?);
-        let x = Scalar::rand(fs_rng);
+        absorb_digests(fs_rng, &input_digest, &shuffled_digest, m, n);
 
-        let challenge_powers = scalar_powers(x, statement.m * statement.n)[1..].to_vec();
+        self.verify_rounds(
+            proof_parameters,
+            (m, n),
+            input_ciphers,
+            shuffled_ciphers,
+            chunk,
+            &shuffled_digest,
+            fs_rng,
+        )
+    }
+
+    /// The rounds of the verification, once the statement is absorbed.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_rounds<T, I, O>(
+        &self,
+        proof_parameters: &Parameters<Scalar, Enc, Comm>,
+        (m, n): (usize, usize),
+        input_ciphers: &I,
+        shuffled_ciphers: &O,
+        chunk: usize,
+        shuffled_digest: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError>
+    where
+        T: Transcript,
+        I: CiphertextSource<Enc::Ciphertext> + ?Sized,
+        O: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        // round 1
+        fs_rng.absorb_serializable(&self.a_commits);
+        let x = derive_challenge::<Scalar>(fs_rng);
 
         // round 2
-        fs_rng.absorb(&to_bytes![self.b_commits]?);
-        let y = Scalar::rand(fs_rng);
-        let z = Scalar::rand(fs_rng);
+        fs_rng.absorb_serializable(&self.b_commits);
+        let y = derive_challenge::<Scalar>(fs_rng);
+        let z = derive_challenge::<Scalar>(fs_rng);
 
         // PRODUCT ARGUMENT -------------------------------------------------------------
-        let z_vec = vec![-z; statement.n];
+        let z_vec = vec![-z; n];
         let zero = Scalar::zero();
         let single_neg_z_commit = Comm::commit(proof_parameters.commit_key, &z_vec, zero)?;
-        let neg_z_commit = vec![single_neg_z_commit; statement.m];
-
-        let c_d = self
-            .a_commits
-            .iter()
-            .zip(self.b_commits.iter())
-            .map(|(&a, &b)| a * y + b)
-            .collect::<Vec<_>>();
 
-        let verifier_side_expected_product = (1..=statement.n * statement.m)
-            .zip(challenge_powers.iter())
-            .map(|(i, x_pow_i)| y * Scalar::from(i as u64) + x_pow_i - z)
+        // prod_i (y i + x^i - z), with the powers of x computed along
+        let mut x_pow_i = Scalar::one();
+        let verifier_side_expected_product = (1..=m * n)
+            .map(|i| {
+                x_pow_i *= x;
+                y * Scalar::from(i as u64) + x_pow_i - z
+            })
             .product();
 
-        let product_argument_parameters = product_argument::Parameters::new(
-            statement.m,
-            statement.n,
-            proof_parameters.commit_key,
-        );
+        let product_argument_parameters =
+            product_argument::Parameters::new(m, n, proof_parameters.commit_key);
 
-        let commitments_to_a = c_d
+        let commitments_to_a = self
+            .a_commits
             .iter()
-            .zip(neg_z_commit.iter())
-            .map(|(&d_commit, &z_commit)| d_commit + z_commit)
+            .zip(self.b_commits.iter())
+            .map(|(&a, &b)| a * y + b + single_neg_z_commit)
             .collect::<Vec<_>>();
         let product_argument_statement =
             product_argument::Statement::new(&commitments_to_a, verifier_side_expected_product);
@@ -116,20 +206,31 @@ where
             proof_parameters.generator,
         );
 
-        let shuffled_chunks = statement
-            .shuffled_ciphers
-            .chunks(statement.n)
-            .map(|c| c.to_vec())
-            .collect::<Vec<_>>();
-
-        let product = dot_product(&challenge_powers, statement.input_ciphers).unwrap();
-
-        let multi_exp_statement =
-            multi_exponentiation::Statement::new(&shuffled_chunks, product, &self.b_commits);
-
-        self.multi_exp_proof
-            .verify(&multi_exp_parameters, &multi_exp_statement, fs_rng)?;
-
-        Ok(())
+        // sum_i x^i input_i, one multi-scalar multiplication per read
+        let mut product = Enc::Ciphertext::zero();
+        let mut x_pow_i = Scalar::one();
+        for_each_chunk(input_ciphers, chunk, |_, ciphers| {
+            let powers = ciphers
+                .iter()
+                .map(|_| {
+                    x_pow_i *= x;
+                    x_pow_i
+                })
+                .collect::<Vec<_>>();
+            product = product + Enc::multi_scalar_mul(&powers, &ciphers)?;
+            Ok(())
+        })?;
+
+        // The shuffled ciphertexts of the statement are the ones of the multi-exponentiation
+        self.multi_exp_proof.verify_from_source(
+            &multi_exp_parameters,
+            &self.b_commits,
+            product,
+            shuffled_ciphers,
+            (m, n),
+            chunk,
+            shuffled_digest,
+            fs_rng,
+        )
     }
 }
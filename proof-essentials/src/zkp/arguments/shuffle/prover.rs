@@ -1,17 +1,18 @@
-use super::{proof::Proof, Parameters, Statement, Witness};
+use super::{absorb_statement, proof::Proof, Parameters, Statement, Witness};
 
 use crate::error::CryptoError;
 use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
 use crate::utils::rand::sample_vector;
-use crate::utils::vector_arithmetic::{dot_product, reshape};
+use crate::utils::scratch::Scratch;
+use crate::vector_commitment::matrix::{Matrix, MatrixCommitment};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::arguments::scalar_powers;
+use crate::zkp::arguments::streaming::CiphertextSource;
 use crate::zkp::arguments::{matrix_elements_product as product_argument, multi_exponentiation};
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::transcript::{
+    absorb_serializable, derive_challenge, AbsorbSerializable, Transcript,
+};
 
 use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
-use digest::Digest;
 use rand::Rng;
 
 pub struct Prover<'a, Scalar, Enc, Comm>
@@ -44,76 +45,225 @@ where
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
+    pub fn prove<R: Rng, T: Transcript>(
         &self,
         rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
-        fs_rng.absorb(&to_bytes![b"shuffle_argument"]?);
+        self.prove_with_scratch(rng, &mut Scratch::new(), fs_rng)
+    }
 
-        let r: Vec<Scalar> = sample_vector(rng, self.statement.m);
+    /// Online phase of [`Prover::prove_with_scratch`], with the randomness and blinding commitments of
+    /// `precomputation`, which must have been made for the dimensions of the statement.
+    pub fn prove_precomputed<T: Transcript>(
+        &self,
+        precomputation: Precomputation<Scalar, Enc, Comm>,
+        scratch: &mut Scratch<Scalar>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
+        StreamingProver::new(
+            self.parameters,
+            self.statement.m,
+            self.statement.n,
+            self.witness,
+        )
+        .prove_precomputed(
+            precomputation,
+            self.statement.input_ciphers,
+            self.statement.shuffled_ciphers,
+            scratch,
+            fs_rng,
+        )
+    }
 
-        let index = (1..=self.statement.m * self.statement.n)
-            .map(|x| Scalar::from(x as u64))
-            .collect::<Vec<_>>();
+    /// Prove with the temporary matrices taken from `scratch`, and given back to it once done.
+    pub fn prove_with_scratch<R: Rng, T: Transcript>(
+        &self,
+        rng: &mut R,
+        scratch: &mut Scratch<Scalar>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
+        StreamingProver::new(
+            self.parameters,
+            self.statement.m,
+            self.statement.n,
+            self.witness,
+        )
+        .prove_with_scratch(
+            rng,
+            self.statement.input_ciphers,
+            self.statement.shuffled_ciphers,
+            scratch,
+            fs_rng,
+        )
+    }
+}
 
-        let a = self.witness.permutation.permute_array(&index);
+/// Prover of a shuffle of `m` columns of `n` ciphertexts that are read from [`CiphertextSource`]s, such as a file
+/// of [`SerializedCiphertexts`](crate::zkp::arguments::streaming::SerializedCiphertexts), instead of being held in
+/// memory. The ciphertexts are read in passes of one column at a time, and the proof is the same as the one of
+/// [`Prover`] for the same statement.
+///
+/// The scalar matrices of the witness, which are several times smaller than the ciphertexts, are still held whole.
+pub struct StreamingProver<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    parameters: &'a Parameters<'a, Scalar, Enc, Comm>,
+    m: usize,
+    n: usize,
+    witness: &'a Witness<'a, Scalar>,
+}
 
-        let a_chunks = reshape(&a, self.statement.m, self.statement.n)?;
+impl<'a, Scalar, Enc, Comm> StreamingProver<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &'a Parameters<'a, Scalar, Enc, Comm>,
+        m: usize,
+        n: usize,
+        witness: &'a Witness<'a, Scalar>,
+    ) -> Self {
+        Self {
+            parameters,
+            m,
+            n,
+            witness,
+        }
+    }
 
-        let a_commits = a_chunks
-            .iter()
-            .zip(r.iter())
-            .map(|(chunk, &r)| Comm::commit(self.parameters.commit_key, chunk, r))
-            .collect::<Result<Vec<_>, CryptoError>>()?;
+    pub fn prove<R, T, I, O>(
+        &self,
+        rng: &mut R,
+        input_ciphers: &I,
+        shuffled_ciphers: &O,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError>
+    where
+        R: Rng,
+        T: Transcript,
+        I: CiphertextSource<Enc::Ciphertext> + ?Sized,
+        O: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        self.prove_with_scratch(
+            rng,
+            input_ciphers,
+            shuffled_ciphers,
+            &mut Scratch::new(),
+            fs_rng,
+        )
+    }
+
+    /// Prove with the temporary matrices and challenge powers taken from `scratch`, and given back to it once done,
+    /// so that the proofs of many shuffles of the same size reuse the same buffers.
+    pub fn prove_with_scratch<R, T, I, O>(
+        &self,
+        rng: &mut R,
+        input_ciphers: &I,
+        shuffled_ciphers: &O,
+        scratch: &mut Scratch<Scalar>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError>
+    where
+        R: Rng,
+        T: Transcript,
+        I: CiphertextSource<Enc::Ciphertext> + ?Sized,
+        O: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        let precomputation = Precomputation::new(rng, self.parameters, self.m, self.n)?;
+
+        self.prove_precomputed(
+            precomputation,
+            input_ciphers,
+            shuffled_ciphers,
+            scratch,
+            fs_rng,
+        )
+    }
+
+    /// Online phase of [`StreamingProver::prove_with_scratch`], with the randomness and blinding commitments of
+    /// `precomputation`, which must have been made for the same `m` and `n`. It draws no randomness, and its work
+    /// is the part of the proof that depends on the permutation and the ciphertexts.
+    pub fn prove_precomputed<T, I, O>(
+        &self,
+        precomputation: Precomputation<Scalar, Enc, Comm>,
+        input_ciphers: &I,
+        shuffled_ciphers: &O,
+        scratch: &mut Scratch<Scalar>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError>
+    where
+        T: Transcript,
+        I: CiphertextSource<Enc::Ciphertext> + ?Sized,
+        O: CiphertextSource<Enc::Ciphertext> + ?Sized,
+    {
+        let (m, n) = (self.m, self.n);
+        if input_ciphers.len() != m * n
+            || shuffled_ciphers.len() != m * n
+            || self.witness.permutation.size != m * n
+            || self.witness.rho.len() != m * n
+            || (precomputation.m, precomputation.n) != (m, n)
+        {
+            return Err(CryptoError::InvalidShuffleStatement);
+        }
+
+        fs_rng.absorb(&to_bytes![b"shuffle_argument"]?);
+
+        let Precomputation {
+            r,
+            s,
+            product_argument,
+            multi_exp,
+            ..
+        } = precomputation;
+
+        // Column j of A holds the images 1 + pi(jn + i) of the permutation
+        let a_matrix = self.permuted_matrix(|pi_i| Scalar::from(pi_i as u64 + 1), scratch)?;
+        let a_commits = MatrixCommitment::<Scalar, Comm>::commit_matrix(
+            self.parameters.commit_key,
+            &a_matrix,
+            &r,
+        )?;
 
         // Public data
-        fs_rng.absorb(&to_bytes![
+        absorb_serializable!(
+            fs_rng,
             self.parameters.public_key,
             self.parameters.commit_key
-        ]?);
-
-        // statement
-        fs_rng.absorb(
-            &to_bytes![
-                self.statement.input_ciphers,
-                self.statement.shuffled_ciphers,
-                self.statement.m as u32,
-                self.statement.n as u32
-            ]
-            .unwrap(),
         );
 
-        // round 1
-        fs_rng.absorb(&to_bytes![a_commits]?);
-        let x = Scalar::rand(fs_rng);
-
-        let challenge_powers = scalar_powers(x, self.witness.permutation.size)[1..].to_vec();
-
-        let b = self.witness.permutation.permute_array(&challenge_powers);
-        let s: Vec<Scalar> = sample_vector(rng, self.statement.m);
+        absorb_statement(fs_rng, input_ciphers, shuffled_ciphers, m, n)?;
 
-        let b_chunks = b
-            .chunks(self.statement.n)
-            .map(|c| c.to_vec())
-            .collect::<Vec<_>>();
-
-        let b_commits = b_chunks
-            .iter()
-            .zip(s.iter())
-            .map(|(b, &s)| Comm::commit(self.parameters.commit_key, b, s))
-            .collect::<Result<Vec<_>, CryptoError>>()?;
+        // round 1
+        fs_rng.absorb_serializable(&a_commits);
+        let x = derive_challenge::<Scalar>(fs_rng);
+
+        let b_matrix = {
+            let mut challenge_powers = scratch.take(m * n + 1);
+            challenge_powers.push(Scalar::one());
+            for i in 0..m * n {
+                challenge_powers.push(challenge_powers[i] * x);
+            }
+            let b_matrix = self.permuted_matrix(|pi_i| challenge_powers[pi_i + 1], scratch);
+            scratch.recycle(challenge_powers);
+            b_matrix?
+        };
+        let b_commits = MatrixCommitment::<Scalar, Comm>::commit_matrix(
+            self.parameters.commit_key,
+            &b_matrix,
+            &s,
+        )?;
 
         //round 2
-        fs_rng.absorb(&to_bytes![b_commits]?);
-        let y = Scalar::rand(fs_rng);
-        let z = Scalar::rand(fs_rng);
+        fs_rng.absorb_serializable(&b_commits);
+        let y = derive_challenge::<Scalar>(fs_rng);
+        let z = derive_challenge::<Scalar>(fs_rng);
 
-        let d = a
-            .iter()
-            .zip(b.iter())
-            .map(|(&a, &b)| y * a + b)
-            .collect::<Vec<_>>();
         let t = r
             .iter()
             .zip(s.iter())
@@ -121,30 +271,34 @@ where
             .collect::<Vec<_>>();
 
         // Engage in product argument ---------------------------------------------------------------------
-        let product_argument_parameters = product_argument::Parameters::new(
-            self.statement.m,
-            self.statement.n,
-            self.parameters.commit_key,
-        );
-
-        let d_minus_z = d.iter().map(|&x| x - z).collect::<Vec<Scalar>>();
-        let d_minus_z_chunks = d_minus_z
-            .chunks(self.statement.n)
-            .map(|c| c.to_vec())
-            .collect::<Vec<_>>();
+        let product_argument_parameters =
+            product_argument::Parameters::new(m, n, self.parameters.commit_key);
+
+        // D - Z is computed in place of A
+        let mut d_minus_z_columns = a_matrix.into_columns();
+        for (d_j, b_j) in d_minus_z_columns.iter_mut().zip(b_matrix.columns().iter()) {
+            for (d, &b) in d_j.iter_mut().zip(b_j.iter()) {
+                *d = y * *d + b - z;
+            }
+        }
+        let d_minus_z_matrix = Matrix::from_columns(d_minus_z_columns)?;
 
-        let d_minus_z_commits = d_minus_z_chunks
+        // The commitments to D - Z follow from the ones to A and B as y C_A + C_B + com(-z; 0), like on the verifier
+        // side, instead of committing to the matrix again
+        let neg_z_commit = Comm::commit(self.parameters.commit_key, &vec![-z; n], Scalar::zero())?;
+        let d_minus_z_commits = a_commits
             .iter()
-            .zip(t.iter())
-            .map(|(chunk, &random)| Comm::commit(self.parameters.commit_key, chunk, random))
-            .collect::<Result<Vec<_>, CryptoError>>()?;
+            .zip(b_commits.iter())
+            .map(|(&a, &b)| a * y + b + neg_z_commit)
+            .collect::<Vec<_>>();
 
-        let claimed_product = d_minus_z.iter().product();
+        let claimed_product = d_minus_z_matrix.columns().iter().flatten().product();
 
         let product_argument_statement =
             product_argument::Statement::new(&d_minus_z_commits, claimed_product);
 
-        let product_argument_witness = product_argument::Witness::new(&d_minus_z_chunks, &t);
+        let product_argument_witness =
+            product_argument::Witness::new(d_minus_z_matrix.columns(), &t);
 
         let product_argument_prover = product_argument::prover::Prover::new(
             &product_argument_parameters,
@@ -152,7 +306,9 @@ where
             &product_argument_witness,
         );
 
-        let product_argument_proof = product_argument_prover.prove(rng, fs_rng)?;
+        let product_argument_proof =
+            product_argument_prover.prove_precomputed(product_argument, fs_rng)?;
+        scratch.recycle_all(d_minus_z_matrix.into_columns());
 
         // Engage in multi-exponentation argument ----------------------------------------------------------
         let multi_exp_parameters = multi_exponentiation::Parameters::new(
@@ -162,10 +318,23 @@ where
             self.parameters.generator,
         );
 
-        let minus_rho_witness = self.witness.rho.iter().map(|&x| -x).collect::<Vec<_>>();
-        let rho = dot_product(&minus_rho_witness, &b)?;
+        // rho = -<rho_witness, b> and temp = <b, shuffled_ciphers>, one column at a time
+        let mut rho = Scalar::zero();
+        let mut temp = Enc::Ciphertext::zero();
+        for (j, (b_j, rho_j)) in b_matrix
+            .columns()
+            .iter()
+            .zip(self.witness.rho.chunks(n))
+            .enumerate()
+        {
+            rho -= b_j
+                .iter()
+                .zip(rho_j.iter())
+                .map(|(&b, &rho)| b * rho)
+                .sum::<Scalar>();
+            temp = temp + Enc::multi_scalar_mul(b_j, &shuffled_ciphers.read(j * n, n)?)?;
+        }
 
-        let temp = dot_product(&b, self.statement.shuffled_ciphers)?;
         let zero_cipher = Enc::Plaintext::zero();
         let masking_cipher = Enc::encrypt(
             self.parameters.encrypt_parameters,
@@ -176,26 +345,20 @@ where
 
         let product = temp + masking_cipher;
 
-        let shuffled_chunks = self
-            .statement
-            .shuffled_ciphers
-            .chunks(self.statement.n)
-            .map(|c| c.to_vec())
-            .collect::<Vec<_>>();
-
-        let multi_exp_statement =
-            multi_exponentiation::Statement::new(&shuffled_chunks, product, &b_commits);
+        let multi_exp_witness = multi_exponentiation::Witness::new(b_matrix.columns(), &s, rho);
 
-        let multi_exp_witness = multi_exponentiation::Witness::new(&b_chunks, &s, rho);
-
-        let multi_exp_proof = multi_exponentiation::MultiExponentiation::prove(
-            rng,
+        let multi_exp_proof = multi_exponentiation::prover::Prover::prove_precomputed_from_source(
+            multi_exp,
             &multi_exp_parameters,
-            &multi_exp_statement,
+            &b_commits,
+            product,
+            shuffled_ciphers,
             &multi_exp_witness,
             fs_rng,
         )?;
 
+        scratch.recycle_all(b_matrix.into_columns());
+
         // Produce proof
         let proof = Proof {
             a_commits,
@@ -206,4 +369,107 @@ where
 
         Ok(proof)
     }
+
+    /// Matrix whose column $j$ holds $f(\pi(jn + i))$ for the permutation $\pi$ of the witness.
+    fn permuted_matrix<F: Fn(usize) -> Scalar>(
+        &self,
+        f: F,
+        scratch: &mut Scratch<Scalar>,
+    ) -> Result<Matrix<Scalar>, CryptoError> {
+        Matrix::from_columns(
+            self.witness
+                .permutation
+                .mapping
+                .chunks(self.n)
+                .map(|column| {
+                    let mut entries = scratch.take(self.n);
+                    entries.extend(column.iter().map(|&pi_i| f(pi_i)));
+                    entries
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Offline phase of a shuffle proof of `m` columns of `n` ciphertexts: all the randomness of the prover and of its
+/// sub-arguments, with the commitments and encryptions that blind them, none of which depend on the permutation or
+/// the ciphertexts. It can be made in idle time, e.g. while the previous round of a game is played, and then handed
+/// to [`StreamingProver::prove_precomputed`] once the shuffle is known.
+///
+/// The offline phase takes all but 4 of the single scalar multiplications of the prover, those of the encryptions
+/// that blind the multi-exponentiation argument, and about $5n + 4m$ commitment terms. The online phase keeps the
+/// commitments to the witness and the $2m^2 n$ terms of the diagonals of the multi-exponentiation argument, which
+/// dominate the prover's work for large shuffles, see
+/// [`ShuffleArgument::estimate_cost`](super::ShuffleArgument::estimate_cost).
+///
+/// A precomputation blinds a single proof, so it is consumed by it: reusing its randomness for two shuffles would
+/// reveal the difference of their witnesses.
+pub struct Precomputation<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    m: usize,
+    n: usize,
+    r: Vec<Scalar>,
+    s: Vec<Scalar>,
+    product_argument: product_argument::prover::Precomputation<Scalar, Comm>,
+    multi_exp: multi_exponentiation::prover::Precomputation<Scalar, Enc, Comm>,
+}
+
+impl<Scalar, Enc, Comm> Precomputation<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    /// Precompute a proof of `m` columns of `n` ciphertexts, both at least 2.
+    pub fn new<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<Scalar, Enc, Comm>,
+        m: usize,
+        n: usize,
+    ) -> Result<Self, CryptoError> {
+        if m < 2 || n < 2 {
+            return Err(CryptoError::InvalidShuffleStatement);
+        }
+
+        let r: Vec<Scalar> = sample_vector(rng, m);
+        let s: Vec<Scalar> = sample_vector(rng, m);
+
+        let product_argument_parameters =
+            product_argument::Parameters::new(m, n, parameters.commit_key);
+        let multi_exp_parameters = multi_exponentiation::Parameters::new(
+            parameters.encrypt_parameters,
+            parameters.public_key,
+            parameters.commit_key,
+            parameters.generator,
+        );
+
+        Ok(Self {
+            m,
+            n,
+            r,
+            s,
+            product_argument: product_argument::prover::Precomputation::new(
+                rng,
+                &product_argument_parameters,
+            )?,
+            multi_exp: multi_exponentiation::prover::Precomputation::new(
+                rng,
+                &multi_exp_parameters,
+                m,
+                n,
+            )?,
+        })
+    }
+
+    pub fn m(&self) -> usize {
+        self.m
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
 }
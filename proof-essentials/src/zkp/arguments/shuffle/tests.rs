@@ -1,6 +1,6 @@
 #[cfg(test)]
-
 mod test {
+    use crate::error::CryptoError;
     use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
     use crate::utils::permutation::Permutation;
     use crate::utils::rand::sample_vector;
@@ -70,27 +70,853 @@ mod test {
         let witness = Witness::new(&permutation, &masking_factors);
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        let valid_proof =
-            ShuffleArgument::prove(rng, &parameters, &statement, &witness, &mut fs_rng).unwrap();
+        let valid_proof = ShuffleArgument::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
             Ok(()),
-            ShuffleArgument::verify(&parameters, &statement, &valid_proof, &mut fs_rng)
+            ShuffleArgument::verify(
+                &parameters,
+                &statement,
+                &valid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
 
         let new_permutation = Permutation::new(rng, number_of_ciphers);
         let bad_witness = Witness::new(&new_permutation, &masking_factors);
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        let invalid_proof =
-            ShuffleArgument::prove(rng, &parameters, &statement, &bad_witness, &mut fs_rng)
-                .unwrap();
+        let invalid_proof = ShuffleArgument::prove(
+            rng,
+            &parameters,
+            &statement,
+            &bad_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_ne!(
             Ok(()),
-            ShuffleArgument::verify(&parameters, &statement, &invalid_proof, &mut fs_rng)
+            ShuffleArgument::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
+        );
+    }
+
+    #[test]
+    fn shuffle_argument_with_el_gamal_commitments() {
+        use crate::vector_commitment::el_gamal::ElGamalCommitment;
+
+        type Comm = ElGamalCommitment<Curve>;
+        type ShuffleArgument<'a> = shuffle::ShuffleArgument<'a, Scalar, Enc, Comm>;
+        type Parameters<'a> = shuffle::Parameters<'a, Scalar, Enc, Comm>;
+
+        let m = 2;
+        let n = 5;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = ShuffleArgument::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            ShuffleArgument::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn proof_in_context() {
+        use crate::zkp::transcript::{AuditedTranscript, TranscriptEvent};
+        use ark_ff::to_bytes;
+
+        let m = 2;
+        let n = 4;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        let context: &[u8] = b"game 7, round 3";
+        let mut transcript = AuditedTranscript::new(FS::from_seed(b"Initialised with some input"));
+        let proof = ShuffleArgument::prove_in_context(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            Some(context),
+            &mut transcript,
+        )
+        .unwrap();
+
+        // The domain comes first and the context right after it, before any message of the argument
+        let domain: &[u8] = b"Test Domain";
+        let events = &transcript.log().events;
+        assert_eq!(
+            events[0],
+            TranscriptEvent::Message {
+                label: b"absorb".to_vec(),
+                message: to_bytes![domain.len() as u64, domain].unwrap()
+            }
+        );
+        assert_eq!(
+            events[1],
+            TranscriptEvent::Message {
+                label: b"absorb".to_vec(),
+                message: to_bytes![b"context", context.len() as u64, context].unwrap()
+            }
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            ShuffleArgument::verify_in_context(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                Some(context),
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        for other_context in [None, Some(&b"game 7, round 4"[..])] {
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert!(ShuffleArgument::verify_in_context(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                other_context,
+                &mut fs_rng
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn poseidon_transcript() {
+        use crate::utils::poseidon;
+        use crate::zkp::transcript::PoseidonTranscript;
+
+        let m = 2;
+        let n = 4;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        // The challenges are squeezed from a sponge over the base field of the curve
+        let poseidon_parameters = poseidon::Parameters::<starknet_curve::Fq>::new(4).unwrap();
+        let transcript = || PoseidonTranscript::new(&poseidon_parameters, b"Shuffle Test");
+
+        let proof = ShuffleArgument::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut transcript(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            ShuffleArgument::verify(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut transcript()
+            ),
+            Ok(())
+        );
+
+        let other_deck: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let other_statement = Statement::new(&ciphers, &other_deck, m, n);
+        assert!(ShuffleArgument::verify(
+            &parameters,
+            &other_statement,
+            &proof,
+            b"Test Domain",
+            &mut transcript()
+        )
+        .is_err());
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert!(ShuffleArgument::verify(
+            &parameters,
+            &statement,
+            &proof,
+            b"Test Domain",
+            &mut fs_rng
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn streaming_prover() {
+        use crate::zkp::arguments::streaming::{
+            write_ciphertexts, CiphertextSource, SerializedCiphertexts,
+        };
+        use std::io::Cursor;
+
+        let m = 4;
+        let n = 6;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+
+        // The mix writes its output straight to a file, here an in-memory one
+        let mut output = Vec::new();
+        write_ciphertexts(
+            &mut output,
+            permutation
+                .permute_array(&ciphers)
+                .iter()
+                .zip(masking_factors.iter())
+                .map(|(&cipher, masking_factor)| {
+                    cipher
+                        + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                            .unwrap()
+                }),
+        )
+        .unwrap();
+        let shuffled_source =
+            SerializedCiphertexts::<Ciphertext, _>::new(Cursor::new(output), number_of_ciphers);
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = ShuffleArgument::prove_streaming(
+            rng,
+            &parameters,
+            m,
+            n,
+            &ciphers,
+            &shuffled_source,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let shuffled_deck = shuffled_source.read(0, number_of_ciphers).unwrap();
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            ShuffleArgument::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+
+        // Sources of the wrong length are rejected before anything is read
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            ShuffleArgument::prove_streaming(
+                rng,
+                &parameters,
+                m,
+                n,
+                &ciphers[1..].to_vec(),
+                &shuffled_source,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .err(),
+            Some(CryptoError::InvalidShuffleStatement)
+        );
+    }
+
+    #[test]
+    fn prepared_verification() {
+        let m = 2;
+        let n = 5;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        let prepared_parameters = shuffle::PreparedParameters::new(&parameters).unwrap();
+        let prepared_statement =
+            shuffle::PreparedStatement::<_, _, Blake2s>::new(&pk, &statement).unwrap();
+
+        // Several proofs of the same shuffle, verified against the same preparation
+        for _ in 0..3 {
+            let proof = ShuffleArgument::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            )
+            .unwrap();
+
+            assert_eq!(
+                ShuffleArgument::verify_prepared(
+                    &prepared_parameters,
+                    &prepared_statement,
+                    &proof,
+                    b"Test Domain",
+                    &mut FS::from_seed(b"Initialised with some input"),
+                ),
+                Ok(())
+            );
+            assert_ne!(
+                ShuffleArgument::verify_prepared(
+                    &prepared_parameters,
+                    &prepared_statement,
+                    &proof,
+                    b"Other Domain",
+                    &mut FS::from_seed(b"Initialised with some input"),
+                ),
+                Ok(())
+            );
+        }
+
+        // A proof of another shuffle of the same input does not verify against the prepared statement
+        let other_permutation = Permutation::new(rng, number_of_ciphers);
+        let other_deck = other_permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let other_statement = Statement::new(&ciphers, &other_deck, m, n);
+        let other_proof = ShuffleArgument::prove(
+            rng,
+            &parameters,
+            &other_statement,
+            &Witness::new(&other_permutation, &masking_factors),
+            b"Test Domain",
+            &mut FS::from_seed(b"Initialised with some input"),
+        )
+        .unwrap();
+        assert_ne!(
+            ShuffleArgument::verify_prepared(
+                &prepared_parameters,
+                &prepared_statement,
+                &other_proof,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            ),
+            Ok(())
+        );
+
+        let truncated_deck = shuffled_deck[1..].to_vec();
+        let invalid_statement = Statement::new(&ciphers, &truncated_deck, m, n);
+        assert_eq!(
+            shuffle::PreparedStatement::<_, _, Blake2s>::new(&pk, &invalid_statement).err(),
+            Some(CryptoError::InvalidShuffleStatement)
+        );
+    }
+
+    #[test]
+    fn cost_estimate() {
+        use crate::utils::msm::{Plain, ScalarMul};
+        use crate::utils::scratch::Scratch;
+        use ark_serialize::CanonicalSerialize;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static MULTIPLICATIONS: AtomicUsize = AtomicUsize::new(0);
+        static MULTI_SCALAR_TERMS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counting;
+
+        impl ScalarMul<Curve> for Counting {
+            fn mul(base: &starknet_curve::Affine, scalar: &Scalar) -> Curve {
+                MULTIPLICATIONS.fetch_add(1, Ordering::Relaxed);
+                <Plain as ScalarMul<Curve>>::mul(base, scalar)
+            }
+
+            fn multi_scalar_mul(
+                bases: &[starknet_curve::Affine],
+                scalars: &[Scalar],
+            ) -> Result<Curve, CryptoError> {
+                MULTI_SCALAR_TERMS.fetch_add(bases.len(), Ordering::Relaxed);
+                <Plain as ScalarMul<Curve>>::multi_scalar_mul(bases, scalars)
+            }
+        }
+
+        type Enc = el_gamal::ElGamal<Curve, Counting>;
+        type Comm = pedersen::PedersenCommitment<Curve, Counting>;
+        type ShuffleArgument<'a> = shuffle::ShuffleArgument<'a, Scalar, Enc, Comm>;
+
+        let counts = || shuffle::cost::OperationCount {
+            scalar_multiplications: MULTIPLICATIONS.swap(0, Ordering::Relaxed),
+            multi_scalar_terms: MULTI_SCALAR_TERMS.swap(0, Ordering::Relaxed),
+        };
+
+        let rng = &mut thread_rng();
+        for (m, n) in [(2, 2), (2, 5), (3, 4), (6, 3)] {
+            let estimate = ShuffleArgument::estimate_cost(m, n).unwrap();
+
+            let encrypt_parameters = Enc::setup(rng).unwrap();
+            let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+            let commit_key = Comm::setup(rng, n);
+            let generator = Generator::rand(rng);
+
+            let ciphers: Vec<Ciphertext> = sample_vector(rng, m * n);
+            let masking_factors: Vec<Scalar> = sample_vector(rng, m * n);
+            let permutation = Permutation::new(rng, m * n);
+            let shuffled_deck = permutation
+                .permute_array(&ciphers)
+                .iter()
+                .zip(masking_factors.iter())
+                .map(|(&cipher, masking_factor)| {
+                    cipher
+                        + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                            .unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            let parameters =
+                shuffle::Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+            let statement = shuffle::Statement::new(&ciphers, &shuffled_deck, m, n);
+            let witness = Witness::new(&permutation, &masking_factors);
+
+            counts();
+            let proof = ShuffleArgument::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            )
+            .unwrap();
+            assert_eq!(counts(), estimate.prover);
+            assert_eq!(proof.serialized_size(), estimate.proof_size);
+
+            assert_eq!(
+                ShuffleArgument::verify(
+                    &parameters,
+                    &statement,
+                    &proof,
+                    b"Test Domain",
+                    &mut FS::from_seed(b"Initialised with some input"),
+                ),
+                Ok(())
+            );
+            assert_eq!(counts(), estimate.verifier);
+
+            // The offline phase takes all but the blinding encryptions that involve the witness
+            let precomputation =
+                shuffle::prover::Precomputation::new(rng, &parameters, m, n).unwrap();
+            let offline = counts();
+            ShuffleArgument::prove_precomputed(
+                &parameters,
+                &statement,
+                &witness,
+                precomputation,
+                &mut Scratch::new(),
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            )
+            .unwrap();
+            let online = counts();
+            assert_eq!(online.scalar_multiplications, 4);
+            assert_eq!(
+                offline.scalar_multiplications + online.scalar_multiplications,
+                estimate.prover.scalar_multiplications
+            );
+            assert_eq!(
+                offline.multi_scalar_terms + online.multi_scalar_terms,
+                estimate.prover.multi_scalar_terms
+            );
+        }
+
+        assert_eq!(
+            ShuffleArgument::estimate_cost(1, 8),
+            Err(CryptoError::InvalidShuffleStatement)
+        );
+        let splits = ShuffleArgument::estimate_costs(12)
+            .iter()
+            .map(|estimate| (estimate.m, estimate.n))
+            .collect::<Vec<_>>();
+        assert_eq!(splits, vec![(2, 6), (3, 4), (4, 3), (6, 2)]);
+        assert!(ShuffleArgument::estimate_costs(13).is_empty());
+    }
+
+    #[test]
+    fn streaming_verifier() {
+        use crate::zkp::arguments::streaming::{write_ciphertexts, SerializedCiphertexts};
+        use std::io::Cursor;
+
+        let m = 3;
+        let n = 4;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        let proof = ShuffleArgument::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut FS::from_seed(b"Initialised with some input"),
+        )
+        .unwrap();
+
+        // The auditor reads both decks from files, here in-memory ones
+        let serialized = |deck: &Vec<Ciphertext>| {
+            let mut bytes = Vec::new();
+            write_ciphertexts(&mut bytes, deck.iter().copied()).unwrap();
+            SerializedCiphertexts::<Ciphertext, _>::new(Cursor::new(bytes), deck.len())
+        };
+        let input_source = serialized(&ciphers);
+        let shuffled_source = serialized(&shuffled_deck);
+
+        for chunk in [1, 5, n, number_of_ciphers] {
+            assert_eq!(
+                ShuffleArgument::verify_streaming(
+                    &parameters,
+                    m,
+                    n,
+                    &input_source,
+                    &shuffled_source,
+                    chunk,
+                    &proof,
+                    b"Test Domain",
+                    &mut FS::from_seed(b"Initialised with some input"),
+                ),
+                Ok(())
+            );
+        }
+
+        let mut tampered_deck = shuffled_deck.clone();
+        tampered_deck.swap(0, 1);
+        assert_ne!(
+            ShuffleArgument::verify_streaming(
+                &parameters,
+                m,
+                n,
+                &input_source,
+                &serialized(&tampered_deck),
+                n,
+                &proof,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            ShuffleArgument::verify_streaming(
+                &parameters,
+                n,
+                m + 1,
+                &input_source,
+                &shuffled_source,
+                n,
+                &proof,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            ),
+            Err(CryptoError::InvalidShuffleStatement)
+        );
+    }
+
+    #[test]
+    fn prove_with_scratch() {
+        use crate::utils::scratch::Scratch;
+
+        let m = 3;
+        let n = 4;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+
+        let mut scratch = Scratch::new();
+        let mut pooled = None;
+
+        // A shuffle in every round reuses the buffers of the previous rounds
+        let mut ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        for _ in 0..3 {
+            let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+            let permutation = Permutation::new(rng, number_of_ciphers);
+            let shuffled_deck = permutation
+                .permute_array(&ciphers)
+                .iter()
+                .zip(masking_factors.iter())
+                .map(|(&cipher, masking_factor)| {
+                    cipher
+                        + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                            .unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+            let witness = Witness::new(&permutation, &masking_factors);
+
+            let proof = ShuffleArgument::prove_with_scratch(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                &mut scratch,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            )
+            .unwrap();
+
+            assert_eq!(
+                ShuffleArgument::verify(
+                    &parameters,
+                    &statement,
+                    &proof,
+                    b"Test Domain",
+                    &mut FS::from_seed(b"Initialised with some input"),
+                ),
+                Ok(())
+            );
+
+            // Every buffer is given back, and none is added after the first round
+            assert_eq!(*pooled.get_or_insert(scratch.len()), scratch.len());
+            ciphers = shuffled_deck;
+        }
+        assert_eq!(pooled, Some(2 * m + 1));
+    }
+
+    #[test]
+    fn precomputed_proof() {
+        use crate::utils::scratch::Scratch;
+        use shuffle::prover::Precomputation;
+
+        let m = 3;
+        let n = 4;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+
+        // Offline, before the shuffle is known
+        let precomputation = Precomputation::new(rng, &parameters, m, n).unwrap();
+        assert_eq!((precomputation.m(), precomputation.n()), (m, n));
+
+        // Online
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        let proof = ShuffleArgument::prove_precomputed(
+            &parameters,
+            &statement,
+            &witness,
+            precomputation,
+            &mut Scratch::new(),
+            b"Test Domain",
+            &mut FS::from_seed(b"Initialised with some input"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            ShuffleArgument::verify(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut FS::from_seed(b"Initialised with some input"),
+            ),
+            Ok(())
+        );
+
+        // A precomputation only fits a shuffle of its own dimensions
+        let transposed = Precomputation::new(rng, &parameters, n, m).unwrap();
+        let mismatched = ShuffleArgument::prove_precomputed(
+            &parameters,
+            &statement,
+            &witness,
+            transposed,
+            &mut Scratch::new(),
+            b"Test Domain",
+            &mut FS::from_seed(b"Initialised with some input"),
         );
+        assert!(matches!(
+            mismatched,
+            Err(CryptoError::InvalidShuffleStatement)
+        ));
+        assert!(Precomputation::new(rng, &parameters, 1, number_of_ciphers).is_err());
     }
 }
@@ -2,12 +2,12 @@ use super::{Parameters, Statement};
 
 use crate::error::CryptoError;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::CommitmentEquations;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
 use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Comm>
@@ -32,11 +32,11 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Comm>,
         statement: &Statement<Scalar, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
         if self.b_blinded.len() != proof_parameters.n {
             return Err(CryptoError::ProofVerificationError(String::from(
@@ -66,7 +66,7 @@ where
             self.diff_commit
         ]?);
 
-        let x = Scalar::rand(fs_rng);
+        let x = derive_challenge::<Scalar>(fs_rng);
 
         if self.b_blinded[proof_parameters.n - 1] != x * statement.b {
             return Err(CryptoError::ProofVerificationError(String::from(
@@ -74,19 +74,17 @@ where
             )));
         }
 
-        // verify that blinded a is correctly formed
-        // let left = statement.a_commit.mul(x.into_repr()) + self.d_commit;
-        let left = *statement.a_commit * x + self.d_commit;
-        let right = Comm::commit(proof_parameters.commit_key, &self.a_blinded, self.r_blinded)?;
-        if left != right {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Single Value Product Argument (5.3)",
-            )));
-        }
+        let mut equations = CommitmentEquations::<Scalar, Comm>::new();
+
+        // blinded a is correctly formed: x c_a + c_d = com(a~; r~)
+        equations.add(
+            vec![x, Scalar::one()],
+            vec![*statement.a_commit, self.d_commit],
+            self.a_blinded.clone(),
+            self.r_blinded,
+        )?;
 
-        //verify that diffs are correctly formed
-        // let left = self.diff_commit.mul(x.into_repr()) + self.delta_commit;
-        let left = self.diff_commit * x + self.delta_commit;
+        // diffs are correctly formed: x c_diff + c_delta = com(x b~_i - b~_{i-1} a~_i; s~)
         let blinded_diffs = self
             .b_blinded
             .iter()
@@ -96,8 +94,21 @@ where
             .map(|((&b, &b_minus_one), &a)| x * b - b_minus_one * a)
             .collect::<Vec<_>>();
 
-        let right = Comm::commit(proof_parameters.commit_key, &blinded_diffs, self.s_blinded)?;
-        if left != right {
+        equations.add(
+            vec![x, Scalar::one()],
+            vec![self.diff_commit, self.delta_commit],
+            blinded_diffs,
+            self.s_blinded,
+        )?;
+
+        let seed = to_bytes![
+            x,
+            self.a_blinded,
+            self.b_blinded,
+            self.r_blinded,
+            self.s_blinded
+        ]?;
+        if !equations.verify::<T::Hash>(proof_parameters.commit_key, &seed)? {
             return Err(CryptoError::ProofVerificationError(String::from(
                 "Single Value Product Argument (5.3)",
             )));
@@ -4,11 +4,10 @@ use super::{Parameters, Statement, Witness};
 use crate::error::CryptoError;
 use crate::utils::rand::sample_vector;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
 use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::rand::Rng;
-use digest::Digest;
 use std::iter;
 
 pub struct Prover<'a, Scalar, Comm>
@@ -38,10 +37,21 @@ where
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
+    pub fn prove<R: Rng, T: Transcript>(
         &self,
         rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        let precomputation = Precomputation::new(rng, self.parameters)?;
+        self.prove_precomputed(precomputation, fs_rng)
+    }
+
+    /// Online phase of [`Prover::prove`], with the randomness and the blinding commitments of `precomputation`,
+    /// which must have been made for the same parameters.
+    pub(crate) fn prove_precomputed<T: Transcript>(
+        &self,
+        precomputation: Precomputation<Scalar, Comm>,
+        fs_rng: &mut T,
     ) -> Result<Proof<Scalar, Comm>, CryptoError> {
         fs_rng.absorb(&to_bytes![b"single_value_product_argument"]?);
 
@@ -59,29 +69,17 @@ where
             )
             .collect();
 
-        let d: Vec<Scalar> = sample_vector(rng, self.parameters.n);
-        let mut deltas: Vec<Scalar> = sample_vector(rng, self.parameters.n - 2);
-        deltas.insert(0, d[0]);
-        deltas.push(Scalar::zero());
-
-        // pick random r_d
-        let r_d = Scalar::rand(rng);
-
-        // pick random s_1, s_x
-        let s_1 = Scalar::rand(rng);
-        let s_x = Scalar::rand(rng);
-
-        let d_commit = Comm::commit(&self.parameters.commit_key, &d, r_d)?;
+        let Precomputation {
+            d,
+            deltas,
+            r_d,
+            s_1,
+            s_x,
+            d_commit,
+            delta_commit,
+        } = precomputation;
 
         let minus_one = -Scalar::one();
-        let delta_ds = deltas
-            .iter()
-            .take(deltas.len() - 1)
-            .zip(d.iter().skip(1))
-            .map(|(delta, d)| minus_one * delta * d)
-            .collect::<Vec<_>>();
-
-        let delta_commit = Comm::commit(&self.parameters.commit_key, &delta_ds, s_1)?;
 
         // skip frist a, skip first d, skip last b, and use all deltas
         let diffs = self
@@ -100,7 +98,7 @@ where
             )
             .collect::<Vec<_>>();
 
-        let diff_commit = Comm::commit(&self.parameters.commit_key, &diffs, s_x)?;
+        let diff_commit = Comm::commit(self.parameters.commit_key, &diffs, s_x)?;
 
         //public information
         fs_rng.absorb(&to_bytes![
@@ -111,9 +109,9 @@ where
         //commits
         fs_rng.absorb(&to_bytes![d_commit, delta_commit, diff_commit]?);
 
-        let x = Scalar::rand(fs_rng);
+        let x = derive_challenge::<Scalar>(fs_rng);
 
-        let a_blinded = Self::blind(&self.witness.a, &d, x);
+        let a_blinded = Self::blind(self.witness.a, &d, x);
         let r_blinded = x * self.witness.random_for_a_commit + r_d;
 
         let b_blinded = Self::blind(&b, &deltas, x);
@@ -135,6 +133,7 @@ where
         Ok(proof)
     }
 
+    #[allow(clippy::ptr_arg)]
     fn blind(x: &Vec<Scalar>, blinders: &Vec<Scalar>, challenge: Scalar) -> Vec<Scalar> {
         let blinded = x
             .iter()
@@ -145,3 +144,71 @@ where
         blinded
     }
 }
+
+/// Randomness of the single value product argument with the commitments to the blinding vector $d$ and to the
+/// products $-\delta_i d_{i+1}$, which do not depend on the witness and can be computed ahead of the proof. A
+/// precomputation blinds a single proof, so it is consumed by it.
+pub(crate) struct Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    d: Vec<Scalar>,
+    deltas: Vec<Scalar>,
+    r_d: Scalar,
+    s_1: Scalar,
+    s_x: Scalar,
+    d_commit: Comm::Commitment,
+    delta_commit: Comm::Commitment,
+}
+
+impl<Scalar, Comm> Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) fn new<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<Scalar, Comm>,
+    ) -> Result<Self, CryptoError> {
+        let d: Vec<Scalar> = sample_vector(rng, parameters.n);
+        let mut deltas: Vec<Scalar> = sample_vector(rng, parameters.n - 2);
+        deltas.insert(0, d[0]);
+        deltas.push(Scalar::zero());
+
+        // pick random r_d
+        let r_d = Scalar::rand(rng);
+
+        // pick random s_1, s_x
+        let s_1 = Scalar::rand(rng);
+        let s_x = Scalar::rand(rng);
+
+        let minus_one = -Scalar::one();
+        let delta_ds = deltas
+            .iter()
+            .take(deltas.len() - 1)
+            .zip(d.iter().skip(1))
+            .map(|(delta, d)| minus_one * delta * d)
+            .collect::<Vec<_>>();
+
+        let (d_commit, delta_commit) = match Comm::batch_commit(
+            parameters.commit_key,
+            &[d.clone(), delta_ds],
+            &[r_d, s_1],
+        )?[..]
+        {
+            [d_commit, delta_commit] => (d_commit, delta_commit),
+            _ => unreachable!(),
+        };
+
+        Ok(Self {
+            d,
+            deltas,
+            r_d,
+            s_1,
+            s_x,
+            d_commit,
+            delta_commit,
+        })
+    }
+}
@@ -1,5 +1,4 @@
 #[cfg(test)]
-
 mod test {
     use crate::error::CryptoError;
     use crate::utils::rand::sample_vector;
@@ -42,29 +41,54 @@ mod test {
         let statement = Statement::new(&a_commit, b);
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        let valid_proof =
-            SingleValueProd::prove(rng, &parameters, &statement, &witness, &mut fs_rng).unwrap();
+        let valid_proof = SingleValueProd::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
             Ok(()),
-            valid_proof.verify(&parameters, &statement, &mut fs_rng)
+            SingleValueProd::verify(
+                &parameters,
+                &statement,
+                &valid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
 
         a[0] = a[0] + a[0];
         let bad_witness = Witness::new(&a, &r);
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        let invalid_proof =
-            SingleValueProd::prove(rng, &parameters, &statement, &bad_witness, &mut fs_rng)
-                .unwrap();
+        let invalid_proof = SingleValueProd::prove(
+            rng,
+            &parameters,
+            &statement,
+            &bad_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
             Err(CryptoError::ProofVerificationError(String::from(
                 "Single Value Product Argument (5.3)",
             ))),
-            SingleValueProd::verify(&parameters, &statement, &invalid_proof, &mut fs_rng)
+            SingleValueProd::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
     }
 }
@@ -0,0 +1,438 @@
+use crate::error::CryptoError;
+
+use ark_ff::Zero;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::cfg_chunks;
+use ark_std::io::{Read, Seek, SeekFrom, Write};
+use digest::Digest;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+/// Ciphertexts that a prover or a verifier reads a range at a time, so that the shuffle of a very large batch never
+/// holds more than one range of them in memory. A source is read several times, so it must return the same ciphertexts on
+/// every read.
+pub trait CiphertextSource<C> {
+    /// Total number of ciphertexts.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read the `len` ciphertexts starting at index `start`.
+    fn read(&self, start: usize, len: usize) -> Result<Vec<C>, CryptoError>;
+}
+
+impl<C: Copy> CiphertextSource<C> for Vec<C> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn read(&self, start: usize, len: usize) -> Result<Vec<C>, CryptoError> {
+        self.get(start..start + len)
+            .map(|range| range.to_vec())
+            .ok_or(CryptoError::CiphertextVectorLengthError(
+                start + len,
+                self.len(),
+            ))
+    }
+}
+
+/// Ciphertexts split into consecutive chunks, read as one flat sequence.
+impl<C: Copy> CiphertextSource<C> for Vec<Vec<C>> {
+    fn len(&self) -> usize {
+        self.iter().map(|chunk| chunk.len()).sum()
+    }
+
+    fn read(&self, start: usize, len: usize) -> Result<Vec<C>, CryptoError> {
+        let mut range = Vec::with_capacity(len);
+        let mut offset = start;
+        for chunk in self {
+            if offset >= chunk.len() {
+                offset -= chunk.len();
+                continue;
+            }
+            let take = (len - range.len()).min(chunk.len() - offset);
+            range.extend_from_slice(&chunk[offset..offset + take]);
+            offset = 0;
+            if range.len() == len {
+                break;
+            }
+        }
+        if range.len() != len {
+            return Err(CryptoError::CiphertextVectorLengthError(
+                start + len,
+                CiphertextSource::len(self),
+            ));
+        }
+
+        Ok(range)
+    }
+}
+
+/// Ciphertexts stored back to back with their canonical encoding in a file or any other seekable reader, such as the
+/// output of [`write_ciphertexts`]. Every encoding must have the size of the encoding of the zero
+/// ciphertext, which holds for the compressed curve points of ElGamal.
+pub struct SerializedCiphertexts<C, R: Read + Seek> {
+    reader: RefCell<R>,
+    len: usize,
+    encoded_size: usize,
+    _ciphertext: PhantomData<C>,
+}
+
+impl<C, R> SerializedCiphertexts<C, R>
+where
+    C: CanonicalSerialize + CanonicalDeserialize + Zero,
+    R: Read + Seek,
+{
+    /// Read `len` ciphertexts from `reader`, starting at its beginning.
+    pub fn new(reader: R, len: usize) -> Self {
+        Self {
+            reader: RefCell::new(reader),
+            len,
+            encoded_size: C::zero().serialized_size(),
+            _ciphertext: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+}
+
+impl<C, R> CiphertextSource<C> for SerializedCiphertexts<C, R>
+where
+    C: CanonicalSerialize + CanonicalDeserialize + Zero,
+    R: Read + Seek,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn read(&self, start: usize, len: usize) -> Result<Vec<C>, CryptoError> {
+        if start + len > self.len {
+            return Err(CryptoError::CiphertextVectorLengthError(
+                start + len,
+                self.len,
+            ));
+        }
+
+        let mut reader = self.reader.borrow_mut();
+        reader.seek(SeekFrom::Start((start * self.encoded_size) as u64))?;
+
+        (0..len)
+            .map(|_| C::deserialize(&mut *reader).map_err(|_| CryptoError::InvalidCiphertext))
+            .collect()
+    }
+}
+
+/// Ciphertexts encoded back to back in borrowed bytes, decoded on demand instead of being copied and decoded
+/// whole. The bytes can be a memory-mapped file, since memory maps such as those of the `memmap2` crate dereference
+/// to `&[u8]`, so that a verifier auditing a large mix only pages in the ciphertexts it reads.
+///
+/// As with [`SerializedCiphertexts`], every encoding must have the size of the encoding of the zero ciphertext.
+#[derive(Clone, Copy)]
+pub struct CiphertextSlice<'a, C> {
+    bytes: &'a [u8],
+    encoded_size: usize,
+    _ciphertext: PhantomData<C>,
+}
+
+impl<'a, C> CiphertextSlice<'a, C>
+where
+    C: CanonicalSerialize + CanonicalDeserialize + Zero + Send,
+{
+    /// View of the ciphertexts written by [`write_ciphertexts`]. Fails if `bytes` does not hold a whole number of
+    /// encodings.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, CryptoError> {
+        let encoded_size = C::zero().serialized_size();
+        if !bytes.len().is_multiple_of(encoded_size) {
+            return Err(CryptoError::InvalidSerialization);
+        }
+
+        Ok(Self {
+            bytes,
+            encoded_size,
+            _ciphertext: PhantomData,
+        })
+    }
+
+    /// View of the ciphertexts of a serialized vector, such as a
+    /// [`CiphertextVector`](crate::homomorphic_encryption::el_gamal::vector::CiphertextVector), whose encoding is
+    /// the number of ciphertexts as a little-endian `u64` followed by the ciphertexts.
+    pub fn from_vector_encoding(bytes: &'a [u8]) -> Result<Self, CryptoError> {
+        if bytes.len() < 8 {
+            return Err(CryptoError::InvalidSerialization);
+        }
+        let (len, bytes) = bytes.split_at(8);
+        let len = u64::from_le_bytes(len.try_into().expect("the prefix has 8 bytes"));
+
+        let slice = Self::new(bytes)?;
+        if slice.len() as u64 != len {
+            return Err(CryptoError::InvalidSerialization);
+        }
+
+        Ok(slice)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len() / self.encoded_size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Decode the ciphertext at `index`.
+    pub fn get(&self, index: usize) -> Result<C, CryptoError> {
+        if index >= self.len() {
+            return Err(CryptoError::CiphertextVectorLengthError(
+                index + 1,
+                self.len(),
+            ));
+        }
+
+        let start = index * self.encoded_size;
+        C::deserialize(&self.bytes[start..start + self.encoded_size])
+            .map_err(|_| CryptoError::InvalidCiphertext)
+    }
+
+    /// Decode every ciphertext, in parallel when the `parallel` feature is enabled.
+    pub fn decode(&self) -> Result<Vec<C>, CryptoError> {
+        cfg_chunks!(self.bytes, self.encoded_size)
+            .map(|bytes| C::deserialize(bytes).map_err(|_| CryptoError::InvalidCiphertext))
+            .collect()
+    }
+}
+
+impl<'a, C> CiphertextSource<C> for CiphertextSlice<'a, C>
+where
+    C: CanonicalSerialize + CanonicalDeserialize + Zero + Send,
+{
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn read(&self, start: usize, len: usize) -> Result<Vec<C>, CryptoError> {
+        let out_of_range = |end| CryptoError::CiphertextVectorLengthError(end, self.len());
+        let end = start
+            .checked_add(len)
+            .ok_or_else(|| out_of_range(usize::MAX))?;
+        if end > self.len() {
+            return Err(out_of_range(end));
+        }
+
+        let byte_start = start
+            .checked_mul(self.encoded_size)
+            .ok_or_else(|| out_of_range(end))?;
+        let byte_end = end
+            .checked_mul(self.encoded_size)
+            .ok_or_else(|| out_of_range(end))?;
+        CiphertextSlice::new(&self.bytes[byte_start..byte_end])?.decode()
+    }
+}
+
+/// Deserialize a proof, or any other value, straight from borrowed bytes such as a memory-mapped file. Fails if
+/// the bytes do not hold exactly one encoding of `T`.
+pub fn deserialize_from_slice<T: CanonicalDeserialize>(mut bytes: &[u8]) -> Result<T, CryptoError> {
+    let value = T::deserialize(&mut bytes).map_err(|_| CryptoError::InvalidSerialization)?;
+    if !bytes.is_empty() {
+        return Err(CryptoError::InvalidSerialization);
+    }
+
+    Ok(value)
+}
+
+/// Encode the `ciphertexts`, for instance as they come out of a mix, back to back into `writer` in one pass, in the
+/// layout read by [`SerializedCiphertexts`]. Return how many were written.
+pub fn write_ciphertexts<C, W, I>(mut writer: W, ciphertexts: I) -> Result<usize, CryptoError>
+where
+    C: CanonicalSerialize,
+    W: Write,
+    I: IntoIterator<Item = C>,
+{
+    let mut count = 0;
+    for ciphertext in ciphertexts {
+        ciphertext
+            .serialize(&mut writer)
+            .map_err(|_| CryptoError::InvalidCiphertext)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Digest of a sequence of ciphertexts fed a range at a time. The shuffle and multi-exponentiation arguments absorb
+/// this digest instead of the ciphertexts themselves, which would have to be buffered whole by the transcript.
+pub(crate) struct CiphertextDigest<D: Digest> {
+    hasher: D,
+    count: u64,
+}
+
+impl<D: Digest> CiphertextDigest<D> {
+    pub(crate) fn new() -> Self {
+        Self {
+            hasher: D::new(),
+            count: 0,
+        }
+    }
+
+    pub(crate) fn update<C: CanonicalSerialize>(
+        &mut self,
+        ciphertexts: &[C],
+    ) -> Result<(), CryptoError> {
+        let mut bytes = Vec::new();
+        for ciphertext in ciphertexts {
+            ciphertext
+                .serialize(&mut bytes)
+                .map_err(|_| CryptoError::InvalidCiphertext)?;
+        }
+        self.hasher.update(&bytes);
+        self.count += ciphertexts.len() as u64;
+
+        Ok(())
+    }
+
+    /// Digest of every ciphertext fed so far, followed by their number.
+    pub(crate) fn finalize(mut self) -> Vec<u8> {
+        self.hasher.update(self.count.to_le_bytes());
+        self.hasher.finalize().to_vec()
+    }
+
+    /// Digest the ciphertexts of `source`, `chunk` at a time.
+    pub(crate) fn of_source<C, S>(source: &S, chunk: usize) -> Result<Vec<u8>, CryptoError>
+    where
+        C: CanonicalSerialize,
+        S: CiphertextSource<C> + ?Sized,
+    {
+        let mut digest = Self::new();
+        for_each_chunk(source, chunk, |_, ciphertexts| digest.update(&ciphertexts))?;
+
+        Ok(digest.finalize())
+    }
+}
+
+/// Read `source` in consecutive ranges of `chunk` ciphertexts, the last one possibly shorter, and pass each range
+/// with the index of its first ciphertext to `f`.
+pub(crate) fn for_each_chunk<C, S, F>(source: &S, chunk: usize, mut f: F) -> Result<(), CryptoError>
+where
+    S: CiphertextSource<C> + ?Sized,
+    F: FnMut(usize, Vec<C>) -> Result<(), CryptoError>,
+{
+    let chunk = chunk.max(1);
+    let mut start = 0;
+    while start < source.len() {
+        let len = chunk.min(source.len() - start);
+        f(start, source.read(start, len)?)?;
+        start += len;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        deserialize_from_slice, write_ciphertexts, CiphertextDigest, CiphertextSlice,
+        CiphertextSource, SerializedCiphertexts,
+    };
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::el_gamal::{self, vector::CiphertextVector};
+    use crate::utils::rand::sample_vector;
+
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::rand::thread_rng;
+    use blake2::Blake2s;
+    use std::io::Cursor;
+
+    type Curve = starknet_curve::Projective;
+    type Ciphertext = el_gamal::Ciphertext<Curve>;
+
+    #[test]
+    fn ciphertext_sources() {
+        let rng = &mut thread_rng();
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, 12);
+        let chunks = ciphers.chunks(4).map(|c| c.to_vec()).collect::<Vec<_>>();
+
+        let mut bytes = Vec::new();
+        assert_eq!(
+            write_ciphertexts(&mut bytes, ciphers.iter().copied()),
+            Ok(12)
+        );
+        let serialized = SerializedCiphertexts::<Ciphertext, _>::new(Cursor::new(bytes), 12);
+
+        for (start, len) in [(0, 12), (0, 4), (3, 5), (11, 1), (12, 0)] {
+            let expected = ciphers[start..start + len].to_vec();
+            assert_eq!(ciphers.read(start, len).unwrap(), expected);
+            assert_eq!(chunks.read(start, len).unwrap(), expected);
+            assert_eq!(serialized.read(start, len).unwrap(), expected);
+        }
+        assert_eq!(CiphertextSource::len(&chunks), 12);
+
+        let error = Err(CryptoError::CiphertextVectorLengthError(13, 12));
+        assert_eq!(ciphers.read(10, 3), error);
+        assert_eq!(chunks.read(10, 3), error);
+        assert_eq!(serialized.read(10, 3), error);
+
+        // The digest does not depend on how the ciphertexts are split
+        let digest = CiphertextDigest::<Blake2s>::of_source(&ciphers, 12).unwrap();
+        assert_eq!(
+            CiphertextDigest::<Blake2s>::of_source(&serialized, 5).unwrap(),
+            digest
+        );
+        assert_ne!(
+            CiphertextDigest::<Blake2s>::of_source(&ciphers[..11].to_vec(), 12).unwrap(),
+            digest
+        );
+    }
+
+    #[test]
+    fn borrowed_ciphertexts() {
+        let rng = &mut thread_rng();
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, 12);
+
+        let mut bytes = Vec::new();
+        write_ciphertexts(&mut bytes, ciphers.iter().copied()).unwrap();
+        let slice = CiphertextSlice::<Ciphertext>::new(&bytes).unwrap();
+        assert_eq!(CiphertextSource::len(&slice), 12);
+        assert_eq!(slice.get(5), Ok(ciphers[5]));
+        assert_eq!(
+            slice.get(12),
+            Err(CryptoError::CiphertextVectorLengthError(13, 12))
+        );
+        assert_eq!(slice.decode().unwrap(), ciphers);
+        assert_eq!(slice.read(3, 5).unwrap(), ciphers[3..8].to_vec());
+        assert_eq!(
+            slice.read(usize::MAX, 2),
+            Err(CryptoError::CiphertextVectorLengthError(usize::MAX, 12))
+        );
+        assert_eq!(
+            slice.read(10, 3),
+            Err(CryptoError::CiphertextVectorLengthError(13, 12))
+        );
+        assert_eq!(
+            CiphertextSlice::<Ciphertext>::new(&bytes[1..]).err(),
+            Some(CryptoError::InvalidSerialization)
+        );
+
+        // A serialized ciphertext vector is read in place, and any value is deserialized from borrowed bytes
+        let vector = CiphertextVector::from(ciphers.clone());
+        let mut bytes = Vec::new();
+        vector.serialize(&mut bytes).unwrap();
+        let slice = CiphertextSlice::<Ciphertext>::from_vector_encoding(&bytes).unwrap();
+        assert_eq!(slice.decode().unwrap(), ciphers);
+        assert_eq!(deserialize_from_slice(&bytes), Ok(vector));
+
+        bytes.push(0);
+        assert_eq!(
+            deserialize_from_slice::<CiphertextVector<Curve>>(&bytes),
+            Err(CryptoError::InvalidSerialization)
+        );
+        assert_eq!(
+            CiphertextSlice::<Ciphertext>::from_vector_encoding(&bytes).err(),
+            Some(CryptoError::InvalidSerialization)
+        );
+    }
+}
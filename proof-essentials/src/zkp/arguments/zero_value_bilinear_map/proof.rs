@@ -1,15 +1,13 @@
 use super::{BilinearMap, Parameters, Statement};
 
 use crate::error::CryptoError;
-use crate::utils::vector_arithmetic::dot_product;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::arguments::scalar_powers;
+use crate::zkp::arguments::{scalar_powers, CommitmentEquations};
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
 use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Comm>
@@ -35,24 +33,12 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Comm>,
         statement: &Statement<Scalar, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
-        if self.vector_of_committed_diagonals[proof_parameters.m + 1]
-            != Comm::commit(
-                proof_parameters.commit_key,
-                &vec![Scalar::zero()],
-                Scalar::zero(),
-            )?
-        {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Zero Argument (5.2)",
-            )));
-        }
-
         fs_rng.absorb(&to_bytes![b"zero_argument"]?);
 
         // Public parameters
@@ -75,7 +61,7 @@ where
             .unwrap(),
         );
 
-        let x = Scalar::rand(fs_rng);
+        let x = derive_challenge::<Scalar>(fs_rng);
 
         // Precompute all powers of the challenge from 0 to number_of_diagonals
         let challenge_powers = scalar_powers(x, 2 * proof_parameters.m);
@@ -88,41 +74,52 @@ where
         let mut first_m_non_zero_powers_reversed = first_m_non_zero_powers[..].to_vec();
         first_m_non_zero_powers_reversed.reverse();
 
-        // Verify commitment to A against a commitment on blinded a with blinded random r
-        let left: Comm::Commitment =
-            self.a_0_commit + dot_product(&first_m_non_zero_powers, statement.commitment_to_a)?;
-        let right = Comm::commit(
-            &proof_parameters.commit_key,
-            &self.a_blinded,
+        let mut equations = CommitmentEquations::<Scalar, Comm>::new();
+
+        // The diagonal m + 1 is a commitment to zero with zero randomness
+        equations.add(
+            vec![Scalar::one()],
+            vec![self.vector_of_committed_diagonals[proof_parameters.m + 1]],
+            vec![],
+            Scalar::zero(),
+        )?;
+
+        // Commitment to A against a commitment on blinded a with blinded random r
+        equations.add(
+            [&[Scalar::one()], &first_m_non_zero_powers[..]].concat(),
+            [&[self.a_0_commit], &statement.commitment_to_a[..]].concat(),
+            self.a_blinded.clone(),
             self.r_blinded,
         )?;
-        if left != right {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Zero Argument (5.2)",
-            )));
-        }
 
-        // Verify commitment to B against a commitment on blinded b with blinded random s
-        let left = self.b_m_commit
-            + dot_product(&first_m_non_zero_powers_reversed, statement.commitment_to_b)?;
-        let right = Comm::commit(
-            &proof_parameters.commit_key,
-            &self.b_blinded,
+        // Commitment to B against a commitment on blinded b with blinded random s
+        equations.add(
+            [&[Scalar::one()], &first_m_non_zero_powers_reversed[..]].concat(),
+            [&[self.b_m_commit], &statement.commitment_to_b[..]].concat(),
+            self.b_blinded.clone(),
             self.s_blinded,
         )?;
-        if left != right {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Zero Argument (5.2)",
-            )));
-        }
 
-        // Verify commitments to the diagonals against a commitment on bilinear_map(blinded a, blinded a) with blinded random t
-        let left = dot_product(&challenge_powers, &self.vector_of_committed_diagonals)?;
+        // Commitments to the diagonals against a commitment on bilinear_map(blinded a, blinded b) with blinded random t
         let a_star_b = statement
             .bilinear_map
             .compute_mapping(&self.a_blinded, &self.b_blinded)?;
-        let right = Comm::commit(proof_parameters.commit_key, &vec![a_star_b], self.t_blinded)?;
-        if left != right {
+        equations.add(
+            challenge_powers,
+            self.vector_of_committed_diagonals.clone(),
+            vec![a_star_b],
+            self.t_blinded,
+        )?;
+
+        let seed = to_bytes![
+            x,
+            self.a_blinded,
+            self.b_blinded,
+            self.r_blinded,
+            self.s_blinded,
+            self.t_blinded
+        ]?;
+        if !equations.verify::<T::Hash>(proof_parameters.commit_key, &seed)? {
             return Err(CryptoError::ProofVerificationError(String::from(
                 "Zero Argument (5.2)",
             )));
@@ -4,9 +4,11 @@ use crate::error::CryptoError;
 use crate::utils::{rand::sample_vector, vector_arithmetic::dot_product};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::scalar_powers;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
-use digest::Digest;
+use ark_std::{cfg_into_iter, cfg_iter};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use rand::Rng;
 
@@ -37,21 +39,34 @@ where
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
+    pub fn prove<R: Rng, T: Transcript>(
         &self,
         rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<Proof<Scalar, Comm>, CryptoError> {
-        fs_rng.absorb(&to_bytes![b"zero_argument"]?);
-
-        let a_0: Vec<Scalar> = sample_vector(rng, self.parameters.n);
-        let b_m: Vec<Scalar> = sample_vector(rng, self.parameters.n);
+        let precomputation = Precomputation::new(rng, self.parameters)?;
+        self.prove_precomputed(precomputation, fs_rng)
+    }
 
-        let r_0 = Scalar::rand(rng);
-        let s_m = Scalar::rand(rng);
+    /// Online phase of [`Prover::prove`], with the randomness and the blinding commitments of `precomputation`,
+    /// which must have been made for the same parameters.
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn prove_precomputed<T: Transcript>(
+        &self,
+        precomputation: Precomputation<Scalar, Comm>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        fs_rng.absorb(&to_bytes![b"zero_argument"]?);
 
-        let a_0_commit = Comm::commit(self.parameters.commit_key, &a_0, r_0)?;
-        let b_m_commit = Comm::commit(self.parameters.commit_key, &b_m, s_m)?;
+        let Precomputation {
+            a_0,
+            b_m,
+            r_0,
+            s_m,
+            t,
+            a_0_commit,
+            b_m_commit,
+        } = precomputation;
 
         let a_0_vec = vec![a_0.clone(); 1];
         let extended_a = [&a_0_vec[..], &self.witness.matrix_a[..]].concat();
@@ -68,16 +83,12 @@ where
             )
             .unwrap();
 
-        let mut t: Vec<Scalar> = sample_vector(rng, 2 * self.parameters.m + 1);
-        t[self.parameters.m + 1] = Scalar::zero();
-
-        let vector_of_committed_diagonals = diagonals
-            .iter()
-            .zip(t.iter())
-            .map(|(&diagonal, &random)| -> Result<_, CryptoError> {
-                Comm::commit(self.parameters.commit_key, &vec![diagonal], random)
-            })
-            .collect::<Result<Vec<_>, CryptoError>>()?;
+        let commit_key = self.parameters.commit_key;
+        let diagonals = diagonals
+            .into_iter()
+            .map(|diagonal| vec![diagonal])
+            .collect::<Vec<_>>();
+        let vector_of_committed_diagonals = Comm::batch_commit(commit_key, &diagonals, &t)?;
 
         // Public parameters
         fs_rng.absorb(
@@ -102,7 +113,7 @@ where
             .unwrap(),
         );
 
-        let x = Scalar::rand(fs_rng);
+        let x = derive_challenge::<Scalar>(fs_rng);
 
         // Precompute all powers of the challenge from 0 to number_of_diagonals of the extended matrix
         let challenge_powers = scalar_powers(x, 2 * self.parameters.m);
@@ -121,28 +132,30 @@ where
         // a1[3]x + a2[3]x^2 ... am[3]x^m
         // ...
         // a1[n]x + a2[n]x^2 ... am[n]x^m = b[n]
-        let mut a_blinded: Vec<Scalar> = Vec::with_capacity(self.parameters.m + 1);
-        for i in 0..self.parameters.n {
-            let mut poly = a_0[i];
-            for j in 0..self.parameters.m {
-                poly = poly + self.witness.matrix_a[j][i] * first_m_non_zero_powers[j];
-            }
-            a_blinded.push(poly);
-        }
-
-        let mut b_blinded: Vec<Scalar> = Vec::with_capacity(self.parameters.m + 1);
-        for i in 0..self.parameters.n {
-            let mut poly = b_m[i];
-            for j in 0..self.parameters.m {
-                poly = poly + self.witness.matrix_b[j][i] * first_m_non_zero_powers_reversed[j];
-            }
-            b_blinded.push(poly);
-        }
+        let a_blinded = cfg_into_iter!(0..self.parameters.n)
+            .map(|i| {
+                let mut poly = a_0[i];
+                for j in 0..self.parameters.m {
+                    poly += self.witness.matrix_a[j][i] * first_m_non_zero_powers[j];
+                }
+                poly
+            })
+            .collect::<Vec<Scalar>>();
+
+        let b_blinded = cfg_into_iter!(0..self.parameters.n)
+            .map(|i| {
+                let mut poly = b_m[i];
+                for j in 0..self.parameters.m {
+                    poly += self.witness.matrix_b[j][i] * first_m_non_zero_powers_reversed[j];
+                }
+                poly
+            })
+            .collect::<Vec<Scalar>>();
 
         let r_blinded =
-            r_0 + dot_product(&self.witness.randoms_for_a_commit, &first_m_non_zero_powers)?;
+            r_0 + dot_product(self.witness.randoms_for_a_commit, &first_m_non_zero_powers)?;
         let s_blinded = dot_product(
-            &self.witness.randoms_for_b_commit,
+            self.witness.randoms_for_b_commit,
             &first_m_non_zero_powers_reversed,
         )? + s_m;
         let t_blinded = dot_product(&t, &challenge_powers)?;
@@ -180,40 +193,34 @@ where
         let num_of_diagonals = 2 * m - 1;
 
         let mut diagonal_sums = vec![Scalar::zero(); num_of_diagonals];
-        let center = num_of_diagonals / 2 as usize;
-
-        for d in 1..m {
-            let mut tmp_product1 = Scalar::zero();
-            let mut tmp_product2 = Scalar::zero();
-            for i in d..m {
-                let dot = self
-                    .statement
-                    .bilinear_map
-                    .compute_mapping(&a_chunks[i - d], &b_chunks[i])
-                    .unwrap();
-                tmp_product1 = tmp_product1 + dot;
-
-                let dot = self
-                    .statement
-                    .bilinear_map
-                    .compute_mapping(&a_chunks[i], &b_chunks[i - d])
-                    .unwrap();
-                tmp_product2 = tmp_product2 + dot;
-            }
-
-            diagonal_sums[center - d] = tmp_product1;
-            diagonal_sums[center + d] = tmp_product2;
+        let center = num_of_diagonals / 2;
+
+        let bilinear_map = self.statement.bilinear_map;
+        let off_diagonals = cfg_into_iter!(1..m)
+            .map(|d| {
+                let mut tmp_product1 = Scalar::zero();
+                let mut tmp_product2 = Scalar::zero();
+                for i in d..m {
+                    tmp_product1 += bilinear_map
+                        .compute_mapping(&a_chunks[i - d], &b_chunks[i])
+                        .unwrap();
+                    tmp_product2 += bilinear_map
+                        .compute_mapping(&a_chunks[i], &b_chunks[i - d])
+                        .unwrap();
+                }
+
+                (tmp_product1, tmp_product2)
+            })
+            .collect::<Vec<_>>();
+
+        for (d, (below, above)) in (1..m).zip(off_diagonals) {
+            diagonal_sums[center - d] = below;
+            diagonal_sums[center + d] = above;
         }
 
-        let product: Scalar = a_chunks
-            .iter()
-            .zip(b_chunks.iter())
-            .map(|(a_i, b_i)| {
-                self.statement
-                    .bilinear_map
-                    .compute_mapping(a_i, b_i)
-                    .unwrap()
-            })
+        let product: Scalar = cfg_iter!(a_chunks)
+            .zip(cfg_iter!(b_chunks))
+            .map(|(a_i, b_i)| bilinear_map.compute_mapping(a_i, b_i).unwrap())
             .sum();
 
         diagonal_sums[center] = product;
@@ -222,3 +229,60 @@ where
         Ok(diagonal_sums)
     }
 }
+
+/// Randomness of the zero argument with the commitments to the blinding vectors $a_0$ and $b_m$, which do not depend
+/// on the witness and can be computed ahead of the proof. A precomputation blinds a single proof, so it is consumed
+/// by it.
+pub(crate) struct Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    a_0: Vec<Scalar>,
+    b_m: Vec<Scalar>,
+    r_0: Scalar,
+    s_m: Scalar,
+    t: Vec<Scalar>,
+    a_0_commit: Comm::Commitment,
+    b_m_commit: Comm::Commitment,
+}
+
+impl<Scalar, Comm> Precomputation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) fn new<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<Scalar, Comm>,
+    ) -> Result<Self, CryptoError> {
+        let a_0: Vec<Scalar> = sample_vector(rng, parameters.n);
+        let b_m: Vec<Scalar> = sample_vector(rng, parameters.n);
+
+        let r_0 = Scalar::rand(rng);
+        let s_m = Scalar::rand(rng);
+
+        let mut t: Vec<Scalar> = sample_vector(rng, 2 * parameters.m + 1);
+        t[parameters.m + 1] = Scalar::zero();
+
+        let (a_0_commit, b_m_commit) = match Comm::batch_commit(
+            parameters.commit_key,
+            &[a_0.clone(), b_m.clone()],
+            &[r_0, s_m],
+        )?[..]
+        {
+            [a_0_commit, b_m_commit] => (a_0_commit, b_m_commit),
+            _ => unreachable!(),
+        };
+
+        Ok(Self {
+            a_0,
+            b_m,
+            r_0,
+            s_m,
+            t,
+            a_0_commit,
+            b_m_commit,
+        })
+    }
+}
@@ -78,6 +78,7 @@ mod test {
             &proof_parameters,
             &statement,
             &valid_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -85,7 +86,13 @@ mod test {
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
             Ok(()),
-            valid_proof.verify(&proof_parameters, &statement, &mut fs_rng)
+            ZeroValueArgument::verify(
+                &proof_parameters,
+                &statement,
+                &valid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
 
         let bad_witness = Witness::new(&a_chunks, &r, &a_chunks, &r);
@@ -96,6 +103,7 @@ mod test {
             &proof_parameters,
             &statement,
             &bad_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -105,7 +113,13 @@ mod test {
             Err(CryptoError::ProofVerificationError(String::from(
                 "Zero Argument (5.2)",
             ))),
-            ZeroValueArgument::verify(&proof_parameters, &statement, &invalid_proof, &mut fs_rng)
+            ZeroValueArgument::verify(
+                &proof_parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            )
         );
     }
 }
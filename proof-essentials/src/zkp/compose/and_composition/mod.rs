@@ -0,0 +1,154 @@
+pub mod proof;
+pub mod prover;
+mod tests;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// AND-composition of a sigma protocol `S`. Given N statements of `S` and a witness for each of them, runs the
+/// N protocols in parallel and answers a single shared challenge, producing one aggregated proof.
+///
+/// The composition is itself a sigma protocol, so it can be nested inside other combinators.
+pub struct AndComposition<S: SigmaProtocol> {
+    _protocol: PhantomData<S>,
+}
+
+/// Statement of an AND-composition: the list of statements which all have to hold.
+pub type Statement<S> = Vec<<S as SigmaProtocol>::Statement>;
+
+/// Witness of an AND-composition: one witness per statement, in the same order.
+pub type Witness<S> = Vec<<S as SigmaProtocol>::Witness>;
+
+impl<S: SigmaProtocol> SigmaProtocol for AndComposition<S> {
+    type Scalar = S::Scalar;
+    type Parameters = S::Parameters;
+    type Statement = Statement<S>;
+    type Witness = Witness<S>;
+    type Commitment = Vec<S::Commitment>;
+    type Randomness = Vec<S::Randomness>;
+    type Response = Vec<S::Response>;
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        if statement.is_empty() || statement.len() != witness.len() {
+            return Err(CryptoError::InvalidAndCompositionStatement);
+        }
+
+        let (commitments, randomness) = statement
+            .iter()
+            .zip(witness.iter())
+            .map(|(st, w)| S::commit(rng, parameters, st, w))
+            .collect::<Result<Vec<_>, CryptoError>>()?
+            .into_iter()
+            .unzip();
+
+        Ok((commitments, randomness))
+    }
+
+    fn respond(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        if statement.len() != witness.len() || statement.len() != randomness.len() {
+            return Err(CryptoError::InvalidAndCompositionStatement);
+        }
+
+        statement
+            .iter()
+            .zip(witness.iter())
+            .zip(randomness.iter())
+            .map(|((st, w), random)| S::respond(parameters, st, w, random, challenge))
+            .collect()
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        let (commitments, responses) = statement
+            .iter()
+            .map(|st| S::simulate(rng, parameters, st, challenge))
+            .collect::<Result<Vec<_>, CryptoError>>()?
+            .into_iter()
+            .unzip();
+
+        Ok((commitments, responses))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        if statement.is_empty()
+            || commitment.len() != statement.len()
+            || response.len() != statement.len()
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "AND Composition",
+            )));
+        }
+
+        for ((st, commitment), response) in
+            statement.iter().zip(commitment.iter()).zip(response.iter())
+        {
+            S::check(parameters, st, commitment, challenge, response).map_err(|_| {
+                CryptoError::ProofVerificationError(String::from("AND Composition"))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: SigmaProtocol> ArgumentOfKnowledge for AndComposition<S> {
+    type CommonReferenceString = S::Parameters;
+    type Statement = Statement<S>;
+    type Witness = Witness<S>;
+    type Proof = proof::Proof<S>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::<S>::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<S: SigmaProtocol> AndComposition<S> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"AND Composition";
+}
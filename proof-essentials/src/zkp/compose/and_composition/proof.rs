@@ -0,0 +1,40 @@
+use super::{AndComposition, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<S: SigmaProtocol> {
+    pub(crate) commitments: Vec<S::Commitment>,
+    pub(crate) responses: Vec<S::Response>,
+}
+
+impl<S: SigmaProtocol> Proof<S> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &S::Parameters,
+        statement: &Statement<S>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            b"and_composition",
+            parameters,
+            statement,
+            self.commitments
+        ]?);
+
+        let c = derive_challenge::<S::Scalar>(fs_rng);
+
+        AndComposition::<S>::check(
+            parameters,
+            statement,
+            &self.commitments,
+            &c,
+            &self.responses,
+        )
+    }
+}
@@ -0,0 +1,49 @@
+use super::{proof::Proof, AndComposition, Statement, Witness};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<S>
+where
+    S: SigmaProtocol,
+{
+    phantom: PhantomData<S>,
+}
+
+impl<S> Prover<S>
+where
+    S: SigmaProtocol,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &S::Parameters,
+        statement: &Statement<S>,
+        witness: &Witness<S>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<S>, CryptoError> {
+        let (commitments, randomness) =
+            AndComposition::<S>::commit(rng, parameters, statement, witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            b"and_composition",
+            parameters,
+            statement,
+            commitments
+        ]?);
+
+        let c = derive_challenge::<S::Scalar>(fs_rng);
+
+        let responses =
+            AndComposition::<S>::respond(parameters, statement, witness, &randomness, &c)?;
+
+        Ok(Proof {
+            commitments,
+            responses,
+        })
+    }
+}
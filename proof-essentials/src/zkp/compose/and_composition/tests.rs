@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::compose::{and_composition, or_composition, AndProof};
+    use crate::zkp::proofs::schnorr_identification;
+    use crate::zkp::ArgumentOfKnowledge;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Point = starknet_curve::Affine;
+    type Scalar = starknet_curve::Fr;
+    type Schnorr = schnorr_identification::SchnorrIdentification<Curve>;
+    type SchnorrAnd = and_composition::AndComposition<Schnorr>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn schnorr_keys(generator: &Point, number_of_keys: usize) -> (Vec<Scalar>, Vec<Point>) {
+        let rng = &mut thread_rng();
+        let secret_keys = (0..number_of_keys)
+            .map(|_| Scalar::rand(rng))
+            .collect::<Vec<_>>();
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| generator.mul(*sk).into_affine())
+            .collect::<Vec<_>>();
+
+        (secret_keys, public_keys)
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let (secret_keys, public_keys) = schnorr_keys(&generator, 4);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = SchnorrAnd::prove(
+            rng,
+            &generator,
+            &public_keys,
+            &secret_keys,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrAnd::verify(
+                &generator,
+                &public_keys,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized = AndProof::<Schnorr>::deserialize(&serialized[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrAnd::verify(
+                &generator,
+                &public_keys,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let (mut secret_keys, public_keys) = schnorr_keys(&generator, 3);
+
+        // Knowing all but one of the witnesses is not enough
+        secret_keys[2] = Scalar::rand(rng);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = SchnorrAnd::prove(
+            rng,
+            &generator,
+            &public_keys,
+            &secret_keys,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrAnd::verify(
+                &generator,
+                &public_keys,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "AND Composition"
+            )))
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrAnd::prove(
+                rng,
+                &generator,
+                &public_keys,
+                &secret_keys[..2].to_vec(),
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .err(),
+            Some(CryptoError::InvalidAndCompositionStatement)
+        );
+    }
+
+    #[test]
+    fn test_or_of_ands() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let (first_sks, first_pks) = schnorr_keys(&generator, 2);
+        let (_, second_pks) = schnorr_keys(&generator, 2);
+
+        // Prove knowledge of both keys of the first pair or both keys of the second pair
+        let statement = vec![first_pks, second_pks];
+        let witness = or_composition::Witness::new(0, &first_sks);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = or_composition::OrComposition::<SchnorrAnd>::prove(
+            rng,
+            &generator,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            or_composition::OrComposition::<SchnorrAnd>::verify(
+                &generator,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod and_composition;
+pub mod or_composition;
+pub mod repetition;
+pub mod signature_of_knowledge;
+
+/// Proof that the prover knows a witness for every one of several statements of a sigma protocol.
+pub type AndProof<S> = and_composition::proof::Proof<S>;
+
+/// Proof that the prover knows a witness for at least one out of several statements of a sigma protocol.
+pub type OrProof<S> = or_composition::proof::Proof<S>;
+
+/// Proof that the prover knows a witness for a statement of a sigma protocol, repeated to amplify soundness.
+pub type RepetitionProof<S> = repetition::proof::Proof<S>;
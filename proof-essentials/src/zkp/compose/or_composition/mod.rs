@@ -0,0 +1,69 @@
+pub mod proof;
+pub mod prover;
+mod tests;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// OR-composition of a sigma protocol `S` (Cramer-Damgard-Schoenmakers). Given N statements of `S`, proves
+/// knowledge of a witness for at least one of them without revealing which. The prover simulates the
+/// branches it has no witness for and splits the Fiat-Shamir challenge so that all branch challenges add up to it.
+pub struct OrComposition<'a, S: SigmaProtocol> {
+    _protocol: PhantomData<&'a S>,
+}
+
+/// Statement of an OR-composition: the list of statements, at least one of which the prover can prove.
+pub type Statement<S> = Vec<<S as SigmaProtocol>::Statement>;
+
+/// Witness of an OR-composition: the index of the statement the prover knows a witness for, along with that witness.
+pub struct Witness<'a, S: SigmaProtocol> {
+    pub index: usize,
+    pub witness: &'a S::Witness,
+}
+
+impl<'a, S: SigmaProtocol> Witness<'a, S> {
+    pub fn new(index: usize, witness: &'a S::Witness) -> Self {
+        Self { index, witness }
+    }
+}
+
+impl<'a, S: SigmaProtocol> ArgumentOfKnowledge for OrComposition<'a, S> {
+    type CommonReferenceString = S::Parameters;
+    type Statement = Statement<S>;
+    type Witness = Witness<'a, S>;
+    type Proof = proof::Proof<S>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::<S>::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<'a, S: SigmaProtocol> OrComposition<'a, S> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"OR Composition";
+}
@@ -0,0 +1,66 @@
+use super::Statement;
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::{to_bytes, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<S: SigmaProtocol> {
+    pub(crate) commitments: Vec<S::Commitment>,
+    pub(crate) challenges: Vec<S::Scalar>,
+    pub(crate) responses: Vec<S::Response>,
+}
+
+impl<S: SigmaProtocol> Proof<S> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &S::Parameters,
+        statement: &Statement<S>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        if statement.is_empty()
+            || self.commitments.len() != statement.len()
+            || self.challenges.len() != statement.len()
+            || self.responses.len() != statement.len()
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition",
+            )));
+        }
+
+        fs_rng.absorb(&to_bytes![
+            b"or_composition",
+            parameters,
+            statement,
+            self.commitments
+        ]?);
+
+        let c = derive_challenge::<S::Scalar>(fs_rng);
+
+        let challenge_sum = self
+            .challenges
+            .iter()
+            .fold(S::Scalar::zero(), |acc, &c_i| acc + c_i);
+
+        if challenge_sum != c {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition",
+            )));
+        }
+
+        for (((st, commitment), challenge), response) in statement
+            .iter()
+            .zip(self.commitments.iter())
+            .zip(self.challenges.iter())
+            .zip(self.responses.iter())
+        {
+            S::check(parameters, st, commitment, challenge, response)
+                .map_err(|_| CryptoError::ProofVerificationError(String::from("OR Composition")))?;
+        }
+
+        Ok(())
+    }
+}
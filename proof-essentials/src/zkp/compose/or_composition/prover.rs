@@ -0,0 +1,85 @@
+use super::{proof::Proof, Statement, Witness};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::{to_bytes, Zero};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+use std::marker::PhantomData;
+
+pub struct Prover<S>
+where
+    S: SigmaProtocol,
+{
+    phantom: PhantomData<S>,
+}
+
+impl<S> Prover<S>
+where
+    S: SigmaProtocol,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &S::Parameters,
+        statement: &Statement<S>,
+        witness: &Witness<S>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<S>, CryptoError> {
+        if witness.index >= statement.len() {
+            return Err(CryptoError::InvalidOrCompositionStatement);
+        }
+
+        let mut commitments = Vec::with_capacity(statement.len());
+        let mut challenges = Vec::with_capacity(statement.len());
+        let mut responses = Vec::with_capacity(statement.len());
+
+        // Simulate every branch except the one we hold a witness for
+        let mut randomness = None;
+        for (i, st) in statement.iter().enumerate() {
+            if i == witness.index {
+                let (commitment, random) = S::commit(rng, parameters, st, witness.witness)?;
+                randomness = Some(random);
+                commitments.push(commitment);
+                challenges.push(S::Scalar::zero());
+            } else {
+                let challenge = S::Scalar::rand(rng);
+                let (commitment, response) = S::simulate(rng, parameters, st, &challenge)?;
+                commitments.push(commitment);
+                challenges.push(challenge);
+                responses.push(response);
+            }
+        }
+
+        fs_rng.absorb(&to_bytes![
+            b"or_composition",
+            parameters,
+            statement,
+            commitments
+        ]?);
+
+        let c = derive_challenge::<S::Scalar>(fs_rng);
+
+        // The real branch gets whatever is left of the challenge
+        let simulated_sum = challenges
+            .iter()
+            .fold(S::Scalar::zero(), |acc, &c_i| acc + c_i);
+        challenges[witness.index] = c - simulated_sum;
+
+        let response = S::respond(
+            parameters,
+            &statement[witness.index],
+            witness.witness,
+            &randomness.unwrap(),
+            &challenges[witness.index],
+        )?;
+        responses.insert(witness.index, response);
+
+        Ok(Proof {
+            commitments,
+            challenges,
+            responses,
+        })
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::compose::{or_composition, OrProof};
+    use crate::zkp::proofs::{chaum_pedersen_dl_equality, schnorr_identification};
+    use crate::zkp::ArgumentOfKnowledge;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Point = starknet_curve::Affine;
+    type Scalar = starknet_curve::Fr;
+    type Schnorr = schnorr_identification::SchnorrIdentification<Curve>;
+    type SchnorrOr<'a> = or_composition::OrComposition<'a, Schnorr>;
+    type DLEquality<'a> = chaum_pedersen_dl_equality::DLEquality<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn schnorr_keys(generator: &Point, number_of_keys: usize) -> (Vec<Scalar>, Vec<Point>) {
+        let rng = &mut thread_rng();
+        let secret_keys = (0..number_of_keys)
+            .map(|_| Scalar::rand(rng))
+            .collect::<Vec<_>>();
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| generator.mul(*sk).into_affine())
+            .collect::<Vec<_>>();
+
+        (secret_keys, public_keys)
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let (secret_keys, public_keys) = schnorr_keys(&generator, 4);
+
+        for (index, sk) in secret_keys.iter().enumerate() {
+            let witness = or_composition::Witness::new(index, sk);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = SchnorrOr::prove(
+                rng,
+                &generator,
+                &public_keys,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                SchnorrOr::verify(
+                    &generator,
+                    &public_keys,
+                    &proof,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let (_, public_keys) = schnorr_keys(&generator, 3);
+
+        let wrong_sk = Scalar::rand(rng);
+        let witness = or_composition::Witness::new(1, &wrong_sk);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = SchnorrOr::prove(
+            rng,
+            &generator,
+            &public_keys,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::verify(
+                &generator,
+                &public_keys,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition"
+            )))
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let out_of_range = or_composition::Witness::new(3, &wrong_sk);
+        assert_eq!(
+            SchnorrOr::prove(
+                rng,
+                &generator,
+                &public_keys,
+                &out_of_range,
+                b"Test Domain",
+                &mut fs_rng
+            )
+            .err(),
+            Some(CryptoError::InvalidOrCompositionStatement)
+        );
+    }
+
+    #[test]
+    fn test_serialize_deserialize_proof() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let (secret_keys, public_keys) = schnorr_keys(&generator, 2);
+
+        let witness = or_composition::Witness::new(0, &secret_keys[0]);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = SchnorrOr::prove(
+            rng,
+            &generator,
+            &public_keys,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized = OrProof::<Schnorr>::deserialize(&serialized[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::verify(
+                &generator,
+                &public_keys,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_dl_equality_branches() {
+        let rng = &mut thread_rng();
+        let g = Curve::rand(rng).into_affine();
+        let h = Curve::rand(rng).into_affine();
+        let parameters = chaum_pedersen_dl_equality::Parameters::new(&g, &h);
+
+        let secret = Scalar::rand(rng);
+        let valid_a = g.mul(secret).into_affine();
+        let valid_b = h.mul(secret).into_affine();
+
+        // a pair of points which do not share a discrete logarithm
+        let other_a = Curve::rand(rng).into_affine();
+        let other_b = Curve::rand(rng).into_affine();
+
+        let statement = vec![
+            chaum_pedersen_dl_equality::Statement::new(&other_a, &other_b),
+            chaum_pedersen_dl_equality::Statement::new(&valid_a, &valid_b),
+        ];
+        let witness = or_composition::Witness::new(1, &secret);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = or_composition::OrComposition::<DLEquality>::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            or_composition::OrComposition::<DLEquality>::verify(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+}
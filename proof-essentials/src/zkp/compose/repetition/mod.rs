@@ -0,0 +1,123 @@
+pub mod proof;
+pub mod prover;
+mod tests;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::derive_challenge;
+use crate::zkp::transcript::{Transcript, TranscriptRng};
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ff::ToBytes;
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// k-fold parallel repetition of a sigma protocol `S`. The prover sends k commitments, receives k independent
+/// challenges from a single transcript and answers all of them, so a cheating prover has to guess every challenge.
+/// This amplifies the soundness of protocols whose challenges are drawn from a small set, such as single bits.
+///
+/// The k transcripts are verified together with [`SigmaProtocol::batch_check`].
+pub struct Repetition<'a, S: SigmaProtocol> {
+    _protocol: PhantomData<&'a S>,
+}
+
+pub struct Parameters<'a, S: SigmaProtocol> {
+    pub parameters: &'a S::Parameters,
+    pub(crate) repetitions: usize,
+    pub(crate) challenge_bits: Option<u32>,
+}
+
+impl<'a, S: SigmaProtocol> Parameters<'a, S> {
+    /// Repeat `S` with challenges drawn from the whole scalar field.
+    pub fn new(parameters: &'a S::Parameters, repetitions: usize) -> Result<Self, CryptoError> {
+        if repetitions == 0 {
+            return Err(CryptoError::InvalidRepetitionParameters);
+        }
+
+        Ok(Self {
+            parameters,
+            repetitions,
+            challenge_bits: None,
+        })
+    }
+
+    /// Repeat `S` with challenges that are integers of at most `challenge_bits` bits.
+    pub fn with_challenge_bits(
+        parameters: &'a S::Parameters,
+        repetitions: usize,
+        challenge_bits: u32,
+    ) -> Result<Self, CryptoError> {
+        if repetitions == 0 || challenge_bits == 0 || challenge_bits > 64 {
+            return Err(CryptoError::InvalidRepetitionParameters);
+        }
+
+        Ok(Self {
+            parameters,
+            repetitions,
+            challenge_bits: Some(challenge_bits),
+        })
+    }
+
+    pub fn repetitions(&self) -> usize {
+        self.repetitions
+    }
+
+    /// Draw one challenge per repetition from the transcript.
+    pub(crate) fn challenges<T: Transcript>(&self, fs_rng: &mut T) -> Vec<S::Scalar> {
+        (0..self.repetitions)
+            .map(|_| match self.challenge_bits {
+                Some(bits) => {
+                    S::Scalar::from(TranscriptRng::new(fs_rng).gen::<u64>() >> (64 - bits))
+                }
+                None => derive_challenge::<S::Scalar>(fs_rng),
+            })
+            .collect()
+    }
+}
+
+impl<'a, S: SigmaProtocol> ToBytes for Parameters<'a, S> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.parameters.write(&mut w)?;
+        (self.repetitions as u64).write(&mut w)?;
+        self.challenge_bits.unwrap_or(0).write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+impl<'a, S: SigmaProtocol> ArgumentOfKnowledge for Repetition<'a, S> {
+    type CommonReferenceString = Parameters<'a, S>;
+    type Statement = S::Statement;
+    type Witness = S::Witness;
+    type Proof = proof::Proof<S>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::<S>::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<'a, S: SigmaProtocol> Repetition<'a, S> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Repetition";
+}
@@ -0,0 +1,56 @@
+use super::Parameters;
+use crate::error::CryptoError;
+use crate::zkp::transcript::{Transcript, TranscriptRng};
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<S: SigmaProtocol> {
+    pub(crate) commitments: Vec<S::Commitment>,
+    pub(crate) responses: Vec<S::Response>,
+}
+
+impl<S: SigmaProtocol> Proof<S> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<S>,
+        statement: &S::Statement,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error = || CryptoError::ProofVerificationError(String::from("Repetition"));
+
+        if self.commitments.len() != parameters.repetitions
+            || self.responses.len() != parameters.repetitions
+        {
+            return Err(verification_error());
+        }
+
+        fs_rng.absorb(&to_bytes![
+            b"repetition",
+            parameters,
+            statement,
+            self.commitments
+        ]?);
+        let challenges = parameters.challenges(fs_rng);
+
+        // The weights of the batch check are drawn once the responses are fixed
+        let mut serialized_responses = Vec::with_capacity(self.responses.serialized_size());
+        self.responses
+            .serialize(&mut serialized_responses)
+            .map_err(|e| CryptoError::IoError(e.to_string()))?;
+        fs_rng.absorb(&serialized_responses);
+
+        S::batch_check(
+            &mut TranscriptRng::new(fs_rng),
+            parameters.parameters,
+            statement,
+            &self.commitments,
+            &challenges,
+            &self.responses,
+        )
+        .map_err(|_| verification_error())
+    }
+}
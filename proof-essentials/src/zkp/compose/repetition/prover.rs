@@ -0,0 +1,54 @@
+use super::{proof::Proof, Parameters};
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<S>
+where
+    S: SigmaProtocol,
+{
+    phantom: PhantomData<S>,
+}
+
+impl<S> Prover<S>
+where
+    S: SigmaProtocol,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<S>,
+        statement: &S::Statement,
+        witness: &S::Witness,
+        fs_rng: &mut T,
+    ) -> Result<Proof<S>, CryptoError> {
+        let (commitments, randomness): (Vec<_>, Vec<_>) = (0..parameters.repetitions)
+            .map(|_| S::commit(rng, parameters.parameters, statement, witness))
+            .collect::<Result<Vec<_>, CryptoError>>()?
+            .into_iter()
+            .unzip();
+
+        fs_rng.absorb(&to_bytes![
+            b"repetition",
+            parameters,
+            statement,
+            commitments
+        ]?);
+        let challenges = parameters.challenges(fs_rng);
+
+        let responses = randomness
+            .iter()
+            .zip(challenges.iter())
+            .map(|(random, c)| S::respond(parameters.parameters, statement, witness, random, c))
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(Proof {
+            commitments,
+            responses,
+        })
+    }
+}
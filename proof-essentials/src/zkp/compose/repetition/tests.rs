@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::compose::{repetition, RepetitionProof};
+    use crate::zkp::proofs::{chaum_pedersen_dl_equality, schnorr_identification};
+    use crate::zkp::ArgumentOfKnowledge;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Schnorr = schnorr_identification::SchnorrIdentification<Curve>;
+    type SchnorrRepetition<'a> = repetition::Repetition<'a, Schnorr>;
+    type DLEquality<'a> = chaum_pedersen_dl_equality::DLEquality<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_key = Scalar::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+
+        for parameters in [
+            repetition::Parameters::<Schnorr>::new(&generator, 4).unwrap(),
+            repetition::Parameters::<Schnorr>::with_challenge_bits(&generator, 128, 1).unwrap(),
+        ] {
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = SchnorrRepetition::prove(
+                rng,
+                &parameters,
+                &public_key,
+                &secret_key,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                SchnorrRepetition::verify(
+                    &parameters,
+                    &public_key,
+                    &proof,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Ok(())
+            );
+
+            let mut serialized = vec![0; proof.serialized_size()];
+            proof.serialize(&mut serialized[..]).unwrap();
+            let deserialized = RepetitionProof::<Schnorr>::deserialize(&serialized[..]).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                SchnorrRepetition::verify(
+                    &parameters,
+                    &public_key,
+                    &deserialized,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Ok(())
+            );
+        }
+
+        // Protocol relying on the default one-by-one batch check
+        let g = Curve::rand(rng).into_affine();
+        let h = Curve::rand(rng).into_affine();
+        let a = g.mul(secret_key).into_affine();
+        let b = h.mul(secret_key).into_affine();
+        let dl_parameters = chaum_pedersen_dl_equality::Parameters::new(&g, &h);
+        let statement = chaum_pedersen_dl_equality::Statement::new(&a, &b);
+        let parameters =
+            repetition::Parameters::<DLEquality>::with_challenge_bits(&dl_parameters, 64, 2)
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = repetition::Repetition::<DLEquality>::prove(
+            rng,
+            &parameters,
+            &statement,
+            &secret_key,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            repetition::Repetition::<DLEquality>::verify(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_key = Scalar::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Repetition",
+        )));
+
+        let parameters =
+            repetition::Parameters::<Schnorr>::with_challenge_bits(&generator, 32, 1).unwrap();
+
+        let wrong_key = Scalar::rand(rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = SchnorrRepetition::prove(
+            rng,
+            &parameters,
+            &public_key,
+            &wrong_key,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrRepetition::verify(
+                &parameters,
+                &public_key,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // A single bad repetition is caught by the batch check
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let mut proof = SchnorrRepetition::prove(
+            rng,
+            &parameters,
+            &public_key,
+            &secret_key,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        proof.responses[7] += Scalar::from(1u64);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrRepetition::verify(
+                &parameters,
+                &public_key,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // Proof for a different number of repetitions
+        let other_parameters =
+            repetition::Parameters::<Schnorr>::with_challenge_bits(&generator, 16, 1).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrRepetition::verify(
+                &other_parameters,
+                &public_key,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        assert!(repetition::Parameters::<Schnorr>::new(&generator, 0).is_err());
+        assert!(repetition::Parameters::<Schnorr>::with_challenge_bits(&generator, 8, 65).is_err());
+    }
+}
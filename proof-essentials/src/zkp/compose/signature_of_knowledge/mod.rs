@@ -0,0 +1,90 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::derive_challenge;
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::to_bytes;
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use digest::Digest;
+
+mod tests;
+
+/// Signature of knowledge (Chase and Lysyanskaya, Crypto 2006) built from a sigma protocol `S`: a Fiat-Shamir proof
+/// of knowledge of a witness for a statement of `S` whose challenge also depends on a message. Only someone knowing
+/// a witness can sign, and the signature verifies only for the message and statement it was produced for.
+///
+/// Unlike the Fiat-Shamir proofs of the individual protocols, the transcript is derived from the message alone
+/// rather than from a caller-provided random oracle, so signatures can be checked by anyone holding the message.
+pub struct SignatureOfKnowledge<S: SigmaProtocol> {
+    _protocol: PhantomData<S>,
+}
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Signature<S: SigmaProtocol> {
+    pub(crate) commitment: S::Commitment,
+    pub(crate) response: S::Response,
+}
+
+impl<S: SigmaProtocol> SignatureOfKnowledge<S> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Signature of Knowledge";
+
+    /// Sign `message` with a witness for `statement`.
+    pub fn sign<R: Rng, D: Digest>(
+        rng: &mut R,
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        witness: &S::Witness,
+        message: &[u8],
+    ) -> Result<Signature<S>, CryptoError> {
+        let (commitment, randomness) = S::commit(rng, parameters, statement, witness)?;
+
+        let challenge = Self::challenge::<D>(parameters, statement, &commitment, message)?;
+        let response = S::respond(parameters, statement, witness, &randomness, &challenge)?;
+
+        Ok(Signature {
+            commitment,
+            response,
+        })
+    }
+
+    pub fn verify<D: Digest>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        message: &[u8],
+        signature: &Signature<S>,
+    ) -> Result<(), CryptoError> {
+        let challenge =
+            Self::challenge::<D>(parameters, statement, &signature.commitment, message)?;
+
+        S::check(
+            parameters,
+            statement,
+            &signature.commitment,
+            &challenge,
+            &signature.response,
+        )
+        .map_err(|_| {
+            CryptoError::SignatureVerificationError(String::from("Signature of Knowledge"))
+        })
+    }
+
+    fn challenge<D: Digest>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        commitment: &S::Commitment,
+        message: &[u8],
+    ) -> Result<S::Scalar, CryptoError> {
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            parameters,
+            statement,
+            commitment,
+            message
+        ]?);
+
+        Ok(derive_challenge::<S::Scalar>(&mut fs_rng))
+    }
+}
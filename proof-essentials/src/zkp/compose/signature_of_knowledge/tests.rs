@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::compose::{and_composition, signature_of_knowledge};
+    use crate::zkp::proofs::schnorr_identification;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Schnorr = schnorr_identification::SchnorrIdentification<Curve>;
+    type SchnorrAnd = and_composition::AndComposition<Schnorr>;
+    type SchnorrSok = signature_of_knowledge::SignatureOfKnowledge<Schnorr>;
+    type SchnorrAndSok = signature_of_knowledge::SignatureOfKnowledge<SchnorrAnd>;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_key = Scalar::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+        let message = b"Transfer 10 tokens to Bob";
+
+        let signature =
+            SchnorrSok::sign::<_, Blake2s>(rng, &generator, &public_key, &secret_key, message)
+                .unwrap();
+        assert_eq!(
+            SchnorrSok::verify::<Blake2s>(&generator, &public_key, message, &signature),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; signature.serialized_size()];
+        signature.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            signature_of_knowledge::Signature::<Schnorr>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(
+            SchnorrSok::verify::<Blake2s>(&generator, &public_key, message, &deserialized),
+            Ok(())
+        );
+
+        let expected_error = Err(CryptoError::SignatureVerificationError(String::from(
+            "Signature of Knowledge",
+        )));
+
+        // The signature is bound to the message
+        assert_eq!(
+            SchnorrSok::verify::<Blake2s>(
+                &generator,
+                &public_key,
+                b"Transfer 99 tokens to Eve",
+                &signature
+            ),
+            expected_error
+        );
+
+        // and to the statement
+        let other_key = generator.mul(Scalar::rand(rng)).into_affine();
+        assert_eq!(
+            SchnorrSok::verify::<Blake2s>(&generator, &other_key, message, &signature),
+            expected_error
+        );
+
+        // A wrong witness does not produce a valid signature
+        let wrong_key = Scalar::rand(rng);
+        let signature =
+            SchnorrSok::sign::<_, Blake2s>(rng, &generator, &public_key, &wrong_key, message)
+                .unwrap();
+        assert_eq!(
+            SchnorrSok::verify::<Blake2s>(&generator, &public_key, message, &signature),
+            expected_error
+        );
+    }
+
+    #[test]
+    fn test_composed_protocol() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_keys = (0..3).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| generator.mul(*sk).into_affine())
+            .collect::<Vec<_>>();
+        let message = b"Jointly signed by three keys";
+
+        let signature =
+            SchnorrAndSok::sign::<_, Blake2s>(rng, &generator, &public_keys, &secret_keys, message)
+                .unwrap();
+        assert_eq!(
+            SchnorrAndSok::verify::<Blake2s>(&generator, &public_keys, message, &signature),
+            Ok(())
+        );
+
+        assert!(SchnorrAndSok::verify::<Blake2s>(
+            &generator,
+            &public_keys[..2].to_vec(),
+            message,
+            &signature
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::error::CryptoError;
+use crate::zkp::SigmaProtocol;
+
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// Prover of a sigma protocol `S` run interactively, e.g. over a network with a live verifier instead of through the
+/// Fiat-Shamir transform. The three moves are [`Prover::prover_round1`], which sends the commitment, the verifier's
+/// [`Verifier::challenge`], and [`Prover::prover_round2`], which answers it.
+///
+/// The second round consumes the prover, so the randomness of a commitment is never used to answer two challenges,
+/// which would reveal the witness.
+pub struct Prover<'a, S: SigmaProtocol> {
+    parameters: &'a S::Parameters,
+    statement: &'a S::Statement,
+    witness: &'a S::Witness,
+    randomness: Option<S::Randomness>,
+}
+
+impl<'a, S: SigmaProtocol> Prover<'a, S> {
+    pub fn new(
+        parameters: &'a S::Parameters,
+        statement: &'a S::Statement,
+        witness: &'a S::Witness,
+    ) -> Self {
+        Self {
+            parameters,
+            statement,
+            witness,
+            randomness: None,
+        }
+    }
+
+    /// First move: commit to fresh randomness. It can only be played once.
+    pub fn prover_round1<R: Rng>(&mut self, rng: &mut R) -> Result<S::Commitment, CryptoError> {
+        if self.randomness.is_some() {
+            return Err(CryptoError::InvalidProtocolRound);
+        }
+
+        let (commitment, randomness) =
+            S::commit(rng, self.parameters, self.statement, self.witness)?;
+        self.randomness = Some(randomness);
+
+        Ok(commitment)
+    }
+
+    /// Third move: answer the verifier's challenge. Fails if no commitment was sent.
+    pub fn prover_round2(self, challenge: &S::Scalar) -> Result<S::Response, CryptoError> {
+        let randomness = self.randomness.ok_or(CryptoError::InvalidProtocolRound)?;
+
+        S::respond(
+            self.parameters,
+            self.statement,
+            self.witness,
+            &randomness,
+            challenge,
+        )
+    }
+}
+
+/// Verifier of a sigma protocol `S` run interactively: it receives the prover's commitment, replies with a uniformly
+/// random challenge and checks the response. Public-coin protocols are sound as long as the challenge is only drawn
+/// once the commitment has been received, which [`Verifier::challenge`] enforces.
+pub struct Verifier<'a, S: SigmaProtocol> {
+    parameters: &'a S::Parameters,
+    statement: &'a S::Statement,
+    transcript: Option<(S::Commitment, S::Scalar)>,
+}
+
+impl<'a, S: SigmaProtocol> Verifier<'a, S> {
+    pub fn new(parameters: &'a S::Parameters, statement: &'a S::Statement) -> Self {
+        Self {
+            parameters,
+            statement,
+            transcript: None,
+        }
+    }
+
+    /// Second move: receive the prover's commitment and draw the challenge. It can only be played once.
+    pub fn challenge<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        commitment: &S::Commitment,
+    ) -> Result<S::Scalar, CryptoError> {
+        if self.transcript.is_some() {
+            return Err(CryptoError::InvalidProtocolRound);
+        }
+
+        let challenge = S::Scalar::rand(rng);
+        self.transcript = Some((commitment.clone(), challenge));
+
+        Ok(challenge)
+    }
+
+    /// Check the prover's response to the challenge.
+    pub fn verify(self, response: &S::Response) -> Result<(), CryptoError> {
+        let (commitment, challenge) = self.transcript.ok_or(CryptoError::InvalidProtocolRound)?;
+
+        S::check(
+            self.parameters,
+            self.statement,
+            &commitment,
+            &challenge,
+            response,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Prover, Verifier};
+    use crate::error::CryptoError;
+    use crate::zkp::compose::and_composition::AndComposition;
+    use crate::zkp::proofs::schnorr_identification::SchnorrIdentification;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+
+    type Curve = starknet_curve::Projective;
+    type Point = starknet_curve::Affine;
+    type Scalar = starknet_curve::Fr;
+    type Schnorr = SchnorrIdentification<Curve>;
+    type SchnorrAnd = AndComposition<Schnorr>;
+
+    /// Serialize a message and read it back, as if it was sent over the network.
+    fn send<T: CanonicalSerialize + CanonicalDeserialize>(message: &T) -> T {
+        let mut bytes = Vec::new();
+        message.serialize(&mut bytes).unwrap();
+        T::deserialize(&bytes[..]).unwrap()
+    }
+
+    #[test]
+    fn interactive_schnorr() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_key = Scalar::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+
+        let mut prover = Prover::<Schnorr>::new(&generator, &public_key, &secret_key);
+        let mut verifier = Verifier::<Schnorr>::new(&generator, &public_key);
+
+        let commitment = prover.prover_round1(rng).unwrap();
+        let challenge = verifier.challenge(rng, &send(&commitment)).unwrap();
+        let response = prover.prover_round2(&send(&challenge)).unwrap();
+        assert_eq!(verifier.verify(&send(&response)), Ok(()));
+
+        // A prover without the secret key cannot answer
+        let wrong_key = Scalar::rand(rng);
+        let mut prover = Prover::<Schnorr>::new(&generator, &public_key, &wrong_key);
+        let mut verifier = Verifier::<Schnorr>::new(&generator, &public_key);
+
+        let commitment = prover.prover_round1(rng).unwrap();
+        let challenge = verifier.challenge(rng, &commitment).unwrap();
+        let response = prover.prover_round2(&challenge).unwrap();
+        assert_eq!(
+            verifier.verify(&response),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Schnorr Identification"
+            )))
+        );
+    }
+
+    #[test]
+    fn rounds_out_of_order() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_key = Scalar::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+
+        let prover = Prover::<Schnorr>::new(&generator, &public_key, &secret_key);
+        assert_eq!(
+            prover.prover_round2(&Scalar::rand(rng)).err(),
+            Some(CryptoError::InvalidProtocolRound)
+        );
+
+        let mut prover = Prover::<Schnorr>::new(&generator, &public_key, &secret_key);
+        let commitment = prover.prover_round1(rng).unwrap();
+        assert_eq!(
+            prover.prover_round1(rng).err(),
+            Some(CryptoError::InvalidProtocolRound)
+        );
+
+        let verifier = Verifier::<Schnorr>::new(&generator, &public_key);
+        assert_eq!(
+            verifier.verify(&Scalar::rand(rng)),
+            Err(CryptoError::InvalidProtocolRound)
+        );
+
+        let mut verifier = Verifier::<Schnorr>::new(&generator, &public_key);
+        verifier.challenge(rng, &commitment).unwrap();
+        assert_eq!(
+            verifier.challenge(rng, &commitment).err(),
+            Some(CryptoError::InvalidProtocolRound)
+        );
+    }
+
+    #[test]
+    fn interactive_and_composition() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_keys = vec![Scalar::rand(rng), Scalar::rand(rng)];
+        let public_keys: Vec<Point> = secret_keys
+            .iter()
+            .map(|sk| generator.mul(*sk).into_affine())
+            .collect();
+
+        let mut prover = Prover::<SchnorrAnd>::new(&generator, &public_keys, &secret_keys);
+        let mut verifier = Verifier::<SchnorrAnd>::new(&generator, &public_keys);
+
+        let commitment = prover.prover_round1(rng).unwrap();
+        let challenge = verifier.challenge(rng, &commitment).unwrap();
+        let response = prover.prover_round2(&challenge).unwrap();
+        assert_eq!(verifier.verify(&response), Ok(()));
+    }
+}
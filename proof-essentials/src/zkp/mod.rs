@@ -1,30 +1,187 @@
 use crate::error::CryptoError;
-use ark_marlin::rng::FiatShamirRng;
+use crate::zkp::transcript::Transcript;
+use ark_ff::{to_bytes, Field, ToBytes};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::rand::Rng;
-use digest::Digest;
 
 pub mod arguments;
+pub mod compose;
+pub mod interactive;
 pub mod proofs;
 pub mod transcript;
+pub mod transform;
 
+/// Non-interactive argument of knowledge, made and checked over any [`Transcript`].
+///
+/// Every proof is bound to a `domain`: a label naming the context it is made for (the application, the game, the
+/// message...). The domain is absorbed into the transcript before anything else, so a proof only verifies under the
+/// domain it was produced for and cannot be replayed in another context. Data known to the application at proving
+/// time (a game id, a round number, the id of the player) can further be bound with [`prove_in_context`].
+///
+/// Implementations provide [`prove_in_context`] and [`verify_in_context`], which absorb the domain and then the
+/// context with `absorb_domain`; [`prove`] and [`verify`] run them without a context.
+///
+/// [`prove_in_context`]: ArgumentOfKnowledge::prove_in_context
+/// [`verify_in_context`]: ArgumentOfKnowledge::verify_in_context
+/// [`prove`]: ArgumentOfKnowledge::prove
+/// [`verify`]: ArgumentOfKnowledge::verify
 pub trait ArgumentOfKnowledge {
     type CommonReferenceString;
     type Statement;
     type Witness;
     type Proof;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        Self::prove_in_context(
+            rng,
+            common_reference_string,
+            statement,
+            witness,
+            domain,
+            None,
+            fs_rng,
+        )
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        Self::verify_in_context(
+            common_reference_string,
+            statement,
+            proof,
+            domain,
+            None,
+            fs_rng,
+        )
+    }
+
+    /// Prove under the application-supplied `context`, which is absorbed into the transcript right after the domain.
+    /// The proof only verifies with [`verify_in_context`](ArgumentOfKnowledge::verify_in_context) under the same
+    /// context; without one, it is a proof of [`prove`](ArgumentOfKnowledge::prove).
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<Self::Proof, CryptoError>;
 
-    fn verify<D: Digest>(
+    /// Verify a proof made by [`prove_in_context`](ArgumentOfKnowledge::prove_in_context) under `context`.
+    fn verify_in_context<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError>;
+}
+
+/// Absorb the domain separator of a proof into the transcript, prefixed by its length, followed by the application
+/// context if there is one, labeled and prefixed by its length so that no context collides with another one or with
+/// the absence of context.
+pub(crate) fn absorb_domain<T: Transcript>(
+    fs_rng: &mut T,
+    domain: &[u8],
+    context: Option<&[u8]>,
+) -> Result<(), CryptoError> {
+    fs_rng.absorb(&to_bytes![domain.len() as u64, domain]?);
+    if let Some(context) = context {
+        fs_rng.absorb(&to_bytes![b"context", context.len() as u64, context]?);
+    }
+    Ok(())
+}
+
+/// Trait exposing the three moves of a sigma protocol (commitment, challenge, response) together with
+/// its special honest-verifier zero-knowledge simulator. Protocols implementing it can be combined
+/// generically (see [`compose`]) instead of only being run through their own Fiat-Shamir prover.
+pub trait SigmaProtocol {
+    type Scalar: Field;
+    type Parameters: ToBytes;
+    type Statement: ToBytes;
+    type Witness;
+
+    /// First message sent by the prover
+    type Commitment: Clone + ToBytes + CanonicalSerialize + CanonicalDeserialize;
+
+    /// Secret randomness kept by the prover between the first and the last message
+    type Randomness;
+
+    /// Last message sent by the prover
+    type Response: Clone + CanonicalSerialize + CanonicalDeserialize;
+
+    /// Produce the prover's first message and the randomness needed to answer the challenge.
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError>;
+
+    /// Answer the verifier's challenge.
+    fn respond(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError>;
+
+    /// Produce an accepting (commitment, response) pair for a given challenge without knowing a witness.
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError>;
+
+    /// Check that (commitment, challenge, response) is an accepting transcript for the statement.
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
     ) -> Result<(), CryptoError>;
+
+    /// Check several transcripts for the same statement at once. The default checks them one by one; protocols with
+    /// a linear verification equation can override it with a single check of a random linear combination.
+    fn batch_check<R: Rng>(
+        _rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitments: &[Self::Commitment],
+        challenges: &[Self::Scalar],
+        responses: &[Self::Response],
+    ) -> Result<(), CryptoError> {
+        if commitments.len() != challenges.len() || commitments.len() != responses.len() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Batch Sigma Protocol",
+            )));
+        }
+
+        for ((commitment, challenge), response) in commitments
+            .iter()
+            .zip(challenges.iter())
+            .zip(responses.iter())
+        {
+            Self::check(parameters, statement, commitment, challenge, response)?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that a Pedersen commitment $C = bG + rH$ opens to a bit $b \in \{0, 1\}$. This is the OR-composition of
+/// the two Schnorr proofs $C = rH$ and $C - G = rH$, specialised so that the proof is two group elements and three
+/// scalars.
+///
+/// Only the first generator and the blinding generator of the commit key are used.
+pub struct BitProof<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+pub type Parameters<C> = pedersen::CommitKey<C>;
+
+/// Commitment to the value that is claimed to be a bit.
+pub type Statement<C> = pedersen::Commitment<C>;
+
+/// Opening of the statement: the committed bit and its blinding factor.
+#[derive(Copy, Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub bit: bool,
+    pub randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(bit: bool, randomness: C::ScalarField) -> Self {
+        Self { bit, randomness }
+    }
+}
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for BitProof<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> BitProof<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Bit Proof";
+}
@@ -0,0 +1,64 @@
+use super::{Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) random_commits: (C::Affine, C::Affine),
+    /// Challenge of the branch $b = 0$; the challenge of the other branch is what remains of the Fiat-Shamir
+    /// challenge.
+    pub(crate) challenge: C::ScalarField,
+    pub(crate) openings: (C::ScalarField, C::ScalarField),
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error = CryptoError::ProofVerificationError(String::from("Bit Proof"));
+
+        if commit_key.is_empty() {
+            return Err(verification_error);
+        }
+
+        let g = commit_key.g[0];
+        let h = commit_key.h;
+
+        fs_rng.absorb(&to_bytes![
+            b"bit_proof",
+            commit_key,
+            statement,
+            vec![self.random_commits.0, self.random_commits.1]
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let challenge_0 = self.challenge;
+        let challenge_1 = c - challenge_0;
+
+        let statement_0 = statement.0.into_projective();
+        let statement_1 = statement_0 - g.into_projective();
+
+        if h.mul(self.openings.0.into_repr())
+            != self.random_commits.0.into_projective() + statement_0.mul(challenge_0.into_repr())
+            || h.mul(self.openings.1.into_repr())
+                != self.random_commits.1.into_projective()
+                    + statement_1.mul(challenge_1.into_repr())
+        {
+            return Err(verification_error);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use super::{proof::Proof, Parameters, Statement, Witness};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        if commit_key.is_empty() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                1,
+                0,
+            ));
+        }
+
+        let g = commit_key.g[0];
+        let h = commit_key.h;
+
+        // Branch i proves knowledge of the discrete logarithm of C - iG in base H
+        let branches = [
+            statement.0.into_projective(),
+            statement.0.into_projective() - g.into_projective(),
+        ];
+        let real = witness.bit as usize;
+        let simulated = 1 - real;
+
+        let simulated_challenge = C::ScalarField::rand(rng);
+        let simulated_opening = C::ScalarField::rand(rng);
+        let simulated_commit = h.mul(simulated_opening.into_repr())
+            - branches[simulated].mul(simulated_challenge.into_repr());
+
+        let random = C::ScalarField::rand(rng);
+        let real_commit = h.mul(random.into_repr());
+
+        let mut random_commits = [real_commit, real_commit];
+        random_commits[simulated] = simulated_commit;
+        let random_commits = C::batch_normalization_into_affine(&random_commits);
+
+        fs_rng.absorb(&to_bytes![
+            b"bit_proof",
+            commit_key,
+            statement,
+            random_commits
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let real_challenge = c - simulated_challenge;
+        let real_opening = random + real_challenge * witness.randomness;
+
+        let mut challenges = [real_challenge, real_challenge];
+        challenges[simulated] = simulated_challenge;
+        let mut openings = [real_opening, real_opening];
+        openings[simulated] = simulated_opening;
+
+        Ok(Proof {
+            random_commits: (random_commits[0], random_commits[1]),
+            challenge: challenges[0],
+            openings: (openings[0], openings[1]),
+        })
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{proofs::bit, ArgumentOfKnowledge};
+    use ark_ff::{One, Zero};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type BitProof = bit::BitProof<Curve>;
+    type Witness = bit::Witness<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let commit_key = Pedersen::setup(rng, 1);
+
+        for (bit, value) in [(false, Scalar::zero()), (true, Scalar::one())] {
+            let witness = Witness::new(bit, Scalar::rand(rng));
+            let statement =
+                Pedersen::commit(&commit_key, &vec![value], witness.randomness).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = BitProof::prove(
+                rng,
+                &commit_key,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                BitProof::verify(&commit_key, &statement, &proof, b"Test Domain", &mut fs_rng),
+                Ok(())
+            );
+
+            let mut serialized = vec![0; proof.serialized_size()];
+            proof.serialize(&mut serialized[..]).unwrap();
+            let deserialized = bit::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                BitProof::verify(
+                    &commit_key,
+                    &statement,
+                    &deserialized,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let commit_key = Pedersen::setup(rng, 1);
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Bit Proof",
+        )));
+
+        // A commitment to a value which is not a bit
+        let randomness = Scalar::rand(rng);
+        let statement =
+            Pedersen::commit(&commit_key, &vec![Scalar::from(2u64)], randomness).unwrap();
+        for bit in [false, true] {
+            let witness = Witness::new(bit, randomness);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = BitProof::prove(
+                rng,
+                &commit_key,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                BitProof::verify(&commit_key, &statement, &proof, b"Test Domain", &mut fs_rng),
+                expected_error
+            );
+        }
+
+        // Claiming the wrong bit
+        let statement = Pedersen::commit(&commit_key, &vec![Scalar::one()], randomness).unwrap();
+        let witness = Witness::new(false, randomness);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = BitProof::prove(
+            rng,
+            &commit_key,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BitProof::verify(&commit_key, &statement, &proof, b"Test Domain", &mut fs_rng),
+            expected_error
+        );
+    }
+}
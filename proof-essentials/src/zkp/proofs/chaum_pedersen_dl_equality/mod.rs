@@ -3,12 +3,15 @@ pub mod prover;
 mod test;
 
 use crate::error::CryptoError;
-use crate::zkp::ArgumentOfKnowledge;
-use ark_ec::ProjectiveCurve;
-use ark_marlin::rng::FiatShamirRng;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, ToBytes};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
 use ark_std::marker::PhantomData;
 use ark_std::rand::Rng;
-use digest::Digest;
+use ark_std::UniformRand;
 
 pub struct DLEquality<'a, C: ProjectiveCurve> {
     _group: PhantomData<&'a C>,
@@ -26,6 +29,15 @@ impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
     }
 }
 
+impl<'a, C: ProjectiveCurve> ToBytes for Parameters<'a, C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.g.write(&mut w)?;
+        self.h.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
 /// Statement for a Chaum-Pedersen proof of discrete logarithm equality.
 /// Expects two points $A$ and $B$ such that for some secret $x$ and parameters
 /// $G$ and $H$, $A = xG$ and $B=xH$
@@ -38,7 +50,16 @@ impl<'a, C: ProjectiveCurve> Statement<'a, C> {
     }
 }
 
-type Witness<C> = <C as ProjectiveCurve>::ScalarField;
+impl<'a, C: ProjectiveCurve> ToBytes for Statement<'a, C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0.write(&mut w)?;
+        self.1.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+pub type Witness<C> = <C as ProjectiveCurve>::ScalarField;
 
 impl<'a, C> ArgumentOfKnowledge for DLEquality<'a, C>
 where
@@ -49,22 +70,122 @@ where
     type Witness = Witness<C>;
     type Proof = proof::Proof<C>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove_in_context<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
         prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
     }
 
-    fn verify<D: Digest>(
+    fn verify_in_context<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
         proof.verify(common_reference_string, statement, fs_rng)
     }
 }
+
+/// First message of the Chaum-Pedersen protocol when it is run as a [`SigmaProtocol`]: the random
+/// nonce multiplied by each of the two bases.
+#[derive(Copy, Clone, PartialEq, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<C: ProjectiveCurve>(pub C::Affine, pub C::Affine);
+
+impl<C: ProjectiveCurve> ToBytes for Commitment<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0.write(&mut w)?;
+        self.1.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+impl<'a, C: ProjectiveCurve> SigmaProtocol for DLEquality<'a, C> {
+    type Scalar = C::ScalarField;
+    type Parameters = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Commitment = Commitment<C>;
+    type Randomness = C::ScalarField;
+    type Response = C::ScalarField;
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        _witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        let omega = C::ScalarField::rand(rng);
+        let a = parameters.g.mul(omega.into_repr()).into_affine();
+        let b = parameters.h.mul(omega.into_repr()).into_affine();
+
+        Ok((Commitment(a, b), omega))
+    }
+
+    fn respond(
+        _parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        Ok(*randomness + *challenge * witness)
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        let r = C::ScalarField::rand(rng);
+        let a = parameters.g.mul(r.into_repr()) - statement.0.mul(challenge.into_repr());
+        let b = parameters.h.mul(r.into_repr()) - statement.1.mul(challenge.into_repr());
+
+        Ok((Commitment(a.into_affine(), b.into_affine()), r))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        // g * r ==? a + x*c
+        if parameters.g.mul(response.into_repr())
+            != commitment.0.into_projective() + statement.0.mul(challenge.into_repr())
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Chaum-Pedersen",
+            )));
+        }
+
+        // h * r ==? b + y*c
+        if parameters.h.mul(response.into_repr())
+            != commitment.1.into_projective() + statement.1.mul(challenge.into_repr())
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Chaum-Pedersen",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C: ProjectiveCurve> DLEquality<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Chaum-Pedersen DL Equality";
+}
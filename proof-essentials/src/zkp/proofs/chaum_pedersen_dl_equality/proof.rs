@@ -1,16 +1,14 @@
 use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
 use super::{Parameters, Statement};
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::to_bytes;
-use ark_marlin::rng::FiatShamirRng;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use ark_std::UniformRand;
-use digest::Digest;
 
-#[derive(CanonicalDeserialize, CanonicalSerialize)]
+#[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
 pub struct Proof<C>
 where
     C: ProjectiveCurve,
@@ -21,11 +19,11 @@ where
 }
 
 impl<C: ProjectiveCurve> Proof<C> {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         parameters: &Parameters<C>,
         statement: &Statement<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
         fs_rng.absorb(&to_bytes![
             b"chaum_pedersen",
@@ -34,9 +32,9 @@ impl<C: ProjectiveCurve> Proof<C> {
             statement.0,
             statement.1
         ]?);
-        fs_rng.absorb(&to_bytes![&self.a, &self.b]?);
+        fs_rng.absorb(&to_bytes![self.a.into_affine(), self.b.into_affine()]?);
 
-        let c = C::ScalarField::rand(fs_rng);
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
 
         // g * r ==? a + x*c
         if parameters.g.mul(self.r) != self.a + statement.0.mul(c) {
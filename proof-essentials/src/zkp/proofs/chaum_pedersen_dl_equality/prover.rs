@@ -1,13 +1,12 @@
 use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
 use super::proof::Proof;
 use super::{Parameters, Statement, Witness};
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{to_bytes, PrimeField};
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::{rand::Rng, UniformRand};
-use digest::Digest;
 
 use std::marker::PhantomData;
 
@@ -22,12 +21,12 @@ impl<C> Prover<C>
 where
     C: ProjectiveCurve,
 {
-    pub fn create_proof<R: Rng, D: Digest>(
+    pub fn create_proof<R: Rng, T: Transcript>(
         rng: &mut R,
         parameters: &Parameters<C>,
         statement: &Statement<C>,
         witness: &Witness<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<Proof<C>, CryptoError> {
         fs_rng.absorb(
             &to_bytes![
@@ -44,9 +43,9 @@ where
         let a = parameters.g.mul(omega.into_repr());
         let b = parameters.h.mul(omega.into_repr());
 
-        fs_rng.absorb(&to_bytes![a, b]?);
+        fs_rng.absorb(&to_bytes![a.into_affine(), b.into_affine()]?);
 
-        let c = C::ScalarField::rand(fs_rng);
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
 
         let r = omega + c * *witness;
 
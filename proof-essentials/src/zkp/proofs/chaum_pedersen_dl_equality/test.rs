@@ -7,6 +7,7 @@ mod test {
     use crate::zkp::ArgumentOfKnowledge;
     use ark_ec::{AffineCurve, ProjectiveCurve};
     use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
     use ark_std::{rand::thread_rng, UniformRand};
     use blake2::Blake2s;
     use rand::{prelude::ThreadRng, Rng};
@@ -51,14 +52,21 @@ mod test {
             &mut rng,
             &crs,
             &statement,
-            &witness,
+            witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
         assert_eq!(
-            DLEquality::<starknet_curve::Projective>::verify(&crs, &statement, &proof, &mut fs_rng),
+            DLEquality::<starknet_curve::Projective>::verify(
+                &crs,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
             Ok(())
         );
 
@@ -86,7 +94,8 @@ mod test {
             &mut rng,
             &crs,
             &statement,
-            &wrong_witness,
+            wrong_witness,
+            b"Test Domain",
             &mut fs_rng,
         )
         .unwrap();
@@ -97,11 +106,54 @@ mod test {
                 &crs,
                 &statement,
                 &invalid_proof,
-                &mut fs_rng
+                b"Test Domain",
+                &mut fs_rng,
             ),
             Err(CryptoError::ProofVerificationError(String::from(
                 "Chaum-Pedersen"
             )))
         );
     }
+
+    #[test]
+    fn test_serialize_deserialize_proof() {
+        let (mut rng, g, h, secret) = test_template();
+
+        let point_a = g.mul(secret).into_affine();
+        let point_b = h.mul(secret).into_affine();
+
+        let crs = Parameters::new(&g, &h);
+        let statement = chaum_pedersen_dl_equality::Statement::<Curve>::new(&point_a, &point_b);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = DLEquality::<Curve>::prove(
+            &mut rng,
+            &crs,
+            &statement,
+            &secret,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+
+        let deserialized =
+            chaum_pedersen_dl_equality::proof::Proof::<Curve>::deserialize(&serialized[..])
+                .unwrap();
+        assert_eq!(proof, deserialized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            DLEquality::<Curve>::verify(
+                &crs,
+                &statement,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
 }
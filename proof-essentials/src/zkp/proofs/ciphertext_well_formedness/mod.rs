@@ -0,0 +1,94 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::ToBytes;
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that an ElGamal ciphertext $c = (c_1, c_2)$ is a well-formed encryption under a given public key, by
+/// knowledge of its randomness $r$ such that $c_1 = rG$: it then encrypts the message $c_2 - r \cdot pk$.
+///
+/// The generator, the public key and the ciphertext are all hashed into the challenge together with the prover's
+/// commitment (strong Fiat-Shamir), so a proof cannot be reused for another ciphertext or another key, and
+/// ciphertexts with $c_1 = 0$, which would carry their message in the clear, are rejected.
+pub struct CiphertextWellFormedness<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub encrypt_parameters: &'a el_gamal::Parameters<C>,
+    pub public_key: &'a el_gamal::PublicKey<C>,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(
+        encrypt_parameters: &'a el_gamal::Parameters<C>,
+        public_key: &'a el_gamal::PublicKey<C>,
+    ) -> Self {
+        Self {
+            encrypt_parameters,
+            public_key,
+        }
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ToBytes for Parameters<'a, C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.encrypt_parameters.generator.write(&mut w)?;
+        self.public_key.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// The ciphertext claimed to be well formed.
+pub type Statement<C> = el_gamal::Ciphertext<C>;
+
+/// Encryption randomness of the ciphertext.
+pub type Witness<C> = <C as ProjectiveCurve>::ScalarField;
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for CiphertextWellFormedness<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<'a, C: ProjectiveCurve> CiphertextWellFormedness<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"ElGamal Ciphertext Well-Formedness";
+}
@@ -0,0 +1,53 @@
+use super::{CiphertextWellFormedness, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) random_commit: C::Affine,
+    pub(crate) opening: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            CryptoError::ProofVerificationError(String::from("ElGamal Ciphertext Well-Formedness"));
+
+        if statement.0.is_zero() {
+            return Err(verification_error);
+        }
+
+        fs_rng.absorb(&to_bytes![
+            CiphertextWellFormedness::<C>::PROTOCOL_NAME,
+            parameters,
+            statement,
+            self.random_commit
+        ]?);
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        // G * z ==? A + c * c_1
+        if parameters
+            .encrypt_parameters
+            .generator
+            .mul(self.opening.into_repr())
+            != self.random_commit.into_projective() + statement.0.mul(c.into_repr())
+        {
+            return Err(verification_error);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use super::{proof::Proof, CiphertextWellFormedness, Parameters, Statement, Witness};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, Zero};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        if witness.is_zero() {
+            return Err(CryptoError::InvalidCiphertextStatement);
+        }
+
+        let omega = C::ScalarField::rand(rng);
+        let random_commit = parameters
+            .encrypt_parameters
+            .generator
+            .mul(omega.into_repr())
+            .into_affine();
+
+        fs_rng.absorb(&to_bytes![
+            CiphertextWellFormedness::<C>::PROTOCOL_NAME,
+            parameters,
+            statement,
+            random_commit
+        ]?);
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        Ok(Proof {
+            random_commit,
+            opening: omega + c * witness,
+        })
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::zkp::{proofs::ciphertext_well_formedness, ArgumentOfKnowledge};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::Zero;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Plaintext = el_gamal::Plaintext<Curve>;
+    type WellFormedness<'a> = ciphertext_well_formedness::CiphertextWellFormedness<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let r = Scalar::rand(rng);
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &r).unwrap();
+        let parameters = ciphertext_well_formedness::Parameters::new(&pp, &pk);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = WellFormedness::prove(
+            rng,
+            &parameters,
+            &ciphertext,
+            &r,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            WellFormedness::verify(
+                &parameters,
+                &ciphertext,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            ciphertext_well_formedness::proof::Proof::<Curve>::deserialize(&serialized[..])
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            WellFormedness::verify(
+                &parameters,
+                &ciphertext,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let (other_pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "ElGamal Ciphertext Well-Formedness",
+        )));
+
+        let r = Scalar::rand(rng);
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &r).unwrap();
+        let parameters = ciphertext_well_formedness::Parameters::new(&pp, &pk);
+
+        // Wrong randomness
+        let wrong_r = Scalar::rand(rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = WellFormedness::prove(
+            rng,
+            &parameters,
+            &ciphertext,
+            &wrong_r,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            WellFormedness::verify(
+                &parameters,
+                &ciphertext,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = WellFormedness::prove(
+            rng,
+            &parameters,
+            &ciphertext,
+            &r,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        // The proof is bound to the public key and to both components of the ciphertext
+        let other_parameters = ciphertext_well_formedness::Parameters::new(&pp, &other_pk);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            WellFormedness::verify(
+                &other_parameters,
+                &ciphertext,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let mauled = el_gamal::Ciphertext(
+            ciphertext.0,
+            (ciphertext.1.into_projective() + Curve::rand(rng)).into_affine(),
+        );
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            WellFormedness::verify(&parameters, &mauled, &proof, b"Test Domain", &mut fs_rng),
+            expected_error
+        );
+
+        // A ciphertext with no randomness exposes its message
+        let m = Plaintext::rand(rng);
+        let in_the_clear = ElGamal::encrypt(&pp, &pk, &m, &Scalar::zero()).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            WellFormedness::prove(
+                rng,
+                &parameters,
+                &in_the_clear,
+                &Scalar::zero(),
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Err(CryptoError::InvalidCiphertextStatement)
+        );
+        let forged = ciphertext_well_formedness::proof::Proof::<Curve> {
+            random_commit: Curve::rand(rng).into_affine(),
+            opening: Scalar::rand(rng),
+        };
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            WellFormedness::verify(
+                &parameters,
+                &in_the_clear,
+                &forged,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+    }
+}
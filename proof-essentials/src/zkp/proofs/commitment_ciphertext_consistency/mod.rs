@@ -0,0 +1,180 @@
+mod test;
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal;
+use crate::vector_commitment::pedersen;
+use crate::zkp::proofs::linear_relation::{self, Equation, LinearRelation, Term};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that a Pedersen commitment $C = m G_1 + s H$ and an ElGamal ciphertext $c = (r G, m B + r pk)$ hide the
+/// same scalar $m$, where $B$ is the base used to encode scalars as ElGamal plaintexts. This lets a protocol move a
+/// value between its committed and its encrypted representation.
+///
+/// The statement is proven as the linear relation $C = m G_1 + s H \wedge c_1 = r G \wedge c_2 = m B + r pk$.
+pub struct CommitmentCiphertextConsistency<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub commit_key: &'a pedersen::CommitKey<C>,
+    pub encrypt_parameters: &'a el_gamal::Parameters<C>,
+    pub public_key: &'a el_gamal::PublicKey<C>,
+    pub message_base: &'a C::Affine,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(
+        commit_key: &'a pedersen::CommitKey<C>,
+        encrypt_parameters: &'a el_gamal::Parameters<C>,
+        public_key: &'a el_gamal::PublicKey<C>,
+        message_base: &'a C::Affine,
+    ) -> Self {
+        Self {
+            commit_key,
+            encrypt_parameters,
+            public_key,
+            message_base,
+        }
+    }
+}
+
+/// Statement for a consistency proof: a commitment to a single scalar and an encryption of that scalar.
+#[derive(Copy, Clone)]
+pub struct Statement<'a, C: ProjectiveCurve> {
+    pub commitment: &'a pedersen::Commitment<C>,
+    pub ciphertext: &'a el_gamal::Ciphertext<C>,
+}
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(
+        commitment: &'a pedersen::Commitment<C>,
+        ciphertext: &'a el_gamal::Ciphertext<C>,
+    ) -> Self {
+        Self {
+            commitment,
+            ciphertext,
+        }
+    }
+}
+
+/// Common message, together with the commitment blinding factor and the encryption randomness.
+#[derive(Copy, Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub message: C::ScalarField,
+    pub blinding: C::ScalarField,
+    pub randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(
+        message: C::ScalarField,
+        blinding: C::ScalarField,
+        randomness: C::ScalarField,
+    ) -> Self {
+        Self {
+            message,
+            blinding,
+            randomness,
+        }
+    }
+}
+
+pub type Proof<C> = linear_relation::proof::Proof<C>;
+
+impl<'a, C: ProjectiveCurve> CommitmentCiphertextConsistency<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Commitment Ciphertext Consistency";
+
+    fn relation(
+        parameters: &Parameters<'a, C>,
+        statement: &Statement<'a, C>,
+    ) -> Result<
+        (
+            linear_relation::Parameters<C>,
+            linear_relation::Statement<C>,
+        ),
+        CryptoError,
+    > {
+        let commit_key = parameters.commit_key;
+        let message_generator = *commit_key.g.first().ok_or_else(|| {
+            CryptoError::CommitmentLengthError(String::from("Pedersen"), 1, commit_key.len())
+        })?;
+
+        let relation = linear_relation::Parameters::new(
+            3,
+            vec![
+                Equation::new(vec![
+                    Term::new(0, message_generator),
+                    Term::new(1, commit_key.h),
+                ]),
+                Equation::new(vec![Term::new(2, parameters.encrypt_parameters.generator)]),
+                Equation::new(vec![
+                    Term::new(0, *parameters.message_base),
+                    Term::new(2, *parameters.public_key),
+                ]),
+            ],
+        )?;
+
+        let images = vec![
+            statement.commitment.0,
+            statement.ciphertext.0,
+            statement.ciphertext.1,
+        ];
+
+        Ok((relation, images))
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for CommitmentCiphertextConsistency<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let (relation, images) = Self::relation(common_reference_string, statement)?;
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        LinearRelation::prove(
+            rng,
+            &relation,
+            &images,
+            &vec![witness.message, witness.blinding, witness.randomness],
+            domain,
+            fs_rng,
+        )
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let (relation, images) = Self::relation(common_reference_string, statement)?;
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        LinearRelation::verify(&relation, &images, proof, domain, fs_rng).map_err(|_| {
+            CryptoError::ProofVerificationError(String::from("Commitment Ciphertext Consistency"))
+        })
+    }
+}
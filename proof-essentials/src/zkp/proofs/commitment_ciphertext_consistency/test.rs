@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{proofs::commitment_ciphertext_consistency, ArgumentOfKnowledge};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type Consistency<'a> =
+        commitment_ciphertext_consistency::CommitmentCiphertextConsistency<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn encode(base: &starknet_curve::Affine, message: &Scalar) -> el_gamal::Plaintext<Curve> {
+        el_gamal::Plaintext(base.mul(*message).into_affine())
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let commit_key = Pedersen::setup(rng, 1);
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let message_base = Curve::rand(rng).into_affine();
+
+        let witness = commitment_ciphertext_consistency::Witness::new(
+            Scalar::rand(rng),
+            Scalar::rand(rng),
+            Scalar::rand(rng),
+        );
+        let commitment =
+            Pedersen::commit(&commit_key, &vec![witness.message], witness.blinding).unwrap();
+        let ciphertext = ElGamal::encrypt(
+            &pp,
+            &pk,
+            &encode(&message_base, &witness.message),
+            &witness.randomness,
+        )
+        .unwrap();
+
+        let parameters = commitment_ciphertext_consistency::Parameters::new(
+            &commit_key,
+            &pp,
+            &pk,
+            &message_base,
+        );
+        let statement = commitment_ciphertext_consistency::Statement::new(&commitment, &ciphertext);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Consistency::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Consistency::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let commit_key = Pedersen::setup(rng, 1);
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let message_base = Curve::rand(rng).into_affine();
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Commitment Ciphertext Consistency",
+        )));
+
+        let witness = commitment_ciphertext_consistency::Witness::new(
+            Scalar::rand(rng),
+            Scalar::rand(rng),
+            Scalar::rand(rng),
+        );
+        let commitment =
+            Pedersen::commit(&commit_key, &vec![witness.message], witness.blinding).unwrap();
+
+        // The ciphertext encrypts a different message
+        let other_message = Scalar::rand(rng);
+        let ciphertext = ElGamal::encrypt(
+            &pp,
+            &pk,
+            &encode(&message_base, &other_message),
+            &witness.randomness,
+        )
+        .unwrap();
+
+        let parameters = commitment_ciphertext_consistency::Parameters::new(
+            &commit_key,
+            &pp,
+            &pk,
+            &message_base,
+        );
+        let statement = commitment_ciphertext_consistency::Statement::new(&commitment, &ciphertext);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = Consistency::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Consistency::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // A valid proof checked against another public key
+        let ciphertext = ElGamal::encrypt(
+            &pp,
+            &pk,
+            &encode(&message_base, &witness.message),
+            &witness.randomness,
+        )
+        .unwrap();
+        let statement = commitment_ciphertext_consistency::Statement::new(&commitment, &ciphertext);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Consistency::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let (other_pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let other_parameters = commitment_ciphertext_consistency::Parameters::new(
+            &commit_key,
+            &pp,
+            &other_pk,
+            &message_base,
+        );
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Consistency::verify(
+                &other_parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // An empty commit key cannot hold the message
+        let empty_key = Pedersen::setup(rng, 0);
+        let parameters =
+            commitment_ciphertext_consistency::Parameters::new(&empty_key, &pp, &pk, &message_base);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Consistency::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng
+            )
+            .err(),
+            Some(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                1,
+                0
+            ))
+        );
+    }
+}
@@ -0,0 +1,259 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::{self, PedersenCommitment};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ec::ProjectiveCurve;
+use ark_ff::ToBytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// Proof that two Pedersen commitments $C_1 = \langle m, G_1 \rangle + r_1 H_1$ and
+/// $C_2 = \langle m, G_2 \rangle + r_2 H_2$, possibly under different commit keys, hide the same message $m$.
+pub struct CommitmentEquality<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub commit_key_1: &'a pedersen::CommitKey<C>,
+    pub commit_key_2: &'a pedersen::CommitKey<C>,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(
+        commit_key_1: &'a pedersen::CommitKey<C>,
+        commit_key_2: &'a pedersen::CommitKey<C>,
+    ) -> Self {
+        Self {
+            commit_key_1,
+            commit_key_2,
+        }
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ToBytes for Parameters<'a, C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.commit_key_1.write(&mut w)?;
+        self.commit_key_2.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// A pair of Pedersen commitments, the first under `commit_key_1` and the second under `commit_key_2`.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitmentPair<C: ProjectiveCurve>(
+    pub pedersen::Commitment<C>,
+    pub pedersen::Commitment<C>,
+);
+
+impl<C: ProjectiveCurve> CommitmentPair<C> {
+    pub fn new(
+        commitment_1: pedersen::Commitment<C>,
+        commitment_2: pedersen::Commitment<C>,
+    ) -> Self {
+        Self(commitment_1, commitment_2)
+    }
+}
+
+impl<C: ProjectiveCurve> ToBytes for CommitmentPair<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0.write(&mut w)?;
+        self.1.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+pub type Statement<C> = CommitmentPair<C>;
+
+/// Common message of both commitments together with the blinding factor used for each of them.
+#[derive(Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub message: Vec<C::ScalarField>,
+    pub randomness_1: C::ScalarField,
+    pub randomness_2: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(
+        message: &[C::ScalarField],
+        randomness_1: C::ScalarField,
+        randomness_2: C::ScalarField,
+    ) -> Self {
+        Self {
+            message: message.to_vec(),
+            randomness_1,
+            randomness_2,
+        }
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for CommitmentEquality<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<'a, C: ProjectiveCurve> SigmaProtocol for CommitmentEquality<'a, C> {
+    type Scalar = C::ScalarField;
+    type Parameters = Parameters<'a, C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Commitment = CommitmentPair<C>;
+    type Randomness = Witness<C>;
+    type Response = (Vec<C::ScalarField>, C::ScalarField, C::ScalarField);
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        let random_message = (0..witness.message.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let random = Witness::new(
+            &random_message,
+            C::ScalarField::rand(rng),
+            C::ScalarField::rand(rng),
+        );
+
+        let random_commits = CommitmentPair(
+            PedersenCommitment::<C>::commit(
+                parameters.commit_key_1,
+                &random.message,
+                random.randomness_1,
+            )?,
+            PedersenCommitment::<C>::commit(
+                parameters.commit_key_2,
+                &random.message,
+                random.randomness_2,
+            )?,
+        );
+
+        Ok((random_commits, random))
+    }
+
+    fn respond(
+        _parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        let opening_message = randomness
+            .message
+            .iter()
+            .zip(witness.message.iter())
+            .map(|(random, m)| *random + *challenge * m)
+            .collect::<Vec<_>>();
+
+        Ok((
+            opening_message,
+            randomness.randomness_1 + *challenge * witness.randomness_1,
+            randomness.randomness_2 + *challenge * witness.randomness_2,
+        ))
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        let length =
+            ark_std::cmp::min(parameters.commit_key_1.len(), parameters.commit_key_2.len());
+        let opening_message = (0..length)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let opening_randomness_1 = C::ScalarField::rand(rng);
+        let opening_randomness_2 = C::ScalarField::rand(rng);
+
+        let random_commits = CommitmentPair(
+            PedersenCommitment::<C>::commit(
+                parameters.commit_key_1,
+                &opening_message,
+                opening_randomness_1,
+            )? + statement.0 * -*challenge,
+            PedersenCommitment::<C>::commit(
+                parameters.commit_key_2,
+                &opening_message,
+                opening_randomness_2,
+            )? + statement.1 * -*challenge,
+        );
+
+        Ok((
+            random_commits,
+            (opening_message, opening_randomness_1, opening_randomness_2),
+        ))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            |_| CryptoError::ProofVerificationError(String::from("Commitment Equality"));
+
+        let opened_1 =
+            PedersenCommitment::<C>::commit(parameters.commit_key_1, &response.0, response.1)
+                .map_err(verification_error)?;
+        let opened_2 =
+            PedersenCommitment::<C>::commit(parameters.commit_key_2, &response.0, response.2)
+                .map_err(verification_error)?;
+
+        if opened_1 != commitment.0 + statement.0 * *challenge
+            || opened_2 != commitment.1 + statement.1 * *challenge
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Equality",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C: ProjectiveCurve> CommitmentEquality<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Commitment Equality";
+}
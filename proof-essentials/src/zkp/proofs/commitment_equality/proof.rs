@@ -0,0 +1,50 @@
+use super::{CommitmentEquality, CommitmentPair, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) random_commits: CommitmentPair<C>,
+    pub(crate) opening_message: Vec<C::ScalarField>,
+    pub(crate) opening_randomness_1: C::ScalarField,
+    pub(crate) opening_randomness_2: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            b"commitment_equality",
+            parameters,
+            statement,
+            self.random_commits
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        CommitmentEquality::<C>::check(
+            parameters,
+            statement,
+            &self.random_commits,
+            &c,
+            &(
+                self.opening_message.clone(),
+                self.opening_randomness_1,
+                self.opening_randomness_2,
+            ),
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use super::{proof::Proof, CommitmentEquality, Parameters, Statement, Witness};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (random_commits, randomness) =
+            CommitmentEquality::<C>::commit(rng, parameters, statement, witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            b"commitment_equality",
+            parameters,
+            statement,
+            random_commits
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let (opening_message, opening_randomness_1, opening_randomness_2) =
+            CommitmentEquality::<C>::respond(parameters, statement, witness, &randomness, &c)?;
+
+        Ok(Proof {
+            random_commits,
+            opening_message,
+            opening_randomness_1,
+            opening_randomness_2,
+        })
+    }
+}
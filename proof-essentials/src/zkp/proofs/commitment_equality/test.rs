@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{proofs::commitment_equality, ArgumentOfKnowledge};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type Equality<'a> = commitment_equality::CommitmentEquality<'a, Curve>;
+    type Parameters<'a> = commitment_equality::Parameters<'a, Curve>;
+    type Statement = commitment_equality::Statement<Curve>;
+    type Witness = commitment_equality::Witness<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let n = 6;
+
+        let commit_key_1 = Pedersen::setup(rng, n);
+        let commit_key_2 = Pedersen::setup(rng, n + 2);
+        let parameters = Parameters::new(&commit_key_1, &commit_key_2);
+
+        let message: Vec<Scalar> = sample_vector(rng, n);
+        let witness = Witness::new(&message, Scalar::rand(rng), Scalar::rand(rng));
+        let statement = Statement::new(
+            Pedersen::commit(&commit_key_1, &message, witness.randomness_1).unwrap(),
+            Pedersen::commit(&commit_key_2, &message, witness.randomness_2).unwrap(),
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Equality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Equality::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            commitment_equality::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Equality::verify(
+                &parameters,
+                &statement,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let n = 6;
+
+        let commit_key_1 = Pedersen::setup(rng, n);
+        let commit_key_2 = Pedersen::setup(rng, n);
+        let parameters = Parameters::new(&commit_key_1, &commit_key_2);
+
+        let message: Vec<Scalar> = sample_vector(rng, n);
+        let mut other_message = message.clone();
+        other_message[0] = Scalar::rand(rng);
+
+        let witness = Witness::new(&message, Scalar::rand(rng), Scalar::rand(rng));
+        let statement = Statement::new(
+            Pedersen::commit(&commit_key_1, &message, witness.randomness_1).unwrap(),
+            Pedersen::commit(&commit_key_2, &other_message, witness.randomness_2).unwrap(),
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = Equality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Equality::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Equality"
+            )))
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use super::{CommitmentOpening, Parameters, Statement, Witness};
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen;
+use crate::zkp::arguments::compressed_sigma;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof of knowledge of an opening of a Pedersen vector commitment with a response compressed by
+/// [`compressed_sigma`]: the proof has $2 \lceil \log_2(n + 1) \rceil + 1$ group elements and one scalar for a commit
+/// key of length $n$, instead of the $n + 1$ scalars of [`CommitmentOpening`]. The opening is proven against the
+/// whole commit key, messages shorter than the key being padded with zeros.
+pub struct CompressedCommitmentOpening<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C: ProjectiveCurve> {
+    pub(crate) random_commit: pedersen::Commitment<C>,
+    pub(crate) compressed_response: compressed_sigma::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for CompressedCommitmentOpening<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let commit_key = common_reference_string;
+
+        let mut witness = witness.clone();
+        if witness.message.len() < commit_key.len() {
+            witness
+                .message
+                .resize(commit_key.len(), C::ScalarField::zero());
+        }
+
+        let (random_commit, randomness) =
+            CommitmentOpening::<C>::commit(rng, commit_key, statement, &witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            b"compressed_commitment_opening",
+            commit_key,
+            statement,
+            random_commit
+        ]?);
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let (mut response, opening_randomness) =
+            CommitmentOpening::<C>::respond(commit_key, statement, &witness, &randomness, &c)?;
+        response.push(opening_randomness);
+
+        let generators = Self::generators(commit_key);
+        let linear_form = Self::linear_form(statement, &random_commit, &c);
+        let compressed_response = compressed_sigma::prover::Prover::new(
+            &compressed_sigma::Parameters::new(&generators),
+            &compressed_sigma::Statement::new(&linear_form),
+            &compressed_sigma::Witness::new(&response),
+        )
+        .prove(fs_rng)?;
+
+        Ok(Proof {
+            random_commit,
+            compressed_response,
+        })
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let commit_key = common_reference_string;
+
+        fs_rng.absorb(&to_bytes![
+            b"compressed_commitment_opening",
+            commit_key,
+            statement,
+            proof.random_commit
+        ]?);
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let generators = Self::generators(commit_key);
+        let linear_form = Self::linear_form(statement, &proof.random_commit, &c);
+        proof
+            .compressed_response
+            .verify(
+                &compressed_sigma::Parameters::new(&generators),
+                &compressed_sigma::Statement::new(&linear_form),
+                fs_rng,
+            )
+            .map_err(|_| {
+                CryptoError::ProofVerificationError(String::from("Compressed Commitment Opening"))
+            })
+    }
+}
+
+impl<C: ProjectiveCurve> CompressedCommitmentOpening<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Compressed Commitment Opening";
+
+    /// The response $(z, \phi)$ of the sigma protocol satisfies $\langle z, G \rangle + \phi H = A + cC$.
+    fn generators(commit_key: &Parameters<C>) -> Vec<C::Affine> {
+        let mut generators = commit_key.g.clone();
+        generators.push(commit_key.h);
+
+        generators
+    }
+
+    fn linear_form(
+        statement: &Statement<C>,
+        random_commit: &pedersen::Commitment<C>,
+        challenge: &C::ScalarField,
+    ) -> C {
+        random_commit.0.into_projective() + statement.0.mul(challenge.into_repr())
+    }
+}
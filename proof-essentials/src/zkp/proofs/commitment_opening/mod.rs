@@ -0,0 +1,177 @@
+pub mod compressed;
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::{self, PedersenCommitment};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ec::ProjectiveCurve;
+use ark_ff::Zero;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::{iter, UniformRand};
+
+/// Proof of knowledge of an opening $(m, r)$ of a Pedersen vector commitment $C = \langle m, G \rangle + rH$.
+pub struct CommitmentOpening<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+pub type Parameters<C> = pedersen::CommitKey<C>;
+
+pub type Statement<C> = pedersen::Commitment<C>;
+
+/// Opening of a Pedersen commitment: the committed message and the blinding factor.
+#[derive(Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub message: Vec<C::ScalarField>,
+    pub randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(message: &[C::ScalarField], randomness: C::ScalarField) -> Self {
+        Self {
+            message: message.to_vec(),
+            randomness,
+        }
+    }
+}
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for CommitmentOpening<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> SigmaProtocol for CommitmentOpening<C> {
+    type Scalar = C::ScalarField;
+    type Parameters = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Commitment = pedersen::Commitment<C>;
+    type Randomness = Witness<C>;
+    type Response = (Vec<C::ScalarField>, C::ScalarField);
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        if witness.message.len() > parameters.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                witness.message.len(),
+                parameters.len(),
+            ));
+        }
+
+        // Work over the whole commit key so that real and simulated transcripts have the same shape
+        let random_message = (0..parameters.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let random = Witness::new(&random_message, C::ScalarField::rand(rng));
+
+        let random_commit =
+            PedersenCommitment::<C>::commit(parameters, &random.message, random.randomness)?;
+
+        Ok((random_commit, random))
+    }
+
+    fn respond(
+        _parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        // Message entries missing from the witness are zero
+        let opening_message = randomness
+            .message
+            .iter()
+            .zip(
+                witness
+                    .message
+                    .iter()
+                    .copied()
+                    .chain(iter::repeat(C::ScalarField::zero())),
+            )
+            .map(|(random, m)| *random + *challenge * m)
+            .collect::<Vec<_>>();
+        let opening_randomness = randomness.randomness + *challenge * witness.randomness;
+
+        Ok((opening_message, opening_randomness))
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        // The simulated transcript opens a message of the same length as the commit key
+        let opening_message = (0..parameters.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let opening_randomness = C::ScalarField::rand(rng);
+
+        let random_commit =
+            PedersenCommitment::<C>::commit(parameters, &opening_message, opening_randomness)?
+                + *statement * -*challenge;
+
+        Ok((random_commit, (opening_message, opening_randomness)))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        let opened = PedersenCommitment::<C>::commit(parameters, &response.0, response.1)
+            .map_err(|_| CryptoError::ProofVerificationError(String::from("Commitment Opening")))?;
+
+        if opened != *commitment + *statement * *challenge {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Opening",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> CommitmentOpening<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Commitment Opening";
+}
@@ -0,0 +1,46 @@
+use super::{CommitmentOpening, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) random_commit: pedersen::Commitment<C>,
+    pub(crate) opening_message: Vec<C::ScalarField>,
+    pub(crate) opening_randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            b"commitment_opening",
+            commit_key,
+            statement,
+            self.random_commit
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        CommitmentOpening::<C>::check(
+            commit_key,
+            statement,
+            &self.random_commit,
+            &c,
+            &(self.opening_message.clone(), self.opening_randomness),
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use super::{proof::Proof, CommitmentOpening, Parameters, Statement, Witness};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (random_commit, randomness) =
+            CommitmentOpening::<C>::commit(rng, commit_key, statement, witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            b"commitment_opening",
+            commit_key,
+            statement,
+            random_commit
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let (opening_message, opening_randomness) =
+            CommitmentOpening::<C>::respond(commit_key, statement, witness, &randomness, &c)?;
+
+        Ok(Proof {
+            random_commit,
+            opening_message,
+            opening_randomness,
+        })
+    }
+}
@@ -0,0 +1,292 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::compose::or_composition;
+    use crate::zkp::{proofs::commitment_opening, ArgumentOfKnowledge};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+    use rand::prelude::ThreadRng;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type Opening = commitment_opening::CommitmentOpening<Curve>;
+    type CompressedOpening = commitment_opening::compressed::CompressedCommitmentOpening<Curve>;
+    type CommitKey = pedersen::CommitKey<Curve>;
+    type Commitment = pedersen::Commitment<Curve>;
+    type Witness = commitment_opening::Witness<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn test_template(n: usize) -> (ThreadRng, CommitKey, Witness, Commitment) {
+        let mut rng = thread_rng();
+
+        let commit_key = Pedersen::setup(&mut rng, n);
+
+        let message: Vec<Scalar> = sample_vector(&mut rng, n);
+        let witness = Witness::new(&message, Scalar::rand(&mut rng));
+        let commitment =
+            Pedersen::commit(&commit_key, &witness.message, witness.randomness).unwrap();
+
+        (rng, commit_key, witness, commitment)
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let (mut rng, commit_key, witness, commitment) = test_template(8);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Opening::prove(
+            &mut rng,
+            &commit_key,
+            &commitment,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Opening::verify(
+                &commit_key,
+                &commitment,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            commitment_opening::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Opening::verify(
+                &commit_key,
+                &commitment,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let (mut rng, commit_key, mut witness, commitment) = test_template(8);
+
+        witness.message[3] = Scalar::rand(&mut rng);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = Opening::prove(
+            &mut rng,
+            &commit_key,
+            &commitment,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Opening::verify(
+                &commit_key,
+                &commitment,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Opening"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_message_longer_than_key() {
+        let (mut rng, commit_key, _, commitment) = test_template(4);
+
+        let message: Vec<Scalar> = sample_vector(&mut rng, 5);
+        let witness = Witness::new(&message, Scalar::rand(&mut rng));
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Opening::prove(
+                &mut rng,
+                &commit_key,
+                &commitment,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng
+            )
+            .err(),
+            Some(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                5,
+                4
+            ))
+        );
+    }
+
+    #[test]
+    fn test_composes_with_or() {
+        let (mut rng, commit_key, _, unknown) = test_template(8);
+
+        // A message shorter than the commit key must still give a response as long as a simulated one
+        let message: Vec<Scalar> = sample_vector(&mut rng, 3);
+        let witness = Witness::new(&message, Scalar::rand(&mut rng));
+        let known = Pedersen::commit(&commit_key, &witness.message, witness.randomness).unwrap();
+
+        let statement = vec![unknown, known];
+        let or_witness = or_composition::Witness::new(1, &witness);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = or_composition::OrComposition::<Opening>::prove(
+            &mut rng,
+            &commit_key,
+            &statement,
+            &or_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let simulated = &proof.responses[0].0;
+        let real = &proof.responses[1].0;
+        assert_eq!(simulated.len(), commit_key.len());
+        assert_eq!(real.len(), simulated.len());
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            or_composition::OrComposition::<Opening>::verify(
+                &commit_key,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_compressed_honest_prover() {
+        let (mut rng, commit_key, witness, commitment) = test_template(15);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = CompressedOpening::prove(
+            &mut rng,
+            &commit_key,
+            &commitment,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        assert_eq!(proof.compressed_response.a_vec.len(), 4);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CompressedOpening::verify(
+                &commit_key,
+                &commitment,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            commitment_opening::compressed::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CompressedOpening::verify(
+                &commit_key,
+                &commitment,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        // Messages shorter than the commit key are padded with zeros
+        let short_message: Vec<Scalar> = sample_vector(&mut rng, 6);
+        let short_witness = Witness::new(&short_message, Scalar::rand(&mut rng));
+        let short_commitment = Pedersen::commit(
+            &commit_key,
+            &short_witness.message,
+            short_witness.randomness,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = CompressedOpening::prove(
+            &mut rng,
+            &commit_key,
+            &short_commitment,
+            &short_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CompressedOpening::verify(
+                &commit_key,
+                &short_commitment,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_compressed_malicious_prover() {
+        let (mut rng, commit_key, mut witness, commitment) = test_template(8);
+
+        witness.message[3] = Scalar::rand(&mut rng);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = CompressedOpening::prove(
+            &mut rng,
+            &commit_key,
+            &commitment,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CompressedOpening::verify(
+                &commit_key,
+                &commitment,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Compressed Commitment Opening"
+            )))
+        );
+    }
+}
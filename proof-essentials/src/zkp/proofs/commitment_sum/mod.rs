@@ -0,0 +1,102 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, ToBytes};
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that Pedersen commitments $C_i = m_i G + r_i H$ hide messages summing to a public total $t$. By the
+/// homomorphism of the commitments, $\sum_i C_i - tG = (\sum_i r_i) H$, so it is enough to prove knowledge of the
+/// discrete logarithm of this point in base $H$ with a single Schnorr proof.
+///
+/// Only the first generator and the blinding generator of the commit key are used.
+pub struct CommitmentSum<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+pub type Parameters<C> = pedersen::CommitKey<C>;
+
+/// Commitments to the messages and the public total they are claimed to add up to.
+#[derive(Clone)]
+pub struct Statement<C: ProjectiveCurve> {
+    pub commitments: Vec<pedersen::Commitment<C>>,
+    pub total: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Statement<C> {
+    pub fn new(commitments: &[pedersen::Commitment<C>], total: C::ScalarField) -> Self {
+        Self {
+            commitments: commitments.to_vec(),
+            total,
+        }
+    }
+}
+
+impl<C: ProjectiveCurve> ToBytes for Statement<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.commitments.write(&mut w)?;
+        self.total.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// Blinding factors of the commitments, in the same order.
+pub type Witness<C> = Vec<<C as ProjectiveCurve>::ScalarField>;
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for CommitmentSum<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> CommitmentSum<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Commitment Sum";
+
+    /// $\sum_i C_i - tG$, a commitment to zero with the aggregate randomness when the statement holds.
+    pub(crate) fn aggregate(commit_key: &Parameters<C>, statement: &Statement<C>) -> C::Affine {
+        let sum = statement
+            .commitments
+            .iter()
+            .fold(C::zero(), |acc, commitment| {
+                acc + commitment.0.into_projective()
+            });
+
+        (sum - commit_key.g[0].mul(statement.total.into_repr())).into_affine()
+    }
+}
@@ -0,0 +1,42 @@
+use super::{CommitmentSum, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::proofs::schnorr_identification;
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    /// Proof of knowledge of the aggregate randomness $\sum_i r_i$.
+    pub(crate) randomness_proof: schnorr_identification::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            CryptoError::ProofVerificationError(String::from("Commitment Sum"));
+
+        if commit_key.is_empty() || statement.commitments.is_empty() {
+            return Err(verification_error);
+        }
+
+        fs_rng.absorb(&to_bytes![b"commitment_sum", commit_key, statement]?);
+
+        let aggregate = CommitmentSum::<C>::aggregate(commit_key, statement);
+
+        self.randomness_proof
+            .verify(&commit_key.h, &aggregate, fs_rng)
+            .map_err(|_| verification_error)
+    }
+}
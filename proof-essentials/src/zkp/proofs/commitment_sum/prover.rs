@@ -0,0 +1,53 @@
+use crate::error::CryptoError;
+use crate::zkp::proofs::schnorr_identification;
+use crate::zkp::transcript::Transcript;
+
+use super::{proof::Proof, CommitmentSum, Parameters, Statement, Witness};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        if commit_key.is_empty()
+            || statement.commitments.is_empty()
+            || statement.commitments.len() != witness.len()
+        {
+            return Err(CryptoError::InvalidCommitmentSumStatement);
+        }
+
+        fs_rng.absorb(&to_bytes![b"commitment_sum", commit_key, statement]?);
+
+        let aggregate = CommitmentSum::<C>::aggregate(commit_key, statement);
+        let aggregate_randomness = witness.iter().sum::<C::ScalarField>();
+
+        let randomness_proof = schnorr_identification::prover::Prover::<C>::create_proof(
+            rng,
+            &commit_key.h,
+            &aggregate,
+            &aggregate_randomness,
+            fs_rng,
+        )?;
+
+        Ok(Proof { randomness_proof })
+    }
+}
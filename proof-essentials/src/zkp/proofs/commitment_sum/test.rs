@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{proofs::commitment_sum, ArgumentOfKnowledge};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use blake2::Blake2s;
+    use rand::prelude::ThreadRng;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type CommitKey = pedersen::CommitKey<Curve>;
+    type Commitment = pedersen::Commitment<Curve>;
+    type Sum = commitment_sum::CommitmentSum<Curve>;
+    type Statement = commitment_sum::Statement<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn test_template(
+        n: usize,
+    ) -> (
+        ThreadRng,
+        CommitKey,
+        Vec<Scalar>,
+        Vec<Scalar>,
+        Vec<Commitment>,
+    ) {
+        let mut rng = thread_rng();
+        let commit_key = Pedersen::setup(&mut rng, 1);
+
+        let messages: Vec<Scalar> = sample_vector(&mut rng, n);
+        let randomness: Vec<Scalar> = sample_vector(&mut rng, n);
+        let commitments = messages
+            .iter()
+            .zip(randomness.iter())
+            .map(|(m, r)| Pedersen::commit(&commit_key, &vec![*m], *r).unwrap())
+            .collect::<Vec<_>>();
+
+        (rng, commit_key, messages, randomness, commitments)
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let (mut rng, commit_key, messages, randomness, commitments) = test_template(6);
+        let statement = Statement::new(&commitments, messages.iter().sum());
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Sum::prove(
+            &mut rng,
+            &commit_key,
+            &statement,
+            &randomness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Sum::verify(&commit_key, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            commitment_sum::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Sum::verify(
+                &commit_key,
+                &statement,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let (mut rng, commit_key, messages, randomness, commitments) = test_template(6);
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Commitment Sum",
+        )));
+
+        // Wrong total
+        let wrong_total = messages.iter().sum::<Scalar>() + Scalar::from(1u64);
+        let statement = Statement::new(&commitments, wrong_total);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Sum::prove(
+            &mut rng,
+            &commit_key,
+            &statement,
+            &randomness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Sum::verify(&commit_key, &statement, &proof, b"Test Domain", &mut fs_rng),
+            expected_error
+        );
+
+        // An honest proof does not carry over to another total
+        let statement = Statement::new(&commitments, messages.iter().sum());
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Sum::prove(
+            &mut rng,
+            &commit_key,
+            &statement,
+            &randomness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Sum::verify(
+                &commit_key,
+                &Statement::new(&commitments, wrong_total),
+                &proof,
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            expected_error
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Sum::prove(
+                &mut rng,
+                &commit_key,
+                &statement,
+                &randomness[..5].to_vec(),
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Err(CryptoError::InvalidCommitmentSumStatement)
+        );
+    }
+}
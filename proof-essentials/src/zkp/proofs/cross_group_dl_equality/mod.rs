@@ -0,0 +1,149 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{PrimeField, ToBytes};
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that the same integer $x$ is the discrete logarithm of $X_1 = x G_1$ and $X_2 = x G_2$ in two groups of
+/// different orders, e.g. a pairing-friendly curve used by a SNARK and the curve used by the sigma proofs.
+///
+/// $x$ is decomposed into `bits` bits $b_i$, committed to in both groups as $P_i = b_i G_1 + r_i H_1$ and
+/// $Q_i = b_i G_2 + s_i H_2$ with blindings chosen so that $\sum 2^i r_i = \sum 2^i s_i = 0$, hence
+/// $\sum 2^i P_i = X_1$ and $\sum 2^i Q_i = X_2$. For every bit a two-branch ring signature shows that both
+/// commitments open to 0 or both open to 1. Challenges are 128-bit integers, split between the branches by XOR,
+/// so that they have the same meaning in both scalar fields.
+pub struct CrossGroupDLEquality<C1: ProjectiveCurve, C2: ProjectiveCurve> {
+    _groups: PhantomData<(C1, C2)>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<C1: ProjectiveCurve, C2: ProjectiveCurve> {
+    pub generator_1: C1::Affine,
+    pub blinding_1: C1::Affine,
+    pub generator_2: C2::Affine,
+    pub blinding_2: C2::Affine,
+    pub(crate) bits: usize,
+}
+
+impl<C1: ProjectiveCurve, C2: ProjectiveCurve> Parameters<C1, C2> {
+    /// The discrete logarithm must fit in `bits` bits, which cannot exceed the capacity of either scalar field.
+    pub fn new(
+        generator_1: C1::Affine,
+        blinding_1: C1::Affine,
+        generator_2: C2::Affine,
+        blinding_2: C2::Affine,
+        bits: usize,
+    ) -> Result<Self, CryptoError> {
+        let capacity = ark_std::cmp::min(
+            <C1::ScalarField as PrimeField>::size_in_bits(),
+            <C2::ScalarField as PrimeField>::size_in_bits(),
+        ) - 1;
+        if bits == 0 || bits > capacity {
+            return Err(CryptoError::InvalidCrossGroupParameters);
+        }
+
+        Ok(Self {
+            generator_1,
+            blinding_1,
+            generator_2,
+            blinding_2,
+            bits,
+        })
+    }
+
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+}
+
+impl<C1: ProjectiveCurve, C2: ProjectiveCurve> ToBytes for Parameters<C1, C2> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.generator_1.write(&mut w)?;
+        self.blinding_1.write(&mut w)?;
+        self.generator_2.write(&mut w)?;
+        self.blinding_2.write(&mut w)?;
+        (self.bits as u64).write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// The two points $X_1 = x G_1$ and $X_2 = x G_2$.
+#[derive(Copy, Clone)]
+pub struct Statement<C1: ProjectiveCurve, C2: ProjectiveCurve>(pub C1::Affine, pub C2::Affine);
+
+impl<C1: ProjectiveCurve, C2: ProjectiveCurve> Statement<C1, C2> {
+    pub fn new(point_1: C1::Affine, point_2: C2::Affine) -> Self {
+        Self(point_1, point_2)
+    }
+}
+
+impl<C1: ProjectiveCurve, C2: ProjectiveCurve> ToBytes for Statement<C1, C2> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0.write(&mut w)?;
+        self.1.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// The common discrete logarithm, given in the scalar field of the first group. It must be smaller than $2^{bits}$.
+pub type Witness<C1> = <C1 as ProjectiveCurve>::ScalarField;
+
+impl<C1: ProjectiveCurve, C2: ProjectiveCurve> ArgumentOfKnowledge
+    for CrossGroupDLEquality<C1, C2>
+{
+    type CommonReferenceString = Parameters<C1, C2>;
+    type Statement = Statement<C1, C2>;
+    type Witness = Witness<C1>;
+    type Proof = proof::Proof<C1, C2>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C1: ProjectiveCurve, C2: ProjectiveCurve> CrossGroupDLEquality<C1, C2> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Cross-Group Discrete Log Equality";
+
+    /// Sample independent generators for both groups.
+    pub fn setup<R: Rng>(rng: &mut R, bits: usize) -> Result<Parameters<C1, C2>, CryptoError> {
+        Parameters::new(
+            C1::rand(rng).into_affine(),
+            C1::rand(rng).into_affine(),
+            C2::rand(rng).into_affine(),
+            C2::rand(rng).into_affine(),
+            bits,
+        )
+    }
+}
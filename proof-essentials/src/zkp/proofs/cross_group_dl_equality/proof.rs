@@ -0,0 +1,160 @@
+use super::{CrossGroupDLEquality, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{Transcript, TranscriptRng};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::rand::Rng;
+
+/// Commitments to one bit of the discrete logarithm in both groups, with the ring signature showing that they open
+/// to the same bit. `challenge_0` and `responses_*.0` belong to the branch "the bit is 0"; challenges are stored as
+/// (low, high) 64-bit limbs.
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct BitProof<C1, C2>
+where
+    C1: ProjectiveCurve,
+    C2: ProjectiveCurve,
+{
+    pub(crate) commitment_1: C1::Affine,
+    pub(crate) commitment_2: C2::Affine,
+    pub(crate) challenge_0: (u64, u64),
+    pub(crate) challenge_1: (u64, u64),
+    pub(crate) responses_1: (C1::ScalarField, C1::ScalarField),
+    pub(crate) responses_2: (C2::ScalarField, C2::ScalarField),
+}
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C1, C2>
+where
+    C1: ProjectiveCurve,
+    C2: ProjectiveCurve,
+{
+    pub(crate) bit_proofs: Vec<BitProof<C1, C2>>,
+}
+
+/// Split a 128-bit challenge into the two limbs used for serialization.
+pub(crate) fn to_limbs(challenge: u128) -> (u64, u64) {
+    (challenge as u64, (challenge >> 64) as u64)
+}
+
+pub(crate) fn from_limbs((low, high): (u64, u64)) -> u128 {
+    (low as u128) | ((high as u128) << 64)
+}
+
+/// Random commitment of one branch, recomputed from its challenge and response: $z H - e (P - b G)$.
+pub(crate) fn branch_commit<C: ProjectiveCurve>(
+    generator: &C::Affine,
+    blinding: &C::Affine,
+    commitment: &C::Affine,
+    bit: bool,
+    challenge: u128,
+    response: &C::ScalarField,
+) -> C {
+    let mut image = commitment.into_projective();
+    if bit {
+        image -= generator.into_projective();
+    }
+
+    blinding.mul(response.into_repr()) - image.mul(C::ScalarField::from(challenge).into_repr())
+}
+
+/// $\sum_i 2^i P_i$
+pub(crate) fn recompose<C: ProjectiveCurve>(commitments: &[C::Affine]) -> C {
+    commitments.iter().rev().fold(C::zero(), |acc, commitment| {
+        acc.double() + commitment.into_projective()
+    })
+}
+
+impl<C1: ProjectiveCurve, C2: ProjectiveCurve> Proof<C1, C2> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C1, C2>,
+        statement: &Statement<C1, C2>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            CryptoError::ProofVerificationError(String::from("Cross-Group DL Equality"));
+
+        if self.bit_proofs.len() != parameters.bits {
+            return Err(verification_error);
+        }
+
+        let commitments_1 = self
+            .bit_proofs
+            .iter()
+            .map(|bit_proof| bit_proof.commitment_1)
+            .collect::<Vec<_>>();
+        let commitments_2 = self
+            .bit_proofs
+            .iter()
+            .map(|bit_proof| bit_proof.commitment_2)
+            .collect::<Vec<_>>();
+        if !(recompose::<C1>(&commitments_1) - statement.0.into_projective()).is_zero()
+            || !(recompose::<C2>(&commitments_2) - statement.1.into_projective()).is_zero()
+        {
+            return Err(verification_error);
+        }
+
+        fs_rng.absorb(&to_bytes![
+            CrossGroupDLEquality::<C1, C2>::PROTOCOL_NAME,
+            parameters,
+            statement
+        ]?);
+
+        for bit_proof in &self.bit_proofs {
+            let e_0 = from_limbs(bit_proof.challenge_0);
+            let e_1 = from_limbs(bit_proof.challenge_1);
+
+            let commit_1_0 = branch_commit::<C1>(
+                &parameters.generator_1,
+                &parameters.blinding_1,
+                &bit_proof.commitment_1,
+                false,
+                e_0,
+                &bit_proof.responses_1.0,
+            );
+            let commit_2_0 = branch_commit::<C2>(
+                &parameters.generator_2,
+                &parameters.blinding_2,
+                &bit_proof.commitment_2,
+                false,
+                e_0,
+                &bit_proof.responses_2.0,
+            );
+            let commit_1_1 = branch_commit::<C1>(
+                &parameters.generator_1,
+                &parameters.blinding_1,
+                &bit_proof.commitment_1,
+                true,
+                e_1,
+                &bit_proof.responses_1.1,
+            );
+            let commit_2_1 = branch_commit::<C2>(
+                &parameters.generator_2,
+                &parameters.blinding_2,
+                &bit_proof.commitment_2,
+                true,
+                e_1,
+                &bit_proof.responses_2.1,
+            );
+
+            fs_rng.absorb(&to_bytes![
+                bit_proof.commitment_1,
+                bit_proof.commitment_2,
+                commit_1_0.into_affine(),
+                commit_2_0.into_affine(),
+                commit_1_1.into_affine(),
+                commit_2_1.into_affine()
+            ]?);
+            let challenge: u128 = TranscriptRng::new(fs_rng).gen();
+
+            if e_0 ^ e_1 != challenge {
+                return Err(verification_error);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::{Transcript, TranscriptRng};
+
+use super::proof::{branch_commit, to_limbs, BitProof, Proof};
+use super::{CrossGroupDLEquality, Parameters, Statement, Witness};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, BigInteger, PrimeField};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C1, C2>
+where
+    C1: ProjectiveCurve,
+    C2: ProjectiveCurve,
+{
+    phantom: PhantomData<(C1, C2)>,
+}
+
+/// Sample blindings $r_0, \dots, r_{n-1}$ such that $\sum_i 2^i r_i = 0$.
+fn sample_blindings<F: PrimeField, R: Rng>(rng: &mut R, n: usize) -> Vec<F> {
+    let mut blindings = (0..n - 1).map(|_| F::rand(rng)).collect::<Vec<_>>();
+
+    let mut power = F::one();
+    let mut sum = F::zero();
+    for blinding in &blindings {
+        sum += power * blinding;
+        power.double_in_place();
+    }
+    blindings.push(-sum * power.inverse().unwrap());
+
+    blindings
+}
+
+impl<C1, C2> Prover<C1, C2>
+where
+    C1: ProjectiveCurve,
+    C2: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C1, C2>,
+        statement: &Statement<C1, C2>,
+        witness: &Witness<C1>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C1, C2>, CryptoError> {
+        let n = parameters.bits;
+        let bits = witness.into_repr().to_bits_le();
+        if bits[n..].iter().any(|bit| *bit) {
+            return Err(CryptoError::InvalidCrossGroupWitness);
+        }
+
+        let blindings_1 = sample_blindings::<C1::ScalarField, R>(rng, n);
+        let blindings_2 = sample_blindings::<C2::ScalarField, R>(rng, n);
+
+        fs_rng.absorb(&to_bytes![
+            CrossGroupDLEquality::<C1, C2>::PROTOCOL_NAME,
+            parameters,
+            statement
+        ]?);
+
+        let mut bit_proofs = Vec::with_capacity(n);
+        for i in 0..n {
+            let bit = bits[i];
+            let (r, s) = (blindings_1[i], blindings_2[i]);
+
+            let mut commitment_1 = parameters.blinding_1.mul(r.into_repr());
+            let mut commitment_2 = parameters.blinding_2.mul(s.into_repr());
+            if bit {
+                commitment_1 += parameters.generator_1.into_projective();
+                commitment_2 += parameters.generator_2.into_projective();
+            }
+            let commitment_1 = commitment_1.into_affine();
+            let commitment_2 = commitment_2.into_affine();
+
+            // Real branch
+            let k_1 = C1::ScalarField::rand(rng);
+            let k_2 = C2::ScalarField::rand(rng);
+            let real_commit_1 = parameters.blinding_1.mul(k_1.into_repr());
+            let real_commit_2 = parameters.blinding_2.mul(k_2.into_repr());
+
+            // Simulated branch
+            let simulated_challenge: u128 = rng.gen();
+            let simulated_response_1 = C1::ScalarField::rand(rng);
+            let simulated_response_2 = C2::ScalarField::rand(rng);
+            let simulated_commit_1 = branch_commit::<C1>(
+                &parameters.generator_1,
+                &parameters.blinding_1,
+                &commitment_1,
+                !bit,
+                simulated_challenge,
+                &simulated_response_1,
+            );
+            let simulated_commit_2 = branch_commit::<C2>(
+                &parameters.generator_2,
+                &parameters.blinding_2,
+                &commitment_2,
+                !bit,
+                simulated_challenge,
+                &simulated_response_2,
+            );
+
+            let (commits_0, commits_1) = if bit {
+                (
+                    (simulated_commit_1, simulated_commit_2),
+                    (real_commit_1, real_commit_2),
+                )
+            } else {
+                (
+                    (real_commit_1, real_commit_2),
+                    (simulated_commit_1, simulated_commit_2),
+                )
+            };
+
+            fs_rng.absorb(&to_bytes![
+                commitment_1,
+                commitment_2,
+                commits_0.0.into_affine(),
+                commits_0.1.into_affine(),
+                commits_1.0.into_affine(),
+                commits_1.1.into_affine()
+            ]?);
+            let challenge: u128 = TranscriptRng::new(fs_rng).gen();
+
+            let real_challenge = challenge ^ simulated_challenge;
+            let real_response_1 = k_1 + C1::ScalarField::from(real_challenge) * r;
+            let real_response_2 = k_2 + C2::ScalarField::from(real_challenge) * s;
+
+            let bit_proof = if bit {
+                BitProof {
+                    commitment_1,
+                    commitment_2,
+                    challenge_0: to_limbs(simulated_challenge),
+                    challenge_1: to_limbs(real_challenge),
+                    responses_1: (simulated_response_1, real_response_1),
+                    responses_2: (simulated_response_2, real_response_2),
+                }
+            } else {
+                BitProof {
+                    commitment_1,
+                    commitment_2,
+                    challenge_0: to_limbs(real_challenge),
+                    challenge_1: to_limbs(simulated_challenge),
+                    responses_1: (real_response_1, simulated_response_1),
+                    responses_2: (real_response_2, simulated_response_2),
+                }
+            };
+            bit_proofs.push(bit_proof);
+        }
+
+        Ok(Proof { bit_proofs })
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::{proofs::cross_group_dl_equality, ArgumentOfKnowledge};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::rand::{thread_rng, Rng};
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type OtherCurve = ark_bls12_381::G1Projective;
+    type OtherScalar = ark_bls12_381::Fr;
+    type CrossGroupDLEquality = cross_group_dl_equality::CrossGroupDLEquality<Curve, OtherCurve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn statement(
+        parameters: &cross_group_dl_equality::Parameters<Curve, OtherCurve>,
+        x: &Scalar,
+    ) -> cross_group_dl_equality::Statement<Curve, OtherCurve> {
+        let other_x = OtherScalar::from_repr(<OtherScalar as PrimeField>::BigInt::from_bits_le(
+            &x.into_repr().to_bits_le(),
+        ))
+        .unwrap();
+
+        cross_group_dl_equality::Statement::new(
+            parameters.generator_1.mul(*x).into_affine(),
+            parameters.generator_2.mul(other_x).into_affine(),
+        )
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+
+        for bits in [64, 251] {
+            let parameters = CrossGroupDLEquality::setup(rng, bits).unwrap();
+            let x = if bits == 64 {
+                Scalar::from(rng.gen::<u64>())
+            } else {
+                let mut x_bits = Scalar::rand(rng).into_repr().to_bits_le();
+                x_bits.truncate(bits);
+                Scalar::from_repr(<Scalar as PrimeField>::BigInt::from_bits_le(&x_bits)).unwrap()
+            };
+            let statement = statement(&parameters, &x);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = CrossGroupDLEquality::prove(
+                rng,
+                &parameters,
+                &statement,
+                &x,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                CrossGroupDLEquality::verify(
+                    &parameters,
+                    &statement,
+                    &proof,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let parameters = CrossGroupDLEquality::setup(rng, 64).unwrap();
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Cross-Group DL Equality",
+        )));
+
+        // Different discrete logarithms in the two groups
+        let x = Scalar::from(rng.gen::<u64>());
+        let y = OtherScalar::from(rng.gen::<u64>());
+        let honest_statement = statement(&parameters, &x);
+        let bad_statement = cross_group_dl_equality::Statement::new(
+            honest_statement.0,
+            parameters.generator_2.mul(y).into_affine(),
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = CrossGroupDLEquality::prove(
+            rng,
+            &parameters,
+            &bad_statement,
+            &x,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CrossGroupDLEquality::verify(
+                &parameters,
+                &bad_statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // Tampered challenge split
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let mut proof = CrossGroupDLEquality::prove(
+            rng,
+            &parameters,
+            &honest_statement,
+            &x,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        proof.bit_proofs[3].challenge_0.0 ^= 1;
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CrossGroupDLEquality::verify(
+                &parameters,
+                &honest_statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // Witness larger than the committed bit length
+        let too_large = Scalar::from(u128::MAX);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CrossGroupDLEquality::prove(
+                rng,
+                &parameters,
+                &honest_statement,
+                &too_large,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .err(),
+            Some(CryptoError::InvalidCrossGroupWitness)
+        );
+
+        // Bit length beyond the capacity of the smaller scalar field
+        assert!(CrossGroupDLEquality::setup(rng, 252).is_err());
+        assert!(CrossGroupDLEquality::setup(rng, 0).is_err());
+    }
+}
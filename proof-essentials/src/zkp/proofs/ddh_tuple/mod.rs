@@ -0,0 +1,98 @@
+mod test;
+
+use crate::error::CryptoError;
+use crate::zkp::proofs::chaum_pedersen_dl_equality::{self as dl_equality, DLEquality};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that $(G, A, B, C) = (G, aG, bG, abG)$ is a Diffie-Hellman tuple, by knowledge of $b$ such that
+/// $B = bG$ and $C = bA$. This is the relation behind correct masking and unmasking of ElGamal ciphertexts, where
+/// a party multiplies a shared point by the same secret as its public key.
+///
+/// The statement is proven as the discrete logarithm equality $\log_G(B) = \log_A(C)$.
+pub struct DDHTuple<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub generator: &'a C::Affine,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(generator: &'a C::Affine) -> Self {
+        Self { generator }
+    }
+}
+
+/// Statement for a DDH tuple proof: the points $A = aG$, $B = bG$ and $C = abG$.
+#[derive(Copy, Clone)]
+pub struct Statement<'a, C: ProjectiveCurve>(
+    pub &'a C::Affine,
+    pub &'a C::Affine,
+    pub &'a C::Affine,
+);
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(point_a: &'a C::Affine, point_b: &'a C::Affine, point_c: &'a C::Affine) -> Self {
+        Self(point_a, point_b, point_c)
+    }
+}
+
+/// The discrete logarithm $b$ of $B$.
+pub type Witness<C> = <C as ProjectiveCurve>::ScalarField;
+
+pub type Proof<C> = dl_equality::proof::Proof<C>;
+
+impl<'a, C: ProjectiveCurve> DDHTuple<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"DDH Tuple";
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for DDHTuple<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let parameters =
+            dl_equality::Parameters::new(common_reference_string.generator, statement.0);
+        let dl_statement = dl_equality::Statement::new(statement.1, statement.2);
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        DLEquality::prove(rng, &parameters, &dl_statement, witness, domain, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let parameters =
+            dl_equality::Parameters::new(common_reference_string.generator, statement.0);
+        let dl_statement = dl_equality::Statement::new(statement.1, statement.2);
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        DLEquality::verify(&parameters, &dl_statement, proof, domain, fs_rng)
+            .map_err(|_| CryptoError::ProofVerificationError(String::from("DDH Tuple")))
+    }
+}
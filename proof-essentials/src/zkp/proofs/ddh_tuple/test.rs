@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::{proofs::ddh_tuple, ArgumentOfKnowledge};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Point = starknet_curve::Affine;
+    type Scalar = starknet_curve::Fr;
+    type DDHTuple<'a> = ddh_tuple::DDHTuple<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn mul(point: &Point, scalar: &Scalar) -> Point {
+        point.mul(scalar.into_repr()).into_affine()
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let a = Scalar::rand(rng);
+        let b = Scalar::rand(rng);
+
+        let point_a = mul(&generator, &a);
+        let point_b = mul(&generator, &b);
+        let point_c = mul(&generator, &(a * b));
+
+        let parameters = ddh_tuple::Parameters::new(&generator);
+        let statement = ddh_tuple::Statement::new(&point_a, &point_b, &point_c);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = DDHTuple::prove(
+            rng,
+            &parameters,
+            &statement,
+            &b,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            DDHTuple::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let a = Scalar::rand(rng);
+        let b = Scalar::rand(rng);
+
+        let point_a = mul(&generator, &a);
+        let point_b = mul(&generator, &b);
+        let random_point = Curve::rand(rng).into_affine();
+
+        let parameters = ddh_tuple::Parameters::new(&generator);
+        let statement = ddh_tuple::Statement::new(&point_a, &point_b, &random_point);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = DDHTuple::prove(
+            rng,
+            &parameters,
+            &statement,
+            &b,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            DDHTuple::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "DDH Tuple"
+            )))
+        );
+
+        // A valid proof does not transfer to a tuple with the roles of `a` and `b` mixed up
+        let point_c = mul(&generator, &(a * b));
+        let statement = ddh_tuple::Statement::new(&point_a, &point_b, &point_c);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = DDHTuple::prove(
+            rng,
+            &parameters,
+            &statement,
+            &b,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let other_c = mul(&generator, &(a * a));
+        let statement = ddh_tuple::Statement::new(&point_a, &point_b, &other_c);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert!(
+            DDHTuple::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng).is_err()
+        );
+    }
+}
@@ -0,0 +1,135 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::zkp::proofs::linear_relation::{self, Equation, Term};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{ToBytes, Zero};
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Camenisch-Shoup proof of discrete logarithm inequality: for $A = xG$, proves that $B \neq xH$ without
+/// revealing $x$.
+///
+/// The prover publishes $C = r(xH - B)$ for a random $r$ and proves knowledge of $(\alpha, \beta) = (rx, -r)$ with
+/// $C = \alpha H + \beta B$ and $0 = \alpha G + \beta A$. The verifier additionally checks that $C$ is not the identity.
+pub struct DLInequality<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub g: &'a C::Affine,
+    pub h: &'a C::Affine,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(g: &'a C::Affine, h: &'a C::Affine) -> Self {
+        Self { g, h }
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ToBytes for Parameters<'a, C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.g.write(&mut w)?;
+        self.h.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// Statement for a discrete logarithm inequality proof. Expects two points $A$ and $B$ such that for the secret
+/// $x$ with $A = xG$, $B \neq xH$.
+#[derive(Copy, Clone)]
+pub struct Statement<'a, C: ProjectiveCurve>(pub &'a C::Affine, pub &'a C::Affine);
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(point_a: &'a C::Affine, point_b: &'a C::Affine) -> Self {
+        Self(point_a, point_b)
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ToBytes for Statement<'a, C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0.write(&mut w)?;
+        self.1.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+pub type Witness<C> = <C as ProjectiveCurve>::ScalarField;
+
+impl<'a, C: ProjectiveCurve> DLInequality<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Discrete Logarithm Inequality";
+
+    /// Linear relation $C = \alpha H + \beta B \wedge 0 = \alpha G + \beta A$ over the variables $(\alpha, \beta)$.
+    pub(crate) fn relation(
+        parameters: &Parameters<'a, C>,
+        statement: &Statement<'a, C>,
+        c_commit: &C::Affine,
+    ) -> Result<
+        (
+            linear_relation::Parameters<C>,
+            linear_relation::Statement<C>,
+        ),
+        CryptoError,
+    > {
+        let relation = linear_relation::Parameters::new(
+            2,
+            vec![
+                Equation::new(vec![
+                    Term::new(0, *parameters.h),
+                    Term::new(1, *statement.1),
+                ]),
+                Equation::new(vec![
+                    Term::new(0, *parameters.g),
+                    Term::new(1, *statement.0),
+                ]),
+            ],
+        )?;
+
+        Ok((relation, vec![*c_commit, C::Affine::zero()]))
+    }
+}
+
+impl<'a, C> ArgumentOfKnowledge for DLInequality<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
@@ -0,0 +1,47 @@
+use super::{DLInequality, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::proofs::linear_relation;
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::{to_bytes, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) c_commit: C::Affine,
+    pub(crate) relation_proof: linear_relation::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        // C is the identity exactly when log_g(A) = log_h(B)
+        if self.c_commit.is_zero() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "DL Inequality",
+            )));
+        }
+
+        fs_rng.absorb(&to_bytes![
+            b"dl_inequality",
+            parameters,
+            statement,
+            self.c_commit
+        ]?);
+
+        let (relation, images) = DLInequality::relation(parameters, statement, &self.c_commit)?;
+
+        self.relation_proof
+            .verify(&relation, &images, fs_rng)
+            .map_err(|_| CryptoError::ProofVerificationError(String::from("DL Inequality")))
+    }
+}
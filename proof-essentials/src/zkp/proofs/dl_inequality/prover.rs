@@ -0,0 +1,60 @@
+use crate::error::CryptoError;
+use crate::zkp::proofs::linear_relation;
+use crate::zkp::transcript::Transcript;
+
+use super::{proof::Proof, DLInequality, Parameters, Statement, Witness};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let r = C::ScalarField::rand(rng);
+
+        // C = r(xH - B)
+        let c_commit = (parameters.h.mul(witness.into_repr()) - statement.1.into_projective())
+            .mul(r.into_repr())
+            .into_affine();
+
+        fs_rng.absorb(&to_bytes![
+            b"dl_inequality",
+            parameters,
+            statement,
+            c_commit
+        ]?);
+
+        let (relation, images) = DLInequality::relation(parameters, statement, &c_commit)?;
+        let relation_proof = linear_relation::prover::Prover::create_proof(
+            rng,
+            &relation,
+            &images,
+            &vec![r * witness, -r],
+            fs_rng,
+        )?;
+
+        Ok(Proof {
+            c_commit,
+            relation_proof,
+        })
+    }
+}
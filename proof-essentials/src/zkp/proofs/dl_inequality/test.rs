@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::{proofs::dl_inequality, ArgumentOfKnowledge};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type DLInequality<'a> = dl_inequality::DLInequality<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let g = Curve::rand(rng).into_affine();
+        let h = Curve::rand(rng).into_affine();
+
+        let x = Scalar::rand(rng);
+        let a = g.mul(x).into_affine();
+        let b = h.mul(Scalar::rand(rng)).into_affine();
+
+        let parameters = dl_inequality::Parameters::new(&g, &h);
+        let statement = dl_inequality::Statement::new(&a, &b);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = DLInequality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &x,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            DLInequality::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            dl_inequality::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(proof, deserialized);
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let g = Curve::rand(rng).into_affine();
+        let h = Curve::rand(rng).into_affine();
+
+        // The discrete logarithms are equal
+        let x = Scalar::rand(rng);
+        let a = g.mul(x).into_affine();
+        let b = h.mul(x).into_affine();
+
+        let parameters = dl_inequality::Parameters::new(&g, &h);
+        let statement = dl_inequality::Statement::new(&a, &b);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = DLInequality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &x,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            DLInequality::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "DL Inequality"
+            )))
+        );
+
+        // The prover does not know log_g(A)
+        let b = h.mul(Scalar::rand(rng)).into_affine();
+        let statement = dl_inequality::Statement::new(&a, &b);
+        let another_scalar = Scalar::rand(rng);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = DLInequality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &another_scalar,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            DLInequality::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "DL Inequality"
+            )))
+        );
+    }
+}
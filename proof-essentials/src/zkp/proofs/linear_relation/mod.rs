@@ -0,0 +1,283 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, ToBytes};
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// Proof of knowledge of secret variables $x_1, \dots, x_n$ satisfying a system of group equations of the form
+/// $Y_i = \sum_j x_{k(i, j)} G_{i, j}$ (Camenisch-Stadler notation $PK\{(x_1, \dots, x_n) : Y_i = \prod_j g_{i,j}^{x_{k(i,j)}}\}$).
+///
+/// The same variable may appear in several equations, so a single engine covers Schnorr identification
+/// ($Y = xG$), discrete logarithm equality ($A = xG \wedge B = xH$), representations ($Y = xG + rH$)
+/// and openings of Pedersen commitments.
+pub struct LinearRelation<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// A single summand $x_k G$ of an equation: the index $k$ of the secret variable and the public base $G$.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Term<C: ProjectiveCurve> {
+    pub variable: usize,
+    pub base: C::Affine,
+}
+
+impl<C: ProjectiveCurve> Term<C> {
+    pub fn new(variable: usize, base: C::Affine) -> Self {
+        Self { variable, base }
+    }
+}
+
+/// The right-hand side $\sum_j x_{k(i, j)} G_{i, j}$ of one equation of the relation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Equation<C: ProjectiveCurve> {
+    pub terms: Vec<Term<C>>,
+}
+
+impl<C: ProjectiveCurve> Equation<C> {
+    pub fn new(terms: Vec<Term<C>>) -> Self {
+        Self { terms }
+    }
+
+    /// Evaluate the equation on an assignment of the secret variables.
+    pub fn evaluate(&self, assignment: &[C::ScalarField]) -> C {
+        self.terms.iter().fold(C::zero(), |acc, term| {
+            acc + term.base.mul(assignment[term.variable].into_repr())
+        })
+    }
+}
+
+/// Public parameters of a linear relation proof: the number of secret variables and the shape of every equation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameters<C: ProjectiveCurve> {
+    number_of_variables: usize,
+    equations: Vec<Equation<C>>,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    /// Declare a relation over `number_of_variables` secrets. Fails if there are no equations, if an equation is
+    /// empty or if a term refers to a variable that does not exist.
+    pub fn new(
+        number_of_variables: usize,
+        equations: Vec<Equation<C>>,
+    ) -> Result<Self, CryptoError> {
+        if equations.is_empty()
+            || equations.iter().any(|equation| {
+                equation.terms.is_empty()
+                    || equation
+                        .terms
+                        .iter()
+                        .any(|term| term.variable >= number_of_variables)
+            })
+        {
+            return Err(CryptoError::InvalidLinearRelation);
+        }
+
+        Ok(Self {
+            number_of_variables,
+            equations,
+        })
+    }
+
+    pub fn number_of_variables(&self) -> usize {
+        self.number_of_variables
+    }
+
+    pub fn equations(&self) -> &Vec<Equation<C>> {
+        &self.equations
+    }
+
+    /// Compute the statement (one image per equation) that corresponds to an assignment of the secret variables.
+    pub fn evaluate(&self, assignment: &[C::ScalarField]) -> Result<Statement<C>, CryptoError> {
+        if assignment.len() != self.number_of_variables {
+            return Err(CryptoError::InvalidLinearRelation);
+        }
+
+        let images = self
+            .equations
+            .iter()
+            .map(|equation| equation.evaluate(assignment))
+            .collect::<Vec<_>>();
+
+        Ok(C::batch_normalization_into_affine(&images))
+    }
+}
+
+impl<C: ProjectiveCurve> ToBytes for Parameters<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        (self.number_of_variables as u64).write(&mut w)?;
+        for equation in &self.equations {
+            (equation.terms.len() as u64).write(&mut w)?;
+            for term in &equation.terms {
+                (term.variable as u64).write(&mut w)?;
+                term.base.write(&mut w)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Statement of a linear relation proof: the public image $Y_i$ of every equation, in order.
+pub type Statement<C> = Vec<<C as ProjectiveCurve>::Affine>;
+
+/// Witness of a linear relation proof: the value of every secret variable, indexed as in the equations.
+pub type Witness<C> = Vec<<C as ProjectiveCurve>::ScalarField>;
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for LinearRelation<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> SigmaProtocol for LinearRelation<C> {
+    type Scalar = C::ScalarField;
+    type Parameters = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Commitment = Vec<C::Affine>;
+    type Randomness = Vec<C::ScalarField>;
+    type Response = Vec<C::ScalarField>;
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        if statement.len() != parameters.equations.len()
+            || witness.len() != parameters.number_of_variables
+        {
+            return Err(CryptoError::InvalidLinearRelation);
+        }
+
+        let randomness = (0..parameters.number_of_variables)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let commitment = parameters.evaluate(&randomness)?;
+
+        Ok((commitment, randomness))
+    }
+
+    fn respond(
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        if witness.len() != parameters.number_of_variables
+            || randomness.len() != parameters.number_of_variables
+        {
+            return Err(CryptoError::InvalidLinearRelation);
+        }
+
+        Ok(randomness
+            .iter()
+            .zip(witness.iter())
+            .map(|(random, x)| *random + *challenge * x)
+            .collect())
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        if statement.len() != parameters.equations.len() {
+            return Err(CryptoError::InvalidLinearRelation);
+        }
+
+        let response = (0..parameters.number_of_variables)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+
+        let commitment = parameters
+            .equations
+            .iter()
+            .zip(statement.iter())
+            .map(|(equation, image)| {
+                equation.evaluate(&response) - image.mul(challenge.into_repr())
+            })
+            .collect::<Vec<_>>();
+
+        Ok((C::batch_normalization_into_affine(&commitment), response))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        if statement.len() != parameters.equations.len()
+            || commitment.len() != parameters.equations.len()
+            || response.len() != parameters.number_of_variables
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation",
+            )));
+        }
+
+        let all_hold = parameters
+            .equations
+            .iter()
+            .zip(statement.iter())
+            .zip(commitment.iter())
+            .all(|((equation, image), commit)| {
+                (equation.evaluate(response)
+                    - image.mul(challenge.into_repr())
+                    - commit.into_projective())
+                .is_zero()
+            });
+
+        if !all_hold {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> LinearRelation<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Linear Relation";
+}
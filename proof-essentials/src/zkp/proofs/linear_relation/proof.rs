@@ -0,0 +1,44 @@
+use super::{LinearRelation, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) commitments: Vec<C::Affine>,
+    pub(crate) responses: Vec<C::ScalarField>,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            LinearRelation::<C>::PROTOCOL_NAME,
+            parameters,
+            statement,
+            self.commitments
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        LinearRelation::<C>::check(
+            parameters,
+            statement,
+            &self.commitments,
+            &c,
+            &self.responses,
+        )
+    }
+}
@@ -0,0 +1,50 @@
+use super::{proof::Proof, LinearRelation, Parameters, Statement, Witness};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (commitments, randomness) =
+            LinearRelation::<C>::commit(rng, parameters, statement, witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            LinearRelation::<C>::PROTOCOL_NAME,
+            parameters,
+            statement,
+            commitments
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let responses =
+            LinearRelation::<C>::respond(parameters, statement, witness, &randomness, &c)?;
+
+        Ok(Proof {
+            commitments,
+            responses,
+        })
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod test {
+    use super::super::{Equation, LinearRelation, Parameters, Term};
+    use crate::error::CryptoError;
+    use crate::zkp::compose::or_composition;
+    use crate::zkp::ArgumentOfKnowledge;
+
+    use ark_ec::ProjectiveCurve;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type FS = FiatShamirRng<Blake2s>;
+    type Relation = LinearRelation<Curve>;
+
+    /// $Y = xG + rH \wedge Z = xG$
+    fn setup() -> Parameters<Curve> {
+        let rng = &mut thread_rng();
+        let g = Curve::rand(rng).into_affine();
+        let h = Curve::rand(rng).into_affine();
+
+        Parameters::new(
+            2,
+            vec![
+                Equation::new(vec![Term::new(0, g), Term::new(1, h)]),
+                Equation::new(vec![Term::new(0, g)]),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let parameters = setup();
+
+        let witness = vec![Scalar::rand(rng), Scalar::rand(rng)];
+        let statement = parameters.evaluate(&witness).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Relation::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Relation::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let parameters = setup();
+
+        let witness = vec![Scalar::rand(rng), Scalar::rand(rng)];
+        let statement = parameters.evaluate(&witness).unwrap();
+
+        // The first variable does not match across the two equations
+        let wrong_witness = vec![Scalar::rand(rng), witness[1]];
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = Relation::prove(
+            rng,
+            &parameters,
+            &statement,
+            &wrong_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Relation::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_invalid_relation() {
+        let rng = &mut thread_rng();
+        let g = Curve::rand(rng).into_affine();
+
+        assert_eq!(
+            Parameters::<Curve>::new(1, vec![Equation::new(vec![Term::new(1, g)])]),
+            Err(CryptoError::InvalidLinearRelation)
+        );
+        assert_eq!(
+            Parameters::<Curve>::new(1, vec![]),
+            Err(CryptoError::InvalidLinearRelation)
+        );
+
+        let parameters = setup();
+        let short_witness = vec![Scalar::rand(rng)];
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Relation::prove(
+                rng,
+                &parameters,
+                &vec![g, g],
+                &short_witness,
+                b"Test Domain",
+                &mut fs_rng
+            )
+            .err(),
+            Some(CryptoError::InvalidLinearRelation)
+        );
+    }
+
+    #[test]
+    fn test_serialize_deserialize_proof() {
+        let rng = &mut thread_rng();
+        let parameters = setup();
+
+        let witness = vec![Scalar::rand(rng), Scalar::rand(rng)];
+        let statement = parameters.evaluate(&witness).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Relation::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            super::super::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(proof, deserialized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Relation::verify(
+                &parameters,
+                &statement,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_composes_with_or() {
+        let rng = &mut thread_rng();
+        let parameters = setup();
+
+        let witness = vec![Scalar::rand(rng), Scalar::rand(rng)];
+        let known = parameters.evaluate(&witness).unwrap();
+        let unknown = parameters
+            .evaluate(&[Scalar::rand(rng), Scalar::rand(rng)])
+            .unwrap();
+
+        let statement = vec![unknown, known];
+        let or_witness = or_composition::Witness::new(1, &witness);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = or_composition::OrComposition::<Relation>::prove(
+            rng,
+            &parameters,
+            &statement,
+            &or_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            or_composition::OrComposition::<Relation>::verify(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+}
@@ -1,2 +1,21 @@
+// Each proof keeps its tests in `test.rs` wrapped in a `test` module.
+#![allow(clippy::module_inception)]
+
+pub mod bit;
 pub mod chaum_pedersen_dl_equality;
+pub mod ciphertext_well_formedness;
+pub mod commitment_ciphertext_consistency;
+pub mod commitment_equality;
+pub mod commitment_opening;
+pub mod commitment_sum;
+pub mod cross_group_dl_equality;
+pub mod ddh_tuple;
+pub mod dl_inequality;
+pub mod linear_relation;
+pub mod multi_base_schnorr;
+pub mod okamoto_representation;
+pub mod one_out_of_many;
+pub mod plaintext_equality;
+pub mod plaintext_membership;
+pub mod range;
 pub mod schnorr_identification;
@@ -0,0 +1,181 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, ToBytes};
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// Generalisation of Schnorr identification to any number of bases: proof of knowledge of a representation
+/// $(x_1, \dots, x_n)$ of a point $Y = x_1 G_1 + \dots + x_n G_n$. With one base it is Schnorr identification and with
+/// two it is Okamoto identification.
+pub struct MultiBaseSchnorr<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Clone)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub bases: Vec<C::Affine>,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    pub fn new(bases: Vec<C::Affine>) -> Self {
+        Self { bases }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bases.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty()
+    }
+
+    /// $\sum_i x_i G_i$
+    pub fn evaluate(&self, exponents: &[C::ScalarField]) -> Result<C, CryptoError> {
+        if self.bases.is_empty() || exponents.len() != self.bases.len() {
+            return Err(CryptoError::InvalidRepresentationLength);
+        }
+
+        let scalars = exponents.iter().map(|x| x.into_repr()).collect::<Vec<_>>();
+
+        Ok(VariableBaseMSM::multi_scalar_mul(&self.bases, &scalars))
+    }
+}
+
+impl<C: ProjectiveCurve> ToBytes for Parameters<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.bases.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+pub type Statement<C> = <C as ProjectiveCurve>::Affine;
+
+/// Representation $(x_1, \dots, x_n)$ of the statement, one exponent per base.
+pub type Witness<C> = Vec<<C as ProjectiveCurve>::ScalarField>;
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for MultiBaseSchnorr<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> SigmaProtocol for MultiBaseSchnorr<C> {
+    type Scalar = C::ScalarField;
+    type Parameters = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Commitment = C::Affine;
+    type Randomness = Vec<C::ScalarField>;
+    type Response = Vec<C::ScalarField>;
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        if witness.len() != parameters.len() {
+            return Err(CryptoError::InvalidRepresentationLength);
+        }
+
+        let randoms = (0..parameters.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let random_commit = parameters.evaluate(&randoms)?;
+
+        Ok((random_commit.into_affine(), randoms))
+    }
+
+    fn respond(
+        _parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        if witness.len() != randomness.len() {
+            return Err(CryptoError::InvalidRepresentationLength);
+        }
+
+        Ok(randomness
+            .iter()
+            .zip(witness.iter())
+            .map(|(random, x)| *random - *challenge * x)
+            .collect())
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        let openings = (0..parameters.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let random_commit = parameters.evaluate(&openings)? + statement.mul(challenge.into_repr());
+
+        Ok((random_commit.into_affine(), openings))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            || CryptoError::ProofVerificationError(String::from("Multi-Base Schnorr"));
+
+        let opened = parameters
+            .evaluate(response)
+            .map_err(|_| verification_error())?;
+        if opened + statement.mul(challenge.into_repr()) != commitment.into_projective() {
+            return Err(verification_error());
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> MultiBaseSchnorr<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Multi-Base Schnorr";
+}
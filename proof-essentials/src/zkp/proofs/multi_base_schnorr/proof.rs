@@ -0,0 +1,38 @@
+use super::{MultiBaseSchnorr, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) random_commit: C::Affine,
+    pub(crate) openings: Vec<C::ScalarField>,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            b"multi_base_schnorr",
+            pp,
+            statement,
+            self.random_commit
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        MultiBaseSchnorr::<C>::check(pp, statement, &self.random_commit, &c, &self.openings)
+    }
+}
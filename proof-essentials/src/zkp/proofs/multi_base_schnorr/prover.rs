@@ -0,0 +1,50 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use super::{proof::Proof, MultiBaseSchnorr, Parameters, Statement, Witness};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (random_commit, randomness) =
+            MultiBaseSchnorr::<C>::commit(rng, pp, statement, witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            b"multi_base_schnorr",
+            pp,
+            statement,
+            random_commit
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let openings = MultiBaseSchnorr::<C>::respond(pp, statement, witness, &randomness, &c)?;
+
+        Ok(Proof {
+            random_commit,
+            openings,
+        })
+    }
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::zkp::compose::and_composition;
+    use crate::zkp::{proofs::multi_base_schnorr, ArgumentOfKnowledge};
+    use ark_ec::ProjectiveCurve;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+    use rand::{prelude::ThreadRng, Rng};
+
+    type Curve = starknet_curve::Projective;
+    type Point = starknet_curve::Affine;
+    type MultiBaseSchnorr = multi_base_schnorr::MultiBaseSchnorr<Curve>;
+    type Scalar = starknet_curve::Fr;
+    type Parameters = multi_base_schnorr::Parameters<Curve>;
+    type Witness = multi_base_schnorr::Witness<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn setup<R: Rng>(rng: &mut R, number_of_bases: usize) -> Parameters {
+        Parameters::new(
+            (0..number_of_bases)
+                .map(|_| Curve::rand(rng).into_affine())
+                .collect(),
+        )
+    }
+
+    fn test_template(number_of_bases: usize) -> (ThreadRng, Parameters, Witness, Point) {
+        let mut rng = thread_rng();
+
+        let crs = setup(&mut rng, number_of_bases);
+        let witness: Witness = sample_vector(&mut rng, number_of_bases);
+        let statement = crs.evaluate(&witness).unwrap().into_affine();
+
+        (rng, crs, witness, statement)
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        for number_of_bases in [1, 2, 7] {
+            let (mut rng, crs, witness, statement) = test_template(number_of_bases);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = MultiBaseSchnorr::prove(
+                &mut rng,
+                &crs,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                MultiBaseSchnorr::verify(&crs, &statement, &proof, b"Test Domain", &mut fs_rng),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let (mut rng, crs, witness, statement) = test_template(5);
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Multi-Base Schnorr",
+        )));
+
+        // Only one exponent of the representation is wrong
+        let mut wrong_witness = witness.clone();
+        wrong_witness[3] = Scalar::rand(&mut rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = MultiBaseSchnorr::prove(
+            &mut rng,
+            &crs,
+            &statement,
+            &wrong_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            MultiBaseSchnorr::verify(
+                &crs,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // Valid proof checked against a different list of bases
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = MultiBaseSchnorr::prove(
+            &mut rng,
+            &crs,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let other_crs = Parameters::new(crs.bases[..4].to_vec());
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            MultiBaseSchnorr::verify(&other_crs, &statement, &proof, b"Test Domain", &mut fs_rng),
+            expected_error
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            MultiBaseSchnorr::prove(
+                &mut rng,
+                &crs,
+                &statement,
+                &witness[..4].to_vec(),
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Err(CryptoError::InvalidRepresentationLength)
+        );
+    }
+
+    #[test]
+    fn test_serialize_deserialize_proof() {
+        let (mut rng, crs, witness, statement) = test_template(3);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = MultiBaseSchnorr::prove(
+            &mut rng,
+            &crs,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            multi_base_schnorr::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(proof, deserialized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            MultiBaseSchnorr::verify(&crs, &statement, &deserialized, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_and_composition() {
+        let (mut rng, crs, witness, statement) = test_template(3);
+        let other_witness: Witness = sample_vector(&mut rng, 3);
+        let other_statement = crs.evaluate(&other_witness).unwrap().into_affine();
+
+        let statements = vec![statement, other_statement];
+        let witnesses = vec![witness, other_witness];
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = and_composition::AndComposition::<MultiBaseSchnorr>::prove(
+            &mut rng,
+            &crs,
+            &statements,
+            &witnesses,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            and_composition::AndComposition::<MultiBaseSchnorr>::verify(
+                &crs,
+                &statements,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Ok(())
+        );
+    }
+}
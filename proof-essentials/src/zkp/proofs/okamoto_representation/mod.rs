@@ -0,0 +1,165 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, ToBytes};
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// Okamoto identification: proof of knowledge of a representation $(x, y)$ of a point $Y = xG + yH$
+/// with respect to two generators $G$ and $H$ whose relative discrete logarithm is unknown.
+pub struct OkamotoRepresentation<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub g: C::Affine,
+    pub h: C::Affine,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    pub fn new(g: C::Affine, h: C::Affine) -> Self {
+        Self { g, h }
+    }
+}
+
+impl<C: ProjectiveCurve> ToBytes for Parameters<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.g.write(&mut w)?;
+        self.h.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+pub type Statement<C> = <C as ProjectiveCurve>::Affine;
+
+/// Representation $(x, y)$ of the statement $Y = xG + yH$.
+#[derive(Copy, Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub x: C::ScalarField,
+    pub y: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(x: C::ScalarField, y: C::ScalarField) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for OkamotoRepresentation<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> SigmaProtocol for OkamotoRepresentation<C> {
+    type Scalar = C::ScalarField;
+    type Parameters = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Commitment = C::Affine;
+    type Randomness = (C::ScalarField, C::ScalarField);
+    type Response = (C::ScalarField, C::ScalarField);
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        _witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        let random_x = C::ScalarField::rand(rng);
+        let random_y = C::ScalarField::rand(rng);
+        let random_commit =
+            parameters.g.mul(random_x.into_repr()) + parameters.h.mul(random_y.into_repr());
+
+        Ok((random_commit.into_affine(), (random_x, random_y)))
+    }
+
+    fn respond(
+        _parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        Ok((
+            randomness.0 - *challenge * witness.x,
+            randomness.1 - *challenge * witness.y,
+        ))
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        let opening_x = C::ScalarField::rand(rng);
+        let opening_y = C::ScalarField::rand(rng);
+        let random_commit = parameters.g.mul(opening_x.into_repr())
+            + parameters.h.mul(opening_y.into_repr())
+            + statement.mul(challenge.into_repr());
+
+        Ok((random_commit.into_affine(), (opening_x, opening_y)))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        if parameters.g.mul(response.0.into_repr())
+            + parameters.h.mul(response.1.into_repr())
+            + statement.mul(challenge.into_repr())
+            != commitment.into_projective()
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Okamoto Representation",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> OkamotoRepresentation<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Okamoto Representation";
+}
@@ -0,0 +1,45 @@
+use super::{OkamotoRepresentation, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) random_commit: C::Affine,
+    pub(crate) opening_x: C::ScalarField,
+    pub(crate) opening_y: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            b"okamoto_representation",
+            pp,
+            statement,
+            self.random_commit
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        OkamotoRepresentation::<C>::check(
+            pp,
+            statement,
+            &self.random_commit,
+            &c,
+            &(self.opening_x, self.opening_y),
+        )
+    }
+}
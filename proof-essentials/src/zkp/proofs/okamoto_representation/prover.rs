@@ -0,0 +1,52 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use super::{proof::Proof, OkamotoRepresentation, Parameters, Statement, Witness};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (random_commit, randomness) =
+            OkamotoRepresentation::<C>::commit(rng, pp, statement, witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            b"okamoto_representation",
+            pp,
+            statement,
+            random_commit
+        ]?);
+
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let (opening_x, opening_y) =
+            OkamotoRepresentation::<C>::respond(pp, statement, witness, &randomness, &c)?;
+
+        Ok(Proof {
+            random_commit,
+            opening_x,
+            opening_y,
+        })
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::{proofs::okamoto_representation, ArgumentOfKnowledge};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+    use rand::{prelude::ThreadRng, Rng};
+
+    type Curve = starknet_curve::Projective;
+    type Point = starknet_curve::Affine;
+    type Okamoto = okamoto_representation::OkamotoRepresentation<Curve>;
+    type Scalar = starknet_curve::Fr;
+    type Parameters = okamoto_representation::Parameters<Curve>;
+    type Witness = okamoto_representation::Witness<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn setup<R: Rng>(rng: &mut R) -> Result<Parameters, CryptoError> {
+        Ok(Parameters::new(
+            Curve::rand(rng).into_affine(),
+            Curve::rand(rng).into_affine(),
+        ))
+    }
+
+    fn test_template() -> (ThreadRng, Parameters, Witness, Point) {
+        let mut rng = thread_rng();
+
+        let crs = setup(&mut rng).unwrap();
+
+        let witness = Witness::new(Scalar::rand(&mut rng), Scalar::rand(&mut rng));
+        let statement = (crs.g.mul(witness.x) + crs.h.mul(witness.y)).into_affine();
+
+        (rng, crs, witness, statement)
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let (mut rng, crs, witness, statement) = test_template();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Okamoto::prove(
+            &mut rng,
+            &crs,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Okamoto::verify(&crs, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let (mut rng, crs, witness, statement) = test_template();
+
+        // Only the second half of the representation is wrong
+        let wrong_witness = Witness::new(witness.x, Scalar::rand(&mut rng));
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+
+        let invalid_proof = Okamoto::prove(
+            &mut rng,
+            &crs,
+            &statement,
+            &wrong_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+
+        assert_eq!(
+            Okamoto::verify(
+                &crs,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Okamoto Representation"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_serialize_deserialize_proof() {
+        let (mut rng, crs, witness, statement) = test_template();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Okamoto::prove(
+            &mut rng,
+            &crs,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            okamoto_representation::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(proof, deserialized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Okamoto::verify(&crs, &statement, &deserialized, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,76 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// One-out-of-many proof (Groth-Kohlweiss, Eurocrypt 2015): given a public list of $N = 2^m$ Pedersen
+/// commitments, proves knowledge of an index $\ell$ and a blinding factor $r$ such that the $\ell$-th commitment
+/// is a commitment to zero, $c_\ell = rH$. The proof has $4m$ group elements and $3m + 1$ scalars.
+///
+/// Only the first generator and the blinding generator of the commit key are used.
+pub struct OneOutOfMany<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+pub type Parameters<C> = pedersen::CommitKey<C>;
+
+/// Public list of commitments, one of which opens to zero. The length of the list must be a power of two.
+pub type Statement<C> = Vec<pedersen::Commitment<C>>;
+
+/// Position in the list of the commitment to zero, and its blinding factor.
+#[derive(Copy, Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub index: usize,
+    pub randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(index: usize, randomness: C::ScalarField) -> Self {
+        Self { index, randomness }
+    }
+}
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for OneOutOfMany<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> OneOutOfMany<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"One-out-of-Many";
+}
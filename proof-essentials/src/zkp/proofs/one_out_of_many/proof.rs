@@ -0,0 +1,106 @@
+use super::{Parameters, Statement};
+use crate::error::CryptoError;
+use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+use ark_ff::{to_bytes, One, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) bit_commits: Vec<pedersen::Commitment<C>>,
+    pub(crate) a_commits: Vec<pedersen::Commitment<C>>,
+    pub(crate) b_commits: Vec<pedersen::Commitment<C>>,
+    pub(crate) d_commits: Vec<pedersen::Commitment<C>>,
+    pub(crate) f: Vec<C::ScalarField>,
+    pub(crate) z_a: Vec<C::ScalarField>,
+    pub(crate) z_b: Vec<C::ScalarField>,
+    pub(crate) z_d: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            CryptoError::ProofVerificationError(String::from("One-out-of-Many"));
+
+        let big_n = statement.len();
+        let m = big_n.trailing_zeros() as usize;
+        if !big_n.is_power_of_two()
+            || self.bit_commits.len() != m
+            || self.a_commits.len() != m
+            || self.b_commits.len() != m
+            || self.d_commits.len() != m
+            || self.f.len() != m
+            || self.z_a.len() != m
+            || self.z_b.len() != m
+        {
+            return Err(verification_error);
+        }
+
+        fs_rng.absorb(&to_bytes![
+            b"one_out_of_many",
+            commit_key,
+            statement,
+            self.bit_commits,
+            self.a_commits,
+            self.b_commits,
+            self.d_commits
+        ]?);
+        let x = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let com = |value: C::ScalarField, randomness: C::ScalarField| {
+            pedersen::PedersenCommitment::<C>::commit(commit_key, &vec![value], randomness)
+        };
+
+        // The bit commitments are commitments to bits and f_j = l_j x + a_j
+        for j in 0..m {
+            if self.bit_commits[j] * x + self.a_commits[j] != com(self.f[j], self.z_a[j])?
+                || self.bit_commits[j] * (x - self.f[j]) + self.b_commits[j]
+                    != com(C::ScalarField::zero(), self.z_b[j])?
+            {
+                return Err(verification_error);
+            }
+        }
+
+        // Evaluate p_i(x) = prod_j f_{j, i_j} for every position of the list. The list is then folded with a
+        // single multi-exponentiation.
+        let mut p = vec![C::ScalarField::one()];
+        for j in (0..m).rev() {
+            let f_1 = self.f[j];
+            let f_0 = x - f_1;
+            p = p
+                .iter()
+                .flat_map(|p_i| vec![*p_i * f_0, *p_i * f_1])
+                .collect();
+        }
+
+        let bases = statement.iter().map(|c| c.0).collect::<Vec<_>>();
+        let scalars = p.iter().map(|p_i| p_i.into_repr()).collect::<Vec<_>>();
+        let folded_list = pedersen::Commitment::<C>(
+            VariableBaseMSM::multi_scalar_mul(&bases, &scalars).into_affine(),
+        );
+
+        let mut x_k = C::ScalarField::one();
+        let mut folded_d = Vec::with_capacity(m);
+        for d_commit in &self.d_commits {
+            folded_d.push(*d_commit * -x_k);
+            x_k *= x;
+        }
+
+        if folded_list + folded_d.into_iter().sum() != com(C::ScalarField::zero(), self.z_d)? {
+            return Err(verification_error);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use super::{proof::Proof, Parameters, Statement, Witness};
+use crate::error::CryptoError;
+use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+use ark_ff::{to_bytes, One, PrimeField, Zero};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        commit_key: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let big_n = statement.len();
+        if !big_n.is_power_of_two() || witness.index >= big_n {
+            return Err(CryptoError::InvalidOneOutOfManyStatement);
+        }
+        let m = big_n.trailing_zeros() as usize;
+
+        let com = |value: C::ScalarField, randomness: C::ScalarField| {
+            pedersen::PedersenCommitment::<C>::commit(commit_key, &vec![value], randomness)
+        };
+
+        // Binary decomposition of the secret index, least significant bit first
+        let bits = (0..m)
+            .map(|j| {
+                if (witness.index >> j) & 1 == 1 {
+                    C::ScalarField::one()
+                } else {
+                    C::ScalarField::zero()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut r = Vec::with_capacity(m);
+        let mut a = Vec::with_capacity(m);
+        let mut s = Vec::with_capacity(m);
+        let mut t = Vec::with_capacity(m);
+        let mut rho = Vec::with_capacity(m);
+        for _ in 0..m {
+            r.push(C::ScalarField::rand(rng));
+            a.push(C::ScalarField::rand(rng));
+            s.push(C::ScalarField::rand(rng));
+            t.push(C::ScalarField::rand(rng));
+            rho.push(C::ScalarField::rand(rng));
+        }
+
+        let mut bit_commits = Vec::with_capacity(m);
+        let mut a_commits = Vec::with_capacity(m);
+        let mut b_commits = Vec::with_capacity(m);
+        for j in 0..m {
+            bit_commits.push(com(bits[j], r[j])?);
+            a_commits.push(com(a[j], s[j])?);
+            b_commits.push(com(bits[j] * a[j], t[j])?);
+        }
+
+        // Coefficients of p_i(X) = prod_j f_{j, i_j}(X) where f_{j, 1}(X) = l_j X + a_j and f_{j, 0}(X) = X - f_{j, 1}(X)
+        let mut polynomials = vec![vec![C::ScalarField::one()]];
+        for j in (0..m).rev() {
+            let f_1 = [a[j], bits[j]];
+            let f_0 = [-a[j], C::ScalarField::one() - bits[j]];
+            polynomials = polynomials
+                .iter()
+                .flat_map(|p| vec![multiply_by_linear(p, &f_0), multiply_by_linear(p, &f_1)])
+                .collect();
+        }
+
+        let bases = statement.iter().map(|c| c.0).collect::<Vec<_>>();
+        let mut d_commits = Vec::with_capacity(m);
+        for k in 0..m {
+            let scalars = polynomials
+                .iter()
+                .map(|p| p[k].into_repr())
+                .collect::<Vec<_>>();
+            let folded = pedersen::Commitment::<C>(
+                VariableBaseMSM::multi_scalar_mul(&bases, &scalars).into_affine(),
+            );
+            d_commits.push(folded + com(C::ScalarField::zero(), rho[k])?);
+        }
+
+        fs_rng.absorb(&to_bytes![
+            b"one_out_of_many",
+            commit_key,
+            statement,
+            bit_commits,
+            a_commits,
+            b_commits,
+            d_commits
+        ]?);
+        let x = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let f = (0..m).map(|j| bits[j] * x + a[j]).collect::<Vec<_>>();
+        let z_a = (0..m).map(|j| r[j] * x + s[j]).collect::<Vec<_>>();
+        let z_b = (0..m).map(|j| r[j] * (x - f[j]) + t[j]).collect::<Vec<_>>();
+
+        let mut x_k = C::ScalarField::one();
+        let mut z_d = C::ScalarField::zero();
+        for rho_k in &rho {
+            z_d -= *rho_k * x_k;
+            x_k *= x;
+        }
+        z_d += witness.randomness * x_k;
+
+        Ok(Proof {
+            bit_commits,
+            a_commits,
+            b_commits,
+            d_commits,
+            f,
+            z_a,
+            z_b,
+            z_d,
+        })
+    }
+}
+
+/// Multiply the polynomial with coefficients `p` by the linear polynomial `linear[0] + linear[1] X`.
+fn multiply_by_linear<F: PrimeField>(p: &[F], linear: &[F; 2]) -> Vec<F> {
+    let mut result = vec![F::zero(); p.len() + 1];
+    for (k, coefficient) in p.iter().enumerate() {
+        result[k] += *coefficient * linear[0];
+        result[k + 1] += *coefficient * linear[1];
+    }
+
+    result
+}
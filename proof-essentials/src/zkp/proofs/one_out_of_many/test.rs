@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{proofs::one_out_of_many, ArgumentOfKnowledge};
+    use ark_ff::Zero;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+    use rand::Rng;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type OneOutOfMany = one_out_of_many::OneOutOfMany<Curve>;
+    type CommitKey = pedersen::CommitKey<Curve>;
+    type Commitment = pedersen::Commitment<Curve>;
+    type Witness = one_out_of_many::Witness<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn test_template<R: Rng>(
+        rng: &mut R,
+        size: usize,
+        index: usize,
+    ) -> (CommitKey, Vec<Commitment>, Witness) {
+        let commit_key = Pedersen::setup(rng, 1);
+
+        let mut list = (0..size)
+            .map(|_| {
+                Pedersen::commit(&commit_key, &vec![Scalar::rand(rng)], Scalar::rand(rng)).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let witness = Witness::new(index, Scalar::rand(rng));
+        list[index] =
+            Pedersen::commit(&commit_key, &vec![Scalar::zero()], witness.randomness).unwrap();
+
+        (commit_key, list, witness)
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+
+        for (size, index) in [(1, 0), (2, 1), (8, 5), (32, 0), (32, 31)] {
+            let (commit_key, list, witness) = test_template(rng, size, index);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = OneOutOfMany::prove(
+                rng,
+                &commit_key,
+                &list,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                OneOutOfMany::verify(&commit_key, &list, &proof, b"Test Domain", &mut fs_rng),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let (commit_key, list, witness) = test_template(rng, 16, 6);
+
+        // Claiming the wrong position in the list
+        let wrong_witness = Witness::new(7, witness.randomness);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = OneOutOfMany::prove(
+            rng,
+            &commit_key,
+            &list,
+            &wrong_witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            OneOutOfMany::verify(
+                &commit_key,
+                &list,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "One-out-of-Many"
+            )))
+        );
+
+        // Verifying against a list without a commitment to zero
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = OneOutOfMany::prove(
+            rng,
+            &commit_key,
+            &list,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut other_list = list.clone();
+        other_list[6] =
+            Pedersen::commit(&commit_key, &vec![Scalar::rand(rng)], Scalar::rand(rng)).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            OneOutOfMany::verify(
+                &commit_key,
+                &other_list,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "One-out-of-Many"
+            )))
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            OneOutOfMany::prove(
+                rng,
+                &commit_key,
+                &list[..12].to_vec(),
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .err(),
+            Some(CryptoError::InvalidOneOutOfManyStatement)
+        );
+    }
+
+    #[test]
+    fn test_serialize_deserialize_proof() {
+        let rng = &mut thread_rng();
+        let (commit_key, list, witness) = test_template(rng, 8, 3);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = OneOutOfMany::prove(
+            rng,
+            &commit_key,
+            &list,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized =
+            one_out_of_many::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(proof, deserialized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            OneOutOfMany::verify(
+                &commit_key,
+                &list,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+}
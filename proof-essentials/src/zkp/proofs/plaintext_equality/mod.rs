@@ -0,0 +1,162 @@
+mod test;
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal;
+use crate::zkp::proofs::linear_relation::{self, Equation, LinearRelation, Term};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::to_bytes;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that two ElGamal ciphertexts $c = (r_1 G, m + r_1 pk_1)$ and $c' = (r_2 G, m + r_2 pk_2)$, possibly under
+/// different public keys, encrypt the same plaintext $m$. The prover knows the encryption randomness of both.
+///
+/// The statement is proven as the linear relation $c_1 = r_1 G \wedge c'_1 = r_2 G \wedge c_2 - c'_2 = r_1 pk_1 - r_2 pk_2$.
+pub struct PlaintextEquality<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub encrypt_parameters: &'a el_gamal::Parameters<C>,
+    pub public_key_1: &'a el_gamal::PublicKey<C>,
+    pub public_key_2: &'a el_gamal::PublicKey<C>,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(
+        encrypt_parameters: &'a el_gamal::Parameters<C>,
+        public_key_1: &'a el_gamal::PublicKey<C>,
+        public_key_2: &'a el_gamal::PublicKey<C>,
+    ) -> Self {
+        Self {
+            encrypt_parameters,
+            public_key_1,
+            public_key_2,
+        }
+    }
+}
+
+/// Statement for a plaintext equality proof: the two ciphertexts, encrypted under `public_key_1` and
+/// `public_key_2` respectively.
+#[derive(Copy, Clone)]
+pub struct Statement<'a, C: ProjectiveCurve>(
+    pub &'a el_gamal::Ciphertext<C>,
+    pub &'a el_gamal::Ciphertext<C>,
+);
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(
+        ciphertext_1: &'a el_gamal::Ciphertext<C>,
+        ciphertext_2: &'a el_gamal::Ciphertext<C>,
+    ) -> Self {
+        Self(ciphertext_1, ciphertext_2)
+    }
+}
+
+/// Encryption randomness of both ciphertexts.
+#[derive(Copy, Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub randomness_1: C::ScalarField,
+    pub randomness_2: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(randomness_1: C::ScalarField, randomness_2: C::ScalarField) -> Self {
+        Self {
+            randomness_1,
+            randomness_2,
+        }
+    }
+}
+
+pub type Proof<C> = linear_relation::proof::Proof<C>;
+
+impl<'a, C: ProjectiveCurve> PlaintextEquality<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"ElGamal Plaintext Equality";
+
+    fn relation(
+        parameters: &Parameters<'a, C>,
+        statement: &Statement<'a, C>,
+    ) -> Result<
+        (
+            linear_relation::Parameters<C>,
+            linear_relation::Statement<C>,
+        ),
+        CryptoError,
+    > {
+        let generator = parameters.encrypt_parameters.generator;
+        let minus_public_key_2 = (-parameters.public_key_2.into_projective()).into_affine();
+
+        let relation = linear_relation::Parameters::new(
+            2,
+            vec![
+                Equation::new(vec![Term::new(0, generator)]),
+                Equation::new(vec![Term::new(1, generator)]),
+                Equation::new(vec![
+                    Term::new(0, *parameters.public_key_1),
+                    Term::new(1, minus_public_key_2),
+                ]),
+            ],
+        )?;
+
+        let images = vec![
+            statement.0 .0,
+            statement.1 .0,
+            (statement.0 .1.into_projective() - statement.1 .1.into_projective()).into_affine(),
+        ];
+
+        Ok((relation, images))
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for PlaintextEquality<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let (relation, images) = Self::relation(common_reference_string, statement)?;
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        LinearRelation::prove(
+            rng,
+            &relation,
+            &images,
+            &vec![witness.randomness_1, witness.randomness_2],
+            domain,
+            fs_rng,
+        )
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let (relation, images) = Self::relation(common_reference_string, statement)?;
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        LinearRelation::verify(&relation, &images, proof, domain, fs_rng).map_err(|_| {
+            CryptoError::ProofVerificationError(String::from("ElGamal Plaintext Equality"))
+        })
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::zkp::{proofs::plaintext_equality, ArgumentOfKnowledge};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Plaintext = el_gamal::Plaintext<Curve>;
+    type PlaintextEquality<'a> = plaintext_equality::PlaintextEquality<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk_1, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let (pk_2, _) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let m = Plaintext::rand(rng);
+        let witness = plaintext_equality::Witness::new(Scalar::rand(rng), Scalar::rand(rng));
+        let c_1 = ElGamal::encrypt(&pp, &pk_1, &m, &witness.randomness_1).unwrap();
+        let c_2 = ElGamal::encrypt(&pp, &pk_2, &m, &witness.randomness_2).unwrap();
+
+        let parameters = plaintext_equality::Parameters::new(&pp, &pk_1, &pk_2);
+        let statement = plaintext_equality::Statement::new(&c_1, &c_2);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = PlaintextEquality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            PlaintextEquality::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+
+        // Same key on both sides
+        let c_3 = ElGamal::encrypt(&pp, &pk_1, &m, &witness.randomness_2).unwrap();
+        let parameters = plaintext_equality::Parameters::new(&pp, &pk_1, &pk_1);
+        let statement = plaintext_equality::Statement::new(&c_1, &c_3);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = PlaintextEquality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            PlaintextEquality::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk_1, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let (pk_2, _) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let witness = plaintext_equality::Witness::new(Scalar::rand(rng), Scalar::rand(rng));
+        let c_1 =
+            ElGamal::encrypt(&pp, &pk_1, &Plaintext::rand(rng), &witness.randomness_1).unwrap();
+        let c_2 =
+            ElGamal::encrypt(&pp, &pk_2, &Plaintext::rand(rng), &witness.randomness_2).unwrap();
+
+        let parameters = plaintext_equality::Parameters::new(&pp, &pk_1, &pk_2);
+        let statement = plaintext_equality::Statement::new(&c_1, &c_2);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = PlaintextEquality::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            PlaintextEquality::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "ElGamal Plaintext Equality"
+            )))
+        );
+    }
+}
@@ -0,0 +1,155 @@
+mod test;
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal;
+use crate::zkp::compose::{or_composition, OrProof};
+use crate::zkp::proofs::linear_relation::{self, Equation, LinearRelation, Term};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::to_bytes;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that an ElGamal ciphertext $c = (c_1, c_2)$ encrypts one of a public list of messages $m_1, \dots, m_N$
+/// under a known public key, without revealing which one.
+///
+/// Removing $m_j$ from the ciphertext leaves an encryption of zero exactly when $m = m_j$, so the statement is
+/// proven as the OR-composition over $j$ of the relations $c_1 = rG \wedge c_2 - m_j = r \cdot pk$.
+pub struct PlaintextMembership<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub encrypt_parameters: &'a el_gamal::Parameters<C>,
+    pub public_key: &'a el_gamal::PublicKey<C>,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(
+        encrypt_parameters: &'a el_gamal::Parameters<C>,
+        public_key: &'a el_gamal::PublicKey<C>,
+    ) -> Self {
+        Self {
+            encrypt_parameters,
+            public_key,
+        }
+    }
+}
+
+/// Statement for a plaintext membership proof: the ciphertext and the list of messages it may encrypt.
+#[derive(Copy, Clone)]
+pub struct Statement<'a, C: ProjectiveCurve> {
+    pub ciphertext: &'a el_gamal::Ciphertext<C>,
+    pub messages: &'a Vec<el_gamal::Plaintext<C>>,
+}
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(
+        ciphertext: &'a el_gamal::Ciphertext<C>,
+        messages: &'a Vec<el_gamal::Plaintext<C>>,
+    ) -> Self {
+        Self {
+            ciphertext,
+            messages,
+        }
+    }
+}
+
+/// Position of the encrypted message in the list, and the encryption randomness.
+#[derive(Copy, Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub index: usize,
+    pub randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(index: usize, randomness: C::ScalarField) -> Self {
+        Self { index, randomness }
+    }
+}
+
+pub type Proof<C> = OrProof<LinearRelation<C>>;
+
+impl<'a, C: ProjectiveCurve> PlaintextMembership<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"ElGamal Plaintext Membership";
+
+    fn relation(
+        parameters: &Parameters<'a, C>,
+        statement: &Statement<'a, C>,
+    ) -> Result<
+        (
+            linear_relation::Parameters<C>,
+            or_composition::Statement<LinearRelation<C>>,
+        ),
+        CryptoError,
+    > {
+        let relation = linear_relation::Parameters::new(
+            1,
+            vec![
+                Equation::new(vec![Term::new(0, parameters.encrypt_parameters.generator)]),
+                Equation::new(vec![Term::new(0, *parameters.public_key)]),
+            ],
+        )?;
+
+        let c_1 = statement.ciphertext.0;
+        let c_2 = statement.ciphertext.1.into_projective();
+        let images = statement
+            .messages
+            .iter()
+            .map(|message| vec![c_1, (c_2 - message.0.into_projective()).into_affine()])
+            .collect();
+
+        Ok((relation, images))
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for PlaintextMembership<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let (relation, images) = Self::relation(common_reference_string, statement)?;
+        let randomness = vec![witness.randomness];
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        or_composition::prover::Prover::create_proof(
+            rng,
+            &relation,
+            &images,
+            &or_composition::Witness::new(witness.index, &randomness),
+            fs_rng,
+        )
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let (relation, images) = Self::relation(common_reference_string, statement)?;
+
+        fs_rng.absorb(&to_bytes![Self::PROTOCOL_NAME]?);
+        proof.verify(&relation, &images, fs_rng).map_err(|_| {
+            CryptoError::ProofVerificationError(String::from("ElGamal Plaintext Membership"))
+        })
+    }
+}
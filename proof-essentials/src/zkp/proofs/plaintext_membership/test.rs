@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::utils::rand::sample_vector;
+    use crate::zkp::{proofs::plaintext_membership, ArgumentOfKnowledge};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Plaintext = el_gamal::Plaintext<Curve>;
+    type PlaintextMembership<'a> = plaintext_membership::PlaintextMembership<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+
+        // A masked card belongs to the 52 card encodings of the deck
+        let deck: Vec<Plaintext> = sample_vector(rng, 52);
+        let parameters = plaintext_membership::Parameters::new(&pp, &pk);
+
+        for index in [0, 17, 51] {
+            let witness = plaintext_membership::Witness::new(index, Scalar::rand(rng));
+            let ciphertext = ElGamal::encrypt(&pp, &pk, &deck[index], &witness.randomness).unwrap();
+            let statement = plaintext_membership::Statement::new(&ciphertext, &deck);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = PlaintextMembership::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                PlaintextMembership::verify(
+                    &parameters,
+                    &statement,
+                    &proof,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "ElGamal Plaintext Membership",
+        )));
+
+        let deck: Vec<Plaintext> = sample_vector(rng, 8);
+        let parameters = plaintext_membership::Parameters::new(&pp, &pk);
+
+        // A message outside of the list
+        let witness = plaintext_membership::Witness::new(3, Scalar::rand(rng));
+        let joker = Plaintext::rand(rng);
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &joker, &witness.randomness).unwrap();
+        let statement = plaintext_membership::Statement::new(&ciphertext, &deck);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = PlaintextMembership::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            PlaintextMembership::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // A valid proof checked against a list without the encrypted message
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &deck[3], &witness.randomness).unwrap();
+        let statement = plaintext_membership::Statement::new(&ciphertext, &deck);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = PlaintextMembership::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut other_deck = deck.clone();
+        other_deck[3] = joker;
+        let statement = plaintext_membership::Statement::new(&ciphertext, &other_deck);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            PlaintextMembership::verify(
+                &parameters,
+                &statement,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let statement = plaintext_membership::Statement::new(&ciphertext, &deck);
+        let out_of_range = plaintext_membership::Witness::new(8, witness.randomness);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            PlaintextMembership::prove(
+                rng,
+                &parameters,
+                &statement,
+                &out_of_range,
+                b"Test Domain",
+                &mut fs_rng
+            )
+            .err(),
+            Some(CryptoError::InvalidOrCompositionStatement)
+        );
+    }
+}
@@ -0,0 +1,210 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+use ark_ff::{Field, PrimeField, ToBytes};
+use ark_std::io::Write;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Bulletproofs range proof (Bünz et al., S&P 2018): proves that a Pedersen commitment $V = vG + \gamma H$
+/// opens to a value $v \in [0, 2^n)$ with a proof of $2 \log_2(n) + 9$ elements.
+pub struct RangeProof<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// Public parameters of the range proof. Values are committed to with the first generator of `commit_key`
+/// and blinded with its blinding generator; `g`, `h` and `u` are the independent generators used by the
+/// vector commitments and the inner product argument. Proving that $m$ values are in range at once requires
+/// $nm$ generators in `g` and `h`.
+#[derive(Clone)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub(crate) n: usize,
+    pub(crate) commit_key: pedersen::CommitKey<C>,
+    pub(crate) g: Vec<C::Affine>,
+    pub(crate) h: Vec<C::Affine>,
+    pub(crate) u: C::Affine,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    /// Parameters for proofs over `n`-bit ranges. `n` must be a power of two and `g` and `h` must have the same
+    /// power-of-two length, at least `n`.
+    pub fn new(
+        n: usize,
+        commit_key: &pedersen::CommitKey<C>,
+        g: &[C::Affine],
+        h: &[C::Affine],
+        u: C::Affine,
+    ) -> Result<Self, CryptoError> {
+        if !n.is_power_of_two()
+            || n > C::ScalarField::size_in_bits() - 1
+            || commit_key.is_empty()
+            || g.len() < n
+            || !g.len().is_power_of_two()
+            || h.len() != g.len()
+        {
+            return Err(CryptoError::InvalidRangeParameters);
+        }
+
+        Ok(Self {
+            n,
+            commit_key: commit_key.clone(),
+            g: g.to_vec(),
+            h: h.to_vec(),
+            u,
+        })
+    }
+
+    pub fn bits(&self) -> usize {
+        self.n
+    }
+
+    /// Maximum number of values that can be proven to be in range with a single aggregated proof.
+    pub fn max_aggregation(&self) -> usize {
+        self.g.len() / self.n
+    }
+}
+
+impl<C: ProjectiveCurve> ToBytes for Parameters<C> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        (self.n as u64).write(&mut w)?;
+        self.commit_key.write(&mut w)?;
+        self.g.write(&mut w)?;
+        self.h.write(&mut w)?;
+        self.u.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+/// Commitment to the value that is claimed to be in range.
+pub type Statement<C> = pedersen::Commitment<C>;
+
+/// Opening of the statement: the committed value and its blinding factor.
+#[derive(Copy, Clone)]
+pub struct Witness<C: ProjectiveCurve> {
+    pub value: C::ScalarField,
+    pub randomness: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Witness<C> {
+    pub fn new(value: C::ScalarField, randomness: C::ScalarField) -> Self {
+        Self { value, randomness }
+    }
+}
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for RangeProof<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify(common_reference_string, statement, fs_rng)
+    }
+}
+
+impl<C: ProjectiveCurve> RangeProof<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Bulletproofs Range Proof";
+}
+
+/// Aggregated Bulletproofs range proof: proves that each of $m$ Pedersen commitments opens to a value in
+/// $[0, 2^n)$ with a single proof of $2 \log_2(nm) + 9$ elements. $m$ must be a power of two.
+pub struct AggregatedRangeProof<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// Commitments to the values that are claimed to be in range.
+pub type AggregatedStatement<C> = Vec<pedersen::Commitment<C>>;
+
+/// Openings of the commitments, in the same order.
+pub type AggregatedWitness<C> = Vec<Witness<C>>;
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for AggregatedRangeProof<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = AggregatedStatement<C>;
+    type Witness = AggregatedWitness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_aggregated_proof(
+            rng,
+            common_reference_string,
+            statement,
+            witness,
+            fs_rng,
+        )
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        proof.verify_aggregated(common_reference_string, statement, fs_rng)
+    }
+}
+
+/// $\langle s, G \rangle$ for a vector of scalars and a vector of bases of the same length.
+pub(crate) fn multi_exponentiation<C: ProjectiveCurve>(
+    bases: &[C::Affine],
+    scalars: &[C::ScalarField],
+) -> C {
+    let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+}
+
+/// The vector $(1, x, x^2, \dots, x^{n-1})$.
+pub(crate) fn powers<F: Field>(x: F, n: usize) -> Vec<F> {
+    let mut powers = Vec::with_capacity(n);
+    let mut current = F::one();
+    for _ in 0..n {
+        powers.push(current);
+        current *= x;
+    }
+
+    powers
+}
@@ -0,0 +1,141 @@
+use super::{multi_exponentiation, powers, AggregatedStatement, Parameters, Statement};
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::batch_inverse;
+use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+use crate::zkp::arguments::inner_product;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, Field, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) a_commit: C::Affine,
+    pub(crate) s_commit: C::Affine,
+    pub(crate) t1_commit: pedersen::Commitment<C>,
+    pub(crate) t2_commit: pedersen::Commitment<C>,
+    pub(crate) tau_x: C::ScalarField,
+    pub(crate) mu: C::ScalarField,
+    pub(crate) t_hat: C::ScalarField,
+    pub(crate) inner_product_proof: inner_product::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        self.verify_aggregated(parameters, &vec![*statement], fs_rng)
+    }
+
+    pub fn verify_aggregated<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &AggregatedStatement<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error = CryptoError::ProofVerificationError(String::from("Range"));
+
+        let n = parameters.n;
+        let m = statement.len();
+        if !m.is_power_of_two() || m > parameters.max_aggregation() {
+            return Err(verification_error);
+        }
+        let nm = n * m;
+        let g = &parameters.g[..nm];
+        let h = &parameters.h[..nm];
+
+        fs_rng.absorb(&to_bytes![
+            b"range_proof",
+            parameters,
+            statement,
+            self.a_commit,
+            self.s_commit
+        ]?);
+        let y = derive_challenge::<C::ScalarField>(fs_rng);
+        let z = derive_challenge::<C::ScalarField>(fs_rng);
+
+        fs_rng.absorb(&to_bytes![self.t1_commit, self.t2_commit]?);
+        let x = derive_challenge::<C::ScalarField>(fs_rng);
+
+        fs_rng.absorb(&to_bytes![self.tau_x, self.mu, self.t_hat]?);
+        let w = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let powers_of_y = powers(y, nm);
+        let powers_of_2 = powers(C::ScalarField::from(2u64), n);
+        let powers_of_z = powers(z, m + 2).split_off(2);
+        let sum_of_y = powers_of_y
+            .iter()
+            .fold(C::ScalarField::zero(), |a, b| a + b);
+        let sum_of_2 = powers_of_2
+            .iter()
+            .fold(C::ScalarField::zero(), |a, b| a + b);
+        let sum_of_z = powers_of_z
+            .iter()
+            .fold(C::ScalarField::zero(), |a, b| a + b);
+
+        // Check that t_hat = t(x) = t_0 + t_1 x + t_2 x^2 where t_0 = sum_j z^{2+j} v_j + delta(y, z)
+        let delta = (z - z.square()) * sum_of_y - z * sum_of_z * sum_of_2;
+
+        let lhs = pedersen::PedersenCommitment::<C>::commit(
+            &parameters.commit_key,
+            &vec![self.t_hat],
+            self.tau_x,
+        )?;
+        let rhs = statement
+            .iter()
+            .zip(powers_of_z.iter())
+            .map(|(v, z_j)| *v * *z_j)
+            .sum::<pedersen::Commitment<C>>()
+            + pedersen::PedersenCommitment::<C>::commit(
+                &parameters.commit_key,
+                &vec![delta],
+                C::ScalarField::zero(),
+            )?
+            + self.t1_commit * x
+            + self.t2_commit * x.square();
+
+        if lhs != rhs {
+            return Err(verification_error);
+        }
+
+        // Check that l(x) and r(x) are correctly formed using the inner product argument
+        let powers_of_y_inv = batch_inverse(&powers_of_y)?;
+        let h_prime = C::batch_normalization_into_affine(
+            &h.iter()
+                .zip(powers_of_y_inv.iter())
+                .map(|(h, y_inv)| h.mul(y_inv.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+
+        let g_scalars = vec![-z; nm];
+        let h_scalars = (0..nm)
+            .map(|i| z * powers_of_y[i] + powers_of_z[i / n] * powers_of_2[i % n])
+            .collect::<Vec<_>>();
+
+        let u_prime = parameters.u.mul(w.into_repr()).into_affine();
+
+        let p = self.a_commit.into_projective()
+            + self.s_commit.mul(x.into_repr())
+            + multi_exponentiation::<C>(g, &g_scalars)
+            + multi_exponentiation::<C>(&h_prime, &h_scalars)
+            - parameters.commit_key.h.mul(self.mu.into_repr())
+            + u_prime.mul(self.t_hat.into_repr());
+
+        let g = g.to_vec();
+        self.inner_product_proof
+            .verify(
+                &inner_product::Parameters::new(&g, &h_prime, &u_prime),
+                &inner_product::Statement::new(&p),
+                fs_rng,
+            )
+            .map_err(|_| verification_error)
+    }
+}
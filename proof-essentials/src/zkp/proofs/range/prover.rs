@@ -0,0 +1,180 @@
+use super::{
+    multi_exponentiation, powers, proof::Proof, AggregatedStatement, AggregatedWitness, Parameters,
+    Statement, Witness,
+};
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::{batch_inverse, dot_product};
+use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+use crate::zkp::arguments::inner_product;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, BigInteger, Field, One, PrimeField, Zero};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+use std::marker::PhantomData;
+
+pub struct Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Prover<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        Self::create_aggregated_proof(rng, parameters, &vec![*statement], &vec![*witness], fs_rng)
+    }
+
+    pub fn create_aggregated_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &AggregatedStatement<C>,
+        witness: &AggregatedWitness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let n = parameters.n;
+        let m = statement.len();
+        if !m.is_power_of_two() || witness.len() != m || m > parameters.max_aggregation() {
+            return Err(CryptoError::InvalidRangeStatement);
+        }
+        let nm = n * m;
+        let g = &parameters.g[..nm];
+        let h = &parameters.h[..nm];
+
+        // Concatenated bit decompositions a_L of the values and a_R = a_L - 1^{nm}
+        let a_l = witness
+            .iter()
+            .flat_map(|w| w.value.into_repr().to_bits_le().into_iter().take(n))
+            .map(|bit| {
+                if bit {
+                    C::ScalarField::one()
+                } else {
+                    C::ScalarField::zero()
+                }
+            })
+            .collect::<Vec<_>>();
+        let a_r = a_l
+            .iter()
+            .map(|bit| *bit - C::ScalarField::one())
+            .collect::<Vec<_>>();
+
+        let s_l = (0..nm)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let s_r = (0..nm)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+
+        let alpha = C::ScalarField::rand(rng);
+        let rho = C::ScalarField::rand(rng);
+
+        let blinding_generator = parameters.commit_key.h;
+        let a_commit = (blinding_generator.mul(alpha.into_repr())
+            + multi_exponentiation::<C>(g, &a_l)
+            + multi_exponentiation::<C>(h, &a_r))
+        .into_affine();
+        let s_commit = (blinding_generator.mul(rho.into_repr())
+            + multi_exponentiation::<C>(g, &s_l)
+            + multi_exponentiation::<C>(h, &s_r))
+        .into_affine();
+
+        fs_rng.absorb(&to_bytes![
+            b"range_proof",
+            parameters,
+            statement,
+            a_commit,
+            s_commit
+        ]?);
+        let y = derive_challenge::<C::ScalarField>(fs_rng);
+        let z = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let powers_of_y = powers(y, nm);
+        let powers_of_2 = powers(C::ScalarField::from(2u64), n);
+        // Value j is bound to the challenge power z^{2+j}
+        let powers_of_z = powers(z, m + 2).split_off(2);
+
+        // l(X) = l_0 + l_1 X and r(X) = r_0 + r_1 X
+        let l_0 = a_l.iter().map(|a| *a - z).collect::<Vec<_>>();
+        let l_1 = s_l;
+        let r_0 = (0..nm)
+            .map(|i| powers_of_y[i] * (a_r[i] + z) + powers_of_z[i / n] * powers_of_2[i % n])
+            .collect::<Vec<_>>();
+        let r_1 = (0..nm).map(|i| powers_of_y[i] * s_r[i]).collect::<Vec<_>>();
+
+        // t(X) = <l(X), r(X)> = t_0 + t_1 X + t_2 X^2
+        let t_1 = dot_product(&l_0, &r_1)? + dot_product(&l_1, &r_0)?;
+        let t_2 = dot_product(&l_1, &r_1)?;
+
+        let tau_1 = C::ScalarField::rand(rng);
+        let tau_2 = C::ScalarField::rand(rng);
+        let t1_commit =
+            pedersen::PedersenCommitment::<C>::commit(&parameters.commit_key, &vec![t_1], tau_1)?;
+        let t2_commit =
+            pedersen::PedersenCommitment::<C>::commit(&parameters.commit_key, &vec![t_2], tau_2)?;
+
+        fs_rng.absorb(&to_bytes![t1_commit, t2_commit]?);
+        let x = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let l = (0..nm).map(|i| l_0[i] + l_1[i] * x).collect::<Vec<_>>();
+        let r = (0..nm).map(|i| r_0[i] + r_1[i] * x).collect::<Vec<_>>();
+        let t_hat = dot_product(&l, &r)?;
+
+        let tau_x = tau_2 * x.square()
+            + tau_1 * x
+            + witness
+                .iter()
+                .zip(powers_of_z.iter())
+                .map(|(w, z_j)| *z_j * w.randomness)
+                .sum::<C::ScalarField>();
+        let mu = alpha + rho * x;
+
+        fs_rng.absorb(&to_bytes![tau_x, mu, t_hat]?);
+        let w = derive_challenge::<C::ScalarField>(fs_rng);
+
+        let powers_of_y_inv = batch_inverse(&powers_of_y)?;
+        let h_prime = C::batch_normalization_into_affine(
+            &h.iter()
+                .zip(powers_of_y_inv.iter())
+                .map(|(h, y_inv)| h.mul(y_inv.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+        let u_prime = parameters.u.mul(w.into_repr()).into_affine();
+
+        let p = multi_exponentiation::<C>(g, &l)
+            + multi_exponentiation::<C>(&h_prime, &r)
+            + u_prime.mul(t_hat.into_repr());
+
+        let g = g.to_vec();
+        let inner_product_parameters = inner_product::Parameters::new(&g, &h_prime, &u_prime);
+        let inner_product_statement = inner_product::Statement::new(&p);
+        let inner_product_witness = inner_product::Witness::new(&l, &r);
+        let inner_product_proof = inner_product::prover::Prover::new(
+            &inner_product_parameters,
+            &inner_product_statement,
+            &inner_product_witness,
+        )
+        .prove(fs_rng)?;
+
+        Ok(Proof {
+            a_commit,
+            s_commit,
+            t1_commit,
+            t2_commit,
+            tau_x,
+            mu,
+            t_hat,
+            inner_product_proof,
+        })
+    }
+}
@@ -0,0 +1,304 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{proofs::range, ArgumentOfKnowledge};
+    use ark_ec::ProjectiveCurve;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::thread_rng;
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+    use rand::Rng;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type Range = range::RangeProof<Curve>;
+    type AggregatedRange = range::AggregatedRangeProof<Curve>;
+    type Parameters = range::Parameters<Curve>;
+    type Witness = range::Witness<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn setup<R: Rng>(rng: &mut R, n: usize) -> Parameters {
+        setup_aggregated(rng, n, 1)
+    }
+
+    fn setup_aggregated<R: Rng>(rng: &mut R, n: usize, m: usize) -> Parameters {
+        let commit_key = Pedersen::setup(rng, 1);
+        let g = (0..n * m)
+            .map(|_| Curve::rand(rng).into_affine())
+            .collect::<Vec<_>>();
+        let h = (0..n * m)
+            .map(|_| Curve::rand(rng).into_affine())
+            .collect::<Vec<_>>();
+        let u = Curve::rand(rng).into_affine();
+
+        Parameters::new(n, &commit_key, &g, &h, u).unwrap()
+    }
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let parameters = setup(rng, 32);
+
+        for value in [0u64, 1, 42, u32::MAX as u64] {
+            let witness = Witness::new(Scalar::from(value), Scalar::rand(rng));
+            let statement = Pedersen::commit(
+                &parameters.commit_key,
+                &vec![witness.value],
+                witness.randomness,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = Range::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                Range::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let parameters = setup(rng, 16);
+
+        for value in [Scalar::from(1u64 << 16), -Scalar::from(1u64)] {
+            let witness = Witness::new(value, Scalar::rand(rng));
+            let statement = Pedersen::commit(
+                &parameters.commit_key,
+                &vec![witness.value],
+                witness.randomness,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let invalid_proof = Range::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                Range::verify(
+                    &parameters,
+                    &statement,
+                    &invalid_proof,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Err(CryptoError::ProofVerificationError(String::from("Range")))
+            );
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize_proof() {
+        let rng = &mut thread_rng();
+        let parameters = setup(rng, 8);
+
+        let witness = Witness::new(Scalar::from(200u64), Scalar::rand(rng));
+        let statement = Pedersen::commit(
+            &parameters.commit_key,
+            &vec![witness.value],
+            witness.randomness,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Range::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized = range::proof::Proof::<Curve>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(proof, deserialized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Range::verify(
+                &parameters,
+                &statement,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let rng = &mut thread_rng();
+        let commit_key = Pedersen::setup(rng, 1);
+        let g = vec![Curve::rand(rng).into_affine(); 6];
+
+        assert!(matches!(
+            Parameters::new(6, &commit_key, &g, &g, g[0]),
+            Err(CryptoError::InvalidRangeParameters)
+        ));
+    }
+
+    fn aggregated_template<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters,
+        values: &[Scalar],
+    ) -> (Vec<Witness>, Vec<pedersen::Commitment<Curve>>) {
+        let witness = values
+            .iter()
+            .map(|value| Witness::new(*value, Scalar::rand(rng)))
+            .collect::<Vec<_>>();
+        let statement = witness
+            .iter()
+            .map(|w| {
+                Pedersen::commit(&parameters.commit_key, &vec![w.value], w.randomness).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        (witness, statement)
+    }
+
+    #[test]
+    fn test_aggregated_honest_prover() {
+        let rng = &mut thread_rng();
+        let parameters = setup_aggregated(rng, 16, 4);
+
+        for m in [1, 2, 4] {
+            let values = (0..m)
+                .map(|_| Scalar::from(rng.gen::<u16>()))
+                .collect::<Vec<_>>();
+            let (witness, statement) = aggregated_template(rng, &parameters, &values);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof = AggregatedRange::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng,
+            )
+            .unwrap();
+            assert_eq!(
+                proof.inner_product_proof.l_vec.len(),
+                (16 * m as usize).trailing_zeros() as usize
+            );
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                AggregatedRange::verify(
+                    &parameters,
+                    &statement,
+                    &proof,
+                    b"Test Domain",
+                    &mut fs_rng
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn test_aggregated_malicious_prover() {
+        let rng = &mut thread_rng();
+        let parameters = setup_aggregated(rng, 16, 4);
+
+        // A single value out of range invalidates the whole proof
+        let values = vec![
+            Scalar::from(3u64),
+            Scalar::from(1u64 << 16),
+            Scalar::from(7u64),
+            Scalar::from(0u64),
+        ];
+        let (witness, statement) = aggregated_template(rng, &parameters, &values);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = AggregatedRange::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            AggregatedRange::verify(
+                &parameters,
+                &statement,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from("Range")))
+        );
+
+        // Proofs cannot be replayed against a permuted list of commitments
+        let values = vec![
+            Scalar::from(3u64),
+            Scalar::from(4u64),
+            Scalar::from(7u64),
+            Scalar::from(0u64),
+        ];
+        let (witness, mut statement) = aggregated_template(rng, &parameters, &values);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = AggregatedRange::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        statement.swap(0, 1);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            AggregatedRange::verify(&parameters, &statement, &proof, b"Test Domain", &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from("Range")))
+        );
+
+        // Number of values has to be a power of two within the capacity of the parameters
+        let (witness, statement) = aggregated_template(rng, &parameters, &values[..3]);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert!(matches!(
+            AggregatedRange::prove(
+                rng,
+                &parameters,
+                &statement,
+                &witness,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::InvalidRangeStatement)
+        ));
+    }
+}
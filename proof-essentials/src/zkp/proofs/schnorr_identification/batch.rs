@@ -0,0 +1,133 @@
+use super::Parameters;
+use crate::error::CryptoError;
+use crate::utils::fixed_base::FixedBaseTable;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// Proof of knowledge of the discrete logarithms of N public keys with a single shared challenge. The proof consists
+/// of the challenge and one opening per key, the nonce commitments being recomputed by the verifier, so it takes
+/// N + 1 scalars instead of the N group elements and N scalars of N independent proofs.
+pub struct BatchSchnorrIdentification<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// Public keys whose discrete logarithms are proven.
+pub type Statement<C> = Vec<<C as ProjectiveCurve>::Affine>;
+
+/// Discrete logarithms of the public keys, in the same order.
+pub type Witness<C> = Vec<<C as ProjectiveCurve>::ScalarField>;
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct BatchProof<C: ProjectiveCurve> {
+    pub(crate) challenge: C::ScalarField,
+    pub(crate) openings: Vec<C::ScalarField>,
+}
+
+impl<C: ProjectiveCurve> ArgumentOfKnowledge for BatchSchnorrIdentification<C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Proof = BatchProof<C>;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let pp = common_reference_string;
+        if statement.is_empty() || statement.len() != witness.len() {
+            return Err(CryptoError::InvalidBatchStatement);
+        }
+
+        let randoms = (0..witness.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let random_commits = C::batch_normalization_into_affine(
+            &FixedBaseTable::<C>::for_scalars(pp, randoms.len()).batch_mul(&randoms),
+        );
+
+        let challenge = Self::challenge(fs_rng, pp, statement, &random_commits)?;
+
+        let openings = randoms
+            .iter()
+            .zip(witness.iter())
+            .map(|(random, x)| *random - challenge * x)
+            .collect();
+
+        Ok(BatchProof {
+            challenge,
+            openings,
+        })
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        let pp = common_reference_string;
+        let verification_error =
+            CryptoError::ProofVerificationError(String::from("Batch Schnorr Identification"));
+
+        if statement.is_empty() || statement.len() != proof.openings.len() {
+            return Err(verification_error);
+        }
+
+        let opening_commits =
+            FixedBaseTable::<C>::for_scalars(pp, proof.openings.len()).batch_mul(&proof.openings);
+        let random_commits = C::batch_normalization_into_affine(
+            &statement
+                .iter()
+                .zip(opening_commits)
+                .map(|(public_key, opening_commit)| {
+                    opening_commit + public_key.mul(proof.challenge.into_repr())
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        if Self::challenge(fs_rng, pp, statement, &random_commits)? != proof.challenge {
+            return Err(verification_error);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> BatchSchnorrIdentification<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Batch Schnorr Identification Scheme";
+
+    fn challenge<T: Transcript>(
+        fs_rng: &mut T,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        random_commits: &Vec<C::Affine>,
+    ) -> Result<C::ScalarField, CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            b"batch_schnorr_identity",
+            pp,
+            statement,
+            random_commits
+        ]?);
+
+        Ok(derive_challenge::<C::ScalarField>(fs_rng))
+    }
+}
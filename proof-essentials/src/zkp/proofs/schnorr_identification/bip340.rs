@@ -0,0 +1,203 @@
+use super::SchnorrIdentification;
+use crate::error::CryptoError;
+use crate::utils::encoding::{field_from_be_bytes, field_length, field_to_be_bytes};
+
+use ark_ec::models::SWModelParameters;
+use ark_ec::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use sha2::{Digest, Sha256};
+
+/// 32-byte x-only public key, as defined in BIP-340.
+pub type XOnlyPublicKey = [u8; 32];
+
+/// 64-byte signature `bytes(R) || bytes(s)`, as defined in BIP-340.
+pub type Signature = [u8; 64];
+
+/// BIP-340 mode of the Schnorr module: x-only public keys, SHA-256 tagged hashes and the even-y convention for
+/// both the public key and the nonce commitment. Signatures are always produced over the curve's standard
+/// generator, and both fields of the curve must have 32-byte encodings, otherwise every function fails with
+/// [`CryptoError::FieldSizeError`].
+///
+/// The crate does not ship secp256k1: over parameters of secp256k1 provided by the caller, the signatures are the
+/// ones of BIP-340, which the tests check against the official test vectors.
+impl<P: SWModelParameters> SchnorrIdentification<GroupProjective<P>>
+where
+    P::BaseField: PrimeField,
+{
+    /// Derive the x-only public key of `secret_key`.
+    pub fn bip340_public_key(secret_key: &P::ScalarField) -> Result<XOnlyPublicKey, CryptoError> {
+        check_field_sizes::<P>()?;
+        if secret_key.is_zero() {
+            return Err(CryptoError::InvalidSecretKey);
+        }
+
+        let public_key = GroupAffine::<P>::prime_subgroup_generator()
+            .mul(secret_key.into_repr())
+            .into_affine();
+
+        Ok(encode_field(&public_key.x))
+    }
+
+    /// Sign `message` with `secret_key`. `aux_rand` is the auxiliary randomness mixed into the nonce derivation;
+    /// it should be freshly sampled for every signature.
+    pub fn bip340_sign(
+        secret_key: &P::ScalarField,
+        message: &[u8],
+        aux_rand: &[u8; 32],
+    ) -> Result<Signature, CryptoError> {
+        check_field_sizes::<P>()?;
+        if secret_key.is_zero() {
+            return Err(CryptoError::InvalidSecretKey);
+        }
+
+        let generator = GroupAffine::<P>::prime_subgroup_generator();
+
+        let public_key = generator.mul(secret_key.into_repr()).into_affine();
+        let secret_key = if has_even_y(&public_key) {
+            *secret_key
+        } else {
+            -*secret_key
+        };
+        let public_key_bytes = encode_field(&public_key.x);
+
+        let mut masked_key = encode_field(&secret_key);
+        for (byte, mask) in masked_key
+            .iter_mut()
+            .zip(tagged_hash(b"BIP0340/aux", &[aux_rand]).iter())
+        {
+            *byte ^= mask;
+        }
+
+        let nonce = reduce_hash::<P::ScalarField>(&tagged_hash(
+            b"BIP0340/nonce",
+            &[&masked_key, &public_key_bytes, message],
+        ));
+        if nonce.is_zero() {
+            return Err(CryptoError::InvalidSecretKey);
+        }
+
+        let random_commit = generator.mul(nonce.into_repr()).into_affine();
+        let nonce = if has_even_y(&random_commit) {
+            nonce
+        } else {
+            -nonce
+        };
+        let random_commit_bytes = encode_field(&random_commit.x);
+
+        let challenge = bip340_challenge::<P>(&random_commit_bytes, &public_key_bytes, message);
+        let opening = nonce + challenge * secret_key;
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&random_commit_bytes);
+        signature[32..].copy_from_slice(&encode_field(&opening));
+
+        Ok(signature)
+    }
+
+    /// Verify a BIP-340 signature on `message` under the x-only `public_key`.
+    pub fn bip340_verify(
+        public_key: &XOnlyPublicKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), CryptoError> {
+        check_field_sizes::<P>()?;
+        let error = || CryptoError::SignatureVerificationError(String::from("BIP-340"));
+
+        let public_key_point = lift_x::<P>(public_key).ok_or_else(error)?;
+        let r = field_from_be_bytes::<P::BaseField>(&signature[..32]).map_err(|_| error())?;
+        let s = field_from_be_bytes::<P::ScalarField>(&signature[32..]).map_err(|_| error())?;
+
+        let challenge = bip340_challenge::<P>(&signature[..32], public_key, message);
+
+        let random_commit = (GroupAffine::<P>::prime_subgroup_generator().mul(s.into_repr())
+            - public_key_point.mul(challenge.into_repr()))
+        .into_affine();
+
+        if random_commit.is_zero() || !has_even_y(&random_commit) || random_commit.x != r {
+            return Err(error());
+        }
+
+        Ok(())
+    }
+}
+
+/// BIP-340 encodes coordinates and scalars on 32 bytes.
+fn check_field_sizes<P: SWModelParameters>() -> Result<(), CryptoError>
+where
+    P::BaseField: PrimeField,
+{
+    for length in [
+        field_length::<P::BaseField>(),
+        field_length::<P::ScalarField>(),
+    ] {
+        if length != 32 {
+            return Err(CryptoError::FieldSizeError(length, 32));
+        }
+    }
+
+    Ok(())
+}
+
+fn bip340_challenge<P: SWModelParameters>(
+    random_commit: &[u8],
+    public_key: &[u8],
+    message: &[u8],
+) -> P::ScalarField {
+    reduce_hash(&tagged_hash(
+        b"BIP0340/challenge",
+        &[random_commit, public_key, message],
+    ))
+}
+
+/// The hash as a big-endian integer reduced modulo the order of `F`, one byte at a time, which holds for any
+/// representation of the field.
+fn reduce_hash<F: PrimeField>(hash: &[u8; 32]) -> F {
+    let radix = F::from(256u64);
+
+    hash.iter()
+        .fold(F::zero(), |acc, &byte| acc * radix + F::from(byte as u64))
+}
+
+/// `SHA256(SHA256(tag) || SHA256(tag) || data)`
+fn tagged_hash(tag: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag);
+
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for chunk in data {
+        hasher.update(chunk);
+    }
+
+    hasher.finalize().into()
+}
+
+fn has_even_y<P: SWModelParameters>(point: &GroupAffine<P>) -> bool
+where
+    P::BaseField: PrimeField,
+{
+    point.y.into_repr().is_even()
+}
+
+/// Recover the point with x-coordinate `x` and an even y-coordinate.
+fn lift_x<P: SWModelParameters>(x: &[u8]) -> Option<GroupAffine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    let point = GroupAffine::<P>::get_point_from_x(field_from_be_bytes(x).ok()?, true)?;
+
+    if has_even_y(&point) {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+/// Big-endian 32-byte encoding of an element of a field checked by [`check_field_sizes`].
+fn encode_field<F: PrimeField>(element: &F) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded.copy_from_slice(&field_to_be_bytes(element));
+
+    encoded
+}
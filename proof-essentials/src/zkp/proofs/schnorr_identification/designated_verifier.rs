@@ -0,0 +1,94 @@
+use super::{Parameters, SchnorrIdentification, Statement, Witness};
+use crate::error::CryptoError;
+use crate::zkp::absorb_domain;
+use crate::zkp::compose::{or_composition, OrProof};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::to_bytes;
+use ark_std::rand::Rng;
+
+/// Designated-verifier Schnorr proof: an OR-proof of knowledge of the discrete logarithm of either the prover's
+/// public key or the verifier's public key. The designated verifier knows it did not produce the proof itself and
+/// is convinced, while any third party sees a transcript the verifier could have produced on its own.
+pub type DesignatedVerifierProof<C> = OrProof<SchnorrIdentification<C>>;
+
+/// Public key of the designated verifier.
+pub type VerifierKey<C> = <C as ProjectiveCurve>::Affine;
+
+impl<C: ProjectiveCurve> SchnorrIdentification<C> {
+    /// Prove knowledge of the discrete logarithm of `statement` in a way that only convinces the holder of the
+    /// secret key of `verifier_key`. As with [`ArgumentOfKnowledge::prove_in_context`], the proof is bound to
+    /// `domain` and, if given, to `context`.
+    ///
+    /// [`ArgumentOfKnowledge::prove_in_context`]: crate::zkp::ArgumentOfKnowledge::prove_in_context
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_designated<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        verifier_key: &VerifierKey<C>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<DesignatedVerifierProof<C>, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+        fs_rng.absorb(&to_bytes![b"schnorr_designated_verifier"]?);
+
+        or_composition::prover::Prover::create_proof(
+            rng,
+            pp,
+            &vec![*statement, *verifier_key],
+            &or_composition::Witness::new(0, witness),
+            fs_rng,
+        )
+    }
+
+    /// Produce a designated-verifier proof for `statement` using the verifier's secret key instead of the prover's.
+    /// The result is indistinguishable from an honest proof, which is what makes such proofs non-transferable.
+    #[allow(clippy::too_many_arguments)]
+    pub fn simulate_designated<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        verifier_key: &VerifierKey<C>,
+        verifier_secret_key: &Witness<C>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<DesignatedVerifierProof<C>, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+        fs_rng.absorb(&to_bytes![b"schnorr_designated_verifier"]?);
+
+        or_composition::prover::Prover::create_proof(
+            rng,
+            pp,
+            &vec![*statement, *verifier_key],
+            &or_composition::Witness::new(1, verifier_secret_key),
+            fs_rng,
+        )
+    }
+
+    /// Verify a designated-verifier proof made under `domain` and `context`.
+    pub fn verify_designated<T: Transcript>(
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        verifier_key: &VerifierKey<C>,
+        proof: &DesignatedVerifierProof<C>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+        fs_rng.absorb(&to_bytes![b"schnorr_designated_verifier"]?);
+
+        proof
+            .verify(pp, &vec![*statement, *verifier_key], fs_rng)
+            .map_err(|_| {
+                CryptoError::ProofVerificationError(String::from(
+                    "Designated Verifier Schnorr Identification",
+                ))
+            })
+    }
+}
@@ -1,14 +1,22 @@
+pub mod batch;
+pub mod bip340;
+pub mod designated_verifier;
+pub mod possession;
 pub mod proof;
 pub mod prover;
+pub mod rfc8235;
+#[cfg(test)]
+mod secp256k1;
 mod test;
 
 use crate::error::CryptoError;
-use crate::zkp::ArgumentOfKnowledge;
-use ark_ec::ProjectiveCurve;
-use ark_marlin::rng::FiatShamirRng;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
 use ark_std::marker::PhantomData;
 use ark_std::rand::Rng;
-use digest::Digest;
+use ark_std::UniformRand;
 
 pub struct SchnorrIdentification<C: ProjectiveCurve> {
     _group: PhantomData<C>,
@@ -26,26 +34,172 @@ impl<C: ProjectiveCurve> ArgumentOfKnowledge for SchnorrIdentification<C> {
     type Witness = Witness<C>;
     type Proof = proof::Proof<C>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove_in_context<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
         prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
     }
 
-    fn verify<D: Digest>(
+    fn verify_in_context<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
         proof.verify(common_reference_string, statement, fs_rng)
     }
 }
 
+impl<C: ProjectiveCurve> SigmaProtocol for SchnorrIdentification<C> {
+    type Scalar = C::ScalarField;
+    type Parameters = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Commitment = C::Affine;
+    type Randomness = C::ScalarField;
+    type Response = C::ScalarField;
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        _witness: &Self::Witness,
+    ) -> Result<(Self::Commitment, Self::Randomness), CryptoError> {
+        let random = C::ScalarField::rand(rng);
+        let random_commit = parameters.mul(random.into_repr()).into_affine();
+
+        Ok((random_commit, random))
+    }
+
+    fn respond(
+        _parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        randomness: &Self::Randomness,
+        challenge: &Self::Scalar,
+    ) -> Result<Self::Response, CryptoError> {
+        Ok(*randomness - *challenge * witness)
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Scalar,
+    ) -> Result<(Self::Commitment, Self::Response), CryptoError> {
+        let opening = C::ScalarField::rand(rng);
+        let random_commit =
+            parameters.mul(opening.into_repr()) + statement.mul(challenge.into_repr());
+
+        Ok((random_commit.into_affine(), opening))
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Scalar,
+        response: &Self::Response,
+    ) -> Result<(), CryptoError> {
+        if parameters.mul(response.into_repr()) + statement.mul(challenge.into_repr())
+            != commitment.into_projective()
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Schnorr Identification",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn batch_check<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        commitments: &[Self::Commitment],
+        challenges: &[Self::Scalar],
+        responses: &[Self::Response],
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            CryptoError::ProofVerificationError(String::from("Schnorr Identification"));
+
+        if commitments.len() != challenges.len() || commitments.len() != responses.len() {
+            return Err(verification_error);
+        }
+
+        // sum_i rho_i * (z_i * G + c_i * Y - A_i) ==? 0
+        let mut response_sum = C::ScalarField::zero();
+        let mut challenge_sum = C::ScalarField::zero();
+        let mut commitment_sum = C::zero();
+        for ((commitment, challenge), response) in commitments
+            .iter()
+            .zip(challenges.iter())
+            .zip(responses.iter())
+        {
+            let rho = C::ScalarField::rand(rng);
+            response_sum += rho * response;
+            challenge_sum += rho * challenge;
+            commitment_sum += commitment.mul(rho.into_repr());
+        }
+
+        if parameters.mul(response_sum.into_repr()) + statement.mul(challenge_sum.into_repr())
+            != commitment_sum
+        {
+            return Err(verification_error);
+        }
+
+        Ok(())
+    }
+}
+
 impl<C: ProjectiveCurve> SchnorrIdentification<C> {
     pub const PROTOCOL_NAME: &'static [u8] = b"Schnorr Identification Scheme";
+
+    /// Prove like [`ArgumentOfKnowledge::prove_in_context`], but with a nonce derived deterministically from the
+    /// witness, the statement, the domain and the context rather than sampled from an RNG. A faulty or repeated RNG
+    /// can then no longer produce two proofs sharing a nonce under different challenges, which would reveal the
+    /// witness. Proving twice with the same inputs gives the same proof, and the proofs verify as usual with
+    /// [`ArgumentOfKnowledge::verify_in_context`]. See [`prover::Prover::derive_nonce`] for the transcripts the
+    /// proofs may be made over.
+    pub fn prove_deterministic<T: Transcript>(
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<proof::Proof<C>, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        prover::Prover::create_deterministic_proof(pp, statement, witness, domain, context, fs_rng)
+    }
+
+    /// Verify like [`ArgumentOfKnowledge::verify`] a batch of proofs made under `domain`, each against its own
+    /// transcript in `fs_rngs`, with a single multi-scalar multiplication (see [`proof::Proof::batch_verify`]).
+    pub fn batch_verify<R: Rng, T: Transcript>(
+        pp: &Parameters<C>,
+        statements: &[Statement<C>],
+        proofs: &[proof::Proof<C>],
+        domain: &[u8],
+        fs_rngs: &mut [T],
+        rng: &mut R,
+    ) -> Result<(), CryptoError> {
+        for fs_rng in fs_rngs.iter_mut() {
+            absorb_domain(fs_rng, domain, None)?;
+        }
+
+        proof::Proof::batch_verify(proofs, statements, pp, fs_rngs, rng)
+    }
 }
@@ -0,0 +1,135 @@
+use super::{Parameters, SchnorrIdentification, Statement, Witness};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal::Validate;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::ArgumentOfKnowledge;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use ark_marlin::rng::FiatShamirRng;
+use ark_std::rand::Rng;
+use digest::Digest;
+use std::collections::HashMap;
+
+/// Proof of possession of the secret key of a public key, bound to the identity of its owner. Requiring one at
+/// registration prevents rogue-key attacks, where a party registers a key derived from the keys of others (say
+/// $pk' = X - pk$) whose secret key it does not know, and keeps a proof made for one owner from being replayed
+/// under another identity.
+pub type PossessionProof<C> = super::proof::Proof<C>;
+
+impl<C: ProjectiveCurve> SchnorrIdentification<C> {
+    /// Prove knowledge of the secret key of `public_key` on behalf of `owner`.
+    pub fn prove_possession<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        owner: &[u8],
+        public_key: &Statement<C>,
+        secret_key: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<PossessionProof<C>, CryptoError> {
+        fs_rng.absorb(&to_bytes![b"schnorr_proof_of_possession"]?);
+
+        Self::prove(rng, pp, public_key, secret_key, owner, fs_rng)
+    }
+
+    /// Verify that the registrant `owner` knows the secret key of `public_key`.
+    pub fn verify_possession<T: Transcript>(
+        pp: &Parameters<C>,
+        owner: &[u8],
+        public_key: &Statement<C>,
+        proof: &PossessionProof<C>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![b"schnorr_proof_of_possession"]?);
+
+        Self::verify(pp, public_key, proof, owner, fs_rng)
+            .map_err(|_| CryptoError::ProofVerificationError(String::from("Proof of Possession")))
+    }
+}
+
+/// Registry of public keys indexed by owner. A key is only accepted with a valid proof of possession made for its
+/// owner under the registry's `domain`, and neither an owner nor a key can be registered twice.
+pub struct KeyRegistry<C: ProjectiveCurve> {
+    pp: Parameters<C>,
+    domain: Vec<u8>,
+    keys: HashMap<Vec<u8>, C::Affine>,
+}
+
+impl<C: ProjectiveCurve> KeyRegistry<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Key Registry";
+
+    pub fn new(pp: &Parameters<C>, domain: &[u8]) -> Self {
+        Self {
+            pp: *pp,
+            domain: domain.to_vec(),
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Derive the public key of `secret_key` and prove possession of it for registration by `owner` in the registry
+    /// with the given `domain`.
+    pub fn prove_registration<R: Rng, D: Digest>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        domain: &[u8],
+        owner: &[u8],
+        secret_key: &Witness<C>,
+    ) -> Result<(C::Affine, PossessionProof<C>), CryptoError> {
+        let public_key = pp.mul(secret_key.into_repr()).into_affine();
+
+        let mut fs_rng = Self::transcript::<D>(domain)?;
+        let proof = SchnorrIdentification::prove_possession(
+            rng,
+            pp,
+            owner,
+            &public_key,
+            secret_key,
+            &mut fs_rng,
+        )?;
+
+        Ok((public_key, proof))
+    }
+
+    /// Register `public_key` for `owner` after checking that it is a point of the prime-order subgroup other than
+    /// the identity, and its proof of possession.
+    pub fn register<D: Digest>(
+        &mut self,
+        owner: &[u8],
+        public_key: &C::Affine,
+        proof: &PossessionProof<C>,
+    ) -> Result<(), CryptoError> {
+        if public_key.validate().is_err()
+            || self.keys.contains_key(owner)
+            || self.keys.values().any(|key| key == public_key)
+        {
+            return Err(CryptoError::InvalidKeyRegistration);
+        }
+
+        let mut fs_rng = Self::transcript::<D>(&self.domain)?;
+        SchnorrIdentification::verify_possession(&self.pp, owner, public_key, proof, &mut fs_rng)?;
+
+        self.keys.insert(owner.to_vec(), *public_key);
+
+        Ok(())
+    }
+
+    /// Registered public key of `owner`, if any.
+    pub fn get(&self, owner: &[u8]) -> Option<&C::Affine> {
+        self.keys.get(owner)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    fn transcript<D: Digest>(domain: &[u8]) -> Result<FiatShamirRng<D>, CryptoError> {
+        Ok(FiatShamirRng::<D>::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            domain
+        ]?))
+    }
+}
@@ -1,13 +1,13 @@
 use super::{Parameters, Statement};
 use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
-use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{to_bytes, PrimeField};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
+use ark_std::rand::Rng;
 use ark_std::UniformRand;
-use digest::Digest;
 
 #[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
 pub struct Proof<C>
@@ -19,20 +19,13 @@ where
 }
 
 impl<C: ProjectiveCurve> Proof<C> {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         pp: &Parameters<C>,
         statement: &Statement<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<(), CryptoError> {
-        fs_rng.absorb(&to_bytes![
-            b"schnorr_identity",
-            pp,
-            statement,
-            &self.random_commit
-        ]?);
-
-        let c = C::ScalarField::rand(fs_rng);
+        let c = self.challenge(pp, statement, fs_rng)?;
 
         if pp.mul(self.opening.into_repr()) + statement.mul(c.into_repr()) != self.random_commit {
             return Err(CryptoError::ProofVerificationError(String::from(
@@ -42,4 +35,75 @@ impl<C: ProjectiveCurve> Proof<C> {
 
         Ok(())
     }
+
+    /// Verify `proofs` of the `statements` at once, each against its own transcript in `fs_rngs`, as given to
+    /// [`Proof::verify`].
+    ///
+    /// The equations $s_i G + c_i X_i = R_i$ are combined with random weights $\rho_i$ drawn from `rng` into the
+    /// single multi-scalar multiplication $(\sum_i \rho_i s_i) G + \sum_i \rho_i c_i X_i - \sum_i \rho_i R_i = 0$,
+    /// which costs far less than verifying the proofs one by one. A batch containing an invalid proof passes with
+    /// negligible probability; verify the proofs individually to find out which one failed.
+    pub fn batch_verify<R: Rng, T: Transcript>(
+        proofs: &[Self],
+        statements: &[Statement<C>],
+        pp: &Parameters<C>,
+        fs_rngs: &mut [T],
+        rng: &mut R,
+    ) -> Result<(), CryptoError> {
+        if proofs.len() != statements.len() || proofs.len() != fs_rngs.len() {
+            return Err(CryptoError::InvalidBatchStatement);
+        }
+
+        let random_commits = C::batch_normalization_into_affine(
+            &proofs
+                .iter()
+                .map(|proof| proof.random_commit)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut bases = Vec::with_capacity(2 * proofs.len() + 1);
+        let mut scalars = Vec::with_capacity(2 * proofs.len() + 1);
+        let mut opening_sum = C::ScalarField::zero();
+        for (((proof, statement), random_commit), fs_rng) in proofs
+            .iter()
+            .zip(statements.iter())
+            .zip(random_commits)
+            .zip(fs_rngs.iter_mut())
+        {
+            let c = proof.challenge(pp, statement, fs_rng)?;
+            let rho = C::ScalarField::rand(rng);
+
+            opening_sum += rho * proof.opening;
+            bases.push(*statement);
+            scalars.push((rho * c).into_repr());
+            bases.push(random_commit);
+            scalars.push((-rho).into_repr());
+        }
+        bases.push(*pp);
+        scalars.push(opening_sum.into_repr());
+
+        if !VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Schnorr Identification",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn challenge<T: Transcript>(
+        &self,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        fs_rng: &mut T,
+    ) -> Result<C::ScalarField, CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            b"schnorr_identity",
+            pp,
+            statement,
+            self.random_commit.into_affine()
+        ]?);
+
+        Ok(derive_challenge::<C::ScalarField>(fs_rng))
+    }
 }
@@ -1,13 +1,14 @@
 use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
 
 use super::{proof::Proof, Parameters, Statement, Witness};
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{to_bytes, PrimeField};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::{to_bytes, PrimeField, Zero};
 use ark_std::rand::Rng;
 use ark_std::UniformRand;
-use digest::Digest;
+use hkdf::Hkdf;
+use sha2::Sha256;
 
 use std::marker::PhantomData;
 
@@ -22,25 +23,87 @@ impl<C> Prover<C>
 where
     C: ProjectiveCurve,
 {
-    pub fn create_proof<R: Rng, D: Digest>(
+    pub fn create_proof<R: Rng, T: Transcript>(
         rng: &mut R,
         pp: &Parameters<C>,
         statement: &Statement<C>,
         witness: &Witness<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+        fs_rng: &mut T,
     ) -> Result<Proof<C>, CryptoError> {
         let random = C::ScalarField::rand(rng);
 
+        Self::create_proof_with_nonce(random, pp, statement, witness, fs_rng)
+    }
+
+    /// Create a proof whose nonce is derived from the witness, the statement, the domain and the context instead of
+    /// being sampled, see [`Prover::derive_nonce`].
+    pub fn create_deterministic_proof<T: Transcript>(
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let random = Self::derive_nonce(pp, statement, witness, domain, context)?;
+
+        Self::create_proof_with_nonce(random, pp, statement, witness, fs_rng)
+    }
+
+    /// Derive the nonce with HKDF-SHA256 keyed by the witness, in the spirit of RFC 6979, over the parameters, the
+    /// statement, the domain and the context. The transcript is left untouched, so the verifier derives the same
+    /// challenge over any [`Transcript`]. Messages absorbed into the transcript before the domain do not reach the
+    /// nonce: two deterministic proofs of the same statement under the same domain and context must not be made
+    /// over transcripts that differ otherwise, or they share a nonce under different challenges and reveal the
+    /// witness. Whatever distinguishes them belongs in the context.
+    pub fn derive_nonce(
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        domain: &[u8],
+        context: Option<&[u8]>,
+    ) -> Result<C::ScalarField, CryptoError> {
+        let mut salt = to_bytes![
+            b"schnorr_identity/nonce",
+            pp,
+            statement,
+            domain.len() as u64,
+            domain
+        ]?;
+        if let Some(context) = context {
+            salt.extend(to_bytes![b"context", context.len() as u64, context]?);
+        }
+        let hkdf = Hkdf::<Sha256>::new(Some(&salt), &to_bytes![witness]?);
+
+        let mut bytes = [0u8; 64];
+        hkdf.expand(b"nonce", &mut bytes)
+            .map_err(|_| CryptoError::InvalidSecretKey)?;
+
+        let nonce = C::ScalarField::from_le_bytes_mod_order(&bytes);
+        if nonce.is_zero() {
+            return Err(CryptoError::InvalidSecretKey);
+        }
+
+        Ok(nonce)
+    }
+
+    fn create_proof_with_nonce<T: Transcript>(
+        random: C::ScalarField,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        fs_rng: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
         let random_commit = pp.mul(random.into_repr());
 
         fs_rng.absorb(&to_bytes![
             b"schnorr_identity",
             pp,
             statement,
-            random_commit
+            random_commit.into_affine()
         ]?);
 
-        let c = C::ScalarField::rand(fs_rng);
+        let c = derive_challenge::<C::ScalarField>(fs_rng);
 
         let opening = random - c * witness;
 
@@ -0,0 +1,198 @@
+use super::{proof::Proof, Parameters, SchnorrIdentification, Statement, Witness};
+use crate::error::CryptoError;
+use crate::utils::encoding::{field_from_be_bytes, field_length, field_to_be_bytes};
+
+use ark_ec::models::SWModelParameters;
+use ark_ec::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use digest::Digest;
+
+/// Context bound into the RFC 8235 challenge `H(g || V || A || UserID || OtherInfo)`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Profile<'a> {
+    pub user_id: &'a [u8],
+    pub other_info: &'a [u8],
+}
+
+impl<'a> Profile<'a> {
+    pub fn new(user_id: &'a [u8], other_info: &'a [u8]) -> Self {
+        Self {
+            user_id,
+            other_info,
+        }
+    }
+}
+
+/// Non-interactive Schnorr proof in the RFC 8235 format: the commitment `V = g^v` and the response `r = v - a·c`.
+/// RFC 8235 uses the same response convention as the native proof, so the two formats convert into each other
+/// losslessly; they differ only in how the challenge is derived.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rfc8235Proof<C: ProjectiveCurve> {
+    pub random_commit: C::Affine,
+    pub opening: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> From<Proof<C>> for Rfc8235Proof<C> {
+    fn from(proof: Proof<C>) -> Self {
+        Self {
+            random_commit: proof.random_commit.into_affine(),
+            opening: proof.opening,
+        }
+    }
+}
+
+impl<C: ProjectiveCurve> From<Rfc8235Proof<C>> for Proof<C> {
+    fn from(proof: Rfc8235Proof<C>) -> Self {
+        Self {
+            random_commit: proof.random_commit.into_projective(),
+            opening: proof.opening,
+        }
+    }
+}
+
+impl<P: SWModelParameters> Rfc8235Proof<GroupProjective<P>>
+where
+    P::BaseField: PrimeField,
+{
+    /// Serialise as `V || r`, with `V` in SEC 1 uncompressed form and `r` as a fixed-length big-endian integer.
+    /// Fails if `V` is the identity, which has no uncompressed form and which no honest prover produces.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CryptoError> {
+        if self.random_commit.is_zero() {
+            return Err(encoding_error());
+        }
+
+        let mut bytes = encode_point(&self.random_commit);
+        bytes.extend(field_to_be_bytes(&self.opening));
+
+        Ok(bytes)
+    }
+
+    /// Decode a proof serialised by [`Rfc8235Proof::to_bytes`]. `V` must be in the prime-order subgroup.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CryptoError> {
+        let error = encoding_error;
+
+        let base_length = field_length::<P::BaseField>();
+        let scalar_length = field_length::<P::ScalarField>();
+        if bytes.len() != 1 + 2 * base_length + scalar_length || bytes[0] != 0x04 {
+            return Err(error());
+        }
+
+        let x = field_from_be_bytes(&bytes[1..1 + base_length]).map_err(|_| error())?;
+        let y = field_from_be_bytes(&bytes[1 + base_length..1 + 2 * base_length])
+            .map_err(|_| error())?;
+        let random_commit = GroupAffine::<P>::new(x, y, false);
+        if !random_commit.is_on_curve() || !random_commit.is_in_correct_subgroup_assuming_on_curve()
+        {
+            return Err(error());
+        }
+
+        let opening = field_from_be_bytes(&bytes[1 + 2 * base_length..]).map_err(|_| error())?;
+
+        Ok(Self {
+            random_commit,
+            opening,
+        })
+    }
+}
+
+impl<P: SWModelParameters> SchnorrIdentification<GroupProjective<P>>
+where
+    P::BaseField: PrimeField,
+{
+    /// Prove knowledge of the discrete logarithm of `statement` with the challenge derived as in RFC 8235,
+    /// using `D` as the hash function.
+    pub fn rfc8235_prove<R: Rng, D: Digest>(
+        rng: &mut R,
+        pp: &Parameters<GroupProjective<P>>,
+        statement: &Statement<GroupProjective<P>>,
+        witness: &Witness<GroupProjective<P>>,
+        profile: &Profile,
+    ) -> Result<Rfc8235Proof<GroupProjective<P>>, CryptoError> {
+        let random = P::ScalarField::rand(rng);
+        let random_commit = pp.mul(random.into_repr()).into_affine();
+
+        let c = rfc8235_challenge::<P, D>(pp, &random_commit, statement, profile);
+
+        Ok(Rfc8235Proof {
+            random_commit,
+            opening: random - c * witness,
+        })
+    }
+
+    pub fn rfc8235_verify<D: Digest>(
+        pp: &Parameters<GroupProjective<P>>,
+        statement: &Statement<GroupProjective<P>>,
+        proof: &Rfc8235Proof<GroupProjective<P>>,
+        profile: &Profile,
+    ) -> Result<(), CryptoError> {
+        let error =
+            || CryptoError::ProofVerificationError(String::from("RFC 8235 Schnorr Identification"));
+
+        // RFC 8235 requires the public key to be a valid point of the prime-order subgroup other than the identity
+        if statement.is_zero()
+            || !statement.is_on_curve()
+            || !statement.is_in_correct_subgroup_assuming_on_curve()
+        {
+            return Err(error());
+        }
+
+        let c = rfc8235_challenge::<P, D>(pp, &proof.random_commit, statement, profile);
+
+        if pp.mul(proof.opening.into_repr()) + statement.mul(c.into_repr())
+            != proof.random_commit.into_projective()
+        {
+            return Err(error());
+        }
+
+        Ok(())
+    }
+}
+
+/// `H(g || V || A || UserID || OtherInfo)`, each item prefixed with its 4-byte big-endian length, reduced modulo
+/// the group order.
+fn rfc8235_challenge<P: SWModelParameters, D: Digest>(
+    generator: &GroupAffine<P>,
+    random_commit: &GroupAffine<P>,
+    statement: &GroupAffine<P>,
+    profile: &Profile,
+) -> P::ScalarField
+where
+    P::BaseField: PrimeField,
+{
+    let mut hasher = D::new();
+    for item in [
+        encode_point(generator).as_slice(),
+        encode_point(random_commit).as_slice(),
+        encode_point(statement).as_slice(),
+        profile.user_id,
+        profile.other_info,
+    ] {
+        hasher.update((item.len() as u32).to_be_bytes());
+        hasher.update(item);
+    }
+
+    P::ScalarField::from_be_bytes_mod_order(&hasher.finalize())
+}
+
+fn encoding_error() -> CryptoError {
+    CryptoError::IoError(String::from("Invalid RFC 8235 proof encoding"))
+}
+
+/// SEC 1 uncompressed encoding `0x04 || X || Y`; the identity is encoded as a single zero byte.
+fn encode_point<P: SWModelParameters>(point: &GroupAffine<P>) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    if point.is_zero() {
+        return vec![0x00];
+    }
+
+    let mut bytes = vec![0x04];
+    bytes.extend(field_to_be_bytes(&point.x));
+    bytes.extend(field_to_be_bytes(&point.y));
+
+    bytes
+}
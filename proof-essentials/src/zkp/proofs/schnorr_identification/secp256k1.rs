@@ -0,0 +1,234 @@
+//! secp256k1, for checking the BIP-340 mode against the official test vectors. The field parameters follow the
+//! layout of the Starknet curve crate, with five limbs: the arithmetic of `Fp256` in arkworks 0.3 drops the carry
+//! of additions, so it does not support moduli of the full 256 bits.
+
+use ark_ec::models::{ModelParameters, SWModelParameters};
+use ark_ec::short_weierstrass_jacobian::GroupProjective;
+use ark_ff::biginteger::BigInteger320 as BigInteger;
+use ark_ff::fields::{FftParameters, Fp320, Fp320Parameters, FpParameters};
+use ark_ff::{field_new, Zero};
+
+pub type Fq = Fp320<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp320Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+
+    // TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger::new([
+        0xfffffffefffffc2f,
+        0xfffffffefffffc2e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0000000000000000,
+    ]);
+}
+
+impl FpParameters for FqParameters {
+    // 115792089237316195423570985008687907853269984665640564039457584007908834671663
+    const MODULUS: BigInteger = BigInteger::new([
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // R = 2^320 mod p
+    const R: BigInteger = BigInteger::new([
+        0x0000000000000000,
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    // R2 = (2^320)^2 mod p
+    const R2: BigInteger = BigInteger::new([
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000007a2000e90a1,
+        0x0000000000000001,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    const T: BigInteger = BigInteger::new([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([
+        0xffffffffbfffff0b,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // GENERATOR = 3, in Montgomery form
+    const GENERATOR: BigInteger = BigInteger::new([
+        0x0000000000000000,
+        0x0000000300000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_BITS: u32 = 256;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    // The unused top limb would shave 64 bits, but `from_random_bytes` only supports up to 63, which merely makes
+    // sampling reject more candidates
+    const REPR_SHAVE_BITS: u32 = 63;
+
+    // INV = -p^{-1} (mod 2^64)
+    const INV: u64 = 15580212934572586289;
+}
+
+pub type Fr = Fp320<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp320Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 6;
+
+    // TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T, in Montgomery form
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger::new([
+        0x0112cb0f605a214a,
+        0x92225daffb794500,
+        0x7e42003a6ccb6212,
+        0x55980b07bc222114,
+        0x0000000000000000,
+    ]);
+}
+
+impl FpParameters for FrParameters {
+    // 115792089237316195423570985008687907852837564279074904382605163141518161494337
+    const MODULUS: BigInteger = BigInteger::new([
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // R = 2^320 mod p
+    const R: BigInteger = BigInteger::new([
+        0x0000000000000000,
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ]);
+
+    // R2 = (2^320)^2 mod p
+    const R2: BigInteger = BigInteger::new([
+        0x1e004f504dfd7f79,
+        0x08fcf59774a052ea,
+        0x27c4120fc94e1653,
+        0x3c1a6191e5702644,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([
+        0xdfe92f46681b20a0,
+        0x5d576e7357a4501d,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+    const T: BigInteger = BigInteger::new([
+        0xeeff497a3340d905,
+        0xfaeabb739abd2280,
+        0xffffffffffffffff,
+        0x03ffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([
+        0x777fa4bd19a06c82,
+        0xfd755db9cd5e9140,
+        0xffffffffffffffff,
+        0x01ffffffffffffff,
+        0x0000000000000000,
+    ]);
+
+    // GENERATOR = 7, in Montgomery form
+    const GENERATOR: BigInteger = BigInteger::new([
+        0x0000000000000000,
+        0xc13f6a264e843739,
+        0xe537f5b135039e5d,
+        0x0000000000000008,
+        0x0000000000000000,
+    ]);
+
+    const MODULUS_BITS: u32 = 256;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    // The unused top limb would shave 64 bits, but `from_random_bytes` only supports up to 63, which merely makes
+    // sampling reject more candidates
+    const REPR_SHAVE_BITS: u32 = 63;
+
+    // INV = -p^{-1} (mod 2^64)
+    const INV: u64 = 5408259542528602431;
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Secp256k1Parameters;
+
+impl ModelParameters for Secp256k1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+pub type Projective = GroupProjective<Secp256k1Parameters>;
+
+impl SWModelParameters for Secp256k1Parameters {
+    const COFACTOR: &'static [u64] = &[1];
+
+    const COFACTOR_INV: Fr = field_new!(Fr, "1");
+
+    const COEFF_A: Fq = field_new!(Fq, "0");
+
+    const COEFF_B: Fq = field_new!(Fq, "7");
+
+    const AFFINE_GENERATOR_COEFFS: (Fq, Fq) = (
+        field_new!(
+            Fq,
+            "55066263022277343669578718895168534326250603453777594175500187360389116729240"
+        ),
+        field_new!(
+            Fq,
+            "32670510020758816978083085130507043184471273380659243275938904335757337482424"
+        ),
+    );
+
+    fn mul_by_a(_: &Fq) -> Fq {
+        Fq::zero()
+    }
+}
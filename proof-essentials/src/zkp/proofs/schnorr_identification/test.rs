@@ -2,13 +2,18 @@
 mod test {
 
     use crate::error::CryptoError;
+    use crate::zkp::proofs::schnorr_identification::batch::BatchSchnorrIdentification;
+    use crate::zkp::proofs::schnorr_identification::possession::KeyRegistry;
+    use crate::zkp::proofs::schnorr_identification::rfc8235::{Profile, Rfc8235Proof};
     use crate::zkp::{proofs::schnorr_identification, ArgumentOfKnowledge};
     use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{One, Zero};
     use ark_marlin::rng::FiatShamirRng;
     use ark_std::rand::thread_rng;
     use ark_std::UniformRand;
     use blake2::Blake2s;
     use rand::{prelude::ThreadRng, Rng};
+    use sha2::Sha256;
     use starknet_curve;
 
     type Curve = starknet_curve::Projective;
@@ -16,6 +21,8 @@ mod test {
     type Schnorr<'a> = schnorr_identification::SchnorrIdentification<Curve>;
     type Scalar = starknet_curve::Fr;
     type Parameters = schnorr_identification::Parameters<Curve>;
+    type BatchSchnorr = BatchSchnorrIdentification<Curve>;
+    type Registry = KeyRegistry<Curve>;
     type FS = FiatShamirRng<Blake2s>;
 
     fn setup<R: Rng>(rng: &mut R) -> Result<Parameters, CryptoError> {
@@ -38,10 +45,250 @@ mod test {
         let (mut rng, crs, sk, pk) = test_template();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        let proof = Schnorr::prove(&mut rng, &crs, &pk, &sk, &mut fs_rng).unwrap();
+        let proof = Schnorr::prove(&mut rng, &crs, &pk, &sk, b"Test Domain", &mut fs_rng).unwrap();
 
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
-        assert_eq!(Schnorr::verify(&crs, &pk, &proof, &mut fs_rng), Ok(()));
+        assert_eq!(
+            Schnorr::verify(&crs, &pk, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let (mut rng, crs, sk, pk) = test_template();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Schnorr::prove(&mut rng, &crs, &pk, &sk, b"Game 1", &mut fs_rng).unwrap();
+
+        // The proof does not verify in another context
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify(&crs, &pk, &proof, b"Game 2", &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Schnorr Identification"
+            )))
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify(&crs, &pk, &proof, b"Game 1", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_batch_verification() {
+        let mut rng = thread_rng();
+        let crs = setup(&mut rng).unwrap();
+        let transcripts = |n: usize| -> Vec<FS> {
+            (0..n)
+                .map(|_| FS::from_seed(b"Initialised with some input"))
+                .collect()
+        };
+
+        let (statements, proofs): (Vec<Point>, Vec<_>) = (0..16)
+            .map(|_| {
+                let sk = Scalar::rand(&mut rng);
+                let pk = crs.mul(sk).into_affine();
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                let proof =
+                    Schnorr::prove(&mut rng, &crs, &pk, &sk, b"Test Domain", &mut fs_rng).unwrap();
+                (pk, proof)
+            })
+            .unzip();
+
+        assert_eq!(
+            Schnorr::batch_verify(
+                &crs,
+                &statements,
+                &proofs,
+                b"Test Domain",
+                &mut transcripts(16),
+                &mut rng
+            ),
+            Ok(())
+        );
+
+        // A single invalid proof makes the batch fail
+        let mut swapped = statements.clone();
+        swapped.swap(3, 7);
+        assert_eq!(
+            Schnorr::batch_verify(
+                &crs,
+                &swapped,
+                &proofs,
+                b"Test Domain",
+                &mut transcripts(16),
+                &mut rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Schnorr Identification"
+            )))
+        );
+        assert!(Schnorr::batch_verify(
+            &crs,
+            &statements,
+            &proofs,
+            b"Other Domain",
+            &mut transcripts(16),
+            &mut rng
+        )
+        .is_err());
+
+        assert_eq!(
+            Schnorr::batch_verify(
+                &crs,
+                &statements[1..],
+                &proofs,
+                b"Test Domain",
+                &mut transcripts(16),
+                &mut rng
+            ),
+            Err(CryptoError::InvalidBatchStatement)
+        );
+    }
+
+    #[test]
+    fn test_context_binding() {
+        let (mut rng, crs, sk, pk) = test_template();
+        let context = |round: u64| [b"Game 1".to_vec(), round.to_le_bytes().to_vec()].concat();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Schnorr::prove_in_context(
+            &mut rng,
+            &crs,
+            &pk,
+            &sk,
+            b"Test Domain",
+            Some(&context(1)),
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let verify = |context: Option<&[u8]>| {
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            Schnorr::verify_in_context(&crs, &pk, &proof, b"Test Domain", context, &mut fs_rng)
+        };
+
+        assert_eq!(verify(Some(&context(1))), Ok(()));
+
+        // The proof cannot be replayed in another round, nor without its context
+        let error = Err(CryptoError::ProofVerificationError(String::from(
+            "Schnorr Identification",
+        )));
+        assert_eq!(verify(Some(&context(2))), error);
+        assert_eq!(verify(Some(b"")), error);
+        assert_eq!(verify(None), error);
+
+        // Without a context, the proof is a plain proof
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Schnorr::prove(&mut rng, &crs, &pk, &sk, b"Test Domain", &mut fs_rng).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify_in_context(&crs, &pk, &proof, b"Test Domain", None, &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_deterministic_nonce() {
+        let (mut rng, crs, sk, pk) = test_template();
+
+        let prove = |domain: &[u8], context: Option<&[u8]>| {
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            Schnorr::prove_deterministic(&crs, &pk, &sk, domain, context, &mut fs_rng).unwrap()
+        };
+
+        let proof = prove(b"Test Domain", None);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify(&crs, &pk, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+
+        // The same inputs give the same proof, another domain or context gives another nonce
+        assert_eq!(proof, prove(b"Test Domain", None));
+        assert_ne!(proof.random_commit, prove(b"Game 2", None).random_commit);
+        assert_ne!(
+            proof.random_commit,
+            prove(b"Test Domain", Some(b"")).random_commit
+        );
+        assert_ne!(
+            prove(b"Test Domain", Some(b"round 1")).random_commit,
+            prove(b"Test Domain", Some(b"round 2")).random_commit
+        );
+
+        // A proof in context only verifies in that context
+        let proof = prove(b"Test Domain", Some(b"round 1"));
+        let verify = |context: Option<&[u8]>| {
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            Schnorr::verify_in_context(&crs, &pk, &proof, b"Test Domain", context, &mut fs_rng)
+        };
+        assert_eq!(verify(Some(b"round 1")), Ok(()));
+        assert!(verify(Some(b"round 2")).is_err());
+        assert!(verify(None).is_err());
+
+        let other_sk = Scalar::rand(&mut rng);
+        let other_pk = crs.mul(other_sk).into_affine();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let other_proof = Schnorr::prove_deterministic(
+            &crs,
+            &other_pk,
+            &other_sk,
+            b"Test Domain",
+            None,
+            &mut fs_rng,
+        )
+        .unwrap();
+        assert_ne!(proof.random_commit, other_proof.random_commit);
+    }
+
+    #[test]
+    fn test_deterministic_nonce_over_merlin() {
+        let (_, crs, sk, pk) = test_template();
+
+        let transcript = || merlin::Transcript::new(b"Schnorr Test");
+        let proof = Schnorr::prove_deterministic(
+            &crs,
+            &pk,
+            &sk,
+            b"Test Domain",
+            Some(b"round 1"),
+            &mut transcript(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Schnorr::verify_in_context(
+                &crs,
+                &pk,
+                &proof,
+                b"Test Domain",
+                Some(b"round 1"),
+                &mut transcript()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_deterministic_nonce_over_poseidon() {
+        use crate::utils::poseidon;
+        use crate::zkp::transcript::PoseidonTranscript;
+
+        let (_, crs, sk, pk) = test_template();
+
+        let parameters = poseidon::Parameters::<starknet_curve::Fq>::new(4).unwrap();
+        let transcript = || PoseidonTranscript::new(&parameters, b"Schnorr Test");
+        let proof =
+            Schnorr::prove_deterministic(&crs, &pk, &sk, b"Test Domain", None, &mut transcript())
+                .unwrap();
+
+        assert_eq!(
+            Schnorr::verify(&crs, &pk, &proof, b"Test Domain", &mut transcript()),
+            Ok(())
+        );
     }
 
     #[test]
@@ -51,15 +298,581 @@ mod test {
         let another_scalar = Scalar::rand(&mut rng);
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
 
-        let invalid_proof =
-            Schnorr::prove(&mut rng, &crs, &pk, &another_scalar, &mut fs_rng).unwrap();
+        let invalid_proof = Schnorr::prove(
+            &mut rng,
+            &crs,
+            &pk,
+            &another_scalar,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
         let mut fs_rng = FS::from_seed(b"Initialised with some input");
 
         assert_eq!(
-            Schnorr::verify(&crs, &pk, &invalid_proof, &mut fs_rng),
+            Schnorr::verify(&crs, &pk, &invalid_proof, b"Test Domain", &mut fs_rng),
             Err(CryptoError::ProofVerificationError(String::from(
                 "Schnorr Identification"
             )))
         );
     }
+
+    #[test]
+    fn test_designated_verifier() {
+        let (mut rng, crs, sk, pk) = test_template();
+
+        let verifier_sk = Scalar::rand(&mut rng);
+        let verifier_pk = crs.mul(verifier_sk).into_affine();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Schnorr::prove_designated(
+            &mut rng,
+            &crs,
+            &pk,
+            &sk,
+            &verifier_pk,
+            b"Test Domain",
+            None,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify_designated(
+                &crs,
+                &pk,
+                &verifier_pk,
+                &proof,
+                b"Test Domain",
+                None,
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        // The proof is bound to the domain and the context
+        for (domain, context) in [
+            (&b"Game 2"[..], None),
+            (&b"Test Domain"[..], Some(&b"round 1"[..])),
+        ] {
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert!(Schnorr::verify_designated(
+                &crs,
+                &pk,
+                &verifier_pk,
+                &proof,
+                domain,
+                context,
+                &mut fs_rng
+            )
+            .is_err());
+        }
+
+        // The proof is bound to the designated verifier
+        let other_verifier_pk = crs.mul(Scalar::rand(&mut rng)).into_affine();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify_designated(
+                &crs,
+                &pk,
+                &other_verifier_pk,
+                &proof,
+                b"Test Domain",
+                None,
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Designated Verifier Schnorr Identification"
+            )))
+        );
+
+        // Without either secret key no proof can be made
+        let another_scalar = Scalar::rand(&mut rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = Schnorr::prove_designated(
+            &mut rng,
+            &crs,
+            &pk,
+            &another_scalar,
+            &verifier_pk,
+            b"Test Domain",
+            None,
+            &mut fs_rng,
+        )
+        .unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert!(Schnorr::verify_designated(
+            &crs,
+            &pk,
+            &verifier_pk,
+            &invalid_proof,
+            b"Test Domain",
+            None,
+            &mut fs_rng
+        )
+        .is_err());
+
+        // The verifier can produce an equally valid proof on its own, so the proof does not convince third parties
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let simulated = Schnorr::simulate_designated(
+            &mut rng,
+            &crs,
+            &pk,
+            &verifier_pk,
+            &verifier_sk,
+            b"Test Domain",
+            None,
+            &mut fs_rng,
+        )
+        .unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify_designated(
+                &crs,
+                &pk,
+                &verifier_pk,
+                &simulated,
+                b"Test Domain",
+                None,
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_bip340() {
+        let mut rng = thread_rng();
+
+        let sk = Scalar::rand(&mut rng);
+        let pk = Schnorr::bip340_public_key(&sk).unwrap();
+        let aux_rand: [u8; 32] = rng.gen();
+        let message = b"BIP-340 message";
+
+        let signature = Schnorr::bip340_sign(&sk, message, &aux_rand).unwrap();
+        assert_eq!(Schnorr::bip340_verify(&pk, message, &signature), Ok(()));
+
+        let expected_error = Err(CryptoError::SignatureVerificationError(String::from(
+            "BIP-340",
+        )));
+
+        // The key and the nonce commitment must carry even y-coordinates, so signing with the negated key gives the same
+        // public key and still verifies
+        assert_eq!(Schnorr::bip340_public_key(&-sk).unwrap(), pk);
+        let negated_signature = Schnorr::bip340_sign(&-sk, message, &aux_rand).unwrap();
+        assert_eq!(
+            Schnorr::bip340_verify(&pk, message, &negated_signature),
+            Ok(())
+        );
+
+        assert_eq!(
+            Schnorr::bip340_verify(&pk, b"Another message", &signature),
+            expected_error
+        );
+
+        let other_pk = Schnorr::bip340_public_key(&Scalar::rand(&mut rng)).unwrap();
+        assert_eq!(
+            Schnorr::bip340_verify(&other_pk, message, &signature),
+            expected_error
+        );
+
+        // Non-canonical encodings of s are rejected
+        let mut malleated = signature;
+        malleated[32..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            Schnorr::bip340_verify(&pk, message, &malleated),
+            expected_error
+        );
+
+        assert_eq!(
+            Schnorr::bip340_sign(&Scalar::zero(), message, &aux_rand),
+            Err(CryptoError::InvalidSecretKey)
+        );
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Test vectors of BIP-340, bip-0340/test-vectors.csv
+    #[test]
+    fn test_bip340_vectors() {
+        use crate::utils::encoding::field_from_be_bytes;
+        use crate::zkp::proofs::schnorr_identification::secp256k1;
+
+        type Secp256k1 = schnorr_identification::SchnorrIdentification<secp256k1::Projective>;
+
+        // Secret key, public key, auxiliary randomness, message and signature
+        let signing_vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215\
+                 25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE3341\
+                 8906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+            (
+                "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+                "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+                "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+                "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1B\
+                 AB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            ),
+        ];
+
+        for (secret_key, public_key, aux_rand, message, signature) in signing_vectors {
+            let secret_key = field_from_be_bytes::<secp256k1::Fr>(&from_hex(secret_key)).unwrap();
+            let aux_rand: [u8; 32] = from_hex(aux_rand).try_into().unwrap();
+            let message = from_hex(message);
+            let signature = from_hex(&signature.replace(' ', ""));
+
+            let pk = Secp256k1::bip340_public_key(&secret_key).unwrap();
+            assert_eq!(pk.to_vec(), from_hex(public_key));
+
+            let produced = Secp256k1::bip340_sign(&secret_key, &message, &aux_rand).unwrap();
+            assert_eq!(produced.to_vec(), signature);
+            assert_eq!(Secp256k1::bip340_verify(&pk, &message, &produced), Ok(()));
+        }
+
+        // Vector 4: valid, with an R whose x-coordinate starts with zero bytes
+        let pk: [u8; 32] =
+            from_hex("D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9")
+                .try_into()
+                .unwrap();
+        let message = from_hex("4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703");
+        let signature: [u8; 64] = from_hex(
+            "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C63\
+             76AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+        )
+        .try_into()
+        .unwrap();
+        assert_eq!(Secp256k1::bip340_verify(&pk, &message, &signature), Ok(()));
+
+        let expected_error = Err(CryptoError::SignatureVerificationError(String::from(
+            "BIP-340",
+        )));
+
+        // Vector 5: the public key is not on the curve
+        let off_curve: [u8; 32] =
+            from_hex("EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34")
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            Secp256k1::bip340_verify(&off_curve, &message, &signature),
+            expected_error
+        );
+
+        // r equal to the field size and s equal to the curve order
+        let field_size =
+            from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F");
+        let mut invalid = signature;
+        invalid[..32].copy_from_slice(&field_size);
+        assert_eq!(
+            Secp256k1::bip340_verify(&pk, &message, &invalid),
+            expected_error
+        );
+
+        let curve_order =
+            from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        let mut invalid = signature;
+        invalid[32..].copy_from_slice(&curve_order);
+        assert_eq!(
+            Secp256k1::bip340_verify(&pk, &message, &invalid),
+            expected_error
+        );
+    }
+
+    #[test]
+    fn test_bip340_field_size() {
+        type Bls12Schnorr =
+            schnorr_identification::SchnorrIdentification<ark_bls12_381::G1Projective>;
+
+        // Coordinates of BLS12-381 take 48 bytes, which BIP-340 cannot encode
+        let secret_key = ark_bls12_381::Fr::rand(&mut thread_rng());
+        assert_eq!(
+            Bls12Schnorr::bip340_public_key(&secret_key),
+            Err(CryptoError::FieldSizeError(48, 32))
+        );
+        assert_eq!(
+            Bls12Schnorr::bip340_sign(&secret_key, b"message", &[0; 32]),
+            Err(CryptoError::FieldSizeError(48, 32))
+        );
+        assert_eq!(
+            Bls12Schnorr::bip340_verify(&[0; 32], b"message", &[0; 64]),
+            Err(CryptoError::FieldSizeError(48, 32))
+        );
+    }
+
+    #[test]
+    fn test_rfc8235() {
+        let (mut rng, crs, sk, pk) = test_template();
+
+        let profile = Profile::new(b"Alice", b"session 1");
+        let proof =
+            Schnorr::rfc8235_prove::<_, Sha256>(&mut rng, &crs, &pk, &sk, &profile).unwrap();
+        assert_eq!(
+            Schnorr::rfc8235_verify::<Sha256>(&crs, &pk, &proof, &profile),
+            Ok(())
+        );
+
+        let decoded = Rfc8235Proof::from_bytes(&proof.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded, proof);
+
+        // The identity has no uncompressed encoding
+        let identity = Rfc8235Proof::<Curve> {
+            random_commit: Point::zero(),
+            opening: proof.opening,
+        };
+        assert!(identity.to_bytes().is_err());
+
+        // The challenge binds the prover's identity and the context
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "RFC 8235 Schnorr Identification",
+        )));
+        assert_eq!(
+            Schnorr::rfc8235_verify::<Sha256>(
+                &crs,
+                &pk,
+                &proof,
+                &Profile::new(b"Bob", b"session 1")
+            ),
+            expected_error
+        );
+        assert_eq!(
+            Schnorr::rfc8235_verify::<Sha256>(
+                &crs,
+                &pk,
+                &proof,
+                &Profile::new(b"Alice", b"session 2")
+            ),
+            expected_error
+        );
+
+        // Native proofs convert losslessly, but their challenge is derived differently
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let native = Schnorr::prove(&mut rng, &crs, &pk, &sk, b"Test Domain", &mut fs_rng).unwrap();
+        let converted = Rfc8235Proof::from(native);
+        assert_eq!(
+            schnorr_identification::proof::Proof::from(converted),
+            native
+        );
+        assert_eq!(
+            Schnorr::rfc8235_verify::<Sha256>(&crs, &pk, &converted, &profile),
+            expected_error
+        );
+    }
+
+    #[test]
+    fn test_rfc8235_subgroup() {
+        use crate::utils::encoding::field_to_be_bytes;
+        use ark_bls12_381::{Fq, Fr, G1Affine, G1Projective};
+
+        let rng = &mut thread_rng();
+        let opening = Fr::rand(rng);
+
+        // BLS12-381 G1 has a cofactor, so points on the curve are almost never in the prime-order subgroup
+        let outside = (0u64..)
+            .filter_map(|x| G1Affine::get_point_from_x(Fq::from(x), false))
+            .find(|point| !point.is_in_correct_subgroup_assuming_on_curve())
+            .unwrap();
+        let mut bytes = vec![0x04];
+        bytes.extend(field_to_be_bytes(&outside.x));
+        bytes.extend(field_to_be_bytes(&outside.y));
+        bytes.extend(field_to_be_bytes(&opening));
+        assert!(Rfc8235Proof::<G1Projective>::from_bytes(&bytes).is_err());
+
+        let inside = Rfc8235Proof::<G1Projective> {
+            random_commit: G1Projective::rand(rng).into_affine(),
+            opening,
+        };
+        assert_eq!(
+            Rfc8235Proof::from_bytes(&inside.to_bytes().unwrap()),
+            Ok(inside)
+        );
+    }
+
+    #[test]
+    fn test_batch() {
+        let (mut rng, crs, _, _) = test_template();
+
+        let secret_keys = (0..8).map(|_| Scalar::rand(&mut rng)).collect::<Vec<_>>();
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| crs.mul(*sk).into_affine())
+            .collect::<Vec<_>>();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = BatchSchnorr::prove(
+            &mut rng,
+            &crs,
+            &public_keys,
+            &secret_keys,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        assert_eq!(proof.openings.len(), 8);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchSchnorr::verify(&crs, &public_keys, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Batch Schnorr Identification",
+        )));
+
+        // A single wrong discrete logarithm invalidates the whole batch
+        let mut wrong_keys = secret_keys.clone();
+        wrong_keys[5] = Scalar::rand(&mut rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = BatchSchnorr::prove(
+            &mut rng,
+            &crs,
+            &public_keys,
+            &wrong_keys,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchSchnorr::verify(
+                &crs,
+                &public_keys,
+                &invalid_proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        // The proof is bound to the order of the keys
+        let mut swapped_keys = public_keys.clone();
+        swapped_keys.swap(0, 1);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchSchnorr::verify(&crs, &swapped_keys, &proof, b"Test Domain", &mut fs_rng),
+            expected_error
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchSchnorr::verify(
+                &crs,
+                &public_keys[..7].to_vec(),
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchSchnorr::prove(
+                &mut rng,
+                &crs,
+                &public_keys,
+                &secret_keys[..7].to_vec(),
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Err(CryptoError::InvalidBatchStatement)
+        );
+    }
+
+    #[test]
+    fn test_proof_of_possession() {
+        let (mut rng, crs, sk, pk) = test_template();
+        let domain = b"Card game lobby";
+
+        let mut registry = Registry::new(&crs, domain);
+        let (alice_pk, alice_proof) =
+            Registry::prove_registration::<_, Blake2s>(&mut rng, &crs, domain, b"alice", &sk)
+                .unwrap();
+        assert_eq!(alice_pk, pk);
+        assert_eq!(
+            registry.register::<Blake2s>(b"alice", &alice_pk, &alice_proof),
+            Ok(())
+        );
+        assert_eq!(registry.get(b"alice"), Some(&pk));
+
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Proof of Possession",
+        )));
+
+        // Rogue key: Bob registers `X - pk` for some target X without knowing its secret key, and reuses his own
+        // proof of possession for it
+        let bob_sk = Scalar::rand(&mut rng);
+        let (_, bob_proof) =
+            Registry::prove_registration::<_, Blake2s>(&mut rng, &crs, domain, b"bob", &bob_sk)
+                .unwrap();
+        let rogue_pk = (crs.mul(bob_sk) - pk.into_projective()).into_affine();
+        assert_eq!(
+            registry.register::<Blake2s>(b"bob", &rogue_pk, &bob_proof),
+            expected_error
+        );
+
+        // Alice's proof replayed under another identity, or in another registry
+        let carol_sk = Scalar::rand(&mut rng);
+        let (carol_pk, carol_proof) =
+            Registry::prove_registration::<_, Blake2s>(&mut rng, &crs, domain, b"carol", &carol_sk)
+                .unwrap();
+        assert_eq!(
+            registry.register::<Blake2s>(b"mallory", &carol_pk, &carol_proof),
+            expected_error
+        );
+
+        let mut other_registry = Registry::new(&crs, b"Another lobby");
+        assert_eq!(
+            other_registry.register::<Blake2s>(b"carol", &carol_pk, &carol_proof),
+            expected_error
+        );
+
+        // Neither owners nor keys can be registered twice
+        assert_eq!(
+            registry.register::<Blake2s>(b"alice", &carol_pk, &carol_proof),
+            Err(CryptoError::InvalidKeyRegistration)
+        );
+        let (_, alice_again) =
+            Registry::prove_registration::<_, Blake2s>(&mut rng, &crs, domain, b"dave", &sk)
+                .unwrap();
+        assert_eq!(
+            registry.register::<Blake2s>(b"dave", &pk, &alice_again),
+            Err(CryptoError::InvalidKeyRegistration)
+        );
+
+        // Keys off the curve are rejected before their proof is checked
+        let off_curve = Point::new(carol_pk.x, carol_pk.y + starknet_curve::Fq::one(), false);
+        assert_eq!(
+            registry.register::<Blake2s>(b"erin", &off_curve, &carol_proof),
+            Err(CryptoError::InvalidKeyRegistration)
+        );
+        assert_eq!(
+            registry.register::<Blake2s>(b"erin", &Point::zero(), &carol_proof),
+            Err(CryptoError::InvalidKeyRegistration)
+        );
+
+        assert_eq!(
+            registry.register::<Blake2s>(b"carol", &carol_pk, &carol_proof),
+            Ok(())
+        );
+        assert_eq!(registry.len(), 2);
+    }
 }
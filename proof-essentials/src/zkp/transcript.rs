@@ -1,38 +1,503 @@
-use ark_ff::Field;
-use ark_serialize::CanonicalSerialize;
-use merlin::Transcript;
+use crate::error::CryptoError;
+use crate::utils::hash_to_field::hash_to_field;
+use crate::utils::poseidon::{self, PoseidonSponge};
 
-pub(crate) trait TranscriptProtocol {
-    fn append(&mut self, label: &'static [u8], item: &impl CanonicalSerialize);
+use ark_ff::{to_bytes, BigInteger, Field, PrimeField, ToBytes};
+use ark_marlin::rng::FiatShamirRng;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::fmt;
+use ark_std::io::{self, Read, Write};
+use blake2::Blake2s;
+use digest::Digest;
+use rand::RngCore;
+use sha2::Sha256;
 
+/// Transcript used by the examples and tests of the crate, hashing with BLAKE2s.
+pub type DefaultTranscript = FiatShamirRng<Blake2s>;
+
+/// Transcript hashing with BLAKE3.
+#[cfg(feature = "blake3")]
+pub type Blake3Transcript = FiatShamirRng<blake3::Hasher>;
+
+/// Transcript hashing with SHA3-256.
+#[cfg(feature = "sha3")]
+pub type Sha3Transcript = FiatShamirRng<sha3::Sha3_256>;
+
+/// Transcript hashing with SHA-512. Only the first 32 bytes of each digest seed the challenge stream.
+pub type Sha512Transcript = FiatShamirRng<sha2::Sha512>;
+
+/// Domain separation tag of the challenges derived by [`derive_challenge`].
+pub const CHALLENGE_DST: &[u8] = b"PROOF-ESSENTIALS-V01-CHALLENGE_XMD:SHA-256";
+
+/// Derive a challenge in `F` from the transcript `fs_rng`. For the hash-based transcripts, 32 bytes are drawn from it
+/// and mapped to the field with the `hash_to_field` of RFC 9380, over `expand_message_xmd` with SHA-256 and the tag
+/// [`CHALLENGE_DST`].
+///
+/// Unlike rejection sampling from the transcript, the derivation is fully specified by the RFC, so a verifier written
+/// in another language recomputes the challenge from the 32 transcript bytes alone, and the reduction of 128 extra
+/// bits keeps its bias negligible.
+pub fn derive_challenge<F: Field>(fs_rng: &mut impl Transcript) -> F {
+    fs_rng.squeeze_challenge()
+}
+
+fn challenge_from_bytes<F: Field>(bytes: &[u8]) -> F {
+    hash_to_field::<F, Sha256>(bytes, CHALLENGE_DST, 1)
+        .expect("a single field element fits in an expansion")
+        .remove(0)
+}
+
+/// Streaming absorption into a [`Transcript`]: items are serialized with their canonical encoding straight into
+/// the buffer hashed by the transcript, instead of into the vector built by `to_bytes!`, which `absorb` then copies.
+/// Curve points are absorbed compressed, which also halves the bytes hashed for statements made of points and
+/// ciphertexts.
+///
+/// Several items are absorbed at once with the `absorb_serializable!` macro. Serializing into the transcript buffer
+/// cannot fail for the canonical encodings of the crate.
+pub trait AbsorbSerializable {
+    /// Absorb everything `write` serializes.
+    fn absorb_with<F: Fn(&mut dyn Write) -> Result<(), SerializationError>>(&mut self, write: F);
+
+    /// Absorb the canonical encoding of `item`.
+    fn absorb_serializable<T: CanonicalSerialize + ?Sized>(&mut self, item: &T) {
+        self.absorb_with(|writer| item.serialize(writer))
+    }
+}
+
+impl<T: Transcript> AbsorbSerializable for T {
+    fn absorb_with<F: Fn(&mut dyn Write) -> Result<(), SerializationError>>(&mut self, write: F) {
+        self.absorb(&Streamed(write));
+    }
+}
+
+/// [`ToBytes`] adapter writing the output of a serialization closure, so that `absorb` serializes into its own buffer.
+struct Streamed<F>(F);
+
+impl<F: Fn(&mut dyn Write) -> Result<(), SerializationError>> ToBytes for Streamed<F> {
+    fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        (self.0)(&mut writer).map_err(io::Error::other)
+    }
+}
+
+/// Absorb the canonical encodings of several items, in order, into a [`Transcript`] as a single message:
+/// `absorb_serializable!(fs_rng, statement.input_ciphers, statement.shuffled_ciphers)`.
+macro_rules! absorb_serializable {
+    ($fs_rng:expr, $($item:expr),+ $(,)?) => {
+        $crate::zkp::transcript::AbsorbSerializable::absorb_with($fs_rng, |writer| {
+            use ark_serialize::CanonicalSerialize as _;
+            $(($item).serialize(&mut *writer)?;)+
+            Ok(())
+        })
+    };
+}
+pub(crate) use absorb_serializable;
+
+/// Fiat-Shamir layer: a public-coin transcript that absorbs labeled messages and squeezes challenges from them.
+///
+/// Every [`ArgumentOfKnowledge`](crate::zkp::ArgumentOfKnowledge) of the crate runs over any implementation. It is
+/// implemented for [`FiatShamirRng`], for [`merlin::Transcript`], the STROBE-based transcript of the dalek
+/// ecosystem, so that a proof can be bound to a transcript shared with protocols outside this crate, and for
+/// [`PoseidonTranscript`], whose challenges are cheap to recompute inside an arithmetic circuit.
+pub trait Transcript {
+    /// Hash compressing messages too long to be absorbed at once, such as the ciphertexts of a shuffle read from a
+    /// stream, into the digest that is absorbed instead.
+    type Hash: Digest;
+
+    /// Absorb `message` under `label`.
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]);
+
+    /// Squeeze a challenge under `label`, uniform in the field `F`.
     fn challenge_scalar<F: Field>(&mut self, label: &'static [u8]) -> F;
+
+    /// Fill `dest` with challenge bytes, without a label.
+    fn squeeze_bytes(&mut self, dest: &mut [u8]);
+
+    /// Absorb the canonical byte encoding of `item` under `label`.
+    fn append<T: ToBytes>(&mut self, label: &'static [u8], item: &T) -> Result<(), CryptoError> {
+        self.append_message(label, &to_bytes![item]?);
+        Ok(())
+    }
+
+    /// Absorb the byte encoding of `item` without a label, which is how the arguments of the crate absorb their
+    /// messages. The default appends it under the label `absorb`.
+    fn absorb<T: ToBytes>(&mut self, item: &T) {
+        let mut bytes = Vec::new();
+        item.write(&mut bytes)
+            .expect("the encodings of the crate write into a vector");
+        self.append_message(b"absorb", &bytes);
+    }
+
+    /// Squeeze a challenge in `F` without a label, see [`derive_challenge`]. The default maps 32 bytes of
+    /// [`Transcript::squeeze_bytes`] to the field.
+    fn squeeze_challenge<F: Field>(&mut self) -> F {
+        let mut seed = [0u8; 32];
+        self.squeeze_bytes(&mut seed);
+        challenge_from_bytes(&seed)
+    }
 }
 
-impl TranscriptProtocol for Transcript {
-    fn append(&mut self, label: &'static [u8], item: &impl CanonicalSerialize) {
+impl<D: Digest> Transcript for FiatShamirRng<D> {
+    type Hash = D;
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.absorb(
+            &[
+                &(label.len() as u64).to_le_bytes(),
+                label,
+                &(message.len() as u64).to_le_bytes(),
+                message,
+            ]
+            .concat(),
+        );
+    }
+
+    fn challenge_scalar<F: Field>(&mut self, label: &'static [u8]) -> F {
+        self.absorb(&label.to_vec());
+        derive_challenge(self)
+    }
+
+    fn squeeze_bytes(&mut self, dest: &mut [u8]) {
+        self.fill_bytes(dest)
+    }
+
+    fn absorb<T: ToBytes>(&mut self, item: &T) {
+        FiatShamirRng::absorb(self, item)
+    }
+}
+
+impl Transcript for merlin::Transcript {
+    type Hash = Blake2s;
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        merlin::Transcript::append_message(self, label, message)
+    }
+
+    /// The challenge bytes are mapped to the field as in [`derive_challenge`].
+    fn challenge_scalar<F: Field>(&mut self, label: &'static [u8]) -> F {
+        let mut seed = [0u8; 32];
+        self.challenge_bytes(label, &mut seed);
+        challenge_from_bytes(&seed)
+    }
+
+    fn squeeze_bytes(&mut self, dest: &mut [u8]) {
+        self.challenge_bytes(b"challenge", dest)
+    }
+}
+
+/// [`RngCore`] drawing its bytes from a transcript with [`Transcript::squeeze_bytes`], for the randomness that a
+/// verifier derives from the transcript, such as the weights of a batch check.
+pub struct TranscriptRng<'a, T: Transcript> {
+    transcript: &'a mut T,
+}
+
+impl<'a, T: Transcript> TranscriptRng<'a, T> {
+    pub fn new(transcript: &'a mut T) -> Self {
+        Self { transcript }
+    }
+}
+
+impl<'a, T: Transcript> RngCore for TranscriptRng<'a, T> {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.transcript.squeeze_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Operation performed on a transcript, as recorded by [`AuditedTranscript`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TranscriptEvent {
+    /// A message absorbed under a label.
+    Message { label: Vec<u8>, message: Vec<u8> },
+
+    /// A challenge squeezed under a label, in its canonical encoding.
+    Challenge { label: Vec<u8>, challenge: Vec<u8> },
+}
+
+/// Ordered record of the operations performed on a transcript.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TranscriptLog {
+    pub events: Vec<TranscriptEvent>,
+}
+
+impl TranscriptLog {
+    /// Index of the first event at which `self` and `other` differ, or `None` if they are the same.
+    pub fn first_divergence(&self, other: &Self) -> Option<usize> {
+        let common = self.events.len().min(other.events.len());
+        (0..common)
+            .find(|&i| self.events[i] != other.events[i])
+            .or((self.events.len() != other.events.len()).then_some(common))
+    }
+}
+
+/// One event per line, labels as text and data in hex, so that the logs of two parties can be compared with `diff`.
+impl fmt::Display for TranscriptLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = |bytes: &[u8]| -> String { bytes.iter().map(|b| format!("{:02x}", b)).collect() };
+
+        for (i, event) in self.events.iter().enumerate() {
+            let (kind, label, data) = match event {
+                TranscriptEvent::Message { label, message } => ("message", label, message),
+                TranscriptEvent::Challenge { label, challenge } => ("challenge", label, challenge),
+            };
+            writeln!(
+                f,
+                "{} {} {} {}",
+                i,
+                kind,
+                String::from_utf8_lossy(label),
+                hex(data)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Debugging aid: a transcript recording every message it absorbs and every challenge it squeezes into a
+/// [`TranscriptLog`], before passing them on to the transcript `T` it wraps. Its challenges are those of `T`, so it
+/// can stand in for `T` on either side of a protocol. When a third-party verifier rejects a proof, comparing the logs
+/// of both parties shows the first message on which they disagree.
+pub struct AuditedTranscript<T: Transcript> {
+    transcript: T,
+    log: TranscriptLog,
+}
+
+impl<T: Transcript> AuditedTranscript<T> {
+    pub fn new(transcript: T) -> Self {
+        Self {
+            transcript,
+            log: TranscriptLog::default(),
+        }
+    }
+
+    /// Operations recorded so far.
+    pub fn log(&self) -> &TranscriptLog {
+        &self.log
+    }
+
+    /// Stop recording, returning the wrapped transcript and the log.
+    pub fn into_parts(self) -> (T, TranscriptLog) {
+        (self.transcript, self.log)
+    }
+}
+
+impl<T: Transcript> AuditedTranscript<T> {
+    fn record_challenge<F: Field>(&mut self, label: &[u8], challenge: &F) {
         let mut bytes = Vec::new();
-        item.serialize(&mut bytes).unwrap();
-        self.append_message(label, &bytes)
+        challenge
+            .serialize(&mut bytes)
+            .expect("serializing into a vector cannot fail");
+        self.log.events.push(TranscriptEvent::Challenge {
+            label: label.to_vec(),
+            challenge: bytes,
+        });
+    }
+}
+
+/// Unlabeled messages and challenges are recorded under the labels `absorb` and `challenge`.
+impl<T: Transcript> Transcript for AuditedTranscript<T> {
+    type Hash = T::Hash;
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.log.events.push(TranscriptEvent::Message {
+            label: label.to_vec(),
+            message: message.to_vec(),
+        });
+        self.transcript.append_message(label, message);
+    }
+
+    fn challenge_scalar<F: Field>(&mut self, label: &'static [u8]) -> F {
+        let challenge: F = self.transcript.challenge_scalar(label);
+        self.record_challenge(label, &challenge);
+
+        challenge
+    }
+
+    fn squeeze_bytes(&mut self, dest: &mut [u8]) {
+        self.transcript.squeeze_bytes(dest);
+        self.log.events.push(TranscriptEvent::Challenge {
+            label: b"challenge".to_vec(),
+            challenge: dest.to_vec(),
+        });
+    }
+
+    fn absorb<B: ToBytes>(&mut self, item: &B) {
+        let mut bytes = Vec::new();
+        item.write(&mut bytes)
+            .expect("the encodings of the crate write into a vector");
+        self.log.events.push(TranscriptEvent::Message {
+            label: b"absorb".to_vec(),
+            message: bytes.clone(),
+        });
+        self.transcript.absorb(&bytes);
+    }
+
+    fn squeeze_challenge<F: Field>(&mut self) -> F {
+        let challenge: F = self.transcript.squeeze_challenge();
+        self.record_challenge(b"challenge", &challenge);
+
+        challenge
     }
+}
+
+/// Serializable state of a [`FiatShamirRng`] transcript, to spread the phases of a protocol (mask, shuffle, reveal...)
+/// over several sessions or processes while keeping a single binding transcript.
+///
+/// A checkpoint is a 32-byte output of the transcript, which depends on everything absorbed so far, and the resumed
+/// transcript is seeded with it. Every party must checkpoint and resume at the same point of the protocol, so that
+/// they keep deriving the same challenges.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Checkpoint {
+    state: Vec<u8>,
+}
+
+impl Checkpoint {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Transcript Checkpoint";
+
+    /// Checkpoint `fs_rng`, which is consumed: the protocol continues with [`Checkpoint::resume`].
+    pub fn new<D: Digest>(mut fs_rng: FiatShamirRng<D>) -> Self {
+        let mut state = vec![0u8; 32];
+        fs_rng.fill_bytes(&mut state);
+
+        Self { state }
+    }
+
+    /// Transcript continuing the one this checkpoint was taken from.
+    pub fn resume<D: Digest>(&self) -> Result<FiatShamirRng<D>, CryptoError> {
+        Ok(FiatShamirRng::from_seed(&to_bytes![
+            Self::PROTOCOL_NAME,
+            self.state
+        ]?))
+    }
+}
+
+/// Transcript over a Poseidon sponge on the field `F`, so that a verifier of the proof can be run inside a SNARK
+/// whose circuit is defined over `F`, typically the base field of the curve. Every argument of the crate runs over
+/// it, shuffles included, though the ciphertexts of a shuffle are still compressed with BLAKE2s (its
+/// [`Transcript::Hash`]) before their digests are absorbed.
+///
+/// Byte messages are packed into field elements of $\lfloor (b - 1) / 8 \rfloor$ bytes for a $b$-bit modulus and are
+/// preceded by their length, while [`PoseidonTranscript::append_elements`] absorbs elements of `F` natively. Every
+/// coordinate of a challenge is read from the low bits of one squeezed element, as many as fit below both moduli, so
+/// that no modular reduction is needed and the circuit only decomposes the squeezed element into bits.
+#[derive(Clone, Debug)]
+pub struct PoseidonTranscript<F: PrimeField> {
+    sponge: PoseidonSponge<F>,
+}
+
+impl<F: PrimeField> PoseidonTranscript<F> {
+    /// A transcript for the protocol named `label`.
+    pub fn new(parameters: &poseidon::Parameters<F>, label: &'static [u8]) -> Self {
+        let mut transcript = Self {
+            sponge: PoseidonSponge::new(parameters, poseidon::TRANSCRIPT_DOMAIN),
+        };
+        transcript.append_message(b"protocol", label);
 
-    fn challenge_scalar<F>(&mut self, label: &'static [u8]) -> F
-    where
-        F: Field,
-    {
-        let example = F::one();
-        let size = example.serialized_size();
-        // let size = F::size_in_bits() / 8;
-        let mut buf = vec![0u8; size];
-        self.challenge_bytes(label, &mut buf);
-        F::from_random_bytes(&buf).unwrap()
+        transcript
+    }
+
+    /// Absorb `elements` under `label` without going through their byte encoding.
+    pub fn append_elements(&mut self, label: &'static [u8], elements: &[F]) {
+        self.absorb_bytes(label);
+        self.sponge.absorb(&[F::from(elements.len() as u64)]);
+        self.sponge.absorb(elements);
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let chunk_size = (F::size_in_bits() - 1) / 8;
+        let elements: Vec<F> = bytes
+            .chunks(chunk_size)
+            .map(F::from_le_bytes_mod_order)
+            .collect();
+
+        self.sponge.absorb(&[F::from(bytes.len() as u64)]);
+        self.sponge.absorb(&elements);
+    }
+}
+
+impl<F: PrimeField> Transcript for PoseidonTranscript<F> {
+    type Hash = Blake2s;
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.absorb_bytes(label);
+        self.absorb_bytes(message);
+    }
+
+    fn challenge_scalar<S: Field>(&mut self, label: &'static [u8]) -> S {
+        self.absorb_bytes(label);
+        self.squeeze_challenge()
+    }
+
+    /// Every squeezed element gives as many bytes as a byte message packs into one element.
+    fn squeeze_bytes(&mut self, dest: &mut [u8]) {
+        let chunk_size = (F::size_in_bits() - 1) / 8;
+        for chunk in dest.chunks_mut(chunk_size) {
+            let bytes = self.sponge.squeeze(1)[0].into_repr().to_bytes_le();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Squeezed natively, without going through bytes.
+    fn squeeze_challenge<S: Field>(&mut self) -> S {
+        let bits = F::size_in_bits().min(S::BasePrimeField::size_in_bits()) - 1;
+        let coordinates: Vec<S::BasePrimeField> = self
+            .sponge
+            .squeeze(S::extension_degree() as usize)
+            .iter()
+            .map(|element| {
+                let mut bytes = element.into_repr().to_bytes_le();
+                bytes.truncate(bits.div_ceil(8));
+                if bits % 8 != 0 {
+                    bytes[bits / 8] &= (1 << (bits % 8)) - 1;
+                }
+                S::BasePrimeField::from_le_bytes_mod_order(&bytes)
+            })
+            .collect();
+
+        S::from_base_prime_field_elems(&coordinates)
+            .expect("one coordinate per degree of the extension")
     }
 }
 
 #[cfg(test)]
 mod transcript_test {
+    use super::{
+        derive_challenge, AbsorbSerializable, AuditedTranscript, Checkpoint, DefaultTranscript,
+        PoseidonTranscript, Transcript, TranscriptEvent, CHALLENGE_DST,
+    };
+    use crate::utils::hash_to_field::hash_to_field;
+    use crate::utils::poseidon;
+    use crate::zkp::proofs::schnorr_identification::SchnorrIdentification;
+    use crate::zkp::transform::FiatShamir;
+    use crate::zkp::ArgumentOfKnowledge;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
     use ark_ff::One;
-    use ark_serialize::CanonicalSerialize;
-    use starknet_curve::Fr;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::rand::{thread_rng, RngCore};
+    use ark_std::UniformRand;
+    use blake2::Blake2s;
+    use sha2::Sha256;
+    use starknet_curve::{Fq, Fr, Projective};
+
+    type Schnorr = SchnorrIdentification<Projective>;
+
     #[test]
     fn f_size() {
         let one = Fr::one();
@@ -42,4 +507,449 @@ mod transcript_test {
         // expect serialized_size&uncompressed_size to be same for the field
         assert_eq!(serialized_size, uncompressed_size);
     }
+
+    fn challenge<T: Transcript>(transcript: &mut T, message: &[u8]) -> Fr {
+        transcript.append_message(b"message", message);
+        transcript.challenge_scalar(b"challenge")
+    }
+
+    #[test]
+    fn challenges_depend_on_messages() {
+        let merlin = || merlin::Transcript::new(b"Transcript Test");
+        assert_eq!(
+            challenge(&mut merlin(), b"hello"),
+            challenge(&mut merlin(), b"hello")
+        );
+        assert_ne!(
+            challenge(&mut merlin(), b"hello"),
+            challenge(&mut merlin(), b"world")
+        );
+
+        let fs_rng = || FiatShamirRng::<Blake2s>::from_seed(b"Initialised with some input");
+        assert_eq!(
+            challenge(&mut fs_rng(), b"hello"),
+            challenge(&mut fs_rng(), b"hello")
+        );
+        assert_ne!(
+            challenge(&mut fs_rng(), b"hello"),
+            challenge(&mut fs_rng(), b"world")
+        );
+
+        // Successive challenges from the same transcript differ
+        let mut transcript = merlin();
+        let first = challenge(&mut transcript, b"hello");
+        assert_ne!(first, challenge(&mut transcript, b"hello"));
+    }
+
+    #[test]
+    fn challenges_follow_hash_to_field() {
+        let mut fs_rng = DefaultTranscript::from_seed(b"Initialised with some input");
+        let mut bytes = [0u8; 32];
+        DefaultTranscript::from_seed(b"Initialised with some input").fill_bytes(&mut bytes);
+
+        // A verifier outside the crate only needs the transcript output and RFC 9380
+        let expected = hash_to_field::<Fr, Sha256>(&bytes, CHALLENGE_DST, 1).unwrap();
+        assert_eq!(derive_challenge::<Fr>(&mut fs_rng), expected[0]);
+    }
+
+    #[test]
+    fn audit_log() {
+        let rng = &mut thread_rng();
+        let generator = Projective::rand(rng).into_affine();
+        let secret_key = Fr::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+        let transcript = || AuditedTranscript::new(merlin::Transcript::new(b"Transcript Test"));
+
+        let mut prover_transcript = transcript();
+        let proof = FiatShamir::prove_with_transcript::<SchnorrIdentification<Projective>, _, _>(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            &mut prover_transcript,
+        )
+        .unwrap();
+
+        let mut verifier_transcript = transcript();
+        assert_eq!(
+            FiatShamir::verify_with_transcript(
+                &generator,
+                &public_key,
+                &proof,
+                &mut verifier_transcript
+            ),
+            Ok(())
+        );
+        assert_eq!(prover_transcript.log(), verifier_transcript.log());
+        assert_eq!(
+            prover_transcript
+                .log()
+                .first_divergence(verifier_transcript.log()),
+            None
+        );
+        assert!(matches!(
+            prover_transcript.log().events.last(),
+            Some(TranscriptEvent::Challenge { .. })
+        ));
+
+        // A verifier with another statement disagrees from the statement onwards
+        let other_key = generator.mul(Fr::rand(rng)).into_affine();
+        let mut verifier_transcript = transcript();
+        assert!(FiatShamir::verify_with_transcript(
+            &generator,
+            &other_key,
+            &proof,
+            &mut verifier_transcript
+        )
+        .is_err());
+
+        let prover_log = prover_transcript.log();
+        let verifier_log = verifier_transcript.log();
+        let index = prover_log.first_divergence(verifier_log).unwrap();
+        assert_eq!(
+            prover_log.events[index],
+            TranscriptEvent::Message {
+                label: b"statement".to_vec(),
+                message: ark_ff::to_bytes![public_key].unwrap()
+            }
+        );
+        assert_ne!(prover_log.to_string(), verifier_log.to_string());
+        assert_eq!(
+            prover_log.to_string().lines().nth(index),
+            Some(
+                format!(
+                    "{} message statement {}",
+                    index,
+                    hex(&ark_ff::to_bytes![public_key].unwrap())
+                )
+                .as_str()
+            )
+        );
+    }
+
+    #[test]
+    fn argument_over_any_transcript() {
+        let rng = &mut thread_rng();
+        let generator = Projective::rand(rng).into_affine();
+        let secret_key = Fr::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+
+        let merlin = || merlin::Transcript::new(b"Transcript Test");
+        let proof = Schnorr::prove(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            b"Test Domain",
+            &mut merlin(),
+        )
+        .unwrap();
+        assert_eq!(
+            Schnorr::verify(
+                &generator,
+                &public_key,
+                &proof,
+                b"Test Domain",
+                &mut merlin()
+            ),
+            Ok(())
+        );
+
+        // Auditing does not change the challenges: a proof made over an audited transcript verifies over the plain one
+        let fs_rng = || DefaultTranscript::from_seed(b"Initialised with some input");
+        let mut audited = AuditedTranscript::new(fs_rng());
+        let proof = Schnorr::prove(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            b"Test Domain",
+            &mut audited,
+        )
+        .unwrap();
+        assert!(!audited.log().events.is_empty());
+        assert_eq!(
+            Schnorr::verify(
+                &generator,
+                &public_key,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng()
+            ),
+            Ok(())
+        );
+        assert!(Schnorr::verify(
+            &generator,
+            &public_key,
+            &proof,
+            b"Test Domain",
+            &mut merlin()
+        )
+        .is_err());
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// Known-answer vectors: the digest of "abc" from the specification of each hash function, and the first bytes
+    /// of the challenge stream after absorbing a message.
+    fn check_vectors<D: digest::Digest>(abc: &[u8], stream: &[u8]) {
+        assert_eq!(D::digest(b"abc").to_vec(), abc);
+
+        let mut transcript = FiatShamirRng::<D>::from_seed(b"Initialised with some input");
+        transcript.append_message(b"message", b"hello");
+        let mut bytes = vec![0u8; stream.len()];
+        transcript.fill_bytes(&mut bytes);
+        assert_eq!(bytes, stream);
+    }
+
+    /// A Schnorr proof made and checked over a transcript hashing with `D`.
+    fn check_schnorr<D: digest::Digest>() {
+        let rng = &mut thread_rng();
+        let generator = Projective::rand(rng).into_affine();
+        let secret_key = Fr::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(b"Initialised with some input");
+        let proof = Schnorr::prove(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FiatShamirRng::<D>::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify(&generator, &public_key, &proof, b"Test Domain", &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn streamed_absorption() {
+        let rng = &mut thread_rng();
+        let points: Vec<_> = (0..4)
+            .map(|_| Projective::rand(rng).into_affine())
+            .collect();
+        let scalar = Fr::rand(rng);
+
+        let mut bytes = Vec::new();
+        points.serialize(&mut bytes).unwrap();
+        scalar.serialize(&mut bytes).unwrap();
+        let mut expected = DefaultTranscript::from_seed(b"Initialised with some input");
+        expected.absorb(&bytes);
+
+        // Several items are absorbed as their concatenated encodings, with a single hash
+        let mut fs_rng = DefaultTranscript::from_seed(b"Initialised with some input");
+        absorb_serializable!(&mut fs_rng, points, scalar);
+        assert_eq!(
+            derive_challenge::<Fr>(&mut fs_rng),
+            derive_challenge::<Fr>(&mut expected)
+        );
+
+        let mut fs_rng = DefaultTranscript::from_seed(b"Initialised with some input");
+        fs_rng.absorb_serializable(&points);
+        assert_ne!(
+            derive_challenge::<Fr>(&mut fs_rng),
+            derive_challenge::<Fr>(&mut expected)
+        );
+    }
+
+    #[test]
+    fn checkpoint_and_resume() {
+        let rng = &mut thread_rng();
+        let generator = Projective::rand(rng).into_affine();
+        let secret_keys = [Fr::rand(rng), Fr::rand(rng)];
+        let public_keys: Vec<_> = secret_keys
+            .iter()
+            .map(|sk| generator.mul(*sk).into_affine())
+            .collect();
+
+        // The prover stores its transcript between two phases
+        let mut fs_rng = DefaultTranscript::from_seed(b"Initialised with some input");
+        let first_proof = Schnorr::prove(
+            rng,
+            &generator,
+            &public_keys[0],
+            &secret_keys[0],
+            b"Phase 1",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut stored = Vec::new();
+        Checkpoint::new(fs_rng).serialize(&mut stored).unwrap();
+
+        let mut fs_rng = Checkpoint::deserialize(&stored[..])
+            .unwrap()
+            .resume::<Blake2s>()
+            .unwrap();
+        let second_proof = Schnorr::prove(
+            rng,
+            &generator,
+            &public_keys[1],
+            &secret_keys[1],
+            b"Phase 2",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        // So does the verifier
+        let mut fs_rng = DefaultTranscript::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Schnorr::verify(
+                &generator,
+                &public_keys[0],
+                &first_proof,
+                b"Phase 1",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        let checkpoint = Checkpoint::new(fs_rng);
+        let mut fs_rng = checkpoint.resume::<Blake2s>().unwrap();
+        assert_eq!(
+            Schnorr::verify(
+                &generator,
+                &public_keys[1],
+                &second_proof,
+                b"Phase 2",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        // The second phase is bound to the first
+        let fs_rng = DefaultTranscript::from_seed(b"Initialised with some input");
+        let other_checkpoint = Checkpoint::new(fs_rng);
+        assert_ne!(checkpoint, other_checkpoint);
+
+        let mut fs_rng = other_checkpoint.resume::<Blake2s>().unwrap();
+        assert!(Schnorr::verify(
+            &generator,
+            &public_keys[1],
+            &second_proof,
+            b"Phase 2",
+            &mut fs_rng
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn poseidon_transcript() {
+        let parameters = poseidon::Parameters::<Fq>::new(4).unwrap();
+        let poseidon = || PoseidonTranscript::new(&parameters, b"Transcript Test");
+
+        assert_eq!(
+            challenge(&mut poseidon(), b"hello"),
+            challenge(&mut poseidon(), b"hello")
+        );
+        assert_ne!(
+            challenge(&mut poseidon(), b"hello"),
+            challenge(&mut poseidon(), b"world")
+        );
+
+        // Native elements and their byte encoding are absorbed differently
+        let mut native = poseidon();
+        native.append_elements(b"message", &[Fq::from(7u64)]);
+        let mut encoded = poseidon();
+        encoded.append(b"message", &Fq::from(7u64)).unwrap();
+        assert_ne!(
+            native.challenge_scalar::<Fr>(b"challenge"),
+            encoded.challenge_scalar::<Fr>(b"challenge")
+        );
+
+        // A Schnorr proof over the scalar field of the curve, with a transcript over its base field
+        let rng = &mut thread_rng();
+        let generator = Projective::rand(rng).into_affine();
+        let secret_key = Fr::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+
+        let proof = FiatShamir::prove_with_transcript::<Schnorr, _, _>(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            &mut poseidon(),
+        )
+        .unwrap();
+        assert_eq!(
+            FiatShamir::verify_with_transcript(&generator, &public_key, &proof, &mut poseidon()),
+            Ok(())
+        );
+
+        let other_key = Projective::rand(rng).into_affine();
+        assert!(FiatShamir::verify_with_transcript(
+            &generator,
+            &other_key,
+            &proof,
+            &mut poseidon()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn blake2s_transcript() {
+        check_vectors::<Blake2s>(
+            &[
+                80, 140, 94, 140, 50, 124, 20, 226, 225, 167, 43, 163, 78, 235, 69, 47, 55, 69,
+                139, 32, 158, 214, 58, 41, 77, 153, 155, 76, 134, 103, 89, 130,
+            ],
+            &[
+                28, 113, 35, 154, 44, 130, 92, 145, 65, 252, 195, 229, 210, 17, 37, 82,
+            ],
+        );
+        check_schnorr::<Blake2s>();
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn blake3_transcript() {
+        check_vectors::<blake3::Hasher>(
+            &[
+                100, 55, 179, 172, 56, 70, 81, 51, 255, 182, 59, 117, 39, 58, 141, 181, 72, 197,
+                88, 70, 93, 121, 219, 3, 253, 53, 156, 108, 213, 189, 157, 133,
+            ],
+            &[
+                110, 151, 125, 1, 208, 161, 162, 148, 161, 10, 211, 103, 85, 184, 66, 237,
+            ],
+        );
+        check_schnorr::<blake3::Hasher>();
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn sha3_transcript() {
+        check_vectors::<sha3::Sha3_256>(
+            &[
+                58, 152, 93, 167, 79, 226, 37, 178, 4, 92, 23, 45, 107, 211, 144, 189, 133, 95, 8,
+                110, 62, 157, 82, 91, 70, 191, 226, 69, 17, 67, 21, 50,
+            ],
+            &[
+                201, 150, 120, 131, 181, 44, 67, 155, 105, 82, 178, 108, 32, 29, 42, 212,
+            ],
+        );
+        check_schnorr::<sha3::Sha3_256>();
+    }
+
+    #[test]
+    fn sha512_transcript() {
+        check_vectors::<sha2::Sha512>(
+            &[
+                221, 175, 53, 161, 147, 97, 122, 186, 204, 65, 115, 73, 174, 32, 65, 49, 18, 230,
+                250, 78, 137, 169, 126, 162, 10, 158, 238, 230, 75, 85, 211, 154, 33, 146, 153, 42,
+                39, 79, 193, 168, 54, 186, 60, 35, 163, 254, 235, 189, 69, 77, 68, 35, 100, 60,
+                232, 14, 42, 154, 201, 79, 165, 76, 164, 159,
+            ],
+            &[
+                34, 64, 217, 109, 2, 236, 119, 111, 103, 143, 215, 253, 157, 228, 80, 182,
+            ],
+        );
+        check_schnorr::<sha2::Sha512>();
+    }
 }
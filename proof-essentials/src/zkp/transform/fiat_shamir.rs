@@ -0,0 +1,131 @@
+use super::Transform;
+use crate::error::CryptoError;
+use crate::zkp::transcript::{derive_challenge, Transcript};
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::rand::Rng;
+
+/// Fiat-Shamir transform: the challenge is derived from the transcript after absorbing the statement and the
+/// prover's commitment.
+pub struct FiatShamir;
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<S: SigmaProtocol> {
+    pub(crate) commitment: S::Commitment,
+    pub(crate) response: S::Response,
+}
+
+impl FiatShamir {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Fiat-Shamir Transform";
+
+    /// Run the sigma protocol `S` non-interactively over any [`Transcript`] backend, e.g. a [`merlin::Transcript`]
+    /// shared with a dalek-based protocol. The parameters, the statement and the commitment are appended under their
+    /// own labels before the challenge is squeezed.
+    pub fn prove_with_transcript<S: SigmaProtocol, R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        witness: &S::Witness,
+        transcript: &mut T,
+    ) -> Result<Proof<S>, CryptoError> {
+        let (commitment, randomness) = S::commit(rng, parameters, statement, witness)?;
+
+        let challenge = Self::challenge::<S, T>(parameters, statement, &commitment, transcript)?;
+        let response = S::respond(parameters, statement, witness, &randomness, &challenge)?;
+
+        Ok(Proof {
+            commitment,
+            response,
+        })
+    }
+
+    /// Verify a proof produced by [`FiatShamir::prove_with_transcript`] against a transcript in the same state.
+    pub fn verify_with_transcript<S: SigmaProtocol, T: Transcript>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        proof: &Proof<S>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let challenge =
+            Self::challenge::<S, T>(parameters, statement, &proof.commitment, transcript)?;
+
+        S::check(
+            parameters,
+            statement,
+            &proof.commitment,
+            &challenge,
+            &proof.response,
+        )
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("Fiat-Shamir Transform")))
+    }
+
+    fn challenge<S: SigmaProtocol, T: Transcript>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        commitment: &S::Commitment,
+        transcript: &mut T,
+    ) -> Result<S::Scalar, CryptoError> {
+        transcript.append_message(b"protocol", Self::PROTOCOL_NAME);
+        transcript.append(b"parameters", parameters)?;
+        transcript.append(b"statement", statement)?;
+        transcript.append(b"commitment", commitment)?;
+
+        Ok(transcript.challenge_scalar(b"challenge"))
+    }
+}
+
+impl<S: SigmaProtocol> Transform<S> for FiatShamir {
+    type Proof = Proof<S>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        witness: &S::Witness,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let (commitment, randomness) = S::commit(rng, parameters, statement, witness)?;
+
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            parameters,
+            statement,
+            commitment
+        ]?);
+        let challenge = derive_challenge::<S::Scalar>(fs_rng);
+
+        let response = S::respond(parameters, statement, witness, &randomness, &challenge)?;
+
+        Ok(Proof {
+            commitment,
+            response,
+        })
+    }
+
+    fn verify<T: Transcript>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        proof: &Self::Proof,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            parameters,
+            statement,
+            proof.commitment
+        ]?);
+        let challenge = derive_challenge::<S::Scalar>(fs_rng);
+
+        S::check(
+            parameters,
+            statement,
+            &proof.commitment,
+            &challenge,
+            &proof.response,
+        )
+        .map_err(|_| CryptoError::ProofVerificationError(String::from("Fiat-Shamir Transform")))
+    }
+}
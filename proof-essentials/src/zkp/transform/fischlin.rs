@@ -0,0 +1,189 @@
+use super::Transform;
+use crate::error::CryptoError;
+use crate::zkp::transcript::{Transcript, TranscriptRng};
+use crate::zkp::SigmaProtocol;
+
+use ark_ff::to_bytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::rand::Rng;
+use digest::Digest;
+
+/// Fischlin transform (Crypto 2005). The prover runs `REPETITIONS` instances of the sigma protocol and, for each of
+/// them, searches the challenges $e < 2^{CHALLENGE\_BITS}$ for one whose answer hashes to a small value: the
+/// `HASH_BITS` leading bits of $H(seed, i, e, z_i)$. The proof is accepted when the hash values sum to at most
+/// `MAX_SUM`. A prover has to query the hash on accepting transcripts to succeed, so a witness can be extracted by
+/// observing the queries, without rewinding.
+///
+/// Proofs are `REPETITIONS` times larger and slower to produce than with [`super::FiatShamir`]; soundness is about
+/// `REPETITIONS * HASH_BITS` bits.
+pub struct Fischlin;
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<S: SigmaProtocol> {
+    pub(crate) commitments: Vec<S::Commitment>,
+    pub(crate) challenges: Vec<u64>,
+    pub(crate) responses: Vec<S::Response>,
+}
+
+impl Fischlin {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Fischlin Transform";
+
+    pub const REPETITIONS: usize = 16;
+    pub const HASH_BITS: u32 = 8;
+    pub const CHALLENGE_BITS: u32 = 12;
+    pub const MAX_SUM: u64 = 16;
+
+    /// Absorb the instance and all the commitments, and derive the seed of the hash queries from the transcript.
+    fn seed<S: SigmaProtocol, T: Transcript>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        commitments: &Vec<S::Commitment>,
+        fs_rng: &mut T,
+    ) -> Result<[u8; 32], CryptoError> {
+        fs_rng.absorb(&to_bytes![
+            Self::PROTOCOL_NAME,
+            parameters,
+            statement,
+            commitments
+        ]?);
+
+        Ok(TranscriptRng::new(fs_rng).gen())
+    }
+
+    /// `HASH_BITS` leading bits of $H(seed, i, e, z)$.
+    fn hash<S: SigmaProtocol, D: Digest>(
+        seed: &[u8; 32],
+        repetition: usize,
+        challenge: u64,
+        response: &S::Response,
+    ) -> Result<u64, CryptoError> {
+        let mut serialized_response = Vec::with_capacity(response.serialized_size());
+        response
+            .serialize(&mut serialized_response)
+            .map_err(|e| CryptoError::IoError(e.to_string()))?;
+
+        let digest = D::digest(&to_bytes![
+            seed.as_ref(),
+            repetition as u64,
+            challenge,
+            serialized_response
+        ]?);
+        let mut leading_bytes = [0u8; 8];
+        leading_bytes.copy_from_slice(&digest[..8]);
+
+        Ok(u64::from_be_bytes(leading_bytes) >> (64 - Self::HASH_BITS))
+    }
+}
+
+impl<S: SigmaProtocol> Transform<S> for Fischlin {
+    type Proof = Proof<S>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        witness: &S::Witness,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let (commitments, randomness): (Vec<_>, Vec<_>) = (0..Self::REPETITIONS)
+            .map(|_| S::commit(rng, parameters, statement, witness))
+            .collect::<Result<Vec<_>, CryptoError>>()?
+            .into_iter()
+            .unzip();
+
+        let seed = Self::seed::<S, T>(parameters, statement, &commitments, fs_rng)?;
+
+        let mut challenges = Vec::with_capacity(Self::REPETITIONS);
+        let mut responses = Vec::with_capacity(Self::REPETITIONS);
+        let mut sum = 0;
+        for (i, random) in randomness.iter().enumerate() {
+            // Keep the challenge whose answer hashes to the smallest value, stopping early on zero
+            let mut best: Option<(u64, u64, S::Response)> = None;
+            for challenge in 0..(1u64 << Self::CHALLENGE_BITS) {
+                let response = S::respond(
+                    parameters,
+                    statement,
+                    witness,
+                    random,
+                    &S::Scalar::from(challenge),
+                )?;
+                let hash = Self::hash::<S, T::Hash>(&seed, i, challenge, &response)?;
+
+                if best
+                    .as_ref()
+                    .is_none_or(|(_, best_hash, _)| hash < *best_hash)
+                {
+                    best = Some((challenge, hash, response));
+                }
+                if hash == 0 {
+                    break;
+                }
+            }
+
+            let (challenge, hash, response) = best.unwrap();
+            sum += hash;
+            challenges.push(challenge);
+            responses.push(response);
+        }
+
+        if sum > Self::MAX_SUM {
+            return Err(CryptoError::TransformSearchExhausted);
+        }
+
+        Ok(Proof {
+            commitments,
+            challenges,
+            responses,
+        })
+    }
+
+    fn verify<T: Transcript>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        proof: &Self::Proof,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verification_error =
+            || CryptoError::ProofVerificationError(String::from("Fischlin Transform"));
+
+        if proof.commitments.len() != Self::REPETITIONS
+            || proof.challenges.len() != Self::REPETITIONS
+            || proof.responses.len() != Self::REPETITIONS
+        {
+            return Err(verification_error());
+        }
+
+        let seed = Self::seed::<S, T>(parameters, statement, &proof.commitments, fs_rng)?;
+
+        let mut sum = 0;
+        for (i, ((commitment, challenge), response)) in proof
+            .commitments
+            .iter()
+            .zip(proof.challenges.iter())
+            .zip(proof.responses.iter())
+            .enumerate()
+        {
+            if *challenge >= 1u64 << Self::CHALLENGE_BITS {
+                return Err(verification_error());
+            }
+
+            S::check(
+                parameters,
+                statement,
+                commitment,
+                &S::Scalar::from(*challenge),
+                response,
+            )
+            .map_err(|_| verification_error())?;
+
+            sum += Self::hash::<S, T::Hash>(&seed, i, *challenge, response)?;
+        }
+
+        if sum > Self::MAX_SUM {
+            return Err(verification_error());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+pub mod fiat_shamir;
+pub mod fischlin;
+mod tests;
+
+pub use fiat_shamir::FiatShamir;
+pub use fischlin::Fischlin;
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{absorb_domain, ArgumentOfKnowledge, SigmaProtocol};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Compiler turning a sigma protocol `S` into a non-interactive argument.
+pub trait Transform<S: SigmaProtocol> {
+    type Proof;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        witness: &S::Witness,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError>;
+
+    fn verify<T: Transcript>(
+        parameters: &S::Parameters,
+        statement: &S::Statement,
+        proof: &Self::Proof,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError>;
+}
+
+/// Non-interactive argument of knowledge obtained by compiling the sigma protocol `S` with the transform `T`:
+/// [`FiatShamir`] by default, or [`Fischlin`] when the security proof needs a straight-line extractor.
+pub struct NonInteractive<S: SigmaProtocol, T: Transform<S> = FiatShamir> {
+    _protocol: PhantomData<(S, T)>,
+}
+
+impl<S: SigmaProtocol, X: Transform<S>> ArgumentOfKnowledge for NonInteractive<S, X> {
+    type CommonReferenceString = S::Parameters;
+    type Statement = S::Statement;
+    type Witness = S::Witness;
+    type Proof = X::Proof;
+
+    fn prove_in_context<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        X::prove(rng, common_reference_string, statement, witness, fs_rng)
+    }
+
+    fn verify_in_context<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        domain: &[u8],
+        context: Option<&[u8]>,
+        fs_rng: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_domain(fs_rng, domain, context)?;
+
+        X::verify(common_reference_string, statement, proof, fs_rng)
+    }
+}
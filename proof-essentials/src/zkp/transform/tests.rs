@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::zkp::compose::and_composition;
+    use crate::zkp::proofs::schnorr_identification;
+    use crate::zkp::transform::{fischlin, FiatShamir, Fischlin, NonInteractive};
+    use crate::zkp::ArgumentOfKnowledge;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use merlin::Transcript;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Schnorr = schnorr_identification::SchnorrIdentification<Curve>;
+    type SchnorrAnd = and_composition::AndComposition<Schnorr>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_fiat_shamir() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_key = Scalar::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = NonInteractive::<Schnorr>::prove(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            NonInteractive::<Schnorr, FiatShamir>::verify(
+                &generator,
+                &public_key,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            Ok(())
+        );
+
+        let other_key = Curve::rand(rng).into_affine();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            NonInteractive::<Schnorr>::verify(
+                &generator,
+                &other_key,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Fiat-Shamir Transform"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_fischlin() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_keys = vec![Scalar::rand(rng), Scalar::rand(rng)];
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| generator.mul(*sk).into_affine())
+            .collect::<Vec<_>>();
+
+        type Proof = NonInteractive<SchnorrAnd, Fischlin>;
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Proof::prove(
+            rng,
+            &generator,
+            &public_keys,
+            &secret_keys,
+            b"Test Domain",
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Proof::verify(
+                &generator,
+                &public_keys,
+                &proof,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        let mut serialized = vec![0; proof.serialized_size()];
+        proof.serialize(&mut serialized[..]).unwrap();
+        let deserialized = fischlin::Proof::<SchnorrAnd>::deserialize(&serialized[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Proof::verify(
+                &generator,
+                &public_keys,
+                &deserialized,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            Ok(())
+        );
+
+        // The responses do not answer a modified challenge
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Fischlin Transform",
+        )));
+        let mut tampered = deserialized;
+        tampered.challenges[0] ^= 1;
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Proof::verify(
+                &generator,
+                &public_keys,
+                &tampered,
+                b"Test Domain",
+                &mut fs_rng
+            ),
+            expected_error
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Proof::verify(
+                &generator,
+                &vec![public_keys[1], public_keys[0]],
+                &proof,
+                b"Test Domain",
+                &mut fs_rng,
+            ),
+            expected_error
+        );
+    }
+
+    #[test]
+    fn test_fiat_shamir_over_transcripts() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let secret_key = Scalar::rand(rng);
+        let public_key = generator.mul(secret_key).into_affine();
+        let other_key = Curve::rand(rng).into_affine();
+
+        let expected_error = Err(CryptoError::ProofVerificationError(String::from(
+            "Fiat-Shamir Transform",
+        )));
+
+        // A merlin transcript carrying messages from an outer protocol
+        let transcript = || {
+            let mut transcript = Transcript::new(b"Outer Protocol");
+            transcript.append_message(b"session", b"some session identifier");
+            transcript
+        };
+
+        let proof = FiatShamir::prove_with_transcript::<Schnorr, _, _>(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            &mut transcript(),
+        )
+        .unwrap();
+        assert_eq!(
+            FiatShamir::verify_with_transcript(&generator, &public_key, &proof, &mut transcript()),
+            Ok(())
+        );
+        assert_eq!(
+            FiatShamir::verify_with_transcript(&generator, &other_key, &proof, &mut transcript()),
+            expected_error
+        );
+
+        let mut other_session = Transcript::new(b"Outer Protocol");
+        other_session.append_message(b"session", b"another session identifier");
+        assert_eq!(
+            FiatShamir::verify_with_transcript(&generator, &public_key, &proof, &mut other_session),
+            expected_error
+        );
+
+        // The same driver over the crate's own Fiat-Shamir RNG
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = FiatShamir::prove_with_transcript::<Schnorr, _, _>(
+            rng,
+            &generator,
+            &public_key,
+            &secret_key,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            FiatShamir::verify_with_transcript(&generator, &public_key, &proof, &mut fs_rng),
+            Ok(())
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            FiatShamir::verify_with_transcript(&generator, &other_key, &proof, &mut fs_rng),
+            expected_error
+        );
+    }
+}